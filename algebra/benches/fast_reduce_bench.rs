@@ -1,4 +1,7 @@
-use algebra::reduce::{ReduceAdd, ReduceDouble, ReduceNeg, ReduceSub, TryReduceInv};
+use algebra::{
+    modulus::BarrettModulus,
+    reduce::{Reduce, ReduceAdd, ReduceDouble, ReduceNeg, ReduceSub, TryReduceInv},
+};
 use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion};
 use rand::{distributions::Uniform, thread_rng, Rng};
 
@@ -46,6 +49,40 @@ pub fn criterion_benchmark(c: &mut Criterion) {
             BatchSize::SmallInput,
         );
     });
+
+    let barrett = BarrettModulus::<u32>::new(modulus);
+
+    c.bench_function("barrett reduce 4 limbs slice", |b| {
+        b.iter_batched(
+            || rng.gen::<[u32; 4]>(),
+            |a| barrett.reduce(black_box(&a[..])),
+            BatchSize::SmallInput,
+        );
+    });
+
+    c.bench_function("barrett reduce 4 limbs array", |b| {
+        b.iter_batched(
+            || rng.gen::<[u32; 4]>(),
+            |a| barrett.reduce_limbs(black_box(a)),
+            BatchSize::SmallInput,
+        );
+    });
+
+    c.bench_function("barrett reduce 8 limbs slice", |b| {
+        b.iter_batched(
+            || rng.gen::<[u32; 8]>(),
+            |a| barrett.reduce(black_box(&a[..])),
+            BatchSize::SmallInput,
+        );
+    });
+
+    c.bench_function("barrett reduce 8 limbs array", |b| {
+        b.iter_batched(
+            || rng.gen::<[u32; 8]>(),
+            |a| barrett.reduce_limbs(black_box(a)),
+            BatchSize::SmallInput,
+        );
+    });
 }
 
 criterion_group!(benches, criterion_benchmark);
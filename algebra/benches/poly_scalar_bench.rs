@@ -0,0 +1,33 @@
+use algebra::modulus::ShoupFactor;
+use algebra::polynomial::FieldPolynomial;
+use algebra::{Field, U32FieldEval};
+use criterion::{criterion_group, criterion_main, Criterion};
+use rand::{distributions::Uniform, prelude::*};
+
+const N: usize = 4096;
+
+type F32 = U32FieldEval<132120577>;
+
+pub fn criterion_benchmark(c: &mut Criterion) {
+    let mut rng = thread_rng();
+
+    let mut poly = <FieldPolynomial<F32>>::random(N, &mut rng);
+
+    let scalar = rng.sample(Uniform::new(0, F32::MODULUS_VALUE));
+    let factor = ShoupFactor::new(scalar, F32::MODULUS_VALUE);
+
+    c.bench_function(&format!("field 32 poly mul scalar {}", N), |b| {
+        b.iter(|| {
+            poly.mul_scalar_assign(scalar);
+        })
+    });
+
+    c.bench_function(&format!("field 32 poly mul shoup scalar {}", N), |b| {
+        b.iter(|| {
+            poly.mul_shoup_scalar_assign(factor);
+        })
+    });
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
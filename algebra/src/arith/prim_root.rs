@@ -1,3 +1,5 @@
+use rand::{CryptoRng, Rng};
+
 use crate::AlgebraError;
 
 /// For ease of introduction we use `n` for `degree` and `p` for prime number.
@@ -19,8 +21,31 @@ pub trait PrimitiveRoot<T> {
     fn check_primitive_root(self, root: T, log_degree: u32) -> bool;
 
     /// Try to get a primitive `degree`-th root of unity reduce `p`.
+    ///
+    /// With `std`, the candidates are drawn from a fresh `StdRng`,
+    /// otherwise they are tried in increasing order.
     fn try_primitive_root(self, log_degree: u32) -> Result<T, AlgebraError>;
 
+    /// Try to get a primitive `degree`-th root of unity reduce `p`,
+    /// drawing the candidates from `rng`.
+    fn try_primitive_root_with_rng<R>(
+        self,
+        log_degree: u32,
+        rng: &mut R,
+    ) -> Result<T, AlgebraError>
+    where
+        R: Rng + CryptoRng;
+
     /// Try to get the minimal primitive `degree`-th root of unity reduce `p`.
     fn try_minimal_primitive_root(self, log_degree: u32) -> Result<T, AlgebraError>;
+
+    /// Try to get the minimal primitive `degree`-th root of unity reduce `p`,
+    /// drawing the candidates of the first root from `rng`.
+    fn try_minimal_primitive_root_with_rng<R>(
+        self,
+        log_degree: u32,
+        rng: &mut R,
+    ) -> Result<T, AlgebraError>
+    where
+        R: Rng + CryptoRng;
 }
@@ -0,0 +1,280 @@
+//! Counters of the number theory transforms and the modular multiplications.
+//!
+//! Every counter is process wide and starts disabled. While it's enabled,
+//! each operation of its kind adds to it, from every thread.
+//! Independently of the counters, a [`scope`] counts the operations
+//! of its own thread under a label.
+//!
+//! - [`ntt`] counts the forward transforms of the ntt tables.
+//! - [`inverse_ntt`] counts the inverse transforms of the ntt tables.
+//! - [`mul`] counts the modular multiplications of the field polynomials
+//!   in ntt form, one per value. The multiplications inside the transforms
+//!   are not counted.
+//!
+//! The counters are only public with the `count` feature,
+//! without it counting compiles to nothing.
+//!
+//! Crates above this one declare their own counters with the exported `counter!` macro,
+//! which also adds them to the [`Counts`] of the running scope under the name of the counter.
+
+/// Declares a counter module `$name`, with the counting driven
+/// by the `count` feature of the calling crate.
+///
+/// The scopes record the counter as `"$name"` in their [`Counts`](crate::count::Counts),
+/// so the names of the counters should be distinct across crates.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! counter {
+    ($(#[$doc:meta])* $name:ident) => {
+        $(#[$doc])*
+        #[cfg_attr(not(feature = "count"), allow(dead_code))]
+        pub mod $name {
+            #[cfg(feature = "count")]
+            use core::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+            #[cfg(feature = "count")]
+            static ENABLED: AtomicBool = AtomicBool::new(false);
+            #[cfg(feature = "count")]
+            static COUNT: AtomicUsize = AtomicUsize::new(0);
+
+            /// Starts counting.
+            #[cfg(feature = "count")]
+            #[inline]
+            pub fn enable() {
+                ENABLED.store(true, Ordering::Relaxed);
+            }
+
+            /// Stops counting, the current count is kept.
+            #[cfg(feature = "count")]
+            #[inline]
+            pub fn disable() {
+                ENABLED.store(false, Ordering::Relaxed);
+            }
+
+            /// Returns the current count.
+            #[cfg(feature = "count")]
+            #[inline]
+            pub fn get() -> usize {
+                COUNT.load(Ordering::Relaxed)
+            }
+
+            /// Resets the count to zero.
+            #[cfg(feature = "count")]
+            #[inline]
+            pub fn clear() {
+                COUNT.store(0, Ordering::Relaxed);
+            }
+
+            /// Records one operation if counting is enabled.
+            #[allow(dead_code)]
+            #[inline(always)]
+            pub(crate) fn increment() {
+                add(1);
+            }
+
+            /// Records `count` operations if counting is enabled.
+            #[inline(always)]
+            pub(crate) fn add(count: usize) {
+                #[cfg(feature = "count")]
+                {
+                    if ENABLED.load(Ordering::Relaxed) {
+                        COUNT.fetch_add(count, Ordering::Relaxed);
+                    }
+                    $crate::count::add_to_scope(stringify!($name), count);
+                }
+                #[cfg(not(feature = "count"))]
+                let _ = count;
+            }
+        }
+    };
+}
+
+counter!(
+    /// Counts the forward number theory transforms.
+    ntt
+);
+counter!(
+    /// Counts the inverse number theory transforms.
+    inverse_ntt
+);
+counter!(
+    /// Counts the modular multiplications of the field polynomials in ntt form.
+    mul
+);
+
+#[cfg(feature = "count")]
+#[doc(hidden)]
+pub use report::add_to_scope;
+#[cfg(feature = "count")]
+pub use report::{clear_report, report, scope, Counts, Report};
+
+#[cfg(feature = "count")]
+mod report {
+    use core::{cell::RefCell, fmt};
+    use std::sync::{Mutex, PoisonError};
+
+    use alloc::vec::Vec;
+
+    /// The counts of the operations recorded in a [`scope`], by the name of their counter.
+    ///
+    /// Only the counters which counted something are present, sorted by their names.
+    #[derive(Debug, Default, Clone, PartialEq, Eq)]
+    pub struct Counts {
+        counts: Vec<(&'static str, usize)>,
+    }
+
+    impl Counts {
+        /// Returns the count of the counter `name`, zero if it didn't count anything.
+        #[inline]
+        pub fn get(&self, name: &str) -> usize {
+            self.counts
+                .iter()
+                .find(|(n, _)| *n == name)
+                .map_or(0, |&(_, count)| count)
+        }
+
+        /// Returns an iterator over the names of the counters and their counts.
+        #[inline]
+        pub fn iter(&self) -> impl Iterator<Item = (&'static str, usize)> + '_ {
+            self.counts.iter().copied()
+        }
+
+        fn add(&mut self, name: &'static str, count: usize) {
+            if count == 0 {
+                return;
+            }
+            match self.counts.binary_search_by(|(n, _)| n.cmp(&name)) {
+                Ok(i) => self.counts[i].1 += count,
+                Err(i) => self.counts.insert(i, (name, count)),
+            }
+        }
+
+        fn accumulate(&mut self, other: &Self) {
+            other.iter().for_each(|(name, count)| self.add(name, count));
+        }
+    }
+
+    impl FromIterator<(&'static str, usize)> for Counts {
+        fn from_iter<I: IntoIterator<Item = (&'static str, usize)>>(iter: I) -> Self {
+            let mut counts = Counts::default();
+            iter.into_iter()
+                .for_each(|(name, count)| counts.add(name, count));
+            counts
+        }
+    }
+
+    /// The counts recorded by every label, in the order the labels were first used.
+    #[derive(Debug, Default, Clone, PartialEq, Eq)]
+    pub struct Report {
+        scopes: Vec<(&'static str, Counts)>,
+    }
+
+    impl Report {
+        /// Returns the counts recorded by `label`.
+        #[inline]
+        pub fn get(&self, label: &str) -> Option<&Counts> {
+            self.scopes
+                .iter()
+                .find(|(l, _)| *l == label)
+                .map(|(_, counts)| counts)
+        }
+
+        /// Returns an iterator over the labels and their counts.
+        #[inline]
+        pub fn iter(&self) -> impl Iterator<Item = (&'static str, &Counts)> + '_ {
+            self.scopes.iter().map(|(label, counts)| (*label, counts))
+        }
+
+        fn record(&mut self, label: &'static str, counts: Counts) {
+            match self.scopes.iter_mut().find(|(l, _)| *l == label) {
+                Some((_, total)) => total.accumulate(&counts),
+                None => self.scopes.push((label, counts)),
+            }
+        }
+    }
+
+    impl fmt::Display for Report {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            let width = self.scopes.iter().map(|(l, _)| l.len()).max().unwrap_or(0);
+            for (label, counts) in &self.scopes {
+                write!(f, "{label:<width$}")?;
+                for (name, count) in counts.iter() {
+                    write!(f, "  {name}: {count:>8}")?;
+                }
+                writeln!(f)?;
+            }
+            Ok(())
+        }
+    }
+
+    static REPORT: Mutex<Report> = Mutex::new(Report { scopes: Vec::new() });
+
+    std::thread_local! {
+        /// The counts of the innermost running scope of this thread.
+        static SCOPE_COUNTS: RefCell<Option<Counts>> = const { RefCell::new(None) };
+    }
+
+    /// Adds `count` to the counter `name` of the innermost running scope of this thread, if any.
+    #[inline]
+    pub fn add_to_scope(name: &'static str, count: usize) {
+        SCOPE_COUNTS.with(|scope| {
+            if let Some(counts) = scope.borrow_mut().as_mut() {
+                counts.add(name, count);
+            }
+        });
+    }
+
+    /// Restores the outer scope on drop, also when the scope panics.
+    struct ScopeGuard {
+        label: &'static str,
+        outer: Option<Counts>,
+    }
+
+    impl Drop for ScopeGuard {
+        fn drop(&mut self) {
+            let counts = SCOPE_COUNTS
+                .with(|scope| scope.replace(self.outer.take()))
+                .unwrap_or_default();
+
+            SCOPE_COUNTS.with(|scope| {
+                if let Some(outer) = scope.borrow_mut().as_mut() {
+                    outer.accumulate(&counts);
+                }
+            });
+
+            REPORT
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .record(self.label, counts);
+        }
+    }
+
+    /// Runs `f` and adds the operations counted meanwhile to `label` in the [`report`].
+    ///
+    /// Only the operations of the calling thread are counted, the ones moved
+    /// to other threads by `f`, e.g. with rayon, are not. The counts of nested
+    /// scopes are also added to the outer scopes. Scopes count even if the
+    /// counters are disabled.
+    pub fn scope<R>(label: &'static str, f: impl FnOnce() -> R) -> R {
+        let outer = SCOPE_COUNTS.with(|scope| scope.replace(Some(Counts::default())));
+        let _guard = ScopeGuard { label, outer };
+        f()
+    }
+
+    /// Returns the counts recorded by the scopes since the last [`clear_report`].
+    pub fn report() -> Report {
+        REPORT
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    /// Removes every label from the [`report`].
+    pub fn clear_report() {
+        REPORT
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .scopes
+            .clear();
+    }
+}
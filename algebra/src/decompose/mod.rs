@@ -5,6 +5,7 @@ mod non_pow_of_2;
 mod pow_of_2;
 
 pub use non_pow_of_2::{
-    NonPowOf2ApproxSignedBasis, ScalarIter, SignedDecomposeIter, SignedOnceDecompose,
+    DecomposeDigits, NonPowOf2ApproxSignedBasis, ScalarIter, SignedDecomposeIter,
+    SignedOnceDecompose,
 };
 pub use pow_of_2::PowOf2ApproxSignedBasis;
@@ -1,3 +1,5 @@
+use alloc::{vec, vec::Vec};
+
 use itertools::izip;
 use num_traits::ConstOne;
 
@@ -189,6 +191,48 @@ impl<T: UnsignedInteger> NonPowOf2ApproxSignedBasis<T> {
         )
     }
 
+    /// Decomposes `value` into signed digits, least significant digit first.
+    #[inline]
+    pub fn decompose(&self, value: T) -> Vec<T> {
+        let mut digits = vec![T::ZERO; self.decompose_length];
+        self.decompose_inplace(value, &mut digits);
+        digits
+    }
+
+    /// Decomposes `value` into signed digits, least significant digit first,
+    /// and stores them into the first `decompose_length` elements of `digits`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `digits` is less than `decompose_length`.
+    #[inline]
+    pub fn decompose_inplace(&self, value: T, digits: &mut [T]) {
+        assert!(
+            digits.len() >= self.decompose_length,
+            "digits buffer length {} is less than decompose length {}",
+            digits.len(),
+            self.decompose_length
+        );
+
+        let (adjust_value, mut carry) = self.init_value_carry(value);
+        self.decompose_iter()
+            .zip(digits)
+            .for_each(|(once_decompose, digit)| {
+                once_decompose.decompose_inplace(adjust_value, &mut carry, digit)
+            });
+    }
+
+    /// Returns an iterator over the signed digits of `value`, least significant digit first.
+    #[inline]
+    pub fn decompose_lsb_first(&self, value: T) -> DecomposeDigits<T> {
+        let (value, carry) = self.init_value_carry(value);
+        DecomposeDigits {
+            value,
+            carry,
+            iter: self.decompose_iter(),
+        }
+    }
+
     /// Init carry and adjusted value for a value.
     #[inline]
     pub fn init_value_carry(&self, value: T) -> (T, bool) {
@@ -298,6 +342,25 @@ impl<T: UnsignedInteger> Iterator for SignedDecomposeIter<T> {
     }
 }
 
+/// An iterator over the signed digits of a value, least significant digit first.
+pub struct DecomposeDigits<T: UnsignedInteger> {
+    value: T,
+    carry: bool,
+    iter: SignedDecomposeIter<T>,
+}
+
+impl<T: UnsignedInteger> Iterator for DecomposeDigits<T> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let once_decompose = self.iter.next()?;
+        let (digit, carry) = once_decompose.decompose(self.value, self.carry);
+        self.carry = carry;
+        Some(digit)
+    }
+}
+
 /// The signed decomposition operator which can execute once decomposition.
 pub struct SignedOnceDecompose<T: UnsignedInteger> {
     value_chunk_mask: T,
@@ -566,6 +629,45 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_decompose_inplace() {
+        let mut rng = rand::thread_rng();
+        let modulus_value: ValueT = rng.gen_range(512..(1 << 30)) | 1;
+        let distr = Uniform::new(0, modulus_value);
+
+        for log_basis in [1, 3, 4, 7] {
+            let basis = NonPowOf2ApproxSignedBasis::new(modulus_value, log_basis, None);
+            let len = basis.decompose_length();
+
+            let mut buffer = vec![0; len + 2];
+            for value in (&mut rng).sample_iter(distr).take(100) {
+                let (value_d, mut carry) = basis.init_value_carry(value);
+                let expect: Vec<ValueT> = basis
+                    .decompose_iter()
+                    .map(|once_decompose| {
+                        let (d, c) = once_decompose.decompose(value_d, carry);
+                        carry = c;
+                        d
+                    })
+                    .collect();
+
+                assert_eq!(basis.decompose(value), expect);
+                assert_eq!(basis.decompose_lsb_first(value).collect::<Vec<_>>(), expect);
+
+                basis.decompose_inplace(value, &mut buffer);
+                assert_eq!(&buffer[..len], expect.as_slice());
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_decompose_inplace_short_buffer() {
+        let basis = NonPowOf2ApproxSignedBasis::<ValueT>::new(132120577, 4, None);
+        let mut buffer = vec![0; basis.decompose_length() - 1];
+        basis.decompose_inplace(12345, &mut buffer);
+    }
+
     #[test]
     fn test_decompose_slice() {
         const N: usize = 32;
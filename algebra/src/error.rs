@@ -1,7 +1,8 @@
 //! This module defines some errors that
 //! may occur during the execution of the library.
 
-use std::fmt::Debug;
+use alloc::boxed::Box;
+use core::fmt::Debug;
 
 use thiserror::Error;
 
@@ -49,4 +50,28 @@ pub enum AlgebraError {
         /// modulus
         modulus: Box<dyn Debug>,
     },
+    /// Error that occurs when the modulus value is 0 or 1.
+    #[error("Modulus {modulus:?} should be greater than 1!")]
+    ModulusTooSmall {
+        /// The modulus.
+        modulus: Box<dyn Debug>,
+    },
+    /// Error that occurs when the modulus value leaves less than 2 bits of padding.
+    #[error("Modulus {modulus:?} has {bits} bits, more than the {max_bits} bits supported!")]
+    ModulusTooManyBits {
+        /// The modulus.
+        modulus: Box<dyn Debug>,
+        /// The bit count of the modulus.
+        bits: u32,
+        /// The maximum bit count supported by the value type.
+        max_bits: u32,
+    },
+    /// Error that occurs when a modulus does not divide the target modulus.
+    #[error("Modulus {modulus:?} does not divide {target:?}!")]
+    ModulusNotDivisor {
+        /// The modulus.
+        modulus: Box<dyn Debug>,
+        /// The target modulus.
+        target: Box<dyn Debug>,
+    },
 }
@@ -1,4 +1,9 @@
-use crate::integer::UnsignedInteger;
+use alloc::vec::Vec;
+
+use subtle::{Choice, ConditionallySelectable};
+
+use crate::integer::{AsFrom, AsInto, UnsignedInteger};
+use crate::modulus::ShoupFactor;
 use crate::numeric::Numeric;
 use crate::reduce::*;
 
@@ -6,10 +11,12 @@ use crate::reduce::*;
 mod macros;
 mod impls;
 mod ntt;
+mod scalar;
 
 pub use impls::f32::U32FieldEval;
 pub use impls::f64::U64FieldEval;
 pub use ntt::NttField;
+pub use scalar::ScalarFactor;
 
 /// An abstract for field evaluator.
 pub trait Field: Sized + Clone + Copy {
@@ -38,6 +45,15 @@ pub trait Field: Sized + Clone + Copy {
         Self::MODULUS
     }
 
+    /// Returns `a` if `choice` is `0`, or `b` if `choice` is `1`.
+    ///
+    /// The selection is done by masking without branching on `choice`,
+    /// so it can be used when `choice` depends on secret data.
+    #[inline]
+    fn conditional_select(a: Self::ValueT, b: Self::ValueT, choice: Choice) -> Self::ValueT {
+        <Self::ValueT as ConditionallySelectable>::conditional_select(&a, &b, choice)
+    }
+
     /// Calculates `a + b`.
     #[inline]
     fn add(a: Self::ValueT, b: Self::ValueT) -> Self::ValueT {
@@ -98,6 +114,28 @@ pub trait Field: Sized + Clone + Copy {
         Self::MODULUS.reduce_mul_assign(a, b);
     }
 
+    /// Calculates `value^2`.
+    #[inline]
+    fn square(value: Self::ValueT) -> Self::ValueT {
+        Self::MODULUS.reduce_square(value)
+    }
+
+    /// Calculates `value = value^2`.
+    #[inline]
+    fn square_assign(value: &mut Self::ValueT) {
+        Self::MODULUS.reduce_square_assign(value);
+    }
+
+    /// Calculates `a * scalar`, where `scalar` may exceed the modulus.
+    ///
+    /// The `scalar` is reduced once, then multiplied by [`ShoupFactor`].
+    #[inline]
+    fn mul_scalar_u64(a: Self::ValueT, scalar: u64) -> Self::ValueT {
+        let modulus: u64 = Self::MODULUS_VALUE.as_into();
+        let scalar = ShoupFactor::new(Self::ValueT::as_from(scalar % modulus), Self::MODULUS_VALUE);
+        Self::MODULUS_VALUE.reduce_mul(a, scalar)
+    }
+
     /// Calculates `(a * b) + c`.
     #[inline]
     fn mul_add(a: Self::ValueT, b: Self::ValueT, c: Self::ValueT) -> Self::ValueT {
@@ -116,6 +154,19 @@ pub trait Field: Sized + Clone + Copy {
         Self::MODULUS.reduce_exp(base, exp)
     }
 
+    /// Returns the successive powers `[base^0, base^1, ..., base^max_exp]`,
+    /// spending a single multiplication per power.
+    fn pow_table(base: Self::ValueT, max_exp: usize) -> Vec<Self::ValueT> {
+        let mut powers = Vec::with_capacity(max_exp + 1);
+        let mut power = Self::ONE;
+        powers.push(power);
+        for _ in 0..max_exp {
+            Self::mul_assign(&mut power, base);
+            powers.push(power);
+        }
+        powers
+    }
+
     /// Calculates `base^(2^exp_log)`.
     #[inline]
     fn exp_power_of_2(base: Self::ValueT, exp_log: u32) -> Self::ValueT {
@@ -128,6 +179,18 @@ pub trait Field: Sized + Clone + Copy {
         Self::MODULUS.reduce_dot_product(a, b)
     }
 
+    /// Calculates `∑values`, which is [`Self::ZERO`] for no values.
+    #[inline]
+    fn sum(values: impl IntoIterator<Item = Self::ValueT>) -> Self::ValueT {
+        values.into_iter().fold(Self::ZERO, Self::add)
+    }
+
+    /// Calculates `∏values`, which is [`Self::ONE`] for no values.
+    #[inline]
+    fn product(values: impl IntoIterator<Item = Self::ValueT>) -> Self::ValueT {
+        values.into_iter().fold(Self::ONE, Self::mul)
+    }
+
     /// Calculate the multiplicative inverse of `value`.
     #[inline]
     fn inv(value: Self::ValueT) -> Self::ValueT {
@@ -1,5 +1,5 @@
 use crate::{
-    ntt::{NttTable, NumberTheoryTransform},
+    ntt::{NttBackend, NttTable, NumberTheoryTransform},
     polynomial::{FieldNttPolynomial, FieldPolynomial},
 };
 
@@ -8,9 +8,185 @@ use super::Field;
 /// Extended the [Field] to [NttField], enables fast polynomial multiplication.
 pub trait NttField: Field {
     /// An abstraction over the data structure used to store precomputed values for NTT.
+    ///
+    /// The table is a [`NttBackend`] as well, so that it can be handed out as
+    /// `dyn NttBackend<Self>` with the `dyn-ntt` feature, and so that the gadget
+    /// products can call the [`NttBackend::transform_and_pointwise_mul`] of the table.
+    /// A blanket implementation over [`NumberTheoryTransform`] would rule out the
+    /// fused override of [`crate::ntt::FieldTableWithShoupRoot`], so tables from
+    /// other crates implement [`NttBackend`] themselves, by forwarding its
+    /// methods to their slice transforms.
     type Table: NttTable<ValueT = Self::ValueT>
-        + NumberTheoryTransform<CoeffPoly = FieldPolynomial<Self>, NttPoly = FieldNttPolynomial<Self>>;
+        + NumberTheoryTransform<CoeffPoly = FieldPolynomial<Self>, NttPoly = FieldNttPolynomial<Self>>
+        + NttBackend<Self>;
 
     /// Generate the ntt table of the ntt field with desired `log_n`.
     fn generate_ntt_table(log_n: u32) -> Result<Self::Table, crate::AlgebraError>;
+
+    /// Returns the ntt table of the ntt field with desired `log_n`.
+    ///
+    /// The table is generated on the first call for this field and `log_n`,
+    /// later calls share the same table from a process wide cache.
+    #[cfg(all(feature = "std", not(feature = "dyn-ntt")))]
+    #[inline]
+    fn get_ntt_table(log_n: u32) -> Result<std::sync::Arc<Self::Table>, crate::AlgebraError>
+    where
+        Self: 'static,
+        Self::Table: 'static,
+    {
+        cache::get_or_generate::<Self>(log_n)
+    }
+
+    /// Returns the ntt table of the ntt field with desired `log_n`.
+    ///
+    /// The table is generated on the first call for this field and `log_n`,
+    /// later calls share the same table from a process wide cache.
+    ///
+    /// With the `dyn-ntt` feature the table is returned as a [`NttBackend`]
+    /// trait object, at the cost of a virtual call per transform.
+    #[cfg(feature = "dyn-ntt")]
+    #[inline]
+    fn get_ntt_table(
+        log_n: u32,
+    ) -> Result<std::sync::Arc<dyn NttBackend<Self>>, crate::AlgebraError>
+    where
+        Self: 'static,
+        Self::Table: 'static,
+    {
+        let table: std::sync::Arc<dyn NttBackend<Self>> = cache::get_or_generate::<Self>(log_n)?;
+        Ok(table)
+    }
+
+    /// Generates the ntt tables of this field for all the `log_ns` into the cache
+    /// of [`NttField::get_ntt_table`], so later calls don't generate them.
+    ///
+    /// The tables missing from the cache are generated concurrently, on the rayon
+    /// thread pool with the `parallel` feature or on scoped threads otherwise.
+    /// They are inserted in the order of `log_ns` afterwards, and the tables already
+    /// cached, including the ones another thread inserted meanwhile, are kept.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error of [`NttField::generate_ntt_table`] in the order of `log_ns`,
+    /// nothing is inserted then.
+    #[cfg(feature = "std")]
+    #[inline]
+    fn init_ntt_tables(log_ns: &[u32]) -> Result<(), crate::AlgebraError>
+    where
+        Self: 'static,
+        Self::Table: 'static,
+    {
+        cache::init::<Self>(log_ns)
+    }
+}
+
+#[cfg(feature = "std")]
+mod cache {
+    use std::{
+        any::{Any, TypeId},
+        collections::HashMap,
+        sync::{Arc, Mutex, MutexGuard, OnceLock, PoisonError},
+    };
+
+    use super::NttField;
+
+    type TableMap = HashMap<(TypeId, u32), Arc<dyn Any + Send + Sync>>;
+
+    static NTT_TABLES: OnceLock<Mutex<TableMap>> = OnceLock::new();
+
+    #[inline]
+    fn tables() -> MutexGuard<'static, TableMap> {
+        NTT_TABLES
+            .get_or_init(Default::default)
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+
+    pub(super) fn get_or_generate<F>(log_n: u32) -> Result<Arc<F::Table>, crate::AlgebraError>
+    where
+        F: NttField + 'static,
+        F::Table: 'static,
+    {
+        let key = (TypeId::of::<F>(), log_n);
+
+        let cached = tables().get(&key).cloned();
+
+        let table = match cached {
+            Some(table) => table,
+            None => {
+                // The lock isn't held while generating, so two threads may both
+                // generate the table, but only the first one is kept.
+                let table: Arc<dyn Any + Send + Sync> = Arc::new(F::generate_ntt_table(log_n)?);
+                tables().entry(key).or_insert(table).clone()
+            }
+        };
+
+        Ok(table
+            .downcast::<F::Table>()
+            .expect("the cached ntt table has the type of its field"))
+    }
+
+    pub(super) fn init<F>(log_ns: &[u32]) -> Result<(), crate::AlgebraError>
+    where
+        F: NttField + 'static,
+        F::Table: 'static,
+    {
+        let type_id = TypeId::of::<F>();
+
+        let mut missing: Vec<u32> = Vec::with_capacity(log_ns.len());
+        {
+            let tables = tables();
+            for &log_n in log_ns {
+                if !missing.contains(&log_n) && !tables.contains_key(&(type_id, log_n)) {
+                    missing.push(log_n);
+                }
+            }
+        }
+
+        let generated = generate_all::<F>(&missing);
+
+        // `AlgebraError` isn't `Send`, so the threads only report the failed tables,
+        // and the first of them is generated again here for its error.
+        let generated = missing
+            .iter()
+            .zip(generated)
+            .map(|(&log_n, table)| match table {
+                Some(table) => Ok(table),
+                None => F::generate_ntt_table(log_n),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut tables = tables();
+        for (log_n, table) in missing.into_iter().zip(generated) {
+            let table: Arc<dyn Any + Send + Sync> = Arc::new(table);
+            tables.entry((type_id, log_n)).or_insert(table);
+        }
+
+        Ok(())
+    }
+
+    #[cfg(feature = "parallel")]
+    fn generate_all<F: NttField>(log_ns: &[u32]) -> Vec<Option<F::Table>> {
+        use rayon::prelude::*;
+
+        log_ns
+            .par_iter()
+            .map(|&log_n| F::generate_ntt_table(log_n).ok())
+            .collect()
+    }
+
+    #[cfg(not(feature = "parallel"))]
+    fn generate_all<F: NttField>(log_ns: &[u32]) -> Vec<Option<F::Table>> {
+        std::thread::scope(|s| {
+            let handles: Vec<_> = log_ns
+                .iter()
+                .map(|&log_n| s.spawn(move || F::generate_ntt_table(log_n).ok()))
+                .collect();
+
+            handles
+                .into_iter()
+                .map(|handle| handle.join().expect("ntt table generation panicked"))
+                .collect()
+        })
+    }
 }
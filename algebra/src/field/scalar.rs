@@ -0,0 +1,57 @@
+use core::marker::PhantomData;
+
+use crate::modulus::ShoupFactor;
+
+use super::Field;
+
+/// A field element with precomputed [`ShoupFactor`].
+///
+/// This is efficient if many field elements are multiplied by
+/// the same scalar, e.g. the encoding constant `Δ`.
+pub struct ScalarFactor<F: Field> {
+    factor: ShoupFactor<<F as Field>::ValueT>,
+    _marker: PhantomData<fn() -> F>,
+}
+
+impl<F: Field> Clone for ScalarFactor<F> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<F: Field> Copy for ScalarFactor<F> {}
+
+impl<F: Field> core::fmt::Debug for ScalarFactor<F> {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ScalarFactor")
+            .field("factor", &self.factor)
+            .finish()
+    }
+}
+
+impl<F: Field> ScalarFactor<F> {
+    /// Creates a new [`ScalarFactor<F>`].
+    ///
+    /// * `value` must be less than the field modulus.
+    #[inline]
+    pub fn new(value: <F as Field>::ValueT) -> Self {
+        Self {
+            factor: ShoupFactor::new(value, F::MODULUS_VALUE),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns the value of this [`ScalarFactor<F>`].
+    #[inline]
+    pub fn value(&self) -> <F as Field>::ValueT {
+        self.factor.value()
+    }
+
+    /// Returns the inner [`ShoupFactor`] of this [`ScalarFactor<F>`].
+    #[inline]
+    pub fn factor(&self) -> ShoupFactor<<F as Field>::ValueT> {
+        self.factor
+    }
+}
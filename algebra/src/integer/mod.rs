@@ -8,11 +8,12 @@ mod overflowing;
 mod two;
 mod wrapping;
 
+use core::ops::BitXorAssign;
 use core::{
     fmt::{Debug, Display},
+    hash::Hash,
     ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, Not, Shl, ShlAssign, Shr, ShrAssign},
 };
-use std::ops::BitXorAssign;
 
 use num_traits::{ConstOne, ConstZero, MulAdd, MulAddAssign, NumAssign, Pow, Unsigned};
 use rand::distributions::uniform::SampleUniform;
@@ -41,6 +42,7 @@ pub trait Integer:
     + Ord
     + PartialEq
     + Eq
+    + Hash
     + Debug
     + Display
     + Bits
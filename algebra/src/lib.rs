@@ -1,8 +1,17 @@
 #![cfg_attr(docsrs, feature(doc_auto_cfg))]
 #![deny(missing_docs)]
 #![cfg_attr(feature = "nightly", feature(bigint_helper_methods))]
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 
 //! Basic algebra abstract and some operations for it.
+//!
+//! The crate is `no_std` compatible with the default `std` feature disabled,
+//! it only requires `alloc` then.
+//! Nothing is cached globally without `std`, the NTT tables are passed explicitly then.
+//! With `std`, `NttField::get_ntt_table` also keeps a process wide table cache,
+//! and with `dyn-ntt` it hands the tables out as `dyn NttBackend` objects.
+
+extern crate alloc;
 
 mod error;
 
@@ -23,6 +32,14 @@ pub mod polynomial;
 
 pub mod utils;
 
+#[cfg(feature = "count")]
+pub mod count;
+#[cfg(not(feature = "count"))]
+mod count;
+
+#[cfg(feature = "test-utils")]
+pub mod test_utils;
+
 pub use error::AlgebraError;
 
 pub use field::*;
@@ -10,6 +10,7 @@ macro_rules! impl_barrett_modulus {
             /// The `value` must be greater than 1. It is crucial to reserve 2 bits of padding
             /// for future calculations to prevent overflow errors that could occur
             /// if the primitive data type does not have sufficient space to accommodate the computation.
+            /// [`BarrettModulus::try_new`] returns an error for these values instead.
             #[must_use]
             pub const fn new(value: $ValueT) -> Self {
                 const HALF_BITS: u32 = <$ValueT>::BITS >> 1;
@@ -80,3 +81,24 @@ macro_rules! impl_barrett_modulus {
         }
     };
 }
+
+macro_rules! impl_barrett_signed_reduce {
+    (impl Reduce<$SignedT:ty> for BarrettModulus<$ValueT:ty>) => {
+        impl $crate::reduce::Reduce<$SignedT> for BarrettModulus<$ValueT> {
+            type Output = $ValueT;
+
+            /// Calculates `value (mod modulus)`,
+            /// negative values are mapped into `[0, modulus)`.
+            #[inline]
+            fn reduce(self, value: $SignedT) -> Self::Output {
+                let r =
+                    <Self as $crate::reduce::Reduce<$ValueT>>::reduce(self, value.unsigned_abs());
+                if value < 0 && r != 0 {
+                    self.value - r
+                } else {
+                    r
+                }
+            }
+        }
+    };
+}
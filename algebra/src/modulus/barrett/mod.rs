@@ -1,9 +1,11 @@
-use std::fmt::Display;
+use alloc::boxed::Box;
+use core::fmt::Display;
 
 use crate::{
     integer::{AsFrom, AsInto},
     numeric::Numeric,
-    reduce::{Modulus, ModulusValue},
+    reduce::{LazyReduce, LazyReduceMul, Modulus, ModulusValue},
+    AlgebraError,
 };
 
 #[macro_use]
@@ -27,7 +29,7 @@ pub struct BarrettModulus<T: Numeric> {
 
 impl<T: Numeric> Display for BarrettModulus<T> {
     #[inline]
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "{}", self.value)
     }
 }
@@ -49,6 +51,33 @@ impl<T: Numeric> BarrettModulus<T> {
         }
     }
 
+    /// Creates a new [`BarrettModulus<T>`] with the given value,
+    /// returning an error instead of panicking for an invalid value.
+    ///
+    /// # Errors
+    ///
+    /// - [`AlgebraError::ModulusTooSmall`] if `value` is 0 or 1.
+    /// - [`AlgebraError::ModulusTooManyBits`] if `value` doesn't leave 2 bits of padding.
+    pub fn try_new(value: T) -> Result<Self, AlgebraError> {
+        if value <= T::ONE {
+            return Err(AlgebraError::ModulusTooSmall {
+                modulus: Box::new(value),
+            });
+        }
+
+        let bits = T::BITS - value.leading_zeros();
+        let max_bits = T::BITS - 2;
+        if bits > max_bits {
+            return Err(AlgebraError::ModulusTooManyBits {
+                modulus: Box::new(value),
+                bits,
+                max_bits,
+            });
+        }
+
+        Ok(Self::new_generic(value))
+    }
+
     /// Returns the value of this [`BarrettModulus<T>`].
     #[inline]
     pub const fn value(&self) -> T {
@@ -60,6 +89,54 @@ impl<T: Numeric> BarrettModulus<T> {
     pub const fn ratio(&self) -> [T; 2] {
         self.ratio
     }
+
+    /// Calculates `value (mod 2*modulus)` for a fixed number of limbs,
+    /// where `value` is `∑value[i]*b^i`, the least significant limb first.
+    ///
+    /// Unlike the [`LazyReduce`] fold over a slice, which runs a barrett
+    /// reduction for every limb, this takes a single pass over the limbs:
+    /// every `value[i]` is multiplied by `b^i (mod 2*modulus)` and the products
+    /// are summed in three limbs without reduction. Only the sum is reduced.
+    ///
+    /// The result is congruent to the one of the fold, but both are only
+    /// reduced to `[0, 2*modulus)`, so they may differ by `modulus`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is 0.
+    #[inline]
+    pub fn lazy_reduce_limbs<const N: usize>(self, value: [T; N]) -> T {
+        assert!(N > 0, "At least one limb is required!");
+
+        // `ratio[1]` is `⌊b/modulus⌋`, so this is `b (mod modulus)`.
+        let radix = T::ZERO.wrapping_sub(self.ratio[1].wrapping_mul(self.value));
+
+        // Every product is less than `2*modulus*b`, so `N` of them
+        // never overflow the top limb.
+        let mut sum = [T::ZERO; 3];
+        let mut power = T::ONE;
+        for (i, limb) in value.into_iter().enumerate() {
+            if i > 0 {
+                power = self.lazy_reduce_mul(power, radix);
+            }
+            let (lo, hi) = limb.carrying_mul(power, sum[0]);
+            let (mid, carry) = sum[1].overflowing_add(hi);
+            sum = [lo, mid, sum[2] + T::as_from(carry as u8)];
+        }
+
+        self.lazy_reduce(&sum[..])
+    }
+
+    /// Calculates `value (mod modulus)` for a fixed number of limbs,
+    /// where `value` is `∑value[i]*b^i`, the least significant limb first.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is 0.
+    #[inline]
+    pub fn reduce_limbs<const N: usize>(self, value: [T; N]) -> T {
+        self.value.reduce_once(self.lazy_reduce_limbs(value))
+    }
 }
 
 impl<T: Numeric> Modulus<T> for BarrettModulus<T> {
@@ -89,6 +166,9 @@ impl_barrett_modulus!(impl BarrettModulus<u16>; WideType: u32);
 impl_barrett_modulus!(impl BarrettModulus<u32>; WideType: u64);
 impl_barrett_modulus!(impl BarrettModulus<u64>; WideType: u128);
 
+impl_barrett_signed_reduce!(impl Reduce<i32> for BarrettModulus<u32>);
+impl_barrett_signed_reduce!(impl Reduce<i64> for BarrettModulus<u64>);
+
 #[inline]
 fn div_rem<T: Numeric>(numerator: T, divisor: T) -> (T, T) {
     (numerator / divisor, numerator % divisor)
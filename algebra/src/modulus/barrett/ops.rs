@@ -480,16 +480,28 @@ impl<T: Numeric> ReduceDotProduct<T> for BarrettModulus<T> {
 
         debug_assert_eq!(a.len(), b.len());
 
-        a.chunks_exact(16)
-            .zip(b.chunks_exact(16))
-            .map(|(a_s, b_s)| {
-                let mut c: [T; 2] = [T::ZERO, T::ZERO];
-                for (&a, &b) in a_s.iter().zip(b_s) {
-                    multiply_add(&mut c, a, b);
-                }
-                self.reduce(c)
+        let a_chunks = a.chunks_exact(16);
+        let b_chunks = b.chunks_exact(16);
+
+        // The tail shorter than a chunk must be accumulated too,
+        // otherwise dimensions that aren't a multiple of 16 lose their last terms.
+        let a_remainder = a_chunks.remainder();
+        let b_remainder = b_chunks.remainder();
+
+        let dot_product_once = |a_s: &[T], b_s: &[T]| {
+            let mut c: [T; 2] = [T::ZERO, T::ZERO];
+            for (&a, &b) in a_s.iter().zip(b_s) {
+                multiply_add(&mut c, a, b);
+            }
+            self.reduce(c)
+        };
+
+        a_chunks
+            .zip(b_chunks)
+            .map(|(a_s, b_s)| dot_product_once(a_s, b_s))
+            .fold(dot_product_once(a_remainder, b_remainder), |acc: T, b| {
+                self.value.reduce_add(acc, b)
             })
-            .fold(T::ZERO, |acc: T, b| self.value.reduce_add(acc, b))
     }
 }
 
@@ -568,4 +580,41 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_signed_reduce() {
+        let mut rng = thread_rng();
+
+        let m32: u32 = rng.gen_range(2..(1 << 30));
+        let modulus = BarrettModulus::<u32>::new(m32);
+        assert_eq!(modulus.reduce(-1i32), m32 - 1);
+        assert_eq!(
+            modulus.reduce(i32::MIN),
+            (i32::MIN as i64).rem_euclid(m32 as i64) as u32
+        );
+        for _ in 0..100 {
+            let v: i32 = rng.gen_range(-(m32 as i32) * 2..=m32 as i32);
+            let mut expected = v;
+            while expected < 0 {
+                expected += m32 as i32;
+            }
+            assert_eq!(modulus.reduce(v), modulus.reduce(expected as u32));
+        }
+
+        let m64: u64 = rng.gen_range(2..(1 << 62));
+        let modulus = BarrettModulus::<u64>::new(m64);
+        assert_eq!(modulus.reduce(-1i64), m64 - 1);
+        assert_eq!(
+            modulus.reduce(i64::MIN),
+            (i64::MIN as i128).rem_euclid(m64 as i128) as u64
+        );
+        for _ in 0..100 {
+            let v: i64 = rng.gen_range(-(m64 as i64)..=m64 as i64);
+            let mut expected = v;
+            while expected < 0 {
+                expected += m64 as i64;
+            }
+            assert_eq!(modulus.reduce(v), modulus.reduce(expected as u64));
+        }
+    }
 }
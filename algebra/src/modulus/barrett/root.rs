@@ -1,4 +1,9 @@
-use rand::{distributions::Uniform, prelude::Distribution};
+use alloc::boxed::Box;
+
+use rand::{
+    distributions::{Distribution, Uniform},
+    CryptoRng, Rng,
+};
 
 use crate::arith::PrimitiveRoot;
 use crate::modulus::ShoupFactor;
@@ -26,6 +31,63 @@ impl<T: Numeric> PrimitiveRoot<T> for BarrettModulus<T> {
 
     #[inline]
     fn try_primitive_root(self, log_degree: u32) -> Result<T, AlgebraError> {
+        #[cfg(feature = "std")]
+        {
+            use rand::{rngs::StdRng, SeedableRng};
+
+            self.try_primitive_root_with_rng(log_degree, &mut StdRng::from_entropy())
+        }
+        // Without `std` there is no entropy source,
+        // so the candidates are tried in increasing order.
+        #[cfg(not(feature = "std"))]
+        {
+            let modulus_minus_one = self.modulus_minus_one();
+            let candidates = core::iter::successors(Some(T::ONE + T::ONE), |&r| Some(r + T::ONE))
+                .take_while(move |&r| r <= modulus_minus_one);
+            self.primitive_root_from_candidates(log_degree, candidates)
+        }
+    }
+
+    #[inline]
+    fn try_primitive_root_with_rng<R>(self, log_degree: u32, rng: &mut R) -> Result<T, AlgebraError>
+    where
+        R: Rng + CryptoRng,
+    {
+        let distr = Uniform::new_inclusive(T::ONE + T::ONE, self.modulus_minus_one());
+        self.primitive_root_from_candidates(log_degree, distr.sample_iter(rng))
+    }
+
+    #[inline]
+    fn try_minimal_primitive_root(self, log_degree: u32) -> Result<T, AlgebraError> {
+        let root = self.try_primitive_root(log_degree)?;
+        Ok(self.minimal_primitive_root(root, log_degree))
+    }
+
+    #[inline]
+    fn try_minimal_primitive_root_with_rng<R>(
+        self,
+        log_degree: u32,
+        rng: &mut R,
+    ) -> Result<T, AlgebraError>
+    where
+        R: Rng + CryptoRng,
+    {
+        let root = self.try_primitive_root_with_rng(log_degree, rng)?;
+        Ok(self.minimal_primitive_root(root, log_degree))
+    }
+}
+
+impl<T: Numeric> BarrettModulus<T> {
+    /// Tries the first 100 `candidates` for a generator `g`,
+    /// whose power `g^((p-1)/n)` is a primitive `n`-th root.
+    fn primitive_root_from_candidates<I>(
+        self,
+        log_degree: u32,
+        candidates: I,
+    ) -> Result<T, AlgebraError>
+    where
+        I: Iterator<Item = T>,
+    {
         assert!(log_degree < T::BITS);
 
         // p-1
@@ -43,13 +105,9 @@ impl<T: Numeric> PrimitiveRoot<T> for BarrettModulus<T> {
             });
         }
 
-        let mut rng = rand::thread_rng();
-        let distr = Uniform::new_inclusive(T::ONE + T::ONE, modulus_minus_one);
-
         let mut w = T::ZERO;
 
-        if (0..100).any(|_| {
-            let r = distr.sample(&mut rng);
+        if candidates.take(100).any(|r| {
             w = self.reduce_exp(r, quotient);
             self.check_primitive_root(w, log_degree)
         }) {
@@ -62,10 +120,8 @@ impl<T: Numeric> PrimitiveRoot<T> for BarrettModulus<T> {
         }
     }
 
-    #[inline]
-    fn try_minimal_primitive_root(self, log_degree: u32) -> Result<T, AlgebraError> {
-        let mut root = self.try_primitive_root(log_degree)?;
-
+    /// Finds the minimal primitive `n`-th root among the odd powers of `root`.
+    fn minimal_primitive_root(self, mut root: T, log_degree: u32) -> T {
         let generator_sq = self.reduce_square(root);
         let generator_sq = ShoupFactor::new(generator_sq, self.value);
         let mut current_generator = root;
@@ -80,6 +136,6 @@ impl<T: Numeric> PrimitiveRoot<T> for BarrettModulus<T> {
                 .reduce_mul_assign(&mut current_generator, generator_sq);
         }
 
-        Ok(root)
+        root
     }
 }
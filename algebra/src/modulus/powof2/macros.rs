@@ -25,3 +25,21 @@ macro_rules! impl_powof2_modulus {
         }
     };
 }
+
+macro_rules! impl_powof2_signed_reduce {
+    (impl Reduce<$SignedT:ty> for PowOf2Modulus<$ValueT:ty>) => {
+        impl $crate::reduce::Reduce<$SignedT> for PowOf2Modulus<$ValueT> {
+            type Output = $ValueT;
+
+            /// Calculates `value (mod modulus)`,
+            /// negative values are mapped into `[0, modulus)`.
+            ///
+            /// The modulus divides `2^BITS`, so masking the two's complement
+            /// representation gives the right residue.
+            #[inline]
+            fn reduce(self, value: $SignedT) -> Self::Output {
+                (value as $ValueT) & self.mask
+            }
+        }
+    };
+}
@@ -61,6 +61,9 @@ impl_powof2_modulus!(impl PowOf2Modulus<u64>);
 impl_powof2_modulus!(impl PowOf2Modulus<usize>);
 impl_powof2_modulus!(impl PowOf2Modulus<u128>);
 
+impl_powof2_signed_reduce!(impl Reduce<i32> for PowOf2Modulus<u32>);
+impl_powof2_signed_reduce!(impl Reduce<i64> for PowOf2Modulus<u64>);
+
 #[cfg(test)]
 mod tests {
     use rand::{distributions::Uniform, prelude::*};
@@ -144,4 +147,33 @@ mod tests {
 
         assert_eq!(modulus.reduce_neg(0), 0);
     }
+
+    #[test]
+    fn test_signed_reduce() {
+        let mut rng = thread_rng();
+
+        let m32: u32 = 1 << rng.gen_range(1..31);
+        let modulus = PowOf2Modulus::<u32>::new(m32);
+        assert_eq!(modulus.reduce(-1i32), m32 - 1);
+        for _ in 0..100 {
+            let v: i32 = rng.gen_range(-(m32 as i32)..=m32 as i32);
+            let mut expected = v;
+            while expected < 0 {
+                expected += m32 as i32;
+            }
+            assert_eq!(modulus.reduce(v), modulus.reduce(expected as u32));
+        }
+
+        let m64: u64 = 1 << rng.gen_range(1..63);
+        let modulus = PowOf2Modulus::<u64>::new(m64);
+        assert_eq!(modulus.reduce(-1i64), m64 - 1);
+        for _ in 0..100 {
+            let v: i64 = rng.gen_range(-(m64 as i64)..=m64 as i64);
+            let mut expected = v;
+            while expected < 0 {
+                expected += m64 as i64;
+            }
+            assert_eq!(modulus.reduce(v), modulus.reduce(expected as u64));
+        }
+    }
 }
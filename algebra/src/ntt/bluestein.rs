@@ -0,0 +1,222 @@
+use alloc::{boxed::Box, vec, vec::Vec};
+
+use crate::{
+    ntt::{NttTable, NumberTheoryTransform},
+    AlgebraError, Field, NttField,
+};
+
+/// The smallest log n of the inner power of two transforms,
+/// the concrete ntt tables don't support smaller dimensions.
+const MIN_LOG_M: u32 = 5;
+
+/// Cyclic number theory transforms of any length `L` dividing `p - 1`,
+/// with Bluestein's chirp-z algorithm.
+///
+/// The transform maps `[x_0, ..., x_(L-1)]` to `[X_0, ..., X_(L-1)]` in natural order,
+/// where `X_k = ∑x_j w^(jk)` for a primitive `L`-th root of unity `w`.
+///
+/// With `jk = C(j+k, 2) - C(j, 2) - C(k, 2)`, where `C(i, 2) = i(i-1)/2`,
+/// the sum becomes the correlation of `x_j w^(-C(j, 2))` with the chirp `w^C(i, 2)`.
+/// It's computed by a negacyclic convolution of dimension `M >= 2L - 1` with
+/// the ntt table of the field, no coefficient of the linear product wraps around
+/// into the needed ones, so the sign of the wrapping doesn't matter.
+///
+/// `2M` must divide `p - 1` as well, so the lengths are limited by the
+/// power of two transforms of the field, about `2^16` for most ntt fields.
+#[derive(Clone)]
+pub struct ArbitraryLenNtt<F: NttField> {
+    len: usize,
+    root: <F as Field>::ValueT,
+    table: <F as NttField>::Table,
+    forward: Chirp<F>,
+    inverse: Chirp<F>,
+}
+
+/// The precomputed chirps of a transform with the root `r`.
+#[derive(Clone)]
+struct Chirp<F: NttField> {
+    /// `r^(-C(j, 2))`, multiplied to the inputs.
+    pre: Vec<<F as Field>::ValueT>,
+    /// `r^(-C(k, 2))` times a scalar, multiplied to the outputs.
+    post: Vec<<F as Field>::ValueT>,
+    /// The ntt values of `r^C(i, 2)` for `i < 2L - 1`.
+    kernel: Vec<<F as Field>::ValueT>,
+}
+
+impl<F: NttField> ArbitraryLenNtt<F> {
+    /// Creates a new [`ArbitraryLenNtt`] of the length `len`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AlgebraError::NoPrimitiveRoot`] if `len` is zero or doesn't divide `p - 1`,
+    /// or the error of [`NttField::generate_ntt_table`] if the field has no power of two
+    /// transform large enough for the convolution.
+    pub fn new(len: usize) -> Result<Self, AlgebraError> {
+        let root = <F as Field>::ValueT::try_from(len)
+            .ok()
+            .and_then(|degree| primitive_root::<F>(degree, len))
+            .ok_or_else(|| AlgebraError::NoPrimitiveRoot {
+                degree: Box::new(len),
+                modulus: Box::new(F::MODULUS_VALUE),
+            })?;
+
+        let log_m = (2 * len - 1)
+            .next_power_of_two()
+            .trailing_zeros()
+            .max(MIN_LOG_M);
+        let table = F::generate_ntt_table(log_m)?;
+
+        let inv_root = F::inv(root);
+        let inv_len = F::inv(F::mul_scalar_u64(F::ONE, len as u64));
+
+        let forward = Chirp::new(&table, len, root, F::ONE);
+        let inverse = Chirp::new(&table, len, inv_root, inv_len);
+
+        Ok(Self {
+            len,
+            root,
+            table,
+            forward,
+            inverse,
+        })
+    }
+
+    /// Returns the length of this [`ArbitraryLenNtt`].
+    #[inline]
+    pub fn dimension(&self) -> usize {
+        self.len
+    }
+
+    /// Returns the primitive `L`-th root of unity `w` of this [`ArbitraryLenNtt`].
+    #[inline]
+    pub fn root(&self) -> <F as Field>::ValueT {
+        self.root
+    }
+
+    /// Calculates `X_k = ∑x_j w^(jk)` for all `k`, both in natural order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `values` is not of the length of this [`ArbitraryLenNtt`].
+    #[inline]
+    pub fn transform_slice(&self, values: &mut [<F as Field>::ValueT]) {
+        self.chirp_z(values, &self.forward);
+    }
+
+    /// Calculates `x_j = L^(-1) ∑X_k w^(-jk)` for all `j`, the inverse of [`Self::transform_slice`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `values` is not of the length of this [`ArbitraryLenNtt`].
+    #[inline]
+    pub fn inverse_transform_slice(&self, values: &mut [<F as Field>::ValueT]) {
+        self.chirp_z(values, &self.inverse);
+    }
+
+    /// Calculates the transform of `values` and returns it in a new vector.
+    #[inline]
+    pub fn transform(&self, values: &[<F as Field>::ValueT]) -> Vec<<F as Field>::ValueT> {
+        let mut values = values.to_vec();
+        self.transform_slice(&mut values);
+        values
+    }
+
+    /// Calculates the inverse transform of `values` and returns it in a new vector.
+    #[inline]
+    pub fn inverse_transform(&self, values: &[<F as Field>::ValueT]) -> Vec<<F as Field>::ValueT> {
+        let mut values = values.to_vec();
+        self.inverse_transform_slice(&mut values);
+        values
+    }
+
+    /// Calculates `post_k ∑(x_j pre_j) kernel_(j+k)` in place.
+    ///
+    /// The inputs are reversed, so the correlation is the convolution
+    /// with the kernel at the indices `L - 1 + k`.
+    fn chirp_z(&self, values: &mut [<F as Field>::ValueT], chirp: &Chirp<F>) {
+        let len = self.len;
+        assert_eq!(values.len(), len);
+
+        let mut a = vec![F::ZERO; self.table.dimension()];
+        a.iter_mut()
+            .zip(values.iter().zip(&chirp.pre).rev())
+            .for_each(|(a, (&x, &c))| *a = F::mul(x, c));
+
+        self.table.transform_slice(&mut a);
+        a.iter_mut()
+            .zip(&chirp.kernel)
+            .for_each(|(a, &b)| F::mul_assign(a, b));
+        self.table.inverse_transform_slice(&mut a);
+
+        values
+            .iter_mut()
+            .zip(&a[len - 1..])
+            .zip(&chirp.post)
+            .for_each(|((x, &y), &c)| *x = F::mul(y, c));
+    }
+}
+
+impl<F: NttField> Chirp<F> {
+    fn new(
+        table: &<F as NttField>::Table,
+        len: usize,
+        root: <F as Field>::ValueT,
+        scalar: <F as Field>::ValueT,
+    ) -> Self {
+        // r^C(i, 2) for i < 2L - 1, with C(i + 1, 2) = C(i, 2) + i.
+        let mut kernel = vec![F::ZERO; table.dimension()];
+        let mut power = F::ONE;
+        let mut step = F::ONE;
+        for k in kernel.iter_mut().take(2 * len - 1) {
+            *k = power;
+            F::mul_assign(&mut power, step);
+            F::mul_assign(&mut step, root);
+        }
+
+        let pre: Vec<_> = kernel[..len].iter().map(|&k| F::inv(k)).collect();
+        let post = pre.iter().map(|&c| F::mul(c, scalar)).collect();
+
+        table.transform_slice(&mut kernel);
+
+        Self { pre, post, kernel }
+    }
+}
+
+/// Finds a primitive `len`-th root of unity of the field `F`.
+///
+/// The candidates `g = 2, 3, ...` are raised to `(p-1)/len` until
+/// the power isn't a root of unity of any smaller order `len/q`
+/// for the prime factors `q` of `len`.
+///
+/// `degree` is `len` as a field value.
+fn primitive_root<F: Field>(
+    degree: <F as Field>::ValueT,
+    len: usize,
+) -> Option<<F as Field>::ValueT> {
+    let modulus_minus_one = F::MODULUS_VALUE - F::ONE;
+    if len == 0 || modulus_minus_one % degree != F::ZERO {
+        return None;
+    }
+    let quotient = modulus_minus_one / degree;
+
+    let mut factors = Vec::new();
+    let mut rest = len;
+    let mut q = 2;
+    while q * q <= rest {
+        if rest.is_multiple_of(q) {
+            factors.push(q);
+            while rest.is_multiple_of(q) {
+                rest /= q;
+            }
+        }
+        q += 1;
+    }
+    if rest > 1 {
+        factors.push(rest);
+    }
+
+    core::iter::successors(Some(F::ONE + F::ONE), |&g| Some(g + F::ONE))
+        .take_while(|&g| g < F::MODULUS_VALUE)
+        .map(|g| F::exp(g, quotient))
+        .find(|&w| factors.iter().all(|&q| F::exp(w, len / q) != F::ONE))
+}
@@ -0,0 +1,173 @@
+use alloc::vec::Vec;
+
+use crate::{
+    ntt::NumberTheoryTransform, reduce::Reduce, AlgebraError, Field, NttField, U64FieldEval,
+};
+
+/// The first ntt prime, `2^50 - 39*2^20 + 1`.
+const P0: u64 = 1125899865948161;
+/// The second ntt prime, `2^50 - 43*2^20 + 1`.
+const P1: u64 = 1125899861753857;
+/// The third ntt prime, `2^50 - 58*2^20 + 1`.
+const P2: u64 = 1125899846025217;
+
+type F0 = U64FieldEval<P0>;
+type F1 = U64FieldEval<P1>;
+type F2 = U64FieldEval<P2>;
+
+/// `P0*P1 mod 2^64`.
+const P0_P1_WRAPPING: u64 = P0.wrapping_mul(P1);
+/// `P0*P1*P2 mod 2^64`.
+const P_WRAPPING: u64 = P0_P1_WRAPPING.wrapping_mul(P2);
+/// The mixed radix digits of `(P0*P1*P2 - 1) / 2`, the most significant first.
+const HALF_P_DIGITS: (u64, u64, u64) = ((P2 - 1) / 2, (P1 - 1) / 2, (P0 - 1) / 2);
+
+/// Negacyclic convolutions of polynomials modulo a power of two `2^k`, `k <= 64`.
+///
+/// No ntt prime divides `2^k`, so the product is computed over the integers instead.
+/// The polynomials are multiplied under three 50-bit ntt primes, whose product `P`
+/// is larger than twice any coefficient of the integer product, and every coefficient
+/// is reconstructed in `(-P/2, P/2)` with the Chinese remainder theorem before
+/// it's reduced modulo `2^k`.
+///
+/// Polynomials with `u32` coefficients can be widened to `u64` with `k <= 32`.
+#[derive(Clone)]
+pub struct CrtNtt {
+    log_n: u32,
+    table0: <F0 as NttField>::Table,
+    table1: <F1 as NttField>::Table,
+    table2: <F2 as NttField>::Table,
+    /// `P0^(-1) mod P1`.
+    p0_inv_mod_p1: u64,
+    /// `P0 mod P2`.
+    p0_mod_p2: u64,
+    /// `(P0*P1)^(-1) mod P2`.
+    p0_p1_inv_mod_p2: u64,
+}
+
+impl CrtNtt {
+    /// The largest supported log n, all three primes are `1 mod 2^(MAX_LOG_N+1)`.
+    ///
+    /// The integer product of two polynomials of this dimension with coefficients
+    /// less than `2^64` has coefficients less than `2^147`, so it fits in `(-P/2, P/2)`.
+    pub const MAX_LOG_N: u32 = 19;
+
+    /// Creates the ntt tables of the three primes for the dimension `2^log_n`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AlgebraError::NttTableErr`] if `log_n` is greater than [`Self::MAX_LOG_N`].
+    pub fn new(log_n: u32) -> Result<Self, AlgebraError> {
+        if log_n > Self::MAX_LOG_N {
+            return Err(AlgebraError::NttTableErr);
+        }
+
+        let p0_mod_p2 = F2::MODULUS.reduce(P0);
+        let p1_mod_p2 = F2::MODULUS.reduce(P1);
+
+        Ok(Self {
+            log_n,
+            table0: F0::generate_ntt_table(log_n)?,
+            table1: F1::generate_ntt_table(log_n)?,
+            table2: F2::generate_ntt_table(log_n)?,
+            p0_inv_mod_p1: F1::inv(F1::MODULUS.reduce(P0)),
+            p0_mod_p2,
+            p0_p1_inv_mod_p2: F2::inv(F2::mul(p0_mod_p2, p1_mod_p2)),
+        })
+    }
+
+    /// Returns the log n of this [`CrtNtt`].
+    #[inline]
+    pub fn log_n(&self) -> u32 {
+        self.log_n
+    }
+
+    /// Returns the dimension of this [`CrtNtt`].
+    #[inline]
+    pub fn dimension(&self) -> usize {
+        1 << self.log_n
+    }
+
+    /// Calculates `a * b mod (X^n + 1, 2^k)`.
+    ///
+    /// The coefficients of `a` and `b` may be any `u64`, they are only
+    /// taken modulo `2^k`. The output coefficients are in `[0, 2^k)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` or `b` is not of the dimension of this [`CrtNtt`],
+    /// or if `k` is not in `[1, 64]`.
+    pub fn negacyclic_mul(&self, a: &[u64], b: &[u64], k: u32) -> Vec<u64> {
+        assert_eq!(a.len(), self.dimension());
+        assert_eq!(b.len(), self.dimension());
+        assert!((1..=64).contains(&k), "k = {k} is not in [1, 64]");
+
+        let r0 = residues::<F0>(&self.table0, a, b);
+        let r1 = residues::<F1>(&self.table1, a, b);
+        let r2 = residues::<F2>(&self.table2, a, b);
+
+        let mask = u64::MAX >> (64 - k);
+
+        r0.into_iter()
+            .zip(r1)
+            .zip(r2)
+            .map(|((r0, r1), r2)| self.reconstruct(r0, r1, r2) & mask)
+            .collect()
+    }
+
+    /// Reconstructs `x mod 2^64` from its residues, where `x` is in `(-P/2, P/2)`.
+    ///
+    /// Garner's algorithm gives the mixed radix digits of `x mod P`,
+    /// `x mod P = r0 + P0*v1 + P0*P1*v2`, which are compared with the
+    /// digits of `(P-1)/2` to tell the negative coefficients.
+    #[inline]
+    fn reconstruct(&self, r0: u64, r1: u64, r2: u64) -> u64 {
+        let v1 = F1::mul(F1::sub(r1, F1::MODULUS.reduce(r0)), self.p0_inv_mod_p1);
+
+        let t = F2::add(
+            F2::MODULUS.reduce(r0),
+            F2::mul(self.p0_mod_p2, F2::MODULUS.reduce(v1)),
+        );
+        let v2 = F2::mul(F2::sub(r2, t), self.p0_p1_inv_mod_p2);
+
+        let x = r0
+            .wrapping_add(P0.wrapping_mul(v1))
+            .wrapping_add(P0_P1_WRAPPING.wrapping_mul(v2));
+
+        if (v2, v1, r0) > HALF_P_DIGITS {
+            x.wrapping_sub(P_WRAPPING)
+        } else {
+            x
+        }
+    }
+}
+
+/// Calculates `a * b mod (F::MODULUS, X^n + 1)` for coefficients less than `2^64`.
+fn residues<F: NttField<ValueT = u64>>(table: &F::Table, a: &[u64], b: &[u64]) -> Vec<u64> {
+    let mut a: Vec<u64> = a.iter().map(|&v| F::MODULUS.reduce(v)).collect();
+    let mut b: Vec<u64> = b.iter().map(|&v| F::MODULUS.reduce(v)).collect();
+
+    table.transform_slice(&mut a);
+    table.transform_slice(&mut b);
+    a.iter_mut().zip(&b).for_each(|(x, &y)| F::mul_assign(x, y));
+    table.inverse_transform_slice(&mut a);
+
+    a
+}
+
+/// Calculates `a * b mod (X^n + 1, 2^k)`, where `n` is the length of `a` and `b`.
+///
+/// The ntt tables are generated on every call, keep a [`CrtNtt`]
+/// to multiply many polynomials of the same dimension.
+///
+/// # Panics
+///
+/// Panics if `a` and `b` don't have the same power of two length of at most
+/// `2^CrtNtt::MAX_LOG_N`, or if `k` is not in `[1, 64]`.
+pub fn negacyclic_mul_pow2_modulus(a: &[u64], b: &[u64], k: u32) -> Vec<u64> {
+    let n = a.len();
+    assert!(n.is_power_of_two(), "length {n} is not a power of two");
+    CrtNtt::new(n.trailing_zeros())
+        .expect("the length is too large")
+        .negacyclic_mul(a, b, k)
+}
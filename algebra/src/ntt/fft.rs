@@ -0,0 +1,286 @@
+//! Double precision FFTs over the `2n`-th roots of unity,
+//! for approximate (CKKS style) encodings.
+
+use alloc::{vec, vec::Vec};
+use core::{
+    f64::consts::PI,
+    ops::{Add, Mul, Sub},
+};
+
+use num_traits::Float;
+
+use crate::{
+    integer::{AsFrom, AsInto},
+    polynomial::FieldPolynomial,
+    utils::bit_reverse_permute,
+    Field,
+};
+
+/// The canonical embedding of the real polynomials of `R[X]/(X^n + 1)`.
+///
+/// A polynomial `m` is mapped to its values at the primitive `2n`-th roots of unity.
+/// The values of a real polynomial at conjugate roots are conjugate, so the `n/2` values
+/// `m(ζ^(5^j))` for `j < n/2`, where `ζ = e^(iπ/n)`, determine it. They are the slots.
+///
+/// [`CanonicalEmbedding::encode`] takes real slots, scales the inverse embedding
+/// by `scale` and rounds it into a field polynomial. [`CanonicalEmbedding::decode`]
+/// lifts the coefficients to their centered representatives and maps them back.
+/// The slots come back with an error below [`CanonicalEmbedding::error_bound`],
+/// as long as the polynomial doesn't wrap around the modulus.
+///
+/// Both directions are the special FFTs over the slots, with `O(n log n)` operations.
+#[derive(Debug, Clone)]
+pub struct CanonicalEmbedding {
+    n: usize,
+    /// `5^j mod 2n` for `j < n/2`.
+    rot_group: Vec<usize>,
+    /// `e^(2πik/2n)` for `k <= 2n`.
+    ksi_pows: Vec<Complex>,
+}
+
+impl CanonicalEmbedding {
+    /// Creates a new [`CanonicalEmbedding`] of the polynomials with `n` coefficients.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is not a power of two greater than `1`.
+    pub fn new(n: usize) -> Self {
+        assert!(
+            n.is_power_of_two() && n > 1,
+            "n = {n} is not a power of two greater than 1"
+        );
+        let m = n << 1;
+
+        let mut rot_group = Vec::with_capacity(n >> 1);
+        let mut five_pow = 1;
+        for _ in 0..n >> 1 {
+            rot_group.push(five_pow);
+            five_pow = (five_pow * 5) % m;
+        }
+
+        let ksi_pows = (0..=m)
+            .map(|k| {
+                let angle = 2.0 * PI * k as f64 / m as f64;
+                Complex::new(Float::cos(angle), Float::sin(angle))
+            })
+            .collect();
+
+        Self {
+            n,
+            rot_group,
+            ksi_pows,
+        }
+    }
+
+    /// Returns the coefficient count of the polynomials, `n`.
+    #[inline]
+    pub fn dimension(&self) -> usize {
+        self.n
+    }
+
+    /// Returns the number of slots, `n/2`.
+    #[inline]
+    pub fn slot_count(&self) -> usize {
+        self.n >> 1
+    }
+
+    /// Returns a bound of the error of each slot after [`CanonicalEmbedding::encode`]
+    /// and [`CanonicalEmbedding::decode`] with `scale`, for slots of absolute values
+    /// at most `max_abs`.
+    ///
+    /// Rounding each of the `n` coefficients errs by at most `1/2`, which moves
+    /// each slot by at most `n/2` before the division by `scale`. The floating point
+    /// error of the two FFTs, with `log n` layers each, is bounded by
+    /// `2 * (log n + 1) * n * ε * max_abs`, it's far below the rounding error
+    /// for the usual scales like `2^40`.
+    pub fn error_bound(&self, scale: f64, max_abs: f64) -> f64 {
+        let n = self.n as f64;
+        let layers = self.n.trailing_zeros() as f64 + 1.0;
+        n / (2.0 * scale) + 2.0 * layers * n * f64::EPSILON * max_abs
+    }
+
+    /// Encodes the real `values` into a polynomial whose coefficients are
+    /// the inverse embedding scaled by `scale` and rounded to the field.
+    ///
+    /// The missing slots, after `values.len()`, are zero.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are more than `n/2` values,
+    /// or a scaled coefficient doesn't fit in `(-q/2, q/2)`.
+    pub fn encode<F: Field>(&self, values: &[f64], scale: f64) -> FieldPolynomial<F> {
+        let slots = self.slot_count();
+        assert!(
+            values.len() <= slots,
+            "{} values don't fit in {slots} slots",
+            values.len()
+        );
+
+        let mut uvals = vec![Complex::ZERO; slots];
+        uvals
+            .iter_mut()
+            .zip(values)
+            .for_each(|(u, &v)| *u = Complex::new(v, 0.0));
+        self.inverse_special_fft(&mut uvals);
+
+        // The real parts are the lower half of the coefficients, the imaginary parts the upper half.
+        let mut coeffs = vec![F::ZERO; self.n];
+        let (low, high) = coeffs.split_at_mut(slots);
+        for ((u, re), im) in uvals.iter().zip(low).zip(high) {
+            *re = round_to_field::<F>(u.re * scale);
+            *im = round_to_field::<F>(u.im * scale);
+        }
+
+        FieldPolynomial::new(coeffs)
+    }
+
+    /// Decodes the real slots of `poly`, which was encoded with `scale`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `poly` doesn't have `n` coefficients.
+    pub fn decode<F: Field>(&self, poly: &FieldPolynomial<F>, scale: f64) -> Vec<f64> {
+        assert_eq!(poly.coeff_count(), self.n);
+        let slots = self.slot_count();
+
+        let (low, high) = poly.as_slice().split_at(slots);
+        let mut vals: Vec<Complex> = low
+            .iter()
+            .zip(high)
+            .map(|(&re, &im)| Complex::new(centered::<F>(re) / scale, centered::<F>(im) / scale))
+            .collect();
+        self.special_fft(&mut vals);
+
+        vals.into_iter().map(|v| v.re).collect()
+    }
+
+    /// Evaluates the polynomial given by the `n/2` complex coefficients
+    /// `c_j + i c_(j+n/2)` at the slot roots.
+    fn special_fft(&self, vals: &mut [Complex]) {
+        let size = vals.len();
+        let m = self.n << 1;
+        bit_reverse_permute(vals, size.trailing_zeros());
+
+        let mut len = 2;
+        while len <= size {
+            let lenh = len >> 1;
+            let lenq = len << 2;
+            let gap = m / lenq;
+            for chunk in vals.chunks_exact_mut(len) {
+                let (lo, hi) = chunk.split_at_mut(lenh);
+                for (j, (u, v)) in lo.iter_mut().zip(hi).enumerate() {
+                    let idx = (self.rot_group[j] % lenq) * gap;
+                    let t = *v * self.ksi_pows[idx];
+                    let s = *u;
+                    *u = s + t;
+                    *v = s - t;
+                }
+            }
+            len <<= 1;
+        }
+    }
+
+    /// The inverse of [`Self::special_fft`].
+    fn inverse_special_fft(&self, vals: &mut [Complex]) {
+        let size = vals.len();
+        let m = self.n << 1;
+
+        let mut len = size;
+        while len >= 2 {
+            let lenh = len >> 1;
+            let lenq = len << 2;
+            let gap = m / lenq;
+            for chunk in vals.chunks_exact_mut(len) {
+                let (lo, hi) = chunk.split_at_mut(lenh);
+                for (j, (u, v)) in lo.iter_mut().zip(hi).enumerate() {
+                    let idx = (lenq - (self.rot_group[j] % lenq)) * gap;
+                    let s = *u + *v;
+                    let t = (*u - *v) * self.ksi_pows[idx];
+                    *u = s;
+                    *v = t;
+                }
+            }
+            len >>= 1;
+        }
+
+        bit_reverse_permute(vals, size.trailing_zeros());
+        let inv_size = 1.0 / size as f64;
+        vals.iter_mut().for_each(|v| {
+            v.re *= inv_size;
+            v.im *= inv_size;
+        });
+    }
+}
+
+/// Rounds `value` to the nearest integer and maps it into the field.
+#[inline]
+fn round_to_field<F: Field>(value: f64) -> <F as Field>::ValueT {
+    let rounded = Float::round(value);
+    let half: f64 = (<F as Field>::MODULUS_VALUE >> 1u32).as_into();
+    assert!(
+        Float::abs(rounded) < half,
+        "the scaled coefficient {rounded} doesn't fit in the field"
+    );
+    if rounded >= 0.0 {
+        <F as Field>::ValueT::as_from(rounded)
+    } else {
+        F::neg(<F as Field>::ValueT::as_from(-rounded))
+    }
+}
+
+/// Returns the centered representative of `value` in `[-q/2, q/2]` as a float.
+#[inline]
+fn centered<F: Field>(value: <F as Field>::ValueT) -> f64 {
+    let q = <F as Field>::MODULUS_VALUE;
+    if value <= q >> 1u32 {
+        value.as_into()
+    } else {
+        -AsInto::<f64>::as_into(q - value)
+    }
+}
+
+/// A double precision complex number.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Complex {
+    re: f64,
+    im: f64,
+}
+
+impl Complex {
+    const ZERO: Self = Self::new(0.0, 0.0);
+
+    #[inline]
+    const fn new(re: f64, im: f64) -> Self {
+        Self { re, im }
+    }
+}
+
+impl Add for Complex {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.re + rhs.re, self.im + rhs.im)
+    }
+}
+
+impl Sub for Complex {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.re - rhs.re, self.im - rhs.im)
+    }
+}
+
+impl Mul for Complex {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::new(
+            self.re * rhs.re - self.im * rhs.im,
+            self.re * rhs.im + self.im * rhs.re,
+        )
+    }
+}
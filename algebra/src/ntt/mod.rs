@@ -1,11 +1,165 @@
 //! Defines Number Theory Transform algorithms.
 
-use crate::{arith::PrimitiveRoot, reduce::Modulus, AlgebraError};
+use alloc::vec::Vec;
 
+use crate::{
+    arith::PrimitiveRoot,
+    reduce::{LazyReduceMulAdd, Modulus},
+    utils::bit_reverse_permute,
+    AlgebraError, Field,
+};
+
+mod bluestein;
+mod crt;
+pub mod fft;
+#[cfg(feature = "test-utils")]
+pub mod reference;
 mod table;
 
+pub use bluestein::ArbitraryLenNtt;
+pub use crt::{negacyclic_mul_pow2_modulus, CrtNtt};
 pub use table::*;
 
+/// The butterfly layout of a number theory transform.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum NttAlgorithm {
+    /// Performs one layer of radix-2 butterflies per pass over the values.
+    #[default]
+    Radix2,
+    /// Performs two layers per pass over the values with radix-4 butterflies,
+    /// starting with one radix-2 layer if the layer count is odd.
+    Radix4,
+}
+
+/// The orders of the coefficients and the values of a number theory transform.
+///
+/// The `i`-th value in natural order is the evaluation at `root^(2i+1)`,
+/// in bit-reversed order it's at index `i.reverse_lsbs(log_n)`.
+/// The ntt polynomials, like [`FieldNttPolynomial`](crate::polynomial::FieldNttPolynomial),
+/// always store their values in bit-reversed order.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum NttOrdering {
+    /// Coefficients in natural order, values in bit-reversed order,
+    /// the order of [`NumberTheoryTransform::transform_slice`].
+    #[default]
+    NaturalToBitrev,
+    /// Coefficients in bit-reversed order, values in natural order.
+    BitrevToNatural,
+    /// Both coefficients and values in natural order.
+    NaturalToNatural,
+}
+
+/// The butterfly kernels of the direct radix-2 transforms of [`FieldTableWithShoupRoot`].
+///
+/// A table picks its kernel once, when it's created, with [`NttKernel::detect`],
+/// and keeps the transforms of the kernel as function pointers, so the transforms
+/// don't probe the cpu. [`FieldTableWithShoupRoot::force_kernel`] overrides it.
+/// All kernels give exactly the same outputs.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum NttKernel {
+    /// The portable scalar butterflies.
+    #[default]
+    Scalar,
+    /// The AVX2 butterflies of x86_64 cpus, for `u32` and `u64` values.
+    Avx2,
+    /// The NEON butterflies of aarch64 cpus, for `u32` values.
+    Neon,
+}
+
+impl NttKernel {
+    /// The environment variable which overrides [`NttKernel::detect`],
+    /// one of `scalar`, `avx2` or `neon`.
+    pub const ENV_VAR: &'static str = "PRIMUS_NTT_KERNEL";
+
+    /// Returns `true` if this kernel is compiled for the target and the cpu supports it.
+    ///
+    /// The simd kernels need the `std` feature to detect the cpu features at runtime.
+    pub fn is_available(self) -> bool {
+        match self {
+            NttKernel::Scalar => true,
+            #[cfg(all(feature = "std", target_arch = "x86_64"))]
+            NttKernel::Avx2 => std::is_x86_feature_detected!("avx2"),
+            #[cfg(all(feature = "std", target_arch = "aarch64"))]
+            NttKernel::Neon => std::arch::is_aarch64_feature_detected!("neon"),
+            _ => false,
+        }
+    }
+
+    /// Returns the fastest kernel available on this cpu.
+    pub fn best() -> Self {
+        [NttKernel::Avx2, NttKernel::Neon]
+            .into_iter()
+            .find(|kernel| kernel.is_available())
+            .unwrap_or(NttKernel::Scalar)
+    }
+
+    /// Returns the kernel named by [`NttKernel::ENV_VAR`] if it's available,
+    /// otherwise [`NttKernel::best`].
+    pub fn detect() -> Self {
+        #[cfg(feature = "std")]
+        if let Some(kernel) = std::env::var(Self::ENV_VAR)
+            .ok()
+            .and_then(|name| Self::from_name(&name))
+            .filter(|kernel| kernel.is_available())
+        {
+            return kernel;
+        }
+        Self::best()
+    }
+
+    /// Parses the lowercase name of a kernel.
+    #[cfg(feature = "std")]
+    fn from_name(name: &str) -> Option<Self> {
+        match name.trim() {
+            "scalar" => Some(NttKernel::Scalar),
+            "avx2" => Some(NttKernel::Avx2),
+            "neon" => Some(NttKernel::Neon),
+            _ => None,
+        }
+    }
+}
+
+/// Reusable buffers for the transforms which need temporaries,
+/// like the out-of-place transposes of the four-step transforms.
+///
+/// The buffers grow on their first use and are kept, so the transforms
+/// with a warm workspace, like [`NumberTheoryTransform::transform_slice_with`],
+/// don't allocate.
+#[derive(Debug, Clone, Default)]
+pub struct NttWorkspace<T> {
+    scratch: Vec<T>,
+}
+
+impl<T> NttWorkspace<T> {
+    /// Creates a new, empty [`NttWorkspace`], which doesn't allocate.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            scratch: Vec::new(),
+        }
+    }
+}
+
+impl<T: Copy + Default> NttWorkspace<T> {
+    /// Returns a scratch buffer of `len` values, growing it if needed.
+    ///
+    /// The values are left over from the previous use.
+    #[inline]
+    pub(crate) fn scratch(&mut self, len: usize) -> &mut [T] {
+        if self.scratch.len() < len {
+            self.scratch.resize(len, T::default());
+        }
+        &mut self.scratch[..len]
+    }
+}
+
+/// The smallest log n for which [`FieldTableWithShoupRoot`] switches to
+/// the four-step transforms by default.
+///
+/// Below this size the values fit in the cache and the direct butterflies
+/// are faster.
+pub const DEFAULT_FOUR_STEP_LOG_N: u32 = 16;
+
 /// An abstract for ntt table generation.
 pub trait NttTable: Sized + Clone + Send + Sync {
     /// The value type.
@@ -69,6 +223,184 @@ pub trait NumberTheoryTransform: NttTable {
     /// * `values` - inputs in bit-reversed order, outputs in normal order
     fn inverse_transform_inplace(&self, values: Self::NttPoly) -> Self::CoeffPoly;
 
+    /// Perform fast number theory transforms on a batch of polynomials.
+    ///
+    /// With the `parallel` feature the polynomials are transformed on the rayon thread pool,
+    /// otherwise one after another. Either way the results are the same as
+    /// [`Self::transform_inplace`] on each polynomial.
+    fn transform_batch(&self, polys: Vec<Self::CoeffPoly>) -> Vec<Self::NttPoly>
+    where
+        Self::CoeffPoly: Send,
+        Self::NttPoly: Send,
+    {
+        #[cfg(feature = "parallel")]
+        {
+            use rayon::prelude::*;
+            polys
+                .into_par_iter()
+                .map(|poly| self.transform_inplace(poly))
+                .collect()
+        }
+        #[cfg(not(feature = "parallel"))]
+        polys
+            .into_iter()
+            .map(|poly| self.transform_inplace(poly))
+            .collect()
+    }
+
+    /// Perform fast inverse number theory transforms on a batch of ntt polynomials.
+    ///
+    /// With the `parallel` feature the polynomials are transformed on the rayon thread pool,
+    /// otherwise one after another. Either way the results are the same as
+    /// [`Self::inverse_transform_inplace`] on each polynomial.
+    fn inverse_transform_batch(&self, values: Vec<Self::NttPoly>) -> Vec<Self::CoeffPoly>
+    where
+        Self::CoeffPoly: Send,
+        Self::NttPoly: Send,
+    {
+        #[cfg(feature = "parallel")]
+        {
+            use rayon::prelude::*;
+            values
+                .into_par_iter()
+                .map(|poly| self.inverse_transform_inplace(poly))
+                .collect()
+        }
+        #[cfg(not(feature = "parallel"))]
+        values
+            .into_iter()
+            .map(|poly| self.inverse_transform_inplace(poly))
+            .collect()
+    }
+
+    /// Perform fast number theory transforms on each row of a row-major matrix in place.
+    ///
+    /// Each row is a polynomial, stored contiguously, so the rows are visited
+    /// one block of `cols` values at a time. With the `parallel` feature the rows
+    /// are transformed on the rayon thread pool. The results are the same as
+    /// [`Self::transform_slice`] on each row.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `cols` is not the dimension of this table,
+    /// or `data` doesn't hold `rows * cols` values.
+    fn transform_matrix(&self, data: &mut [<Self as NttTable>::ValueT], rows: usize, cols: usize)
+    where
+        <Self as NttTable>::ValueT: Send,
+    {
+        assert_eq!(cols, self.dimension());
+        assert_eq!(data.len(), rows * cols);
+
+        #[cfg(feature = "parallel")]
+        {
+            use rayon::prelude::*;
+            data.par_chunks_exact_mut(cols)
+                .for_each(|row| self.transform_slice(row));
+        }
+        #[cfg(not(feature = "parallel"))]
+        data.chunks_exact_mut(cols)
+            .for_each(|row| self.transform_slice(row));
+    }
+
+    /// Perform fast inverse number theory transforms on each row of a row-major matrix in place.
+    ///
+    /// The inverse of [`Self::transform_matrix`], the results are the same as
+    /// [`Self::inverse_transform_slice`] on each row.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `cols` is not the dimension of this table,
+    /// or `data` doesn't hold `rows * cols` values.
+    fn inverse_transform_matrix(
+        &self,
+        data: &mut [<Self as NttTable>::ValueT],
+        rows: usize,
+        cols: usize,
+    ) where
+        <Self as NttTable>::ValueT: Send,
+    {
+        assert_eq!(cols, self.dimension());
+        assert_eq!(data.len(), rows * cols);
+
+        #[cfg(feature = "parallel")]
+        {
+            use rayon::prelude::*;
+            data.par_chunks_exact_mut(cols)
+                .for_each(|row| self.inverse_transform_slice(row));
+        }
+        #[cfg(not(feature = "parallel"))]
+        data.chunks_exact_mut(cols)
+            .for_each(|row| self.inverse_transform_slice(row));
+    }
+
+    /// Perform a fast number theory transform of `src` into `dst`,
+    /// instead of into a new polynomial.
+    ///
+    /// The result is the same as [`Self::transform_slice`] on a copy of `src`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `src` and `dst` have different lengths.
+    #[inline]
+    fn transform_into(
+        &self,
+        src: &[<Self as NttTable>::ValueT],
+        dst: &mut [<Self as NttTable>::ValueT],
+    ) where
+        <Self as NttTable>::ValueT: Copy,
+    {
+        dst.copy_from_slice(src);
+        self.transform_slice(dst);
+    }
+
+    /// Perform a fast inverse number theory transform of `src` into `dst`,
+    /// instead of into a new polynomial.
+    ///
+    /// The result is the same as [`Self::inverse_transform_slice`] on a copy of `src`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `src` and `dst` have different lengths.
+    #[inline]
+    fn inverse_transform_into(
+        &self,
+        src: &[<Self as NttTable>::ValueT],
+        dst: &mut [<Self as NttTable>::ValueT],
+    ) where
+        <Self as NttTable>::ValueT: Copy,
+    {
+        dst.copy_from_slice(src);
+        self.inverse_transform_slice(dst);
+    }
+
+    /// Perform a fast number theory transform in place, taking the temporaries
+    /// it needs from `workspace` instead of allocating them.
+    ///
+    /// The result is the same as [`Self::transform_slice`].
+    /// Tables whose transforms don't need temporaries ignore `workspace`.
+    #[inline]
+    fn transform_slice_with(
+        &self,
+        poly: &mut [<Self as NttTable>::ValueT],
+        _workspace: &mut NttWorkspace<<Self as NttTable>::ValueT>,
+    ) {
+        self.transform_slice(poly);
+    }
+
+    /// Perform a fast inverse number theory transform in place, taking the temporaries
+    /// it needs from `workspace` instead of allocating them.
+    ///
+    /// The result is the same as [`Self::inverse_transform_slice`].
+    /// Tables whose transforms don't need temporaries ignore `workspace`.
+    #[inline]
+    fn inverse_transform_slice_with(
+        &self,
+        values: &mut [<Self as NttTable>::ValueT],
+        _workspace: &mut NttWorkspace<<Self as NttTable>::ValueT>,
+    ) {
+        self.inverse_transform_slice(values);
+    }
+
     /// Perform a fast number theory transform in place.
     ///
     /// This function transforms a polynomial slice with coefficient in `[0, 4*modulus)`
@@ -109,6 +441,98 @@ pub trait NumberTheoryTransform: NttTable {
     /// * `values` - inputs in bit-reversed order, outputs in normal order
     fn inverse_transform_slice(&self, values: &mut [<Self as NttTable>::ValueT]);
 
+    /// Perform a fast inverse number theory transform in place and multiply the result by `extra`.
+    ///
+    /// `extra` is folded into the scaling by `n^(-1)` of the transform, so the result
+    /// is the same as [`Self::inverse_transform_inplace`] followed by a multiplication
+    /// of every coefficient by `extra`, without the extra pass over the coefficients.
+    ///
+    /// # Arguments
+    ///
+    /// * `values` - inputs in bit-reversed order, outputs in normal order
+    /// * `extra` - the scalar in `[0, modulus)`
+    fn inverse_transform_scaled(
+        &self,
+        values: Self::NttPoly,
+        extra: <Self as NttTable>::ValueT,
+    ) -> Self::CoeffPoly;
+
+    /// Perform a fast inverse number theory transform in place and multiply the result by `extra`.
+    ///
+    /// This function transforms a ntt polynomial slice with coefficient in `[0, 2*modulus)`
+    /// to a polynomial slice with coefficient in `[0, modulus)`, which is the same as
+    /// [`Self::inverse_transform_slice`] followed by a multiplication by `extra`.
+    ///
+    /// # Arguments
+    ///
+    /// * `values` - inputs in bit-reversed order, outputs in normal order
+    /// * `extra` - the scalar in `[0, modulus)`
+    fn inverse_transform_scaled_slice(
+        &self,
+        values: &mut [<Self as NttTable>::ValueT],
+        extra: <Self as NttTable>::ValueT,
+    );
+
+    /// Perform a fast inverse number theory transform in place and multiply the result by `extra`,
+    /// taking the temporaries it needs from `workspace` instead of allocating them.
+    ///
+    /// The result is the same as [`Self::inverse_transform_scaled_slice`].
+    /// Tables whose transforms don't need temporaries ignore `workspace`.
+    #[inline]
+    fn inverse_transform_scaled_slice_with(
+        &self,
+        values: &mut [<Self as NttTable>::ValueT],
+        extra: <Self as NttTable>::ValueT,
+        _workspace: &mut NttWorkspace<<Self as NttTable>::ValueT>,
+    ) {
+        self.inverse_transform_scaled_slice(values, extra);
+    }
+
+    /// Perform a fast number theory transform in place, with the orders given by `ordering`.
+    ///
+    /// This function transforms a polynomial slice with coefficient in `[0, 4*modulus)`
+    /// to a ntt polynomial slice with coefficient in `[0, modulus)`.
+    /// The orders other than [`NttOrdering::NaturalToBitrev`] cost
+    /// a bit-reversal permutation besides the transform.
+    ///
+    /// # Arguments
+    ///
+    /// * `poly` - inputs and outputs in the orders of `ordering`
+    fn transform_ordered(&self, poly: &mut [<Self as NttTable>::ValueT], ordering: NttOrdering) {
+        let log_n = poly.len().trailing_zeros();
+        if ordering == NttOrdering::BitrevToNatural {
+            bit_reverse_permute(poly, log_n);
+        }
+        self.transform_slice(poly);
+        if ordering != NttOrdering::NaturalToBitrev {
+            bit_reverse_permute(poly, log_n);
+        }
+    }
+
+    /// Perform a fast inverse number theory transform in place, which undoes
+    /// [`Self::transform_ordered`] with the same `ordering`.
+    ///
+    /// This function transforms a ntt polynomial slice with coefficient in `[0, 2*modulus)`
+    /// to a polynomial slice with coefficient in `[0, modulus)`.
+    ///
+    /// # Arguments
+    ///
+    /// * `values` - inputs and outputs in the reverse orders of `ordering`
+    fn inverse_transform_ordered(
+        &self,
+        values: &mut [<Self as NttTable>::ValueT],
+        ordering: NttOrdering,
+    ) {
+        let log_n = values.len().trailing_zeros();
+        if ordering != NttOrdering::NaturalToBitrev {
+            bit_reverse_permute(values, log_n);
+        }
+        self.inverse_transform_slice(values);
+        if ordering == NttOrdering::BitrevToNatural {
+            bit_reverse_permute(values, log_n);
+        }
+    }
+
     /// Perform a fast number theory transform for **monomial** `coeff*X^degree` in place.
     fn transform_monomial(
         &self,
@@ -131,3 +555,72 @@ pub trait NumberTheoryTransform: NttTable {
         values: &mut [<Self as NttTable>::ValueT],
     );
 }
+
+/// An object safe view of the transforms of a ntt table over the field `F`.
+///
+/// [`NumberTheoryTransform`] is generic over its polynomial types and can't be
+/// used behind a pointer. This trait only keeps the slice transforms, so other
+/// implementations, like external accelerators, can be swapped in as
+/// `dyn NttBackend<F>`.
+///
+/// All the transforms work in place, with inputs in normal order and outputs
+/// in bit-reversed order for the forward transform, and the other way around
+/// for the inverse transform.
+pub trait NttBackend<F: Field>: Send + Sync {
+    /// Perform a forward transform, with inputs in `[0, 4*modulus)`
+    /// and outputs in `[0, modulus)`.
+    fn forward(&self, values: &mut [<F as Field>::ValueT]);
+
+    /// Perform a forward transform, with inputs in `[0, 4*modulus)`
+    /// and outputs in `[0, 4*modulus)`.
+    fn forward_lazy(&self, values: &mut [<F as Field>::ValueT]);
+
+    /// Perform an inverse transform, with inputs in `[0, 2*modulus)`
+    /// and outputs in `[0, modulus)`.
+    fn inverse(&self, values: &mut [<F as Field>::ValueT]);
+
+    /// Perform a forward transform of `src` in place, and accumulate the
+    /// pointwise product of the result and `key_row_ntt` into `acc`,
+    /// i.e. `acc = acc + NTT(src) * key_row_ntt`.
+    ///
+    /// `src` is in `[0, 4*modulus)` and holds its transform in `[0, modulus)`
+    /// afterwards, so it can be multiplied with other key rows.
+    /// The coefficients in `acc` may be in [0, 2*modulus) for some case,
+    /// and fall back to [0, modulus) for normal case.
+    ///
+    /// Tables may fuse the multiplication into the last butterfly layer,
+    /// the result is the same as [`Self::forward`] followed by the multiplication.
+    #[inline]
+    fn transform_and_pointwise_mul(
+        &self,
+        src: &mut [<F as Field>::ValueT],
+        key_row_ntt: &[<F as Field>::ValueT],
+        acc: &mut [<F as Field>::ValueT],
+    ) {
+        debug_assert_eq!(src.len(), key_row_ntt.len());
+        debug_assert_eq!(src.len(), acc.len());
+
+        self.forward(src);
+        crate::count::mul::add(acc.len());
+        acc.iter_mut()
+            .zip(src.iter())
+            .zip(key_row_ntt)
+            .for_each(|((z, &x), &y)| *z = F::MODULUS.lazy_reduce_mul_add(x, y, *z));
+    }
+
+    /// Same as [`Self::transform_and_pointwise_mul`], taking the temporaries
+    /// of the transform from `workspace`.
+    #[inline]
+    fn transform_and_pointwise_mul_with(
+        &self,
+        src: &mut [<F as Field>::ValueT],
+        key_row_ntt: &[<F as Field>::ValueT],
+        acc: &mut [<F as Field>::ValueT],
+        _workspace: &mut NttWorkspace<<F as Field>::ValueT>,
+    ) {
+        self.transform_and_pointwise_mul(src, key_row_ntt, acc);
+    }
+
+    /// Returns the polynomial modulus degree.
+    fn degree(&self) -> usize;
+}
@@ -0,0 +1,56 @@
+//! Reference implementations to check the number theory transforms against.
+//!
+//! Enabled by the `test-utils` feature.
+
+use alloc::{vec, vec::Vec};
+
+use crate::{
+    integer::{AsInto, UnsignedInteger},
+    reduce::Modulus,
+};
+
+/// Calculates `a * b mod (X^n + 1, modulus)` with the schoolbook multiplication,
+/// where `n` is the length of `a` and `b`.
+///
+/// Every product is computed and reduced in `u128`, the reduced products
+/// are summed in `u128` and the sums are reduced once at the end,
+/// so this works with any modulus up to `2^64`, e.g. a
+/// [`BarrettModulus`](crate::modulus::BarrettModulus), a
+/// [`PowOf2Modulus`](crate::modulus::PowOf2Modulus) or a
+/// [`NativeModulus`](crate::modulus::NativeModulus).
+/// The inputs may be any values of `T`, not only reduced ones.
+///
+/// # Panics
+///
+/// Panics if `a` and `b` have different lengths.
+pub fn negacyclic_convolution<T, M>(a: &[T], b: &[T], modulus: M) -> Vec<T>
+where
+    T: UnsignedInteger,
+    M: Modulus<T>,
+{
+    assert_eq!(a.len(), b.len(), "polynomial lengths differ");
+    let n = a.len();
+    let q: u128 = AsInto::<u128>::as_into(modulus.modulus_minus_one()) + 1;
+
+    // The reduced products are less than `2^64`, so `n` of them fit in `u128`.
+    let mut positive = vec![0u128; n];
+    let mut negative = vec![0u128; n];
+    for (i, &x) in a.iter().enumerate() {
+        let x: u128 = AsInto::<u128>::as_into(x) % q;
+        for (j, &y) in b.iter().enumerate() {
+            let y: u128 = AsInto::<u128>::as_into(y) % q;
+            let product = x * y % q;
+            if i + j < n {
+                positive[i + j] += product;
+            } else {
+                negative[i + j - n] += product;
+            }
+        }
+    }
+
+    positive
+        .into_iter()
+        .zip(negative)
+        .map(|(p, m)| T::as_from((p % q + q - m % q) % q))
+        .collect()
+}
@@ -0,0 +1,244 @@
+//! AVX2 butterflies for the number theory transform of `u32` and `u64` values.
+//!
+//! The transforms come from [`impl_simd_transform`], so they give exactly
+//! the same outputs as the scalar butterflies.
+
+use core::any::TypeId;
+
+use super::simd::{cast, cast_slice, cast_slice_mut, impl_simd_transform};
+use crate::{modulus::ShoupFactor, numeric::Numeric};
+
+/// Returns `true` if the AVX2 butterflies support the values `T`, i.e. `T` is `u32` or `u64`.
+pub(super) fn supports<T: 'static>() -> bool {
+    let id = TypeId::of::<T>();
+    id == TypeId::of::<u32>() || id == TypeId::of::<u64>()
+}
+
+/// Performs the forward lazy transform with AVX2.
+///
+/// # Safety
+///
+/// The cpu must support AVX2 and [`supports::<T>`] must be `true`.
+pub(super) unsafe fn lazy_transform<T: Numeric>(
+    values: &mut [T],
+    roots: &[ShoupFactor<T>],
+    modulus: T,
+    log_n: u32,
+) {
+    if let (Some(values), Some(roots), Some(modulus)) =
+        (cast_slice_mut(values), cast_slice(roots), cast(modulus))
+    {
+        u32x8::lazy_transform(values, roots, modulus, log_n)
+    } else if let (Some(values), Some(roots), Some(modulus)) =
+        (cast_slice_mut(values), cast_slice(roots), cast(modulus))
+    {
+        u64x4::lazy_transform(values, roots, modulus, log_n)
+    } else {
+        unreachable!("the avx2 butterflies only support u32 and u64")
+    }
+}
+
+/// Performs the inverse lazy transform with AVX2.
+///
+/// `scaled_root` is the root of the last layer multiplied by `inv_n`.
+///
+/// # Safety
+///
+/// The cpu must support AVX2 and [`supports::<T>`] must be `true`.
+pub(super) unsafe fn lazy_inverse_transform<T: Numeric>(
+    values: &mut [T],
+    roots: &[ShoupFactor<T>],
+    inv_n: ShoupFactor<T>,
+    scaled_root: ShoupFactor<T>,
+    modulus: T,
+    log_n: u32,
+) {
+    if let (Some(values), Some(roots), Some(inv_n), Some(scaled_root), Some(modulus)) = (
+        cast_slice_mut(values),
+        cast_slice(roots),
+        cast(inv_n),
+        cast(scaled_root),
+        cast(modulus),
+    ) {
+        u32x8::lazy_inverse_transform(values, roots, inv_n, scaled_root, modulus, log_n)
+    } else if let (Some(values), Some(roots), Some(inv_n), Some(scaled_root), Some(modulus)) = (
+        cast_slice_mut(values),
+        cast_slice(roots),
+        cast(inv_n),
+        cast(scaled_root),
+        cast(modulus),
+    ) {
+        u64x4::lazy_inverse_transform(values, roots, inv_n, scaled_root, modulus, log_n)
+    } else {
+        unreachable!("the avx2 butterflies only support u32 and u64")
+    }
+}
+
+/// Eight `u32` lanes.
+///
+/// The modulus is less than `2^30`, so every lazy value fits in a lane.
+mod u32x8 {
+    use core::arch::x86_64::*;
+
+    use crate::{
+        modulus::ShoupFactor,
+        reduce::{LazyReduceMul, ReduceAdd, ReduceOnce},
+    };
+
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    unsafe fn splat(value: u32) -> __m256i {
+        _mm256_set1_epi32(value as i32)
+    }
+
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    unsafe fn load(values: &[u32]) -> __m256i {
+        debug_assert_eq!(values.len(), 8);
+        _mm256_loadu_si256(values.as_ptr().cast())
+    }
+
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    unsafe fn store(values: &mut [u32], v: __m256i) {
+        debug_assert_eq!(values.len(), 8);
+        _mm256_storeu_si256(values.as_mut_ptr().cast(), v)
+    }
+
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    unsafe fn add(a: __m256i, b: __m256i) -> __m256i {
+        _mm256_add_epi32(a, b)
+    }
+
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    unsafe fn sub(a: __m256i, b: __m256i) -> __m256i {
+        _mm256_sub_epi32(a, b)
+    }
+
+    /// `a - m` if `a >= m`, for `a < 2m`.
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    unsafe fn reduce_once(a: __m256i, m: __m256i) -> __m256i {
+        _mm256_min_epu32(a, _mm256_sub_epi32(a, m))
+    }
+
+    /// The high halves of the lane products.
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    unsafe fn mul_hw(a: __m256i, b: __m256i) -> __m256i {
+        let even = _mm256_srli_epi64::<32>(_mm256_mul_epu32(a, b));
+        let odd = _mm256_mul_epu32(_mm256_srli_epi64::<32>(a), _mm256_srli_epi64::<32>(b));
+        _mm256_blend_epi32::<0b1010_1010>(even, odd)
+    }
+
+    /// Shoup's lazy multiplication `w * b (mod p)`, the result is in `[0, 2p)`.
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    unsafe fn lazy_mul(w: __m256i, w_quotient: __m256i, b: __m256i, p: __m256i) -> __m256i {
+        let hw = mul_hw(w_quotient, b);
+        _mm256_sub_epi32(_mm256_mullo_epi32(w, b), _mm256_mullo_epi32(p, hw))
+    }
+
+    super::impl_simd_transform!("avx2", u32, 8);
+}
+
+/// Four `u64` lanes.
+///
+/// The modulus is less than `2^62`, so every lazy value fits in a lane.
+mod u64x4 {
+    use core::arch::x86_64::*;
+
+    use crate::{
+        modulus::ShoupFactor,
+        reduce::{LazyReduceMul, ReduceAdd, ReduceOnce},
+    };
+
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    unsafe fn splat(value: u64) -> __m256i {
+        _mm256_set1_epi64x(value as i64)
+    }
+
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    unsafe fn load(values: &[u64]) -> __m256i {
+        debug_assert_eq!(values.len(), 4);
+        _mm256_loadu_si256(values.as_ptr().cast())
+    }
+
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    unsafe fn store(values: &mut [u64], v: __m256i) {
+        debug_assert_eq!(values.len(), 4);
+        _mm256_storeu_si256(values.as_mut_ptr().cast(), v)
+    }
+
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    unsafe fn add(a: __m256i, b: __m256i) -> __m256i {
+        _mm256_add_epi64(a, b)
+    }
+
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    unsafe fn sub(a: __m256i, b: __m256i) -> __m256i {
+        _mm256_sub_epi64(a, b)
+    }
+
+    /// `a - m` if `a >= m`, for `a < 2m`.
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    unsafe fn reduce_once(a: __m256i, m: __m256i) -> __m256i {
+        // AVX2 only compares signed lanes, flip the sign bits to compare unsigned values.
+        let sign = _mm256_set1_epi64x(i64::MIN);
+        let less = _mm256_cmpgt_epi64(_mm256_xor_si256(m, sign), _mm256_xor_si256(a, sign));
+        _mm256_sub_epi64(a, _mm256_andnot_si256(less, m))
+    }
+
+    /// The low halves of the lane products.
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    unsafe fn mul_lw(a: __m256i, b: __m256i) -> __m256i {
+        let low = _mm256_mul_epu32(a, b);
+        let cross = _mm256_add_epi64(
+            _mm256_mul_epu32(a, _mm256_srli_epi64::<32>(b)),
+            _mm256_mul_epu32(_mm256_srli_epi64::<32>(a), b),
+        );
+        _mm256_add_epi64(low, _mm256_slli_epi64::<32>(cross))
+    }
+
+    /// The high halves of the lane products.
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    unsafe fn mul_hw(a: __m256i, b: __m256i) -> __m256i {
+        let mask = _mm256_set1_epi64x(u32::MAX as i64);
+        let a_hi = _mm256_srli_epi64::<32>(a);
+        let b_hi = _mm256_srli_epi64::<32>(b);
+
+        let ll = _mm256_mul_epu32(a, b);
+        let lh = _mm256_mul_epu32(a, b_hi);
+        let hl = _mm256_mul_epu32(a_hi, b);
+        let hh = _mm256_mul_epu32(a_hi, b_hi);
+
+        let mid = _mm256_add_epi64(
+            _mm256_srli_epi64::<32>(ll),
+            _mm256_add_epi64(_mm256_and_si256(lh, mask), _mm256_and_si256(hl, mask)),
+        );
+        _mm256_add_epi64(
+            _mm256_add_epi64(hh, _mm256_srli_epi64::<32>(mid)),
+            _mm256_add_epi64(_mm256_srli_epi64::<32>(lh), _mm256_srli_epi64::<32>(hl)),
+        )
+    }
+
+    /// Shoup's lazy multiplication `w * b (mod p)`, the result is in `[0, 2p)`.
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    unsafe fn lazy_mul(w: __m256i, w_quotient: __m256i, b: __m256i, p: __m256i) -> __m256i {
+        let hw = mul_hw(w_quotient, b);
+        _mm256_sub_epi64(mul_lw(w, b), mul_lw(p, hw))
+    }
+
+    super::impl_simd_transform!("avx2", u64, 4);
+}
@@ -5,7 +5,7 @@ pub mod prime32 {
 
     use crate::{
         arith::PrimitiveRoot,
-        ntt::{NttTable, NumberTheoryTransform},
+        ntt::{NttBackend, NttTable, NumberTheoryTransform},
         polynomial::{FieldNttPolynomial, FieldPolynomial},
         reduce::Modulus,
         AlgebraError, Field, NttField,
@@ -18,6 +18,7 @@ pub mod prime32 {
         F: NttField<Table = Self> + Field<ValueT = u32>,
     {
         root: <F as Field>::ValueT,
+        inv_n: <F as Field>::ValueT,
         plan: Plan,
     }
 
@@ -43,26 +44,53 @@ pub mod prime32 {
 
         #[inline]
         fn lazy_transform_slice(&self, poly: &mut [<Self as NttTable>::ValueT]) {
+            crate::count::ntt::increment();
             self.plan.fwd(poly);
         }
 
         #[inline]
         fn transform_slice(&self, poly: &mut [<Self as NttTable>::ValueT]) {
+            crate::count::ntt::increment();
             self.plan.fwd(poly);
         }
 
         #[inline]
         fn lazy_inverse_transform_slice(&self, values: &mut [<Self as NttTable>::ValueT]) {
+            crate::count::inverse_ntt::increment();
             self.plan.inv(values);
             self.plan.normalize(values);
         }
 
         #[inline]
         fn inverse_transform_slice(&self, values: &mut [<Self as NttTable>::ValueT]) {
+            crate::count::inverse_ntt::increment();
             self.plan.inv(values);
             self.plan.normalize(values);
         }
 
+        #[inline]
+        fn inverse_transform_scaled(
+            &self,
+            mut values: Self::NttPoly,
+            extra: <Self as NttTable>::ValueT,
+        ) -> Self::CoeffPoly {
+            self.inverse_transform_scaled_slice(values.as_mut_slice(), extra);
+            FieldPolynomial::new(values.inner_data())
+        }
+
+        #[inline]
+        fn inverse_transform_scaled_slice(
+            &self,
+            values: &mut [<Self as NttTable>::ValueT],
+            extra: <Self as NttTable>::ValueT,
+        ) {
+            crate::count::inverse_ntt::increment();
+            self.plan.inv(values);
+            // The plan only normalizes by `n^(-1)`, so multiply by `n^(-1) * extra` instead.
+            let scalar = F::mul(self.inv_n, extra);
+            values.iter_mut().for_each(|v| F::mul_assign(v, scalar));
+        }
+
         #[inline]
         fn transform_monomial(
             &self,
@@ -112,6 +140,31 @@ pub mod prime32 {
         }
     }
 
+    impl<F> NttBackend<F> for Concrete32Table<F>
+    where
+        F: NttField<Table = Self> + Field<ValueT = u32>,
+    {
+        #[inline]
+        fn forward(&self, values: &mut [u32]) {
+            self.transform_slice(values);
+        }
+
+        #[inline]
+        fn forward_lazy(&self, values: &mut [u32]) {
+            self.lazy_transform_slice(values);
+        }
+
+        #[inline]
+        fn inverse(&self, values: &mut [u32]) {
+            self.inverse_transform_slice(values);
+        }
+
+        #[inline]
+        fn degree(&self) -> usize {
+            self.plan.ntt_size()
+        }
+    }
+
     impl<F> Concrete32Table<F>
     where
         F: NttField<Table = Self> + Field<ValueT = u32>,
@@ -122,7 +175,8 @@ pub mod prime32 {
             let plan =
                 Plan::try_new(1 << log_n, F::MODULUS_VALUE).ok_or(AlgebraError::NttTableErr)?;
             let root = plan.root();
-            Ok(Self { root, plan })
+            let inv_n = F::inv(1 << log_n);
+            Ok(Self { root, inv_n, plan })
         }
 
         /// Get the root of unity.
@@ -138,7 +192,7 @@ pub mod prime64 {
     use concrete_ntt::prime64::Plan;
 
     use crate::{
-        ntt::{NttTable, NumberTheoryTransform},
+        ntt::{NttBackend, NttTable, NumberTheoryTransform},
         polynomial::{FieldNttPolynomial, FieldPolynomial},
         AlgebraError, Field, NttField,
     };
@@ -150,6 +204,7 @@ pub mod prime64 {
         F: NttField<Table = Self> + Field<ValueT = u64>,
     {
         root: <F as Field>::ValueT,
+        inv_n: <F as Field>::ValueT,
         plan: Plan,
     }
 
@@ -175,26 +230,53 @@ pub mod prime64 {
 
         #[inline]
         fn lazy_transform_slice(&self, poly: &mut [<Self as NttTable>::ValueT]) {
+            crate::count::ntt::increment();
             self.plan.fwd(poly);
         }
 
         #[inline]
         fn transform_slice(&self, poly: &mut [<Self as NttTable>::ValueT]) {
+            crate::count::ntt::increment();
             self.plan.fwd(poly);
         }
 
         #[inline]
         fn lazy_inverse_transform_slice(&self, values: &mut [<Self as NttTable>::ValueT]) {
+            crate::count::inverse_ntt::increment();
             self.plan.inv(values);
             self.plan.normalize(values);
         }
 
         #[inline]
         fn inverse_transform_slice(&self, values: &mut [<Self as NttTable>::ValueT]) {
+            crate::count::inverse_ntt::increment();
             self.plan.inv(values);
             self.plan.normalize(values);
         }
 
+        #[inline]
+        fn inverse_transform_scaled(
+            &self,
+            mut values: Self::NttPoly,
+            extra: <Self as NttTable>::ValueT,
+        ) -> Self::CoeffPoly {
+            self.inverse_transform_scaled_slice(values.as_mut_slice(), extra);
+            FieldPolynomial::new(values.inner_data())
+        }
+
+        #[inline]
+        fn inverse_transform_scaled_slice(
+            &self,
+            values: &mut [<Self as NttTable>::ValueT],
+            extra: <Self as NttTable>::ValueT,
+        ) {
+            crate::count::inverse_ntt::increment();
+            self.plan.inv(values);
+            // The plan only normalizes by `n^(-1)`, so multiply by `n^(-1) * extra` instead.
+            let scalar = F::mul(self.inv_n, extra);
+            values.iter_mut().for_each(|v| F::mul_assign(v, scalar));
+        }
+
         #[inline]
         fn transform_monomial(
             &self,
@@ -244,6 +326,31 @@ pub mod prime64 {
         }
     }
 
+    impl<F> NttBackend<F> for Concrete64Table<F>
+    where
+        F: NttField<Table = Self> + Field<ValueT = u64>,
+    {
+        #[inline]
+        fn forward(&self, values: &mut [u64]) {
+            self.transform_slice(values);
+        }
+
+        #[inline]
+        fn forward_lazy(&self, values: &mut [u64]) {
+            self.lazy_transform_slice(values);
+        }
+
+        #[inline]
+        fn inverse(&self, values: &mut [u64]) {
+            self.inverse_transform_slice(values);
+        }
+
+        #[inline]
+        fn degree(&self) -> usize {
+            self.plan.ntt_size()
+        }
+    }
+
     impl<F> Concrete64Table<F>
     where
         F: NttField<Table = Self> + Field<ValueT = u64>,
@@ -254,7 +361,8 @@ pub mod prime64 {
             let plan =
                 Plan::try_new(1 << log_n, F::MODULUS_VALUE).ok_or(AlgebraError::NttTableErr)?;
             let root = plan.root();
-            Ok(Self { root, plan })
+            let inv_n = F::inv(1 << log_n);
+            Ok(Self { root, inv_n, plan })
         }
 
         /// Get the root of unity.
@@ -1,18 +1,30 @@
+use alloc::{boxed::Box, vec, vec::Vec};
+
 use num_traits::{ConstOne, ConstZero, One, Zero};
 
 use crate::{
     arith::PrimitiveRoot,
     modulus::{BarrettModulus, ShoupFactor},
-    ntt::{NttTable, NumberTheoryTransform},
+    ntt::{
+        NttAlgorithm, NttBackend, NttKernel, NttTable, NttWorkspace, NumberTheoryTransform,
+        DEFAULT_FOUR_STEP_LOG_N,
+    },
     polynomial::{FieldNttPolynomial, FieldPolynomial},
     reduce::{
-        LazyReduceMul, Modulus, ReduceAdd, ReduceInv, ReduceMul, ReduceMulAssign, ReduceOnce,
+        LazyReduceMul, LazyReduceMulAdd, Modulus, ReduceAdd, ReduceInv, ReduceMul, ReduceOnce,
         ReduceOnceAssign,
     },
     utils::ReverseLsbs,
     AlgebraError, Field, NttField,
 };
 
+use super::{debug_assert_lazy_bound, four_step::FourStepRoots};
+
+#[cfg(all(feature = "std", target_arch = "x86_64"))]
+use super::avx2 as simd_kernels;
+#[cfg(all(feature = "std", target_arch = "aarch64"))]
+use super::neon as simd_kernels;
+
 /// This struct store the pre-computed data for number theory transform and
 /// inverse number theory transform.
 ///
@@ -25,6 +37,10 @@ use crate::{
 /// 1. `root_powers` holds 1~(n-1)-th powers of root in bit-reversed order, the 0-th power is left unset.
 /// 1. `inv_root_powers` holds 1~(n-1)-th powers of inverse root in scrambled order, the 0-th power is left unset.
 ///
+/// When `log_n` reaches the four-step threshold, the lazy transforms are
+/// split into a column pass and a row pass over a `√n`-sized matrix view
+/// of the values, which keeps large transforms in cache.
+///
 /// ## Compare three orders:
 ///
 /// ```plain
@@ -48,6 +64,10 @@ where
     inv_root_powers: Vec<ShoupFactor<<F as Field>::ValueT>>,
     ordinal_root_powers: Vec<ShoupFactor<<F as Field>::ValueT>>,
     reverse_lsbs: Vec<usize>,
+    algorithm: NttAlgorithm,
+    kernel: NttKernel,
+    kernel_fns: KernelFns<F>,
+    four_step: Option<FourStepRoots<<F as Field>::ValueT>>,
 }
 
 impl<F> Clone for FieldTableWithShoupRoot<F>
@@ -66,6 +86,59 @@ where
             inv_root_powers: self.inv_root_powers.clone(),
             ordinal_root_powers: self.ordinal_root_powers.clone(),
             reverse_lsbs: self.reverse_lsbs.clone(),
+            algorithm: self.algorithm,
+            kernel: self.kernel,
+            kernel_fns: self.kernel_fns,
+            four_step: self.four_step.clone(),
+        }
+    }
+}
+
+/// A lazy forward transform of a [`FieldTableWithShoupRoot<F>`].
+type ForwardFn<F> = fn(&FieldTableWithShoupRoot<F>, &mut [<F as Field>::ValueT]);
+
+/// A lazy inverse transform of a [`FieldTableWithShoupRoot<F>`],
+/// scaled by the given factor.
+type InverseByFn<F> =
+    fn(&FieldTableWithShoupRoot<F>, &mut [<F as Field>::ValueT], ShoupFactor<<F as Field>::ValueT>);
+
+/// The direct radix-2 lazy transforms of a [`FieldTableWithShoupRoot<F>`],
+/// resolved once from its [`NttKernel`].
+struct KernelFns<F: NttField> {
+    forward: ForwardFn<F>,
+    inverse_by: InverseByFn<F>,
+}
+
+impl<F: NttField> Clone for KernelFns<F> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<F: NttField> Copy for KernelFns<F> {}
+
+impl<F: NttField> KernelFns<F> {
+    /// Resolves the transforms of `kernel`, falling back to the scalar
+    /// butterflies if `kernel` isn't available or doesn't support the values.
+    fn resolve(kernel: NttKernel) -> Self {
+        #[cfg(all(feature = "std", any(target_arch = "x86_64", target_arch = "aarch64")))]
+        if kernel != NttKernel::Scalar
+            && kernel.is_available()
+            && simd_kernels::supports::<<F as Field>::ValueT>()
+        {
+            return Self {
+                forward: FieldTableWithShoupRoot::lazy_transform_slice_simd,
+                inverse_by: FieldTableWithShoupRoot::lazy_inverse_transform_slice_simd_by,
+            };
+        }
+        // Without the simd kernels every `kernel` resolves to the scalar butterflies.
+        #[cfg(not(all(feature = "std", any(target_arch = "x86_64", target_arch = "aarch64"))))]
+        let _ = kernel;
+
+        Self {
+            forward: FieldTableWithShoupRoot::lazy_transform_slice_scalar,
+            inverse_by: FieldTableWithShoupRoot::lazy_inverse_transform_slice_scalar_by,
         }
     }
 }
@@ -74,6 +147,96 @@ impl<F> FieldTableWithShoupRoot<F>
 where
     F: NttField,
 {
+    /// Creates a new [`FieldTableWithShoupRoot<F>`] whose transforms use `algorithm`.
+    ///
+    /// Both algorithms give exactly the same outputs.
+    pub fn with_algorithm<M>(
+        modulus: M,
+        log_n: u32,
+        algorithm: NttAlgorithm,
+    ) -> Result<Self, AlgebraError>
+    where
+        M: Modulus<<F as Field>::ValueT> + PrimitiveRoot<<F as Field>::ValueT>,
+    {
+        let mut table = <Self as NttTable>::new(modulus, log_n)?;
+        table.algorithm = algorithm;
+        Ok(table)
+    }
+
+    /// Creates a new [`FieldTableWithShoupRoot<F>`] which uses the four-step
+    /// transforms when `log_n` is at least `four_step_log_n`.
+    ///
+    /// [`NttTable::new`] uses [`DEFAULT_FOUR_STEP_LOG_N`] as the threshold.
+    /// Transforms with `log_n < 2` always use the direct butterflies.
+    /// Both ways give exactly the same outputs.
+    ///
+    /// The four-step transforms take priority over the [`NttKernel`] of the table:
+    /// their row and column passes always run the scalar butterflies, since the
+    /// sub-transforms are small enough to stay in the cache. The simd kernels are
+    /// only used by the direct radix-2 transforms below the threshold.
+    pub fn with_four_step_threshold<M>(
+        modulus: M,
+        log_n: u32,
+        four_step_log_n: u32,
+    ) -> Result<Self, AlgebraError>
+    where
+        M: Modulus<<F as Field>::ValueT> + PrimitiveRoot<<F as Field>::ValueT>,
+    {
+        let mut table = Self::direct(modulus, log_n)?;
+        if log_n >= four_step_log_n.max(2) {
+            table.four_step = Some(FourStepRoots::new(
+                log_n,
+                &table.root_powers,
+                &table.inv_root_powers,
+            ));
+        }
+        Ok(table)
+    }
+
+    /// Returns `true` if the lazy transforms of this
+    /// [`FieldTableWithShoupRoot<F>`] use the four-step layout.
+    #[inline]
+    pub fn is_four_step(&self) -> bool {
+        self.four_step.is_some()
+    }
+
+    /// Returns the algorithm of this [`FieldTableWithShoupRoot<F>`].
+    #[inline]
+    pub fn algorithm(&self) -> NttAlgorithm {
+        self.algorithm
+    }
+
+    /// Returns the butterfly kernel of the direct radix-2 transforms
+    /// of this [`FieldTableWithShoupRoot<F>`].
+    #[inline]
+    pub fn kernel(&self) -> NttKernel {
+        self.kernel
+    }
+
+    /// Makes the direct radix-2 transforms of this [`FieldTableWithShoupRoot<F>`]
+    /// use `kernel` instead of the one picked by [`NttKernel::detect`].
+    ///
+    /// Values which `kernel` doesn't support, like `u64` values with NEON,
+    /// still run the scalar butterflies.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `kernel` is not available, see [`NttKernel::is_available`],
+    /// or if the table uses the radix-4 or the four-step transforms,
+    /// which always run the scalar butterflies.
+    pub fn force_kernel(&mut self, kernel: NttKernel) {
+        assert!(
+            kernel.is_available(),
+            "the ntt kernel {kernel:?} is not available"
+        );
+        assert!(
+            self.algorithm == NttAlgorithm::Radix2 && self.four_step.is_none(),
+            "only the direct radix-2 transforms have a kernel"
+        );
+        self.kernel = kernel;
+        self.kernel_fns = KernelFns::resolve(kernel);
+    }
+
     /// Returns the root of this [`FieldTableWithShoupRoot<F>`].
     #[inline]
     pub fn root(&self) -> <F as Field>::ValueT {
@@ -104,6 +267,12 @@ where
         self.inv_n
     }
 
+    /// Returns the value of `n^(-1)` of this [`FieldTableWithShoupRoot<F>`].
+    #[inline]
+    pub fn inv_degree(&self) -> <F as Field>::ValueT {
+        self.inv_n.value()
+    }
+
     /// Returns a reference to the root powers of this [`FieldTableWithShoupRoot<F>`].
     #[inline]
     pub fn root_powers(&self) -> &[ShoupFactor<<F as Field>::ValueT>] {
@@ -127,15 +296,434 @@ where
     pub fn reverse_lsbs(&self) -> &[usize] {
         &self.reverse_lsbs
     }
-}
 
-impl<F> NttTable for FieldTableWithShoupRoot<F>
-where
-    F: NttField,
-{
-    type ValueT = <F as Field>::ValueT;
+    /// Performs the forward lazy transform with the scalar butterflies,
+    /// whether or not a SIMD implementation is available.
+    ///
+    /// The input is in `[0, 4*modulus)` and so is the output.
+    #[inline]
+    pub fn lazy_transform_slice_scalar(&self, poly: &mut [<F as Field>::ValueT]) {
+        debug_assert_eq!(poly.len(), self.n);
 
-    fn new<M>(modulus: M, log_n: u32) -> Result<Self, crate::AlgebraError>
+        let modulus_value = <F as Field>::MODULUS_VALUE;
+        let twice_modulus_value = modulus_value << 1u32;
+        let four_modulus_value = twice_modulus_value << 1u32;
+        debug_assert_lazy_bound(poly, four_modulus_value);
+
+        self.lazy_transform_layers_scalar(poly, self.log_n, 0);
+    }
+
+    /// Performs the forward layers with the gaps `2^(top-1), ..., 2^bottom`,
+    /// the layers with larger gaps are already done.
+    ///
+    /// The input is in `[0, 4*modulus)` and so is the output.
+    fn lazy_transform_layers_scalar(
+        &self,
+        poly: &mut [<F as Field>::ValueT],
+        top: u32,
+        bottom: u32,
+    ) {
+        let modulus_value = <F as Field>::MODULUS_VALUE;
+        let twice_modulus_value = modulus_value << 1u32;
+        let four_modulus_value = twice_modulus_value << 1u32;
+
+        // The layer with the gap `2^(top-1)` starts from the root power `n/2^top`.
+        let roots = self.root_powers();
+        let mut root_iter = roots[self.n >> top..].iter().copied();
+
+        for gap in (bottom..top).rev().map(|x| 1usize << x) {
+            for vc in poly.chunks_exact_mut(gap << 1) {
+                let root = root_iter.next().unwrap();
+                let (v0, v1) = vc.split_at_mut(gap);
+                for (i, j) in core::iter::zip(v0, v1) {
+                    let u = twice_modulus_value.reduce_once(*i);
+                    let v = modulus_value.lazy_reduce_mul(root, *j);
+                    *i = u + v;
+                    *j = u + twice_modulus_value - v;
+                }
+            }
+            debug_assert_lazy_bound(poly, four_modulus_value);
+        }
+    }
+
+    /// Performs the inverse lazy transform with the scalar butterflies,
+    /// whether or not a SIMD implementation is available.
+    ///
+    /// The input is in `[0, 2*modulus)` and so is the output.
+    #[inline]
+    pub fn lazy_inverse_transform_slice_scalar(&self, values: &mut [<F as Field>::ValueT]) {
+        self.lazy_inverse_transform_slice_scalar_by(values, self.inv_n);
+    }
+
+    /// The scalar inverse transform, with the last layer scaled by `scalar` instead of `inv_n`.
+    fn lazy_inverse_transform_slice_scalar_by(
+        &self,
+        values: &mut [<F as Field>::ValueT],
+        scalar: ShoupFactor<<F as Field>::ValueT>,
+    ) {
+        debug_assert_eq!(values.len(), self.n);
+
+        let log_n = self.log_n;
+
+        let modulus_value = <F as Field>::MODULUS_VALUE;
+        let twice_modulus_value = modulus_value << 1u32;
+        debug_assert_lazy_bound(values, twice_modulus_value);
+
+        self.lazy_inverse_transform_layers_scalar(values);
+
+        let gap = 1 << (log_n - 1);
+
+        let scaled_r = modulus_value.reduce_mul(scalar.value(), self.inv_root_powers()[self.n - 1]);
+        let scaled_r = ShoupFactor::new(scaled_r, modulus_value);
+
+        let (v0, v1) = values.split_at_mut(gap);
+        for (i, j) in core::iter::zip(v0, v1) {
+            let u = *i;
+            let v = *j;
+            *i = modulus_value.lazy_reduce_mul(u + v, scalar);
+            *j = modulus_value.lazy_reduce_mul(u + twice_modulus_value - v, scaled_r);
+        }
+        debug_assert_lazy_bound(values, twice_modulus_value);
+    }
+
+    /// Performs the inverse layers with the gaps `1, 2, ..., n/4`,
+    /// all but the last layer, which is scaled.
+    ///
+    /// The input is in `[0, 2*modulus)` and so is the output.
+    fn lazy_inverse_transform_layers_scalar(&self, values: &mut [<F as Field>::ValueT]) {
+        let modulus_value = <F as Field>::MODULUS_VALUE;
+        let twice_modulus_value = modulus_value << 1u32;
+
+        let roots = self.inv_root_powers();
+        let mut root_iter = roots[1..].iter().copied();
+
+        for gap in (0..self.log_n - 1).map(|x| 1usize << x) {
+            for vc in values.chunks_exact_mut(gap << 1) {
+                let root = root_iter.next().unwrap();
+                let (v0, v1) = vc.split_at_mut(gap);
+                for (i, j) in core::iter::zip(v0, v1) {
+                    let u = *i;
+                    let v = *j;
+                    *i = twice_modulus_value.reduce_add(u, v);
+                    *j = modulus_value.lazy_reduce_mul(u + twice_modulus_value - v, root);
+                }
+            }
+            debug_assert_lazy_bound(values, twice_modulus_value);
+        }
+    }
+
+    /// Performs the forward transform of a polynomial whose
+    /// upper `n/2` coefficients are zero.
+    ///
+    /// The first layer only pairs each coefficient `x_i` with the zero `x_(i+n/2)`,
+    /// so it copies `x_i` to both outputs instead of multiplying by the root.
+    /// The upper half of `poly` is not read, the output is the same as
+    /// [`NumberTheoryTransform::transform_slice`] with that half set to zero.
+    ///
+    /// It always uses the scalar radix-2 butterflies.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `poly` is not `n` or `n` is `1`.
+    pub fn transform_truncated_slice(&self, poly: &mut [<F as Field>::ValueT]) {
+        assert_eq!(poly.len(), self.n);
+        assert!(self.log_n > 0, "The dimension should be at least 2!");
+        crate::count::ntt::increment();
+
+        let modulus_value = <F as Field>::MODULUS_VALUE;
+        let (v0, v1) = poly.split_at_mut(self.n >> 1);
+        v1.copy_from_slice(v0);
+
+        self.lazy_transform_layers_scalar(poly, self.log_n - 1, 0);
+
+        let twice_modulus_value = modulus_value << 1u32;
+        poly.iter_mut().for_each(|v| {
+            let r = twice_modulus_value.reduce_once(*v);
+            *v = modulus_value.reduce_once(r);
+        });
+    }
+
+    /// Performs the inverse transform, but only computes the lower `n/2` coefficients.
+    ///
+    /// In the last layer, the lower outputs `(u + v)/n` don't depend on the upper outputs
+    /// `(u - v)*root/n`, so half of its multiplications are skipped.
+    /// The lower half of `values` is the same as
+    /// [`NumberTheoryTransform::inverse_transform_slice`], the upper half is set to zero.
+    ///
+    /// It always uses the scalar radix-2 butterflies.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `values` is not `n` or `n` is `1`.
+    pub fn inverse_transform_truncated_slice(&self, values: &mut [<F as Field>::ValueT]) {
+        assert_eq!(values.len(), self.n);
+        assert!(self.log_n > 0, "The dimension should be at least 2!");
+        crate::count::inverse_ntt::increment();
+
+        self.lazy_inverse_transform_layers_scalar(values);
+
+        let modulus_value = <F as Field>::MODULUS_VALUE;
+        let inv_n = self.inv_n;
+        let (v0, v1) = values.split_at_mut(self.n >> 1);
+        for (i, &j) in core::iter::zip(v0, v1.iter()) {
+            *i = modulus_value.reduce_mul(*i + j, inv_n);
+        }
+        v1.fill(<F as Field>::ZERO);
+    }
+
+    /// Performs the forward lazy transform with radix-4 butterflies.
+    ///
+    /// Each radix-4 butterfly performs the operations of two consecutive
+    /// radix-2 layers on four values, so the output is exactly the same as
+    /// [`Self::lazy_transform_slice_scalar`].
+    ///
+    /// The input is in `[0, 4*modulus)` and so is the output.
+    pub fn lazy_transform_slice_radix4(&self, poly: &mut [<F as Field>::ValueT]) {
+        debug_assert_eq!(poly.len(), self.n);
+
+        let log_n = self.log_n;
+
+        let modulus_value = <F as Field>::MODULUS_VALUE;
+        let twice_modulus_value = modulus_value << 1u32;
+        let four_modulus_value = twice_modulus_value << 1u32;
+        debug_assert_lazy_bound(poly, four_modulus_value);
+
+        let roots = self.root_powers();
+
+        let butterfly = |i: &mut <F as Field>::ValueT,
+                         j: &mut <F as Field>::ValueT,
+                         root: ShoupFactor<<F as Field>::ValueT>| {
+            let u = twice_modulus_value.reduce_once(*i);
+            let v = modulus_value.lazy_reduce_mul(root, *j);
+            *i = u + v;
+            *j = u + twice_modulus_value - v;
+        };
+
+        let mut layer = 0;
+        if log_n & 1 == 1 {
+            let (v0, v1) = poly.split_at_mut(self.n >> 1);
+            let root = roots[1];
+            core::iter::zip(v0, v1).for_each(|(i, j)| butterfly(i, j, root));
+            debug_assert_lazy_bound(poly, four_modulus_value);
+            layer = 1;
+        }
+
+        while layer < log_n {
+            let quarter = 1usize << (log_n - 2 - layer);
+            let first_roots = &roots[1 << layer..2 << layer];
+            let second_roots = &roots[2 << layer..4 << layer];
+
+            for ((vc, &w), w12) in poly
+                .chunks_exact_mut(quarter << 2)
+                .zip(first_roots)
+                .zip(second_roots.chunks_exact(2))
+            {
+                let (w1, w2) = (w12[0], w12[1]);
+                let (v01, v23) = vc.split_at_mut(quarter << 1);
+                let (v0, v1) = v01.split_at_mut(quarter);
+                let (v2, v3) = v23.split_at_mut(quarter);
+                for (((a0, a1), a2), a3) in v0.iter_mut().zip(v1).zip(v2).zip(v3) {
+                    butterfly(a0, a2, w);
+                    butterfly(a1, a3, w);
+                    butterfly(a0, a1, w1);
+                    butterfly(a2, a3, w2);
+                }
+            }
+            debug_assert_lazy_bound(poly, four_modulus_value);
+
+            layer += 2;
+        }
+    }
+
+    /// Performs the inverse lazy transform with radix-4 butterflies.
+    ///
+    /// Each radix-4 butterfly performs the operations of two consecutive
+    /// radix-2 layers on four values, so the output is exactly the same as
+    /// [`Self::lazy_inverse_transform_slice_scalar`].
+    ///
+    /// The input is in `[0, 2*modulus)` and so is the output.
+    #[inline]
+    pub fn lazy_inverse_transform_slice_radix4(&self, values: &mut [<F as Field>::ValueT]) {
+        self.lazy_inverse_transform_slice_radix4_by(values, self.inv_n);
+    }
+
+    /// The radix-4 inverse transform, with the last layer scaled by `scalar` instead of `inv_n`.
+    fn lazy_inverse_transform_slice_radix4_by(
+        &self,
+        values: &mut [<F as Field>::ValueT],
+        scalar: ShoupFactor<<F as Field>::ValueT>,
+    ) {
+        debug_assert_eq!(values.len(), self.n);
+
+        let n = self.n;
+        let log_n = self.log_n;
+
+        let modulus_value = <F as Field>::MODULUS_VALUE;
+        let twice_modulus_value = modulus_value << 1u32;
+        debug_assert_lazy_bound(values, twice_modulus_value);
+
+        let roots = self.inv_root_powers();
+        let layer_roots = |layer: u32| {
+            let offset = 1 + n - (n >> layer);
+            &roots[offset..offset + (n >> (layer + 1))]
+        };
+
+        let butterfly = |i: &mut <F as Field>::ValueT,
+                         j: &mut <F as Field>::ValueT,
+                         root: ShoupFactor<<F as Field>::ValueT>| {
+            let u = *i;
+            let v = *j;
+            *i = twice_modulus_value.reduce_add(u, v);
+            *j = modulus_value.lazy_reduce_mul(u + twice_modulus_value - v, root);
+        };
+
+        // The last layer is merged with the scaling by `inv_n`.
+        let merged_layers = log_n.saturating_sub(1);
+
+        let mut layer = 0;
+        if merged_layers & 1 == 1 {
+            for (vc, &root) in values.chunks_exact_mut(2).zip(layer_roots(0)) {
+                let (v0, v1) = vc.split_at_mut(1);
+                butterfly(&mut v0[0], &mut v1[0], root);
+            }
+            debug_assert_lazy_bound(values, twice_modulus_value);
+            layer = 1;
+        }
+
+        while layer < merged_layers {
+            let quarter = 1usize << layer;
+            let first_roots = layer_roots(layer);
+            let second_roots = layer_roots(layer + 1);
+
+            for ((vc, w12), &w) in values
+                .chunks_exact_mut(quarter << 2)
+                .zip(first_roots.chunks_exact(2))
+                .zip(second_roots)
+            {
+                let (w1, w2) = (w12[0], w12[1]);
+                let (v01, v23) = vc.split_at_mut(quarter << 1);
+                let (v0, v1) = v01.split_at_mut(quarter);
+                let (v2, v3) = v23.split_at_mut(quarter);
+                for (((a0, a1), a2), a3) in v0.iter_mut().zip(v1).zip(v2).zip(v3) {
+                    butterfly(a0, a1, w1);
+                    butterfly(a2, a3, w2);
+                    butterfly(a0, a2, w);
+                    butterfly(a1, a3, w);
+                }
+            }
+            debug_assert_lazy_bound(values, twice_modulus_value);
+
+            layer += 2;
+        }
+
+        let gap = n >> 1;
+
+        let scaled_r = modulus_value.reduce_mul(scalar.value(), roots[n - 1]);
+        let scaled_r = ShoupFactor::new(scaled_r, modulus_value);
+
+        let (v0, v1) = values.split_at_mut(gap);
+        for (i, j) in core::iter::zip(v0, v1) {
+            let u = *i;
+            let v = *j;
+            *i = modulus_value.lazy_reduce_mul(u + v, scalar);
+            *j = modulus_value.lazy_reduce_mul(u + twice_modulus_value - v, scaled_r);
+        }
+        debug_assert_lazy_bound(values, twice_modulus_value);
+    }
+
+    /// Dispatches the forward lazy transform.
+    ///
+    /// The order is deliberate: the radix-4 algorithm is an explicit choice of the caller,
+    /// then large tables use the cache-blocked four-step layout, and only the direct
+    /// radix-2 transforms use the kernel of the table.
+    /// The four-step transform takes its scratch buffer from `workspace`.
+    fn lazy_transform_slice_in(
+        &self,
+        poly: &mut [<F as Field>::ValueT],
+        workspace: &mut NttWorkspace<<F as Field>::ValueT>,
+    ) {
+        crate::count::ntt::increment();
+        if self.algorithm == NttAlgorithm::Radix4 {
+            self.lazy_transform_slice_radix4(poly);
+            return;
+        }
+
+        if let Some(four_step) = &self.four_step {
+            let scratch = workspace.scratch(self.n);
+            four_step.lazy_transform::<F>(poly, &self.root_powers, scratch);
+            return;
+        }
+
+        (self.kernel_fns.forward)(self, poly);
+    }
+
+    /// Dispatches the lazy inverse transform, scaling the result by `scalar`
+    /// instead of `inv_n`, so that `scalar = inv_n * extra` folds in an extra factor.
+    ///
+    /// The order is the same as [`Self::lazy_transform_slice_in`].
+    /// The four-step transform takes its scratch buffer from `workspace`.
+    fn lazy_inverse_transform_slice_by(
+        &self,
+        values: &mut [<F as Field>::ValueT],
+        scalar: ShoupFactor<<F as Field>::ValueT>,
+        workspace: &mut NttWorkspace<<F as Field>::ValueT>,
+    ) {
+        if self.algorithm == NttAlgorithm::Radix4 {
+            self.lazy_inverse_transform_slice_radix4_by(values, scalar);
+            return;
+        }
+
+        if let Some(four_step) = &self.four_step {
+            let scratch = workspace.scratch(self.n);
+            four_step.lazy_inverse_transform::<F>(values, &self.inv_root_powers, scalar, scratch);
+            return;
+        }
+
+        (self.kernel_fns.inverse_by)(self, values, scalar);
+    }
+
+    /// Performs the forward lazy transform with the simd kernel of the target.
+    #[cfg(all(feature = "std", any(target_arch = "x86_64", target_arch = "aarch64")))]
+    fn lazy_transform_slice_simd(&self, poly: &mut [<F as Field>::ValueT]) {
+        // SAFETY: `KernelFns::resolve` only picks this transform if the kernel
+        // is available and supports the values.
+        unsafe {
+            simd_kernels::lazy_transform(
+                poly,
+                &self.root_powers,
+                <F as Field>::MODULUS_VALUE,
+                self.log_n,
+            )
+        }
+    }
+
+    /// Performs the inverse lazy transform with the simd kernel of the target,
+    /// with the last layer scaled by `scalar` instead of `inv_n`.
+    #[cfg(all(feature = "std", any(target_arch = "x86_64", target_arch = "aarch64")))]
+    fn lazy_inverse_transform_slice_simd_by(
+        &self,
+        values: &mut [<F as Field>::ValueT],
+        scalar: ShoupFactor<<F as Field>::ValueT>,
+    ) {
+        let modulus_value = <F as Field>::MODULUS_VALUE;
+        let scaled_root =
+            modulus_value.reduce_mul(scalar.value(), self.inv_root_powers[self.n - 1]);
+        // SAFETY: `KernelFns::resolve` only picks this transform if the kernel
+        // is available and supports the values.
+        unsafe {
+            simd_kernels::lazy_inverse_transform(
+                values,
+                &self.inv_root_powers,
+                scalar,
+                ShoupFactor::new(scaled_root, modulus_value),
+                modulus_value,
+                self.log_n,
+            )
+        }
+    }
+
+    /// Creates a table which always uses the direct butterflies.
+    fn direct<M>(modulus: M, log_n: u32) -> Result<Self, AlgebraError>
     where
         M: Modulus<<F as Field>::ValueT> + PrimitiveRoot<<F as Field>::ValueT>,
     {
@@ -150,16 +738,11 @@ where
         let root_one = to_root_type(ConstOne::ONE);
         let root_factor = to_root_type(root);
 
-        let mut power = root;
-
-        let mut ordinal_root_powers = vec![ShoupFactor::default(); n * 2];
-        let mut iter = ordinal_root_powers.iter_mut();
-        *iter.next().unwrap() = root_one;
-        *iter.next().unwrap() = root_factor;
-        for root_power in iter {
-            modulus_value.reduce_mul_assign(&mut power, root_factor);
-            *root_power = to_root_type(power);
-        }
+        let ordinal_root_powers: Vec<ShoupFactor<<F as Field>::ValueT>> =
+            F::pow_table(root, n * 2 - 1)
+                .into_iter()
+                .map(to_root_type)
+                .collect();
 
         let inv_root = ordinal_root_powers.last().unwrap().value();
 
@@ -200,6 +783,7 @@ where
         }
 
         let inv_n = to_root_type(modulus_value.reduce_inv(n_cast));
+        let kernel = NttKernel::detect();
 
         Ok(Self {
             root,
@@ -211,8 +795,27 @@ where
             inv_root_powers,
             ordinal_root_powers,
             reverse_lsbs,
+            algorithm: NttAlgorithm::Radix2,
+            kernel,
+            kernel_fns: KernelFns::resolve(kernel),
+            four_step: None,
         })
     }
+}
+
+impl<F> NttTable for FieldTableWithShoupRoot<F>
+where
+    F: NttField,
+{
+    type ValueT = <F as Field>::ValueT;
+
+    #[inline]
+    fn new<M>(modulus: M, log_n: u32) -> Result<Self, crate::AlgebraError>
+    where
+        M: Modulus<<F as Field>::ValueT> + PrimitiveRoot<<F as Field>::ValueT>,
+    {
+        Self::with_four_step_threshold(modulus, log_n, DEFAULT_FOUR_STEP_LOG_N)
+    }
 
     #[inline(always)]
     fn dimension(&self) -> usize {
@@ -242,31 +845,21 @@ where
 
     #[inline]
     fn lazy_transform_slice(&self, poly: &mut [<Self as NttTable>::ValueT]) {
-        debug_assert_eq!(poly.len(), self.n);
-
-        let modulus_value = <F as Field>::MODULUS_VALUE;
-        let twice_modulus_value = modulus_value << 1u32;
-
-        let roots = self.root_powers();
-        let mut root_iter = roots[1..].iter().copied();
-
-        for gap in (0..self.log_n).rev().map(|x| 1usize << x) {
-            for vc in poly.chunks_exact_mut(gap << 1) {
-                let root = root_iter.next().unwrap();
-                let (v0, v1) = vc.split_at_mut(gap);
-                for (i, j) in core::iter::zip(v0, v1) {
-                    let u = twice_modulus_value.reduce_once(*i);
-                    let v = modulus_value.lazy_reduce_mul(root, *j);
-                    *i = u + v;
-                    *j = u + twice_modulus_value - v;
-                }
-            }
-        }
+        self.lazy_transform_slice_in(poly, &mut NttWorkspace::new());
     }
 
     #[inline]
     fn transform_slice(&self, poly: &mut [<Self as NttTable>::ValueT]) {
-        self.lazy_transform_slice(poly);
+        self.transform_slice_with(poly, &mut NttWorkspace::new());
+    }
+
+    #[inline]
+    fn transform_slice_with(
+        &self,
+        poly: &mut [<Self as NttTable>::ValueT],
+        workspace: &mut NttWorkspace<<Self as NttTable>::ValueT>,
+    ) {
+        self.lazy_transform_slice_in(poly, workspace);
 
         let modulus_value = <F as Field>::MODULUS_VALUE;
         let twice_modulus_value = modulus_value << 1u32;
@@ -278,49 +871,65 @@ where
 
     #[inline]
     fn lazy_inverse_transform_slice(&self, values: &mut [<Self as NttTable>::ValueT]) {
-        debug_assert_eq!(values.len(), self.n);
-
-        let log_n = self.log_n;
-
-        let modulus_value = <F as Field>::MODULUS_VALUE;
-        let twice_modulus_value = modulus_value << 1u32;
-
-        let roots = self.inv_root_powers();
-        let mut root_iter = roots[1..].iter().copied();
+        crate::count::inverse_ntt::increment();
+        self.lazy_inverse_transform_slice_by(values, self.inv_n, &mut NttWorkspace::new());
+    }
 
-        for gap in (0..log_n - 1).map(|x| 1usize << x) {
-            for vc in values.chunks_exact_mut(gap << 1) {
-                let root = root_iter.next().unwrap();
-                let (v0, v1) = vc.split_at_mut(gap);
-                for (i, j) in core::iter::zip(v0, v1) {
-                    let u = *i;
-                    let v = *j;
-                    *i = twice_modulus_value.reduce_add(u, v);
-                    *j = modulus_value.lazy_reduce_mul(u + twice_modulus_value - v, root);
-                }
-            }
-        }
+    #[inline]
+    fn inverse_transform_slice(&self, values: &mut [<Self as NttTable>::ValueT]) {
+        self.inverse_transform_slice_with(values, &mut NttWorkspace::new());
+    }
 
-        let gap = 1 << (log_n - 1);
+    #[inline]
+    fn inverse_transform_slice_with(
+        &self,
+        values: &mut [<Self as NttTable>::ValueT],
+        workspace: &mut NttWorkspace<<Self as NttTable>::ValueT>,
+    ) {
+        crate::count::inverse_ntt::increment();
+        self.lazy_inverse_transform_slice_by(values, self.inv_n, workspace);
 
-        let scalar = self.inv_n();
-        let scaled_r = modulus_value.reduce_mul(scalar.value(), root_iter.next().unwrap());
-        let scaled_r = ShoupFactor::new(scaled_r, modulus_value);
+        let modulus_value = <F as Field>::MODULUS_VALUE;
+        values.iter_mut().for_each(|v| {
+            modulus_value.reduce_once_assign(v);
+        });
+    }
 
-        let (v0, v1) = values.split_at_mut(gap);
-        for (i, j) in core::iter::zip(v0, v1) {
-            let u = *i;
-            let v = *j;
-            *i = modulus_value.lazy_reduce_mul(u + v, scalar);
-            *j = modulus_value.lazy_reduce_mul(u + twice_modulus_value - v, scaled_r);
-        }
+    #[inline]
+    fn inverse_transform_scaled(
+        &self,
+        mut values: Self::NttPoly,
+        extra: <Self as NttTable>::ValueT,
+    ) -> Self::CoeffPoly {
+        self.inverse_transform_scaled_slice(values.as_mut_slice(), extra);
+        <FieldPolynomial<F>>::new(values.inner_data())
     }
 
     #[inline]
-    fn inverse_transform_slice(&self, values: &mut [<Self as NttTable>::ValueT]) {
-        self.lazy_inverse_transform_slice(values);
+    fn inverse_transform_scaled_slice(
+        &self,
+        values: &mut [<Self as NttTable>::ValueT],
+        extra: <Self as NttTable>::ValueT,
+    ) {
+        self.inverse_transform_scaled_slice_with(values, extra, &mut NttWorkspace::new());
+    }
 
+    #[inline]
+    fn inverse_transform_scaled_slice_with(
+        &self,
+        values: &mut [<Self as NttTable>::ValueT],
+        extra: <Self as NttTable>::ValueT,
+        workspace: &mut NttWorkspace<<Self as NttTable>::ValueT>,
+    ) {
+        crate::count::inverse_ntt::increment();
         let modulus_value = <F as Field>::MODULUS_VALUE;
+        let scalar = modulus_value.reduce_mul(extra, self.inv_n);
+        self.lazy_inverse_transform_slice_by(
+            values,
+            ShoupFactor::new(scalar, modulus_value),
+            workspace,
+        );
+
         values.iter_mut().for_each(|v| {
             modulus_value.reduce_once_assign(v);
         });
@@ -432,3 +1041,93 @@ where
             });
     }
 }
+
+impl<F> NttBackend<F> for FieldTableWithShoupRoot<F>
+where
+    F: NttField<Modulus = BarrettModulus<<F as Field>::ValueT>>,
+{
+    #[inline]
+    fn forward(&self, values: &mut [<F as Field>::ValueT]) {
+        self.transform_slice(values);
+    }
+
+    #[inline]
+    fn forward_lazy(&self, values: &mut [<F as Field>::ValueT]) {
+        self.lazy_transform_slice(values);
+    }
+
+    #[inline]
+    fn inverse(&self, values: &mut [<F as Field>::ValueT]) {
+        self.inverse_transform_slice(values);
+    }
+
+    #[inline]
+    fn transform_and_pointwise_mul(
+        &self,
+        src: &mut [<F as Field>::ValueT],
+        key_row_ntt: &[<F as Field>::ValueT],
+        acc: &mut [<F as Field>::ValueT],
+    ) {
+        self.transform_and_pointwise_mul_with(src, key_row_ntt, acc, &mut NttWorkspace::new());
+    }
+
+    /// The direct scalar transform multiplies while doing its last layer,
+    /// the other transforms multiply in a pass after the transform.
+    fn transform_and_pointwise_mul_with(
+        &self,
+        src: &mut [<F as Field>::ValueT],
+        key_row_ntt: &[<F as Field>::ValueT],
+        acc: &mut [<F as Field>::ValueT],
+        workspace: &mut NttWorkspace<<F as Field>::ValueT>,
+    ) {
+        debug_assert_eq!(src.len(), self.n);
+        debug_assert_eq!(key_row_ntt.len(), self.n);
+        debug_assert_eq!(acc.len(), self.n);
+
+        crate::count::mul::add(self.n);
+
+        if self.log_n == 0
+            || self.algorithm != NttAlgorithm::Radix2
+            || self.four_step.is_some()
+            || self.kernel != NttKernel::Scalar
+        {
+            self.transform_slice_with(src, workspace);
+            acc.iter_mut()
+                .zip(src.iter())
+                .zip(key_row_ntt)
+                .for_each(|((z, &x), &y)| *z = F::MODULUS.lazy_reduce_mul_add(x, y, *z));
+            return;
+        }
+
+        crate::count::ntt::increment();
+
+        let modulus_value = <F as Field>::MODULUS_VALUE;
+        let twice_modulus_value = modulus_value << 1u32;
+        debug_assert_lazy_bound(src, twice_modulus_value << 1u32);
+
+        self.lazy_transform_layers_scalar(src, self.log_n, 1);
+
+        // The last layer, with the gap `1`, uses the root powers `n/2..n`.
+        let roots = &self.root_powers[self.n >> 1..];
+        src.chunks_exact_mut(2)
+            .zip(roots)
+            .zip(acc.chunks_exact_mut(2))
+            .zip(key_row_ntt.chunks_exact(2))
+            .for_each(|(((vc, &root), zc), kc)| {
+                let u = twice_modulus_value.reduce_once(vc[0]);
+                let v = modulus_value.lazy_reduce_mul(root, vc[1]);
+                let x0 = modulus_value.reduce_once(twice_modulus_value.reduce_once(u + v));
+                let x1 = modulus_value
+                    .reduce_once(twice_modulus_value.reduce_once(u + twice_modulus_value - v));
+                vc[0] = x0;
+                vc[1] = x1;
+                zc[0] = F::MODULUS.lazy_reduce_mul_add(x0, kc[0], zc[0]);
+                zc[1] = F::MODULUS.lazy_reduce_mul_add(x1, kc[1], zc[1]);
+            });
+    }
+
+    #[inline]
+    fn degree(&self) -> usize {
+        self.n
+    }
+}
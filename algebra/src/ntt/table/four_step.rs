@@ -0,0 +1,239 @@
+use alloc::vec::Vec;
+
+use crate::{
+    modulus::ShoupFactor,
+    numeric::Numeric,
+    reduce::{LazyReduceMul, ReduceMul},
+    Field,
+};
+
+use super::debug_assert_lazy_bound;
+
+/// Side length of the square blocks used by [`transpose`].
+const TRANSPOSE_BLOCK: usize = 16;
+
+/// The extra roots used by the four-step transforms.
+///
+/// The values are viewed as a `rows × cols` matrix in row-major order,
+/// with `rows = 2^row_bits` and `cols = 2^col_bits`.
+///
+/// The butterfly layers whose gap is at least `cols` only mix values
+/// in the same column, and they use the same roots for every column.
+/// These layers run as `cols` independent transforms of length `rows`
+/// on the transposed matrix, with the roots of the direct table.
+///
+/// The other layers only mix values in the same row, but every row needs
+/// its own roots. In the direct table those roots are scattered over
+/// `col_bits` blocks, so they're gathered here row by row to let the row
+/// pass read them sequentially.
+#[derive(Debug, Clone)]
+pub(super) struct FourStepRoots<T: Numeric> {
+    row_bits: u32,
+    col_bits: u32,
+    /// `cols - 1` forward roots for each row, layer after layer.
+    row_roots: Vec<ShoupFactor<T>>,
+    /// `cols - 1` inverse roots for each row, layer after layer.
+    inv_row_roots: Vec<ShoupFactor<T>>,
+}
+
+impl<T: Numeric> FourStepRoots<T> {
+    /// Gathers the row roots from the bit-reversed `root_powers`
+    /// and the scrambled `inv_root_powers` of a table with `2^log_n` values.
+    ///
+    /// `log_n` must be at least 2.
+    pub(super) fn new(
+        log_n: u32,
+        root_powers: &[ShoupFactor<T>],
+        inv_root_powers: &[ShoupFactor<T>],
+    ) -> Self {
+        debug_assert!(log_n >= 2);
+
+        let n = 1usize << log_n;
+        let col_bits = log_n >> 1;
+        let row_bits = log_n - col_bits;
+        let rows = 1usize << row_bits;
+        let cols = 1usize << col_bits;
+
+        let mut row_roots = Vec::with_capacity(rows * (cols - 1));
+        let mut inv_row_roots = Vec::with_capacity(rows * (cols - 1));
+
+        for row in 0..rows {
+            for layer in 0..col_bits {
+                let count = 1usize << layer;
+                let offset = (1usize << (row_bits + layer)) + row * count;
+                row_roots.extend_from_slice(&root_powers[offset..offset + count]);
+            }
+
+            for layer in 0..col_bits {
+                let count = cols >> (layer + 1);
+                let offset = 1 + n - (n >> layer) + row * count;
+                inv_row_roots.extend_from_slice(&inv_root_powers[offset..offset + count]);
+            }
+        }
+
+        Self {
+            row_bits,
+            col_bits,
+            row_roots,
+            inv_row_roots,
+        }
+    }
+
+    /// Performs the forward lazy transform, with the same outputs as
+    /// the direct radix-2 butterflies.
+    ///
+    /// The input is in `[0, 4*modulus)` and so is the output.
+    /// `scratch` holds the transposed values, it has the length of `poly`.
+    pub(super) fn lazy_transform<F>(
+        &self,
+        poly: &mut [T],
+        root_powers: &[ShoupFactor<T>],
+        scratch: &mut [T],
+    ) where
+        F: Field<ValueT = T>,
+    {
+        let rows = 1usize << self.row_bits;
+        let cols = 1usize << self.col_bits;
+        debug_assert_eq!(poly.len(), rows * cols);
+
+        let modulus_value = <F as Field>::MODULUS_VALUE;
+        let twice_modulus_value = modulus_value << 1u32;
+        let four_modulus_value = twice_modulus_value << 1u32;
+        debug_assert_lazy_bound(poly, four_modulus_value);
+
+        let butterfly = |i: &mut T, j: &mut T, root: ShoupFactor<T>| {
+            let u = twice_modulus_value.reduce_once(*i);
+            let v = modulus_value.lazy_reduce_mul(root, *j);
+            *i = u + v;
+            *j = u + twice_modulus_value - v;
+        };
+
+        transpose(poly, scratch, rows, cols);
+
+        let column_roots = &root_powers[1..rows];
+        for column in scratch.chunks_exact_mut(rows) {
+            let mut root_iter = column_roots.iter().copied();
+            for gap in (0..self.row_bits).rev().map(|x| 1usize << x) {
+                for vc in column.chunks_exact_mut(gap << 1) {
+                    let root = root_iter.next().unwrap();
+                    let (v0, v1) = vc.split_at_mut(gap);
+                    core::iter::zip(v0, v1).for_each(|(i, j)| butterfly(i, j, root));
+                }
+            }
+        }
+        debug_assert_lazy_bound(scratch, four_modulus_value);
+
+        transpose(scratch, poly, cols, rows);
+
+        for (row, roots) in poly
+            .chunks_exact_mut(cols)
+            .zip(self.row_roots.chunks_exact(cols - 1))
+        {
+            let mut root_iter = roots.iter().copied();
+            for gap in (0..self.col_bits).rev().map(|x| 1usize << x) {
+                for vc in row.chunks_exact_mut(gap << 1) {
+                    let root = root_iter.next().unwrap();
+                    let (v0, v1) = vc.split_at_mut(gap);
+                    core::iter::zip(v0, v1).for_each(|(i, j)| butterfly(i, j, root));
+                }
+            }
+        }
+        debug_assert_lazy_bound(poly, four_modulus_value);
+    }
+
+    /// Performs the inverse lazy transform, with the same outputs as
+    /// the direct radix-2 butterflies.
+    ///
+    /// The input is in `[0, 2*modulus)` and so is the output.
+    /// `scratch` holds the transposed values, it has the length of `values`.
+    pub(super) fn lazy_inverse_transform<F>(
+        &self,
+        values: &mut [T],
+        inv_root_powers: &[ShoupFactor<T>],
+        inv_n: ShoupFactor<T>,
+        scratch: &mut [T],
+    ) where
+        F: Field<ValueT = T>,
+    {
+        let rows = 1usize << self.row_bits;
+        let cols = 1usize << self.col_bits;
+        let n = rows * cols;
+        debug_assert_eq!(values.len(), n);
+
+        let modulus_value = <F as Field>::MODULUS_VALUE;
+        let twice_modulus_value = modulus_value << 1u32;
+        debug_assert_lazy_bound(values, twice_modulus_value);
+
+        let butterfly = |i: &mut T, j: &mut T, root: ShoupFactor<T>| {
+            let u = *i;
+            let v = *j;
+            *i = twice_modulus_value.reduce_add(u, v);
+            *j = modulus_value.lazy_reduce_mul(u + twice_modulus_value - v, root);
+        };
+
+        for (row, roots) in values
+            .chunks_exact_mut(cols)
+            .zip(self.inv_row_roots.chunks_exact(cols - 1))
+        {
+            let mut root_iter = roots.iter().copied();
+            for gap in (0..self.col_bits).map(|x| 1usize << x) {
+                for vc in row.chunks_exact_mut(gap << 1) {
+                    let root = root_iter.next().unwrap();
+                    let (v0, v1) = vc.split_at_mut(gap);
+                    core::iter::zip(v0, v1).for_each(|(i, j)| butterfly(i, j, root));
+                }
+            }
+        }
+        debug_assert_lazy_bound(values, twice_modulus_value);
+
+        transpose(values, scratch, rows, cols);
+
+        let scaled_r = modulus_value.reduce_mul(inv_n.value(), inv_root_powers[n - 1]);
+        let scaled_r = ShoupFactor::new(scaled_r, modulus_value);
+
+        for column in scratch.chunks_exact_mut(rows) {
+            for layer in self.col_bits..self.col_bits + self.row_bits - 1 {
+                let gap = 1usize << (layer - self.col_bits);
+                let offset = 1 + n - (n >> layer);
+                for (vc, &root) in column
+                    .chunks_exact_mut(gap << 1)
+                    .zip(&inv_root_powers[offset..])
+                {
+                    let (v0, v1) = vc.split_at_mut(gap);
+                    core::iter::zip(v0, v1).for_each(|(i, j)| butterfly(i, j, root));
+                }
+            }
+
+            let (v0, v1) = column.split_at_mut(rows >> 1);
+            for (i, j) in core::iter::zip(v0, v1) {
+                let u = *i;
+                let v = *j;
+                *i = modulus_value.lazy_reduce_mul(u + v, inv_n);
+                *j = modulus_value.lazy_reduce_mul(u + twice_modulus_value - v, scaled_r);
+            }
+        }
+
+        transpose(scratch, values, cols, rows);
+        debug_assert_lazy_bound(values, twice_modulus_value);
+    }
+}
+
+/// Writes the transpose of the `rows × cols` row-major matrix `src` into `dst`.
+///
+/// The matrix is walked in square blocks so that both sides stay in cache.
+fn transpose<T: Copy>(src: &[T], dst: &mut [T], rows: usize, cols: usize) {
+    debug_assert_eq!(src.len(), rows * cols);
+    debug_assert_eq!(dst.len(), rows * cols);
+
+    for row_start in (0..rows).step_by(TRANSPOSE_BLOCK) {
+        let row_end = (row_start + TRANSPOSE_BLOCK).min(rows);
+        for col_start in (0..cols).step_by(TRANSPOSE_BLOCK) {
+            let col_end = (col_start + TRANSPOSE_BLOCK).min(cols);
+            for row in row_start..row_end {
+                for col in col_start..col_end {
+                    dst[col * rows + row] = src[row * cols + col];
+                }
+            }
+        }
+    }
+}
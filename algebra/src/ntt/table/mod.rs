@@ -1,11 +1,32 @@
+#[cfg(all(feature = "std", target_arch = "x86_64"))]
+mod avx2;
 #[cfg(feature = "concrete-ntt")]
 mod concrete;
 mod field_ntt_table;
+mod four_step;
+mod naive;
+#[cfg(all(feature = "std", target_arch = "aarch64"))]
+mod neon;
 mod numeric_ntt_table;
+#[cfg(all(feature = "std", any(target_arch = "x86_64", target_arch = "aarch64")))]
+mod simd;
 
 #[cfg(feature = "concrete-ntt")]
 pub use concrete::prime32::Concrete32Table;
 #[cfg(feature = "concrete-ntt")]
 pub use concrete::prime64::Concrete64Table;
 pub use field_ntt_table::FieldTableWithShoupRoot;
+pub use naive::NaiveNttTable;
 pub use numeric_ntt_table::TableWithShoupRoot;
+
+/// Checks in debug builds that all `values` are less than `bound`.
+///
+/// The lazy forward butterflies keep every value in `[0, 4*modulus)` after
+/// each layer, and the lazy inverse butterflies keep them in `[0, 2*modulus)`.
+#[inline(always)]
+fn debug_assert_lazy_bound<T: PartialOrd + Copy + core::fmt::Debug>(values: &[T], bound: T) {
+    debug_assert!(
+        values.iter().all(|&v| v < bound),
+        "ntt values are out of the lazy bound {bound:?}"
+    );
+}
@@ -0,0 +1,270 @@
+use alloc::{boxed::Box, vec::Vec};
+
+use crate::{
+    arith::PrimitiveRoot,
+    ntt::{NttBackend, NttTable, NumberTheoryTransform},
+    polynomial::{FieldNttPolynomial, FieldPolynomial},
+    reduce::{Modulus, ReduceInv, ReduceOnce},
+    utils::ReverseLsbs,
+    AlgebraError, Field, NttField,
+};
+
+/// A reference ntt table which evaluates the polynomial at every root directly.
+///
+/// Every transform costs `O(n^2)` multiplications, so this table is only meant
+/// for checking other tables and running tests at tiny dimensions.
+///
+/// It uses the same root as [`FieldTableWithShoupRoot<F>`](super::FieldTableWithShoupRoot),
+/// the `i`-th output of the forward transform is the input polynomial evaluated
+/// at `root^(2*reverse_lsbs(i)+1)`.
+#[derive(Debug, Clone)]
+pub struct NaiveNttTable<F: Field> {
+    root: <F as Field>::ValueT,
+    log_n: u32,
+    n: usize,
+    inv_n: <F as Field>::ValueT,
+    /// `root^0, root^1, ..., root^(2n-1)`.
+    ordinal_root_powers: Vec<<F as Field>::ValueT>,
+    reverse_lsbs: Vec<usize>,
+}
+
+impl<F: Field> NaiveNttTable<F> {
+    /// Returns the root of this [`NaiveNttTable<F>`].
+    #[inline]
+    pub fn root(&self) -> <F as Field>::ValueT {
+        self.root
+    }
+
+    /// Returns the log n of this [`NaiveNttTable<F>`].
+    #[inline]
+    pub fn log_n(&self) -> u32 {
+        self.log_n
+    }
+
+    /// Returns the n of this [`NaiveNttTable<F>`].
+    #[inline]
+    pub fn n(&self) -> usize {
+        self.n
+    }
+
+    /// Returns the value of `n^(-1)` of this [`NaiveNttTable<F>`].
+    #[inline]
+    pub fn inv_degree(&self) -> <F as Field>::ValueT {
+        self.inv_n
+    }
+
+    /// Returns the root powers `root^0, root^1, ..., root^(2n-1)` of this [`NaiveNttTable<F>`].
+    #[inline]
+    pub fn ordinal_root_powers(&self) -> &[<F as Field>::ValueT] {
+        &self.ordinal_root_powers
+    }
+
+    /// Returns a reference to the reverse lsbs of this [`NaiveNttTable<F>`].
+    #[inline]
+    pub fn reverse_lsbs(&self) -> &[usize] {
+        &self.reverse_lsbs
+    }
+
+    /// Returns the root power `root^(exponent mod 2n)`.
+    #[inline]
+    fn root_power(&self, exponent: usize) -> <F as Field>::ValueT {
+        self.ordinal_root_powers[exponent & ((self.n << 1) - 1)]
+    }
+
+    /// Evaluates `poly` at `root^(2*reverse_lsbs(i)+1)` for every `i`.
+    ///
+    /// The input is in `[0, 4*modulus)`, the output is in `[0, modulus)`.
+    fn evaluate(&self, poly: &mut [<F as Field>::ValueT]) {
+        crate::count::ntt::increment();
+        debug_assert_eq!(poly.len(), self.n);
+        let modulus_value = <F as Field>::MODULUS_VALUE;
+        let twice_modulus_value = modulus_value << 1u32;
+        let coeffs: Vec<_> = poly
+            .iter()
+            .map(|&c| modulus_value.reduce_once(twice_modulus_value.reduce_once(c)))
+            .collect();
+
+        for (v, &i) in poly.iter_mut().zip(&self.reverse_lsbs) {
+            let step = 2 * i + 1;
+            *v = coeffs.iter().enumerate().fold(F::ZERO, |acc, (j, &c)| {
+                F::mul_add(c, self.root_power(step * j), acc)
+            });
+        }
+    }
+
+    /// Interpolates the polynomial from the values at `root^(2*reverse_lsbs(i)+1)`,
+    /// multiplied by `scalar / inv_n`.
+    ///
+    /// The input is in `[0, 2*modulus)`, the output is in `[0, modulus)`.
+    fn interpolate(&self, values: &mut [<F as Field>::ValueT], scalar: <F as Field>::ValueT) {
+        crate::count::inverse_ntt::increment();
+        debug_assert_eq!(values.len(), self.n);
+        let modulus_value = <F as Field>::MODULUS_VALUE;
+        let evaluations: Vec<_> = values
+            .iter()
+            .map(|&v| modulus_value.reduce_once(v))
+            .collect();
+
+        for (j, c) in values.iter_mut().enumerate() {
+            let sum = evaluations
+                .iter()
+                .zip(&self.reverse_lsbs)
+                .fold(F::ZERO, |acc, (&v, &i)| {
+                    F::mul_add(v, self.root_power(((2 * i + 1) * j).wrapping_neg()), acc)
+                });
+            *c = F::mul(sum, scalar);
+        }
+    }
+}
+
+impl<F: NttField> NttTable for NaiveNttTable<F> {
+    type ValueT = <F as Field>::ValueT;
+
+    fn new<M>(modulus: M, log_n: u32) -> Result<Self, AlgebraError>
+    where
+        M: Modulus<Self::ValueT> + PrimitiveRoot<Self::ValueT>,
+    {
+        let n = 1usize << log_n;
+        let modulus_value = F::MODULUS_VALUE;
+
+        let root = modulus.try_minimal_primitive_root(log_n + 1)?;
+        let ordinal_root_powers = F::pow_table(root, n * 2 - 1);
+        let reverse_lsbs: Vec<usize> = (0..n).map(|i| i.reverse_lsbs(log_n)).collect();
+
+        let n_cast =
+            <<F as Field>::ValueT>::try_from(n).map_err(|_| AlgebraError::DegreeConversionErr {
+                degree: n,
+                modulus: Box::new(modulus_value),
+            })?;
+
+        if n_cast >= modulus_value {
+            return Err(AlgebraError::TooLargeDegreeErr {
+                degree: n,
+                modulus: Box::new(modulus_value),
+            });
+        }
+
+        Ok(Self {
+            root,
+            log_n,
+            n,
+            inv_n: modulus_value.reduce_inv(n_cast),
+            ordinal_root_powers,
+            reverse_lsbs,
+        })
+    }
+
+    #[inline]
+    fn dimension(&self) -> usize {
+        self.n
+    }
+}
+
+impl<F: NttField> NumberTheoryTransform for NaiveNttTable<F> {
+    type CoeffPoly = FieldPolynomial<F>;
+
+    type NttPoly = FieldNttPolynomial<F>;
+
+    #[inline]
+    fn transform_inplace(&self, mut poly: Self::CoeffPoly) -> Self::NttPoly {
+        self.evaluate(poly.as_mut_slice());
+        <FieldNttPolynomial<F>>::new(poly.inner_data())
+    }
+
+    #[inline]
+    fn inverse_transform_inplace(&self, mut values: Self::NttPoly) -> Self::CoeffPoly {
+        self.interpolate(values.as_mut_slice(), self.inv_n);
+        <FieldPolynomial<F>>::new(values.inner_data())
+    }
+
+    #[inline]
+    fn lazy_transform_slice(&self, poly: &mut [<Self as NttTable>::ValueT]) {
+        self.evaluate(poly);
+    }
+
+    #[inline]
+    fn transform_slice(&self, poly: &mut [<Self as NttTable>::ValueT]) {
+        self.evaluate(poly);
+    }
+
+    #[inline]
+    fn lazy_inverse_transform_slice(&self, values: &mut [<Self as NttTable>::ValueT]) {
+        self.interpolate(values, self.inv_n);
+    }
+
+    #[inline]
+    fn inverse_transform_slice(&self, values: &mut [<Self as NttTable>::ValueT]) {
+        self.interpolate(values, self.inv_n);
+    }
+
+    #[inline]
+    fn inverse_transform_scaled(
+        &self,
+        mut values: Self::NttPoly,
+        extra: <Self as NttTable>::ValueT,
+    ) -> Self::CoeffPoly {
+        self.inverse_transform_scaled_slice(values.as_mut_slice(), extra);
+        <FieldPolynomial<F>>::new(values.inner_data())
+    }
+
+    #[inline]
+    fn inverse_transform_scaled_slice(
+        &self,
+        values: &mut [<Self as NttTable>::ValueT],
+        extra: <Self as NttTable>::ValueT,
+    ) {
+        self.interpolate(values, F::mul(self.inv_n, extra));
+    }
+
+    fn transform_monomial(
+        &self,
+        coeff: Self::ValueT,
+        degree: usize,
+        values: &mut [<Self as NttTable>::ValueT],
+    ) {
+        debug_assert_eq!(values.len(), self.n);
+        for (v, &i) in values.iter_mut().zip(&self.reverse_lsbs) {
+            *v = F::mul(coeff, self.root_power((2 * i + 1) * degree));
+        }
+    }
+
+    #[inline]
+    fn transform_coeff_one_monomial(
+        &self,
+        degree: usize,
+        values: &mut [<Self as NttTable>::ValueT],
+    ) {
+        self.transform_monomial(F::ONE, degree, values);
+    }
+
+    #[inline]
+    fn transform_coeff_minus_one_monomial(
+        &self,
+        degree: usize,
+        values: &mut [<Self as NttTable>::ValueT],
+    ) {
+        self.transform_monomial(F::MINUS_ONE, degree, values);
+    }
+}
+
+impl<F: NttField> NttBackend<F> for NaiveNttTable<F> {
+    #[inline]
+    fn forward(&self, values: &mut [<F as Field>::ValueT]) {
+        self.evaluate(values);
+    }
+
+    #[inline]
+    fn forward_lazy(&self, values: &mut [<F as Field>::ValueT]) {
+        self.evaluate(values);
+    }
+
+    #[inline]
+    fn inverse(&self, values: &mut [<F as Field>::ValueT]) {
+        self.interpolate(values, self.inv_n);
+    }
+
+    #[inline]
+    fn degree(&self) -> usize {
+        self.n
+    }
+}
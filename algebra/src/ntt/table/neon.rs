@@ -0,0 +1,138 @@
+//! NEON butterflies for the number theory transform of `u32` values.
+//!
+//! The table checks for NEON once, when it picks its kernel. The transforms
+//! come from [`impl_simd_transform`], so they give exactly the same outputs as
+//! the scalar butterflies.
+
+use core::any::TypeId;
+
+use super::simd::{cast, cast_slice, cast_slice_mut, impl_simd_transform};
+use crate::{modulus::ShoupFactor, numeric::Numeric};
+
+/// Returns `true` if the NEON butterflies support the values `T`, i.e. `T` is `u32`.
+pub(super) fn supports<T: 'static>() -> bool {
+    TypeId::of::<T>() == TypeId::of::<u32>()
+}
+
+/// Performs the forward lazy transform with NEON.
+///
+/// # Safety
+///
+/// The cpu must support NEON and [`supports::<T>`] must be `true`.
+pub(super) unsafe fn lazy_transform<T: Numeric>(
+    values: &mut [T],
+    roots: &[ShoupFactor<T>],
+    modulus: T,
+    log_n: u32,
+) {
+    if let (Some(values), Some(roots), Some(modulus)) =
+        (cast_slice_mut(values), cast_slice(roots), cast(modulus))
+    {
+        u32x4::lazy_transform(values, roots, modulus, log_n)
+    } else {
+        unreachable!("the neon butterflies only support u32")
+    }
+}
+
+/// Performs the inverse lazy transform with NEON.
+///
+/// `scaled_root` is the root of the last layer multiplied by `inv_n`.
+///
+/// # Safety
+///
+/// The cpu must support NEON and [`supports::<T>`] must be `true`.
+pub(super) unsafe fn lazy_inverse_transform<T: Numeric>(
+    values: &mut [T],
+    roots: &[ShoupFactor<T>],
+    inv_n: ShoupFactor<T>,
+    scaled_root: ShoupFactor<T>,
+    modulus: T,
+    log_n: u32,
+) {
+    if let (Some(values), Some(roots), Some(inv_n), Some(scaled_root), Some(modulus)) = (
+        cast_slice_mut(values),
+        cast_slice(roots),
+        cast(inv_n),
+        cast(scaled_root),
+        cast(modulus),
+    ) {
+        u32x4::lazy_inverse_transform(values, roots, inv_n, scaled_root, modulus, log_n)
+    } else {
+        unreachable!("the neon butterflies only support u32")
+    }
+}
+
+/// Four `u32` lanes.
+///
+/// The modulus is less than `2^30`, so every lazy value fits in a lane.
+mod u32x4 {
+    use core::arch::aarch64::*;
+
+    use crate::{
+        modulus::ShoupFactor,
+        reduce::{LazyReduceMul, ReduceAdd, ReduceOnce},
+    };
+
+    #[inline]
+    #[target_feature(enable = "neon")]
+    unsafe fn splat(value: u32) -> uint32x4_t {
+        vdupq_n_u32(value)
+    }
+
+    #[inline]
+    #[target_feature(enable = "neon")]
+    unsafe fn load(values: &[u32]) -> uint32x4_t {
+        debug_assert_eq!(values.len(), 4);
+        vld1q_u32(values.as_ptr())
+    }
+
+    #[inline]
+    #[target_feature(enable = "neon")]
+    unsafe fn store(values: &mut [u32], v: uint32x4_t) {
+        debug_assert_eq!(values.len(), 4);
+        vst1q_u32(values.as_mut_ptr(), v)
+    }
+
+    #[inline]
+    #[target_feature(enable = "neon")]
+    unsafe fn add(a: uint32x4_t, b: uint32x4_t) -> uint32x4_t {
+        vaddq_u32(a, b)
+    }
+
+    #[inline]
+    #[target_feature(enable = "neon")]
+    unsafe fn sub(a: uint32x4_t, b: uint32x4_t) -> uint32x4_t {
+        vsubq_u32(a, b)
+    }
+
+    /// `a - m` if `a >= m`, for `a < 2m`.
+    #[inline]
+    #[target_feature(enable = "neon")]
+    unsafe fn reduce_once(a: uint32x4_t, m: uint32x4_t) -> uint32x4_t {
+        vminq_u32(a, vsubq_u32(a, m))
+    }
+
+    /// The high halves of the lane products.
+    #[inline]
+    #[target_feature(enable = "neon")]
+    unsafe fn mul_hw(a: uint32x4_t, b: uint32x4_t) -> uint32x4_t {
+        let low = vmull_u32(vget_low_u32(a), vget_low_u32(b));
+        let high = vmull_high_u32(a, b);
+        vuzp2q_u32(vreinterpretq_u32_u64(low), vreinterpretq_u32_u64(high))
+    }
+
+    /// Shoup's lazy multiplication `w * b (mod p)`, the result is in `[0, 2p)`.
+    #[inline]
+    #[target_feature(enable = "neon")]
+    unsafe fn lazy_mul(
+        w: uint32x4_t,
+        w_quotient: uint32x4_t,
+        b: uint32x4_t,
+        p: uint32x4_t,
+    ) -> uint32x4_t {
+        let hw = mul_hw(w_quotient, b);
+        vmlsq_u32(vmulq_u32(w, b), p, hw)
+    }
+
+    super::impl_simd_transform!("neon", u32, 4);
+}
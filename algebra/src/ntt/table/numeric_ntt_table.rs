@@ -1,3 +1,5 @@
+use alloc::{boxed::Box, vec, vec::Vec};
+
 use num_traits::ConstOne;
 
 use crate::arith::PrimitiveRoot;
@@ -8,6 +10,8 @@ use crate::polynomial::{NttPolynomial, Polynomial};
 use crate::reduce::{LazyReduceMul, Modulus, ReduceMul, ReduceMulAssign};
 use crate::{utils::ReverseLsbs, AlgebraError};
 
+use super::debug_assert_lazy_bound;
+
 /// This struct store the pre-computed data for number theory transform and
 /// inverse number theory transform.
 ///
@@ -81,6 +85,12 @@ impl<T: Numeric> TableWithShoupRoot<T> {
         self.inv_n
     }
 
+    /// Returns the value of `n^(-1)` of this [`TableWithShoupRoot<T>`].
+    #[inline]
+    pub fn inv_degree(&self) -> T {
+        self.inv_n.value()
+    }
+
     /// Returns a reference to the root powers of this [`TableWithShoupRoot<T>`].
     #[inline]
     pub fn root_powers(&self) -> &[ShoupFactor<T>] {
@@ -104,6 +114,49 @@ impl<T: Numeric> TableWithShoupRoot<T> {
     pub fn reverse_lsbs(&self) -> &[usize] {
         &self.reverse_lsbs
     }
+
+    /// The lazy inverse transform, with the last layer scaled by `scalar` instead of `inv_n`,
+    /// so that `scalar = inv_n * extra` folds in an extra factor.
+    fn lazy_inverse_transform_slice_by(&self, values: &mut [T], scalar: ShoupFactor<T>) {
+        debug_assert_eq!(values.len(), self.n);
+
+        let log_n = self.log_n;
+
+        let modulus_value = self.modulus_value;
+        let twice_modulus_value = modulus_value << 1u32;
+        debug_assert_lazy_bound(values, twice_modulus_value);
+
+        let roots = self.inv_root_powers();
+        let mut root_iter = roots[1..].iter().copied();
+
+        for gap in (0..log_n - 1).map(|x| 1usize << x) {
+            for vc in values.chunks_exact_mut(gap << 1) {
+                let root = root_iter.next().unwrap();
+                let (v0, v1) = vc.split_at_mut(gap);
+                for (i, j) in core::iter::zip(v0, v1) {
+                    let u = *i;
+                    let v = *j;
+                    *i = twice_modulus_value.reduce_add(u, v);
+                    *j = modulus_value.lazy_reduce_mul(u + twice_modulus_value - v, root);
+                }
+            }
+            debug_assert_lazy_bound(values, twice_modulus_value);
+        }
+
+        let gap = 1 << (log_n - 1);
+
+        let scaled_r = modulus_value.reduce_mul(scalar.value(), root_iter.next().unwrap());
+        let scaled_r = ShoupFactor::new(scaled_r, modulus_value);
+
+        let (v0, v1) = values.split_at_mut(gap);
+        for (i, j) in core::iter::zip(v0, v1) {
+            let u = *i;
+            let v = *j;
+            *i = modulus_value.lazy_reduce_mul(u + v, scalar);
+            *j = modulus_value.lazy_reduce_mul(u + twice_modulus_value - v, scaled_r);
+        }
+        debug_assert_lazy_bound(values, twice_modulus_value);
+    }
 }
 
 impl<T: Numeric> NttTable for TableWithShoupRoot<T> {
@@ -209,10 +262,13 @@ impl<T: Numeric> NumberTheoryTransform for TableWithShoupRoot<T> {
 
     #[inline]
     fn lazy_transform_slice(&self, poly: &mut [T]) {
+        crate::count::ntt::increment();
         debug_assert_eq!(poly.len(), self.n);
 
         let modulus_value = self.modulus_value;
         let twice_modulus_value = modulus_value << 1u32;
+        let four_modulus_value = twice_modulus_value << 1u32;
+        debug_assert_lazy_bound(poly, four_modulus_value);
 
         let roots = self.root_powers();
         let mut root_iter = roots[1..].iter().copied();
@@ -228,6 +284,7 @@ impl<T: Numeric> NumberTheoryTransform for TableWithShoupRoot<T> {
                     *j = u + twice_modulus_value - v;
                 }
             }
+            debug_assert_lazy_bound(poly, four_modulus_value);
         }
     }
 
@@ -245,49 +302,37 @@ impl<T: Numeric> NumberTheoryTransform for TableWithShoupRoot<T> {
 
     #[inline]
     fn lazy_inverse_transform_slice(&self, values: &mut [Self::ValueT]) {
-        debug_assert_eq!(values.len(), self.n);
+        crate::count::inverse_ntt::increment();
+        self.lazy_inverse_transform_slice_by(values, self.inv_n);
+    }
 
-        let log_n = self.log_n;
+    #[inline]
+    fn inverse_transform_slice(&self, values: &mut [Self::ValueT]) {
+        self.lazy_inverse_transform_slice(values);
 
         let modulus_value = self.modulus_value;
-        let twice_modulus_value = modulus_value << 1u32;
-
-        let roots = self.inv_root_powers();
-        let mut root_iter = roots[1..].iter().copied();
-
-        for gap in (0..log_n - 1).map(|x| 1usize << x) {
-            for vc in values.chunks_exact_mut(gap << 1) {
-                let root = root_iter.next().unwrap();
-                let (v0, v1) = vc.split_at_mut(gap);
-                for (i, j) in core::iter::zip(v0, v1) {
-                    let u = *i;
-                    let v = *j;
-                    *i = twice_modulus_value.reduce_add(u, v);
-                    *j = modulus_value.lazy_reduce_mul(u + twice_modulus_value - v, root);
-                }
-            }
-        }
-
-        let gap = 1 << (log_n - 1);
-
-        let scalar = self.inv_n();
-        let scaled_r = modulus_value.reduce_mul(scalar.value(), root_iter.next().unwrap());
-        let scaled_r = ShoupFactor::new(scaled_r, modulus_value);
-
-        let (v0, v1) = values.split_at_mut(gap);
-        for (i, j) in core::iter::zip(v0, v1) {
-            let u = *i;
-            let v = *j;
-            *i = modulus_value.lazy_reduce_mul(u + v, scalar);
-            *j = modulus_value.lazy_reduce_mul(u + twice_modulus_value - v, scaled_r);
-        }
+        values.iter_mut().for_each(|v| {
+            modulus_value.reduce_once_assign(v);
+        });
     }
 
     #[inline]
-    fn inverse_transform_slice(&self, values: &mut [Self::ValueT]) {
-        self.lazy_inverse_transform_slice(values);
+    fn inverse_transform_scaled(
+        &self,
+        mut values: Self::NttPoly,
+        extra: Self::ValueT,
+    ) -> Self::CoeffPoly {
+        self.inverse_transform_scaled_slice(values.as_mut_slice(), extra);
+        Self::CoeffPoly::new(values.inner_vec())
+    }
 
+    #[inline]
+    fn inverse_transform_scaled_slice(&self, values: &mut [Self::ValueT], extra: Self::ValueT) {
+        crate::count::inverse_ntt::increment();
         let modulus_value = self.modulus_value;
+        let scalar = modulus_value.reduce_mul(extra, self.inv_n);
+        self.lazy_inverse_transform_slice_by(values, ShoupFactor::new(scalar, modulus_value));
+
         values.iter_mut().for_each(|v| {
             modulus_value.reduce_once_assign(v);
         });
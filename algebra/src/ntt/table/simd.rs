@@ -0,0 +1,149 @@
+//! Shared pieces of the SIMD butterflies.
+//!
+//! Each backend provides the lane operations of one vector type and
+//! expands [`impl_simd_transform`] to get transforms that follow the scalar
+//! lazy butterflies of [`FieldTableWithShoupRoot`] step by step.
+//! Layers whose gap is narrower than a vector run the scalar butterflies.
+//!
+//! [`FieldTableWithShoupRoot`]: super::FieldTableWithShoupRoot
+
+use core::any::TypeId;
+
+/// Reinterprets `values` as a slice of `U` if `T` is `U`.
+#[inline]
+pub(super) fn cast_slice_mut<T: 'static, U: 'static>(values: &mut [T]) -> Option<&mut [U]> {
+    (TypeId::of::<T>() == TypeId::of::<U>()).then(|| {
+        // SAFETY: `T` and `U` are the same type.
+        unsafe { core::slice::from_raw_parts_mut(values.as_mut_ptr().cast(), values.len()) }
+    })
+}
+
+/// Reinterprets `values` as a slice of `U` if `T` is `U`.
+#[inline]
+pub(super) fn cast_slice<T: 'static, U: 'static>(values: &[T]) -> Option<&[U]> {
+    (TypeId::of::<T>() == TypeId::of::<U>()).then(|| {
+        // SAFETY: `T` and `U` are the same type.
+        unsafe { core::slice::from_raw_parts(values.as_ptr().cast(), values.len()) }
+    })
+}
+
+/// Reinterprets `value` as a `U` if `T` is `U`.
+#[inline]
+pub(super) fn cast<T: 'static + Copy, U: 'static + Copy>(value: T) -> Option<U> {
+    cast_slice::<T, U>(core::slice::from_ref(&value)).map(|v| v[0])
+}
+
+/// Generates the transforms from the lane operations in scope,
+/// compiled with the target feature `$feature`:
+/// `splat`, `load`, `store`, `add`, `sub`, `reduce_once` and `lazy_mul`.
+macro_rules! impl_simd_transform {
+    ($feature:literal, $ValueT:ty, $LANES:expr) => {
+        const LANES: usize = $LANES;
+
+        #[target_feature(enable = $feature)]
+        pub(super) unsafe fn lazy_transform(
+            values: &mut [$ValueT],
+            roots: &[ShoupFactor<$ValueT>],
+            modulus: $ValueT,
+            log_n: u32,
+        ) {
+            debug_assert_eq!(values.len(), 1 << log_n);
+
+            let twice_modulus = modulus << 1u32;
+            let p = splat(modulus);
+            let twice_p = splat(twice_modulus);
+
+            for layer in 0..log_n {
+                let gap = 1usize << (log_n - 1 - layer);
+                let layer_roots = &roots[1 << layer..2 << layer];
+                for (vc, &root) in values.chunks_exact_mut(gap << 1).zip(layer_roots) {
+                    let (v0, v1) = vc.split_at_mut(gap);
+                    if gap >= LANES {
+                        let w = splat(root.value());
+                        let w_quotient = splat(root.quotient());
+                        for (i, j) in v0.chunks_exact_mut(LANES).zip(v1.chunks_exact_mut(LANES)) {
+                            let u = reduce_once(load(i), twice_p);
+                            let v = lazy_mul(w, w_quotient, load(j), p);
+                            store(i, add(u, v));
+                            store(j, sub(add(u, twice_p), v));
+                        }
+                    } else {
+                        for (i, j) in core::iter::zip(v0, v1) {
+                            let u = twice_modulus.reduce_once(*i);
+                            let v = modulus.lazy_reduce_mul(root, *j);
+                            *i = u + v;
+                            *j = u + twice_modulus - v;
+                        }
+                    }
+                }
+            }
+        }
+
+        #[target_feature(enable = $feature)]
+        pub(super) unsafe fn lazy_inverse_transform(
+            values: &mut [$ValueT],
+            roots: &[ShoupFactor<$ValueT>],
+            inv_n: ShoupFactor<$ValueT>,
+            scaled_root: ShoupFactor<$ValueT>,
+            modulus: $ValueT,
+            log_n: u32,
+        ) {
+            let n = values.len();
+            debug_assert_eq!(n, 1 << log_n);
+
+            let twice_modulus = modulus << 1u32;
+            let p = splat(modulus);
+            let twice_p = splat(twice_modulus);
+
+            for layer in 0..log_n - 1 {
+                let gap = 1usize << layer;
+                let offset = 1 + n - (n >> layer);
+                let layer_roots = &roots[offset..offset + (n >> (layer + 1))];
+                for (vc, &root) in values.chunks_exact_mut(gap << 1).zip(layer_roots) {
+                    let (v0, v1) = vc.split_at_mut(gap);
+                    if gap >= LANES {
+                        let w = splat(root.value());
+                        let w_quotient = splat(root.quotient());
+                        for (i, j) in v0.chunks_exact_mut(LANES).zip(v1.chunks_exact_mut(LANES)) {
+                            let u = load(i);
+                            let v = load(j);
+                            store(i, reduce_once(add(u, v), twice_p));
+                            store(j, lazy_mul(w, w_quotient, sub(add(u, twice_p), v), p));
+                        }
+                    } else {
+                        for (i, j) in core::iter::zip(v0, v1) {
+                            let u = *i;
+                            let v = *j;
+                            *i = twice_modulus.reduce_add(u, v);
+                            *j = modulus.lazy_reduce_mul(u + twice_modulus - v, root);
+                        }
+                    }
+                }
+            }
+
+            let gap = n >> 1;
+            let (v0, v1) = values.split_at_mut(gap);
+            if gap >= LANES {
+                let s = splat(inv_n.value());
+                let s_quotient = splat(inv_n.quotient());
+                let r = splat(scaled_root.value());
+                let r_quotient = splat(scaled_root.quotient());
+                for (i, j) in v0.chunks_exact_mut(LANES).zip(v1.chunks_exact_mut(LANES)) {
+                    let u = load(i);
+                    let v = load(j);
+                    store(i, lazy_mul(s, s_quotient, add(u, v), p));
+                    store(j, lazy_mul(r, r_quotient, sub(add(u, twice_p), v), p));
+                }
+            } else {
+                for (i, j) in core::iter::zip(v0, v1) {
+                    let u = *i;
+                    let v = *j;
+                    *i = modulus.lazy_reduce_mul(u + v, inv_n);
+                    *j = modulus.lazy_reduce_mul(u + twice_modulus - v, scaled_root);
+                }
+            }
+        }
+    };
+}
+
+pub(super) use impl_simd_transform;
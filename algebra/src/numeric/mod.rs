@@ -1,5 +1,7 @@
 //! Defines some traits for extended [UnsignedInteger].
 
+use subtle::ConditionallySelectable;
+
 use crate::integer::{AsFrom, UnsignedInteger};
 
 mod widening;
@@ -7,7 +9,9 @@ mod widening;
 pub use widening::*;
 
 /// [UnsignedInteger] extended trait, insure some arithmetic operation.
-pub trait Numeric: UnsignedInteger + WideningMul + CarryingMul + AsFrom<Self::WideT> {
+pub trait Numeric:
+    UnsignedInteger + WideningMul + CarryingMul + AsFrom<Self::WideT> + ConditionallySelectable
+{
     /// Wide type for multiplication and division.
     type WideT: UnsignedInteger + AsFrom<Self>;
 }
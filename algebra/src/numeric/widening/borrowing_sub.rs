@@ -1,4 +1,4 @@
-use std::ops::Sub;
+use core::ops::Sub;
 
 /// Borrowing sub operation trait
 pub trait BorrowingSub: Sized + Sub<Self, Output = Self> {
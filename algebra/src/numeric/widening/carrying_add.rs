@@ -1,4 +1,4 @@
-use std::ops::Add;
+use core::ops::Add;
 
 /// Carrying add operation trait
 pub trait CarryingAdd: Sized + Add<Self, Output = Self> {
@@ -1,4 +1,4 @@
-use std::ops::{Add, Mul};
+use core::ops::{Add, Mul};
 
 /// Carrying mul operation trait.
 pub trait CarryingMul: Sized + Mul<Self, Output = Self> + Add<Self, Output = Self> {
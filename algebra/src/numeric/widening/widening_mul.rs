@@ -1,4 +1,4 @@
-use std::ops::Mul;
+use core::ops::Mul;
 
 /// Widening mul operation trait.
 pub trait WideningMul: Sized + Mul<Self, Output = Self> {
@@ -0,0 +1,122 @@
+//! Packs a vector of `Z_t` values into the slots of one plaintext polynomial.
+//!
+//! When `t` is a prime with `t ≡ 1 (mod 2n)`, `X^n + 1` splits into `n` linear factors over `Z_t`,
+//! so `Z_t[X]/(X^n + 1)` is isomorphic to `n` copies of `Z_t`.
+//! The isomorphism is the number theory transform over `Z_t`,
+//! which is independent of the field used by the ciphertexts.
+
+use alloc::{vec, vec::Vec};
+
+use crate::{
+    modulus::BarrettModulus,
+    ntt::{NttTable, NumberTheoryTransform, TableWithShoupRoot},
+    AlgebraError,
+};
+
+use super::Polynomial;
+
+/// Encodes and decodes slot vectors for the plaintext modulus `t` and the ring dimension `n`.
+///
+/// The `i`-th slot is the evaluation of the plaintext at the `i`-th root of `X^n + 1`,
+/// in the output order of the number theory transform.
+/// Adding or multiplying (mod `X^n + 1`) two encoded polynomials
+/// adds or multiplies their slots element-wise.
+#[derive(Clone)]
+pub struct BatchEncoder {
+    t: u64,
+    table: TableWithShoupRoot<u64>,
+}
+
+impl BatchEncoder {
+    /// Creates a new [`BatchEncoder`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `t` has no primitive `2n`-th root of unity,
+    /// which happens when `t` is not a prime with `t ≡ 1 (mod 2n)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is not a power of two.
+    pub fn new(t: u64, n: usize) -> Result<Self, AlgebraError> {
+        assert!(
+            n.is_power_of_two(),
+            "ring dimension {n} is not a power of two"
+        );
+        let modulus = <BarrettModulus<u64>>::new(t);
+        let table = <TableWithShoupRoot<u64>>::new(modulus, n.trailing_zeros())?;
+        Ok(Self { t, table })
+    }
+
+    /// Returns the plaintext modulus `t`.
+    #[inline]
+    pub fn plain_modulus(&self) -> u64 {
+        self.t
+    }
+
+    /// Returns the number of slots, which equals the ring dimension `n`.
+    #[inline]
+    pub fn slot_count(&self) -> usize {
+        self.table.n()
+    }
+
+    /// Encodes `values` into a polynomial with coefficients in `[0, t)`.
+    ///
+    /// Missing slots are filled with `0`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are more values than slots, or if any value is not less than `t`.
+    pub fn encode(&self, values: &[u64]) -> Polynomial<u64> {
+        let n = self.slot_count();
+        assert!(
+            values.len() <= n,
+            "{} values exceed the slot count {n}",
+            values.len()
+        );
+        assert!(
+            values.iter().all(|&v| v < self.t),
+            "slot value is not less than the plaintext modulus {}",
+            self.t
+        );
+
+        let mut data = vec![0u64; n];
+        data[..values.len()].copy_from_slice(values);
+        self.table.inverse_transform_slice(&mut data);
+        Polynomial::new(data)
+    }
+
+    /// Decodes the slots of `poly`, whose coefficients should be in `[0, t)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the coefficient count of `poly` is not the slot count.
+    pub fn decode(&self, poly: &Polynomial<u64>) -> Vec<u64> {
+        assert_eq!(
+            poly.coeff_count(),
+            self.slot_count(),
+            "polynomial coefficient count mismatches the slot count"
+        );
+        let mut data = poly.as_slice().to_vec();
+        self.table.transform_slice(&mut data);
+        data
+    }
+}
+
+/// Encodes `values` into one polynomial of `Z_t[X]/(X^n + 1)`.
+///
+/// See [`BatchEncoder`] for the slot layout;
+/// build the encoder once when encoding many vectors.
+#[inline]
+pub fn batch_encode(values: &[u64], t: u64, n: usize) -> Result<Polynomial<u64>, AlgebraError> {
+    Ok(BatchEncoder::new(t, n)?.encode(values))
+}
+
+/// Decodes the slots of a polynomial of `Z_t[X]/(X^n + 1)`.
+///
+/// See [`BatchEncoder`] for the slot layout;
+/// build the encoder once when decoding many polynomials.
+#[inline]
+pub fn batch_decode(poly: &Polynomial<u64>, t: u64, n: usize) -> Result<Vec<u64>, AlgebraError> {
+    Ok(BatchEncoder::new(t, n)?.decode(poly))
+}
@@ -1,6 +1,6 @@
 use core::ops::{Add, AddAssign};
 
-use crate::Field;
+use crate::{polynomial::poly_add_assign_slice, Field};
 
 use super::FieldPolynomial;
 
@@ -19,20 +19,14 @@ impl<F: Field> FieldPolynomial<F> {
 impl<F: Field> AddAssign for FieldPolynomial<F> {
     #[inline]
     fn add_assign(&mut self, rhs: Self) {
-        debug_assert_eq!(self.coeff_count(), rhs.coeff_count());
-        self.iter_mut()
-            .zip(rhs)
-            .for_each(|(a, b)| F::add_assign(a, b));
+        poly_add_assign_slice::<F>(self.as_mut_slice(), rhs.as_slice());
     }
 }
 
 impl<F: Field> AddAssign<&Self> for FieldPolynomial<F> {
     #[inline]
     fn add_assign(&mut self, rhs: &Self) {
-        debug_assert_eq!(self.coeff_count(), rhs.coeff_count());
-        self.iter_mut()
-            .zip(rhs)
-            .for_each(|(a, &b)| F::add_assign(a, b));
+        poly_add_assign_slice::<F>(self.as_mut_slice(), rhs.as_slice());
     }
 }
 
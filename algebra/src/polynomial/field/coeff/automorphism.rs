@@ -0,0 +1,46 @@
+use crate::Field;
+
+use super::FieldPolynomial;
+
+impl<F: Field> FieldPolynomial<F> {
+    /// Applies the Galois automorphism `X -> X^k` on `self`,
+    /// i.e. maps `a_i X^i` to `a_i X^(ik mod 2n)` in `Z_q[X]/(X^n + 1)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `k` is not odd, or the coefficient count is not a power of two.
+    #[inline]
+    pub fn apply_automorphism(&self, k: usize) -> Self {
+        let mut destination = Self::zero(self.coeff_count());
+        self.apply_automorphism_inplace(k, &mut destination);
+        destination
+    }
+
+    /// Applies the Galois automorphism `X -> X^k` on `self`,
+    /// and stores the result into `destination`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `k` is not odd, or the coefficient count is not a power of two.
+    pub fn apply_automorphism_inplace(&self, k: usize, destination: &mut Self) {
+        let n = self.coeff_count();
+        assert!(n.is_power_of_two());
+        assert_eq!(n, destination.coeff_count());
+        assert!(
+            k & 1 == 1,
+            "automorphism degree {k} is not coprime to {}",
+            n << 1
+        );
+
+        let mask = (n << 1) - 1;
+        let k = k & mask;
+        for (i, &c) in self.iter().enumerate() {
+            let j = (i * k) & mask;
+            if j < n {
+                destination[j] = c;
+            } else {
+                destination[j - n] = F::neg(c);
+            }
+        }
+    }
+}
@@ -1,3 +1,4 @@
+use alloc::vec::Vec;
 use core::{
     ops::{Index, IndexMut},
     slice::SliceIndex,
@@ -40,7 +41,7 @@ impl<F: Field> AsMut<[<F as Field>::ValueT]> for FieldPolynomial<F> {
 impl<F: Field> IntoIterator for FieldPolynomial<F> {
     type Item = <F as Field>::ValueT;
 
-    type IntoIter = std::vec::IntoIter<<F as Field>::ValueT>;
+    type IntoIter = alloc::vec::IntoIter<<F as Field>::ValueT>;
 
     #[inline]
     fn into_iter(self) -> Self::IntoIter {
@@ -69,3 +70,19 @@ impl<'a, F: Field> IntoIterator for &'a mut FieldPolynomial<F> {
         self.data.iter_mut()
     }
 }
+
+impl<F: Field> From<Vec<<F as Field>::ValueT>> for FieldPolynomial<F> {
+    #[inline]
+    fn from(data: Vec<<F as Field>::ValueT>) -> Self {
+        Self { data }
+    }
+}
+
+impl<F: Field> FromIterator<<F as Field>::ValueT> for FieldPolynomial<F> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = <F as Field>::ValueT>>(iter: I) -> Self {
+        Self {
+            data: iter.into_iter().collect(),
+        }
+    }
+}
@@ -10,3 +10,31 @@ impl<F: NttField> FieldPolynomial<F> {
         FieldNttPolynomial::new(self.data)
     }
 }
+
+#[cfg(feature = "std")]
+impl<F> FieldPolynomial<F>
+where
+    F: NttField + 'static,
+    <F as NttField>::Table: 'static,
+{
+    /// Converts [FieldPolynomial<F>] to [FieldNttPolynomial<F>] with the cached table
+    /// from [`NttField::get_ntt_table`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the coefficient count isn't a power of two,
+    /// or if the field has no ntt table of this dimension.
+    #[inline]
+    pub fn to_ntt(&self) -> FieldNttPolynomial<F> {
+        let n = self.coeff_count();
+        assert!(
+            n.is_power_of_two(),
+            "the coefficient count {n} of the polynomial is not a power of two"
+        );
+        let ntt_table = F::get_ntt_table(n.trailing_zeros())
+            .unwrap_or_else(|err| panic!("no ntt table for dimension {n}: {err}"));
+        let mut poly = self.clone();
+        crate::ntt::NttBackend::forward(&*ntt_table, poly.as_mut_slice());
+        FieldNttPolynomial::new(poly.data)
+    }
+}
@@ -17,6 +17,20 @@ impl<F: Field> FieldPolynomial<F> {
         once_decompose.decompose_slice_inplace(self.as_slice(), carries, decompose_poly);
     }
 
+    /// Decomposes [FieldPolynomial<F>] into `decompose_length` polynomials,
+    /// least significant digit first, reusing the polynomials in `decompose_polys`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `decompose_polys` is less than `decompose_length`.
+    pub fn decompose_into(
+        &self,
+        basis: &NonPowOf2ApproxSignedBasis<<F as Field>::ValueT>,
+        decompose_polys: &mut [Self],
+    ) {
+        self.as_ref_poly().decompose_into(basis, decompose_polys);
+    }
+
     /// Init carries and adjusted polynomial for a [FieldPolynomial<F>].
     #[inline]
     pub fn init_adjust_poly_carries(
@@ -0,0 +1,100 @@
+use alloc::{vec, vec::Vec};
+
+use crate::{
+    integer::{AsFrom, AsInto},
+    Field,
+};
+
+use super::FieldPolynomial;
+
+impl<F: Field> FieldPolynomial<F> {
+    /// Computes the full-width integer product of `self` and `rhs`,
+    /// without reducing modulo `X^n + 1` or modulo `q`.
+    ///
+    /// Each coefficient is lifted to its centered representative in `[-q/2, q/2]`,
+    /// and the returned vector holds the `2n - 1` coefficients of the product.
+    /// It is a schoolbook multiplication intended for noise analysis, not for performance.
+    ///
+    /// Every coefficient of the product is a sum of at most `n` products bounded by `(q/2)^2`,
+    /// so the `i128` sums are exact as long as `n * (q/2)^2 < 2^127`.
+    /// This rules out e.g. `q ≈ 2^60` with `n ≥ 512`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` and `rhs` have different coefficient counts,
+    /// or if `n * (q/2)^2` doesn't fit in an `i128`.
+    pub fn mul_full(&self, rhs: &Self) -> Vec<i128> {
+        assert_eq!(self.coeff_count(), rhs.coeff_count());
+        let n = self.coeff_count();
+        if n == 0 {
+            return Vec::new();
+        }
+
+        let half: i128 = (<F as Field>::MODULUS_VALUE >> 1u32).as_into();
+        assert!(
+            half.checked_mul(half)
+                .and_then(|square| square.checked_mul(n as i128))
+                .is_some(),
+            "n * (q/2)^2 overflows the i128 coefficients of the full product for n = {n}"
+        );
+
+        let lhs: Vec<i128> = self.copied_iter().map(centered::<F>).collect();
+        let rhs: Vec<i128> = rhs.copied_iter().map(centered::<F>).collect();
+
+        let mut result = vec![0i128; 2 * n - 1];
+        for (i, &a) in lhs.iter().enumerate() {
+            if a == 0 {
+                continue;
+            }
+            result[i..i + n]
+                .iter_mut()
+                .zip(rhs.iter())
+                .for_each(|(r, &b)| *r += a * b);
+        }
+        result
+    }
+
+    /// Folds a full-width integer product back into `Z_q[X]/(X^n + 1)`.
+    ///
+    /// This is the reduction skipped by [`FieldPolynomial::mul_full`],
+    /// which can be used to cross-check it against the NTT product.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `full` has more than `2n - 1` coefficients.
+    pub fn fold_full_product(full: &[i128], n: usize) -> Self {
+        assert!(
+            full.len() < 2 * n || (full.is_empty() && n == 0),
+            "full product has {} coefficients, more than 2n - 1 = {}",
+            full.len(),
+            (2 * n).saturating_sub(1)
+        );
+        let mut folded = vec![0i128; n];
+        let q: i128 = <F as Field>::MODULUS_VALUE.as_into();
+        for (i, &v) in full.iter().enumerate() {
+            let v = v.rem_euclid(q);
+            if i < n {
+                folded[i] += v;
+            } else {
+                folded[i - n] -= v;
+            }
+        }
+        Self::new(
+            folded
+                .into_iter()
+                .map(|v| <F as Field>::ValueT::as_from(v.rem_euclid(q)))
+                .collect(),
+        )
+    }
+}
+
+/// Returns the centered representative of `value` in `[-q/2, q/2]`.
+#[inline]
+fn centered<F: Field>(value: <F as Field>::ValueT) -> i128 {
+    let q = <F as Field>::MODULUS_VALUE;
+    if value <= q >> 1u32 {
+        value.as_into()
+    } else {
+        -AsInto::<i128>::as_into(q - value)
+    }
+}
@@ -1,17 +1,33 @@
+use alloc::{vec, vec::Vec};
+
 use num_traits::{ConstZero, Zero};
 
-use crate::{reduce::ReduceMulAdd, Field};
+use crate::{
+    integer::{AsFrom, AsInto},
+    ntt::{NttTable, NumberTheoryTransform},
+    reduce::ReduceMulAdd,
+    utils::bit_reverse_permute,
+    Field, NttField,
+};
 
+mod automorphism;
 mod basic;
 mod convert;
 mod decompose;
+#[cfg(feature = "noise-analysis")]
+mod full;
+mod norm;
 mod random;
+mod rescale;
+mod rotate;
 
 mod add;
 mod mul;
 mod neg;
 mod sub;
 
+pub use rescale::rescale_slice;
+
 /// Represents a polynomial where coefficients are numeric elements.
 pub struct FieldPolynomial<F: Field> {
     data: Vec<<F as Field>::ValueT>,
@@ -42,6 +58,13 @@ impl<F: Field> PartialEq for FieldPolynomial<F> {
     }
 }
 
+impl<F: Field> core::hash::Hash for FieldPolynomial<F> {
+    #[inline]
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.data.hash(state);
+    }
+}
+
 impl<F: Field> Clone for FieldPolynomial<F> {
     #[inline]
     fn clone(&self) -> Self {
@@ -70,6 +93,31 @@ impl<F: Field> FieldPolynomial<F> {
         Self::new(polynomial.to_vec())
     }
 
+    /// Constructs a new polynomial from the centered representatives `coeffs`,
+    /// mapping each negative value `-v` to `q - v`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a value doesn't lie in `[-q/2, q/2]`.
+    pub fn from_centered(coeffs: &[i64]) -> Self {
+        let half: u64 = (<F as Field>::MODULUS_VALUE >> 1u32).as_into();
+        Self::new(
+            coeffs
+                .iter()
+                .map(|&v| {
+                    let abs = v.unsigned_abs();
+                    assert!(abs <= half, "{v} is not a centered representative");
+                    let abs = <F as Field>::ValueT::as_from(abs);
+                    if v < 0 {
+                        F::neg(abs)
+                    } else {
+                        abs
+                    }
+                })
+                .collect(),
+        )
+    }
+
     /// Extracts a slice containing the entire vector.
     ///
     /// Equivalent to `&s[..]`.
@@ -94,13 +142,13 @@ impl<F: Field> FieldPolynomial<F> {
 
     /// Returns an iterator that allows reading each value or coefficient of the polynomial.
     #[inline]
-    pub fn iter(&self) -> core::slice::Iter<<F as Field>::ValueT> {
+    pub fn iter(&self) -> core::slice::Iter<'_, <F as Field>::ValueT> {
         self.data.iter()
     }
 
     /// Returns an iterator that allows modifying each value or coefficient of the polynomial.
     #[inline]
-    pub fn iter_mut(&mut self) -> core::slice::IterMut<<F as Field>::ValueT> {
+    pub fn iter_mut(&mut self) -> core::slice::IterMut<'_, <F as Field>::ValueT> {
         self.data.iter_mut()
     }
 
@@ -165,4 +213,30 @@ impl<F: Field> FieldPolynomial<F> {
                 F::MODULUS.reduce_mul_add(acc, x, a)
             })
     }
+
+    /// Evaluate `p(x)` for every `x` in `points`.
+    #[inline]
+    pub fn evaluate_many(&self, points: &[<F as Field>::ValueT]) -> Vec<<F as Field>::ValueT> {
+        points.iter().map(|&x| self.evaluate(x)).collect()
+    }
+}
+
+impl<F: NttField> FieldPolynomial<F> {
+    /// Evaluate `p(x)` at all the odd powers of the primitive `2n`-th root of unity `ψ`
+    /// used by `ntt_table`, where `n` is the coefficient count.
+    ///
+    /// The `i`-th value of the result is `p(ψ^(2i+1))`.
+    /// It costs one number theory transform and a bit-reversal permutation.
+    pub fn evaluate_at_roots(
+        &self,
+        ntt_table: &<F as NttField>::Table,
+    ) -> Vec<<F as Field>::ValueT> {
+        let n = ntt_table.dimension();
+        debug_assert_eq!(n, self.coeff_count());
+        let log_n = n.trailing_zeros();
+
+        let mut values = ntt_table.transform(self).inner_data();
+        bit_reverse_permute(&mut values, log_n);
+        values
+    }
 }
@@ -1,9 +1,14 @@
-use std::ops::MulAssign;
+use core::ops::MulAssign;
 
 use crate::{
-    modulus::ShoupFactor,
-    reduce::{ReduceAddAssign, ReduceMul, ReduceMulAdd, ReduceMulAssign},
-    Field, NttField,
+    modulus::{BarrettModulus, ShoupFactor},
+    ntt::{FieldTableWithShoupRoot, NumberTheoryTransform},
+    polynomial::{
+        karatsuba::{negacyclic_karatsuba_mul, negacyclic_naive_mul},
+        poly_scalar_mul_assign_slice,
+    },
+    reduce::{ReduceAddAssign, ReduceMul, ReduceMulAdd, ReduceMulAssign, ReduceNegAssign},
+    Field, NttField, ScalarFactor,
 };
 
 use super::FieldPolynomial;
@@ -19,8 +24,7 @@ impl<F: Field> FieldPolynomial<F> {
     /// Multiply `self` with the a scalar and assign self.
     #[inline]
     pub fn mul_scalar_assign(&mut self, scalar: <F as Field>::ValueT) {
-        self.iter_mut()
-            .for_each(|v| <F as Field>::MODULUS.reduce_mul_assign(v, scalar))
+        poly_scalar_mul_assign_slice::<F>(self.as_mut_slice(), scalar)
     }
 
     /// Multiply `self` with the a scalar and add to self.
@@ -57,6 +61,65 @@ impl<F: Field> FieldPolynomial<F> {
                 .reduce_add_assign(r, <F as Field>::MODULUS_VALUE.reduce_mul(v, scalar))
         });
     }
+
+    /// Multiply `self` with the a precomputed scalar factor and assign self.
+    #[inline]
+    pub fn mul_factor_assign(&mut self, factor: &ScalarFactor<F>) {
+        self.mul_shoup_scalar_assign(factor.factor());
+    }
+
+    /// Multiply `rhs` with the a precomputed scalar factor and add to self.
+    #[inline]
+    pub fn add_mul_factor_assign(&mut self, rhs: &Self, factor: &ScalarFactor<F>) {
+        self.add_mul_shoup_scalar_assign(rhs, factor.factor());
+    }
+
+    /// Multiply `self` with the monomial `X^exp` modulo `X^n + 1` in place.
+    ///
+    /// It is a negacyclic rotation with sign flips, `exp` is reduced modulo `2n`.
+    pub fn mul_monomial_assign(&mut self, exp: usize) {
+        let n = self.coeff_count();
+        if n == 0 {
+            return;
+        }
+
+        let exp = exp % (n << 1);
+        if exp == 0 {
+            return;
+        }
+
+        let neg = |v: &mut <F as Field>::ValueT| <F as Field>::MODULUS.reduce_neg_assign(v);
+        if exp <= n {
+            self.as_mut_slice().rotate_right(exp);
+            self[..exp].iter_mut().for_each(neg);
+        } else {
+            let r = exp - n;
+            self.as_mut_slice().rotate_right(r);
+            self[r..].iter_mut().for_each(neg);
+        }
+    }
+
+    /// Multiply `self` with `rhs` modulo `x^n + 1` by schoolbook multiplication.
+    ///
+    /// It runs in `O(n^2)`, mainly used as a reference for [`FieldPolynomial::mul`].
+    pub fn mul_naive(&self, rhs: &Self) -> Self {
+        Self::new(negacyclic_naive_mul(
+            self.as_slice(),
+            rhs.as_slice(),
+            <F as Field>::MODULUS,
+        ))
+    }
+
+    /// Multiply `self` with `rhs` modulo `x^n + 1` by karatsuba multiplication.
+    ///
+    /// It runs in `O(n^1.58)` and needs no number theory transform table.
+    pub fn mul_karatsuba(&self, rhs: &Self) -> Self {
+        Self::new(negacyclic_karatsuba_mul(
+            self.as_slice(),
+            rhs.as_slice(),
+            <F as Field>::MODULUS,
+        ))
+    }
 }
 
 impl<F: NttField> FieldPolynomial<F> {
@@ -69,3 +132,47 @@ impl<F: NttField> FieldPolynomial<F> {
         a.into_coeff_poly(ntt_table)
     }
 }
+
+impl<F> FieldPolynomial<F>
+where
+    F: NttField<Modulus = BarrettModulus<<F as Field>::ValueT>>,
+{
+    /// Returns the lower `n/2` coefficients of `self * rhs mod (x^n + 1)`.
+    ///
+    /// The inverse transform skips the half of its last layer which only gives
+    /// the upper coefficients. If the upper half of an input is zero, its forward
+    /// transform skips the multiplications of the first layer as well.
+    /// The savings are a fraction of a layer per transform, so it only pays off
+    /// when the upper coefficients are never used, like in a gadget product
+    /// whose result is truncated.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` or `rhs` doesn't have `n` coefficients or `n` is `1`.
+    pub fn mul_low_half(&self, rhs: &Self, ntt_table: &FieldTableWithShoupRoot<F>) -> Self {
+        let n = ntt_table.n();
+        assert_eq!(self.coeff_count(), n);
+        assert_eq!(rhs.coeff_count(), n);
+        let half = n >> 1;
+
+        let transform = |poly: &Self| {
+            let mut values = poly.as_slice().to_vec();
+            if values[half..].iter().all(|&v| v == <F as Field>::ZERO) {
+                ntt_table.transform_truncated_slice(&mut values);
+            } else {
+                ntt_table.transform_slice(&mut values);
+            }
+            values
+        };
+
+        let mut a = transform(self);
+        let b = transform(rhs);
+        a.iter_mut()
+            .zip(&b)
+            .for_each(|(x, &y)| <F as Field>::mul_assign(x, y));
+
+        ntt_table.inverse_transform_truncated_slice(&mut a);
+        a.truncate(half);
+        Self::new(a)
+    }
+}
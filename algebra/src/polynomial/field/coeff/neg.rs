@@ -1,9 +1,6 @@
 use core::ops::Neg;
 
-use crate::{
-    reduce::{ReduceNeg, ReduceNegAssign},
-    Field,
-};
+use crate::{polynomial::poly_neg_assign_slice, reduce::ReduceNeg, Field};
 
 use super::FieldPolynomial;
 
@@ -11,8 +8,7 @@ impl<F: Field> FieldPolynomial<F> {
     /// Performs the unary `-` operation.
     #[inline]
     pub fn neg_assign(&mut self) {
-        self.iter_mut()
-            .for_each(|v| F::MODULUS.reduce_neg_assign(v));
+        poly_neg_assign_slice::<F>(self.as_mut_slice());
     }
 
     /// Performs the unary `-` operation.
@@ -0,0 +1,53 @@
+use crate::{integer::AsInto, Field};
+
+use super::FieldPolynomial;
+
+impl<F: Field> FieldPolynomial<F> {
+    /// Returns the infinity norm of `self`,
+    /// where each coefficient is lifted to its centered representative in `[-q/2, q/2]`.
+    #[inline]
+    pub fn linf_norm(&self) -> u64 {
+        self.copied_iter().map(centered_abs::<F>).max().unwrap_or(0)
+    }
+
+    /// Returns the squared euclidean norm of `self`,
+    /// where each coefficient is lifted to its centered representative in `[-q/2, q/2]`.
+    #[inline]
+    pub fn l2_norm_squared(&self) -> u128 {
+        self.copied_iter()
+            .map(|v| {
+                let v = centered_abs::<F>(v) as u128;
+                v * v
+            })
+            .sum()
+    }
+
+    /// Returns the infinity norm of `self - rhs`,
+    /// where each coefficient is lifted to its centered representative in `[-q/2, q/2]`.
+    #[inline]
+    pub fn distance_linf(&self, rhs: &Self) -> u64 {
+        self.max_distance(rhs).map_or(0, |(_, d)| d)
+    }
+
+    /// Returns the index and the centered distance of the coefficient
+    /// where `self` and `rhs` differ the most, or `None` if both are empty.
+    pub(crate) fn max_distance(&self, rhs: &Self) -> Option<(usize, u64)> {
+        debug_assert_eq!(self.coeff_count(), rhs.coeff_count());
+        self.copied_iter()
+            .zip(rhs.copied_iter())
+            .map(|(a, b)| centered_abs::<F>(F::sub(a, b)))
+            .enumerate()
+            .max_by_key(|&(_, d)| d)
+    }
+}
+
+/// Returns the absolute value of the centered representative of `value`.
+#[inline]
+fn centered_abs<F: Field>(value: <F as Field>::ValueT) -> u64 {
+    let q = <F as Field>::MODULUS_VALUE;
+    if value <= q >> 1u32 {
+        value.as_into()
+    } else {
+        (q - value).as_into()
+    }
+}
@@ -49,6 +49,31 @@ impl<F: Field> FieldPolynomial<F> {
         ))
     }
 
+    /// Generate a random ternary [`FieldPolynomial<F>`] with exactly `hamming_weight` nonzero coefficients.
+    ///
+    /// The positions of the nonzero coefficients are uniformly random,
+    /// and each of them is `1` or `-1` with equal probability.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `hamming_weight` is greater than `n`.
+    #[inline]
+    pub fn random_ternary_with_hamming_weight<R>(
+        n: usize,
+        hamming_weight: usize,
+        rng: &mut R,
+    ) -> Self
+    where
+        R: Rng + CryptoRng,
+    {
+        Self::new(crate::random::sample_fixed_weight(
+            <F as Field>::MINUS_ONE,
+            n,
+            hamming_weight,
+            rng,
+        ))
+    }
+
     /// Generate a random [`FieldPolynomial<F>`] with discrete gaussian distribution.
     #[inline]
     pub fn random_gaussian<R>(
@@ -75,4 +100,18 @@ impl<F: Field> FieldPolynomial<F> {
                 .collect(),
         }
     }
+
+    /// Tests `self == other` by evaluating both at a uniformly random point.
+    ///
+    /// Equal polynomials always pass. By the Schwartz–Zippel lemma,
+    /// unequal polynomials pass with probability at most `deg / p`,
+    /// where `deg` is the degree of `self - other` and `p` is the field modulus.
+    #[inline]
+    pub fn probabilistic_eq<R>(&self, other: &Self, rng: &mut R) -> bool
+    where
+        R: Rng + CryptoRng,
+    {
+        let x = Self::uniform_distribution().sample(rng);
+        self.evaluate(x) == other.evaluate(x)
+    }
 }
@@ -0,0 +1,118 @@
+use alloc::boxed::Box;
+
+use crate::{
+    integer::{AsFrom, AsInto, UnsignedInteger},
+    reduce::ModulusValue,
+    AlgebraError, Field,
+};
+
+use super::FieldPolynomial;
+
+impl<F: Field> FieldPolynomial<F> {
+    /// Rescales `self` from the field `F` to the field `F2`,
+    /// mapping each coefficient `x` to `round(x * q2 / q1) mod q2`.
+    ///
+    /// Each coefficient is lifted to its centered representative in `[-q1/2, q1/2]` first,
+    /// and the rounding is done with exact integer arithmetic.
+    /// It works for both `q2 < q1` and `q2 > q1`.
+    pub fn rescale_to<F2: Field>(&self) -> FieldPolynomial<F2> {
+        let q_in: u128 = <F as Field>::MODULUS_VALUE.as_into();
+        let q_out: u128 = <F2 as Field>::MODULUS_VALUE.as_into();
+        FieldPolynomial::new(
+            self.copied_iter()
+                .map(|v| {
+                    <F2 as Field>::ValueT::as_from(rescale_value(v.as_into(), q_in, q_out, false))
+                })
+                .collect(),
+        )
+    }
+
+    /// Converts `self` from the field `F` to the field `F2`,
+    /// mapping each coefficient `x` to `round(x * q2 / q1) mod q2`.
+    ///
+    /// Unlike [`FieldPolynomial::rescale_to`], ties are rounded to even,
+    /// which keeps the conversion unbiased when it is applied repeatedly.
+    /// Each coefficient is still lifted to its centered representative first.
+    pub fn convert_field<F2: Field>(&self) -> FieldPolynomial<F2> {
+        let q_in: u128 = <F as Field>::MODULUS_VALUE.as_into();
+        let q_out: u128 = <F2 as Field>::MODULUS_VALUE.as_into();
+        FieldPolynomial::new(
+            self.copied_iter()
+                .map(|v| {
+                    <F2 as Field>::ValueT::as_from(rescale_value(v.as_into(), q_in, q_out, true))
+                })
+                .collect(),
+        )
+    }
+
+    /// Lifts `self` from the field `F` to the field `F2` exactly,
+    /// mapping each coefficient `x` to `x * (q2 / q1) mod q2`.
+    ///
+    /// Returns an error if `q1` does not divide `q2`.
+    pub fn lift_field<F2: Field>(&self) -> Result<FieldPolynomial<F2>, AlgebraError> {
+        let q_in: u128 = <F as Field>::MODULUS_VALUE.as_into();
+        let q_out: u128 = <F2 as Field>::MODULUS_VALUE.as_into();
+        if !q_out.is_multiple_of(q_in) {
+            return Err(AlgebraError::ModulusNotDivisor {
+                modulus: Box::new(q_in),
+                target: Box::new(q_out),
+            });
+        }
+        Ok(self.convert_field())
+    }
+}
+
+/// Rescales the coefficients in `values` from the field `F` to `modulus_out`,
+/// and puts the result to the `destination`.
+///
+/// Each coefficient `x` is mapped to `round(x * q2 / q1) mod q2`
+/// as in [`FieldPolynomial::rescale_to`].
+///
+/// # Panics
+///
+/// Panics if `destination` has a different length,
+/// or if `modulus_out` is the native modulus of `u128`.
+pub fn rescale_slice<F: Field, C: UnsignedInteger>(
+    values: &[<F as Field>::ValueT],
+    modulus_out: ModulusValue<C>,
+    destination: &mut [C],
+) {
+    assert_eq!(values.len(), destination.len());
+    let q_in: u128 = <F as Field>::MODULUS_VALUE.as_into();
+    let q_out: u128 = match modulus_out {
+        ModulusValue::Native => 1u128 << C::BITS,
+        ModulusValue::PowerOf2(q) | ModulusValue::Prime(q) | ModulusValue::Others(q) => q.as_into(),
+    };
+    destination
+        .iter_mut()
+        .zip(values)
+        .for_each(|(des, &v)| *des = C::as_from(rescale_value(v.as_into(), q_in, q_out, false)));
+}
+
+/// Returns `round(x * q_out / q_in) mod q_out` over the centered representative of `x`.
+///
+/// Ties are rounded to even if `ties_to_even` is set, otherwise away from zero,
+/// so that `x` and `-x` are mapped symmetrically either way.
+#[inline]
+fn rescale_value(x: u128, q_in: u128, q_out: u128, ties_to_even: bool) -> u128 {
+    let (magnitude, negative) = if x <= q_in >> 1 {
+        (x, false)
+    } else {
+        (q_in - x, true)
+    };
+
+    let product = magnitude * q_out;
+    let (quotient, remainder) = (product / q_in, product % q_in);
+    let round_up = match (remainder << 1).cmp(&q_in) {
+        core::cmp::Ordering::Less => false,
+        core::cmp::Ordering::Equal => !ties_to_even || quotient & 1 == 1,
+        core::cmp::Ordering::Greater => true,
+    };
+    let rounded = (quotient + round_up as u128) % q_out;
+
+    if negative && rounded != 0 {
+        q_out - rounded
+    } else {
+        rounded
+    }
+}
@@ -0,0 +1,36 @@
+use crate::Field;
+
+use super::FieldPolynomial;
+
+impl<F: Field> FieldPolynomial<F> {
+    /// Rotates the coefficients `k` places to the right in `Z_q[X]/(X^n + 1)`,
+    /// i.e. multiplies `self` with `X^k`.
+    ///
+    /// Coefficients wrapping around the end have their signs flipped, `k` is reduced modulo `2n`.
+    #[inline]
+    pub fn rotate_right(&mut self, k: usize) {
+        self.mul_monomial_assign(k);
+    }
+
+    /// Rotates the coefficients `k` places to the left in `Z_q[X]/(X^n + 1)`,
+    /// i.e. multiplies `self` with `X^(-k)`.
+    ///
+    /// It is the inverse of [`FieldPolynomial::rotate_right`] with the same `k`.
+    #[inline]
+    pub fn rotate_left(&mut self, k: usize) {
+        let two_n = self.coeff_count() << 1;
+        if two_n == 0 {
+            return;
+        }
+        self.mul_monomial_assign(two_n - k % two_n);
+    }
+
+    /// Maps `p(X)` to `p(X^(-1))` in `Z_q[X]/(X^n + 1)`.
+    ///
+    /// The constant term is kept, and the rest coefficients are reversed and negated,
+    /// i.e. `[a_0, a_1, ..., a_(n-1)]` becomes `[a_0, -a_(n-1), ..., -a_1]`.
+    #[inline]
+    pub fn reverse_coeffs_negacyclic(&mut self) {
+        self.as_mut_poly().reverse_coeffs_negacyclic();
+    }
+}
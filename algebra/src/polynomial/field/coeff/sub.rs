@@ -1,9 +1,6 @@
-use std::ops::{Sub, SubAssign};
+use core::ops::{Sub, SubAssign};
 
-use crate::{
-    reduce::{ReduceSub, ReduceSubAssign},
-    Field,
-};
+use crate::{polynomial::poly_sub_assign_slice, reduce::ReduceSub, Field};
 
 use super::FieldPolynomial;
 
@@ -22,20 +19,14 @@ impl<F: Field> FieldPolynomial<F> {
 impl<F: Field> SubAssign for FieldPolynomial<F> {
     #[inline]
     fn sub_assign(&mut self, rhs: Self) {
-        debug_assert_eq!(self.coeff_count(), rhs.coeff_count());
-        self.iter_mut()
-            .zip(rhs)
-            .for_each(|(a, b)| F::MODULUS.reduce_sub_assign(a, b));
+        poly_sub_assign_slice::<F>(self.as_mut_slice(), rhs.as_slice());
     }
 }
 
 impl<F: Field> SubAssign<&Self> for FieldPolynomial<F> {
     #[inline]
     fn sub_assign(&mut self, rhs: &Self) {
-        debug_assert_eq!(self.coeff_count(), rhs.coeff_count());
-        self.iter_mut()
-            .zip(rhs)
-            .for_each(|(a, &b)| F::MODULUS.reduce_sub_assign(a, b));
+        poly_sub_assign_slice::<F>(self.as_mut_slice(), rhs.as_slice());
     }
 }
 
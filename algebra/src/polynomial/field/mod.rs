@@ -1,5 +1,9 @@
 mod coeff;
 mod ntt;
+#[cfg(feature = "std")]
+mod packed;
+mod view;
 
-pub use coeff::FieldPolynomial;
+pub use coeff::{rescale_slice, FieldPolynomial};
 pub use ntt::FieldNttPolynomial;
+pub use view::{PolyMut, PolyRef};
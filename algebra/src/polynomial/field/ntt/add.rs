@@ -68,3 +68,12 @@ impl<F: NttField> Add<FieldNttPolynomial<F>> for &FieldNttPolynomial<F> {
         rhs
     }
 }
+
+impl<F: NttField> Add<&FieldNttPolynomial<F>> for &FieldNttPolynomial<F> {
+    type Output = FieldNttPolynomial<F>;
+
+    #[inline]
+    fn add(self, rhs: &FieldNttPolynomial<F>) -> Self::Output {
+        Add::add(self.clone(), rhs)
+    }
+}
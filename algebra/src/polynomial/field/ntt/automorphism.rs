@@ -0,0 +1,45 @@
+use crate::{utils::ReverseLsbs, NttField};
+
+use super::FieldNttPolynomial;
+
+impl<F: NttField> FieldNttPolynomial<F> {
+    /// Applies the Galois automorphism `X -> X^k` on `self`.
+    ///
+    /// The values are evaluations at the odd powers of `ψ` in bit-reversed order,
+    /// so the automorphism only permutes them: `p(X^k)` at `ψ^e` is `p` at `ψ^(ek)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `k` is not odd, or the coefficient count is not a power of two.
+    #[inline]
+    pub fn apply_automorphism(&self, k: usize) -> Self {
+        let mut destination = Self::zero(self.coeff_count());
+        self.apply_automorphism_inplace(k, &mut destination);
+        destination
+    }
+
+    /// Applies the Galois automorphism `X -> X^k` on `self`,
+    /// and stores the result into `destination`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `k` is not odd, or the coefficient count is not a power of two.
+    pub fn apply_automorphism_inplace(&self, k: usize, destination: &mut Self) {
+        let n = self.coeff_count();
+        assert!(n.is_power_of_two());
+        assert_eq!(n, destination.coeff_count());
+        assert!(
+            k & 1 == 1,
+            "automorphism degree {k} is not coprime to {}",
+            n << 1
+        );
+
+        let log_n = n.trailing_zeros();
+        let mask = (n << 1) - 1;
+        let k = k & mask;
+        destination.iter_mut().enumerate().for_each(|(i, v)| {
+            let exp = (((i.reverse_lsbs(log_n) << 1) + 1) * k) & mask;
+            *v = self[(exp >> 1).reverse_lsbs(log_n)];
+        });
+    }
+}
@@ -1,3 +1,4 @@
+use alloc::vec::Vec;
 use core::{
     ops::{Index, IndexMut},
     slice::SliceIndex,
@@ -40,7 +41,7 @@ impl<F: NttField> AsMut<[<F as Field>::ValueT]> for FieldNttPolynomial<F> {
 impl<F: NttField> IntoIterator for FieldNttPolynomial<F> {
     type Item = <F as Field>::ValueT;
 
-    type IntoIter = std::vec::IntoIter<<F as Field>::ValueT>;
+    type IntoIter = alloc::vec::IntoIter<<F as Field>::ValueT>;
 
     #[inline]
     fn into_iter(self) -> Self::IntoIter {
@@ -69,3 +70,19 @@ impl<'a, F: NttField> IntoIterator for &'a mut FieldNttPolynomial<F> {
         self.data.iter_mut()
     }
 }
+
+impl<F: NttField> From<Vec<<F as Field>::ValueT>> for FieldNttPolynomial<F> {
+    #[inline]
+    fn from(data: Vec<<F as Field>::ValueT>) -> Self {
+        Self { data }
+    }
+}
+
+impl<F: NttField> FromIterator<<F as Field>::ValueT> for FieldNttPolynomial<F> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = <F as Field>::ValueT>>(iter: I) -> Self {
+        Self {
+            data: iter.into_iter().collect(),
+        }
+    }
+}
@@ -1,10 +1,15 @@
+use alloc::{vec, vec::Vec};
+
 use num_traits::{ConstZero, Zero};
 
 use crate::{
-    reduce::{LazyReduceMulAdd, ReduceMulAdd},
+    integer::{AsFrom, AsInto, Bits, ConstBounded},
+    numeric::Numeric,
+    reduce::{LazyReduceMulAdd, Reduce, ReduceMul, ReduceMulAdd, ReduceNeg},
     Field, NttField,
 };
 
+mod automorphism;
 mod basic;
 mod convert;
 mod random;
@@ -16,6 +21,8 @@ mod neg;
 mod sub;
 
 /// A representation of a polynomial in Number Theoretic Transform (NTT) form.
+///
+/// The values are stored in bit-reversed order, see [`NttOrdering`](crate::ntt::NttOrdering).
 pub struct FieldNttPolynomial<F: NttField> {
     data: Vec<<F as Field>::ValueT>,
 }
@@ -45,6 +52,13 @@ impl<F: NttField> PartialEq for FieldNttPolynomial<F> {
     }
 }
 
+impl<F: NttField> core::hash::Hash for FieldNttPolynomial<F> {
+    #[inline]
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.data.hash(state);
+    }
+}
+
 impl<F: NttField> Clone for FieldNttPolynomial<F> {
     #[inline]
     fn clone(&self) -> Self {
@@ -103,13 +117,13 @@ impl<F: NttField> FieldNttPolynomial<F> {
 
     /// Returns an iterator that allows reading each value or values of the polynomial.
     #[inline]
-    pub fn iter(&self) -> core::slice::Iter<<F as Field>::ValueT> {
+    pub fn iter(&self) -> core::slice::Iter<'_, <F as Field>::ValueT> {
         self.data.iter()
     }
 
     /// Returns an iterator that allows modifying each value or values of the polynomial.
     #[inline]
-    pub fn iter_mut(&mut self) -> core::slice::IterMut<<F as Field>::ValueT> {
+    pub fn iter_mut(&mut self) -> core::slice::IterMut<'_, <F as Field>::ValueT> {
         self.data.iter_mut()
     }
 
@@ -161,6 +175,7 @@ impl<F: NttField> FieldNttPolynomial<F> {
     /// Performs `self = self + (a * b)`.
     #[inline]
     pub fn add_mul_assign(&mut self, a: &Self, b: &Self) {
+        crate::count::mul::add(self.coeff_count());
         self.into_iter()
             .zip(a)
             .zip(b)
@@ -170,15 +185,131 @@ impl<F: NttField> FieldNttPolynomial<F> {
     /// Performs `self = self + (a * b)`.
     #[inline]
     pub fn add_mul_assign_fast(&mut self, a: &Self, b: &Self) {
+        crate::count::mul::add(self.coeff_count());
         self.into_iter()
             .zip(a)
             .zip(b)
             .for_each(|((z, &x), &y)| *z = F::MODULUS.lazy_reduce_mul_add(x, y, *z));
     }
 
+    /// Performs `self = self - (a * b)`.
+    #[inline]
+    pub fn sub_mul_assign(&mut self, a: &Self, b: &Self) {
+        crate::count::mul::add(self.coeff_count());
+        self.into_iter().zip(a).zip(b).for_each(|((z, &x), &y)| {
+            *z = F::MODULUS.reduce_mul_add(x, F::MODULUS.reduce_neg(y), *z)
+        });
+    }
+
+    /// Performs `self = self - (a * b)`.
+    ///
+    /// The result coefficients may be in [0, 2*modulus) for some case,
+    /// and fall back to [0, modulus) for normal case.
+    #[inline]
+    pub fn sub_mul_assign_fast(&mut self, a: &Self, b: &Self) {
+        crate::count::mul::add(self.coeff_count());
+        self.into_iter().zip(a).zip(b).for_each(|((z, &x), &y)| {
+            *z = F::MODULUS.lazy_reduce_mul_add(x, F::MODULUS.reduce_neg(y), *z)
+        });
+    }
+
+    /// Performs `self = self * c + (a * b)`.
+    #[inline]
+    pub fn mul_add_mul_assign(&mut self, c: &Self, a: &Self, b: &Self) {
+        crate::count::mul::add(2 * self.coeff_count());
+        self.into_iter()
+            .zip(c)
+            .zip(a)
+            .zip(b)
+            .for_each(|(((z, &w), &x), &y)| {
+                *z = F::MODULUS.reduce_mul_add(x, y, F::MODULUS.reduce_mul(*z, w))
+            });
+    }
+
+    /// Performs `self = self * c + (a * b)`.
+    ///
+    /// The result coefficients may be in [0, 2*modulus) for some case,
+    /// and fall back to [0, modulus) for normal case.
+    #[inline]
+    pub fn mul_add_mul_assign_fast(&mut self, c: &Self, a: &Self, b: &Self) {
+        crate::count::mul::add(2 * self.coeff_count());
+        self.into_iter()
+            .zip(c)
+            .zip(a)
+            .zip(b)
+            .for_each(|(((z, &w), &x), &y)| {
+                *z = F::MODULUS.lazy_reduce_mul_add(x, y, F::MODULUS.reduce_mul(*z, w))
+            });
+    }
+
+    /// Computes `out = ∑ a_i * b_i` over `terms`.
+    ///
+    /// The products are accumulated straight into `out`, one pass per term,
+    /// without allocating any temporary polynomial.
+    /// `out` is set to zero if `terms` is empty.
+    pub fn sum_of_products<'a, I>(terms: I, out: &mut Self)
+    where
+        I: IntoIterator<Item = (&'a Self, &'a Self)>,
+        F: 'a,
+    {
+        let mut terms = terms.into_iter();
+        match terms.next() {
+            Some((a, b)) => a.mul_inplace(b, out),
+            None => out.set_zero(),
+        }
+        terms.for_each(|(a, b)| out.add_mul_assign(a, b));
+    }
+
+    /// Performs `self = self + ∑ a_i * b_i`.
+    ///
+    /// The products are accumulated in the double width type
+    /// and only reduced when the accumulation may overflow,
+    /// so `terms` can be arbitrarily long. The accumulator is reduced
+    /// every `⌊(W::MAX - (p-1)) / (p-1)²⌋` terms, where `W` is the double width type,
+    /// e.g. every 16 terms for 30-bit primes with `u32` values.
+    pub fn add_mul_assign_lazy<'a, I>(&mut self, terms: I)
+    where
+        I: IntoIterator<Item = (&'a Self, &'a Self)>,
+        F: 'a,
+    {
+        type W<F> = <<F as Field>::ValueT as Numeric>::WideT;
+
+        let reduce = |w: W<F>| -> <F as Field>::ValueT {
+            F::MODULUS.reduce([
+                <F as Field>::ValueT::as_from(w),
+                <F as Field>::ValueT::as_from(w >> <F as Field>::ValueT::BITS),
+            ])
+        };
+
+        let max = W::<F>::as_from(F::MINUS_ONE);
+        let max_product = max * max;
+        let chunk: usize = ((<W<F> as ConstBounded>::MAX - max) / max_product).as_into();
+        let chunk = chunk.max(1);
+
+        let mut acc: Vec<W<F>> = self.iter().map(|&v| W::<F>::as_from(v)).collect();
+        let mut count = 0;
+
+        for (a, b) in terms {
+            debug_assert_eq!(a.coeff_count(), acc.len());
+            debug_assert_eq!(b.coeff_count(), acc.len());
+            if count == chunk {
+                acc.iter_mut()
+                    .for_each(|w| *w = W::<F>::as_from(reduce(*w)));
+                count = 0;
+            }
+            acc.iter_mut().zip(a).zip(b).for_each(|((w, &x), &y)| {
+                *w += W::<F>::as_from(x) * W::<F>::as_from(y);
+            });
+            count += 1;
+        }
+
+        self.iter_mut().zip(acc).for_each(|(z, w)| *z = reduce(w));
+    }
+
     /// Performs `des = self * b + c`.
     #[inline]
     pub fn mul_add_inplace(&self, b: &Self, c: &Self, des: &mut Self) {
+        crate::count::mul::add(self.coeff_count());
         des.into_iter()
             .zip(self)
             .zip(b)
@@ -189,10 +320,24 @@ impl<F: NttField> FieldNttPolynomial<F> {
     /// Performs `des = self * b + c`.
     #[inline]
     pub fn mul_add_inplace_fast(&self, b: &Self, c: &Self, des: &mut Self) {
+        crate::count::mul::add(self.coeff_count());
         des.into_iter()
             .zip(self)
             .zip(b)
             .zip(c)
             .for_each(|(((d, &a), &b), &c)| *d = F::MODULUS.lazy_reduce_mul_add(a, b, c));
     }
+
+    /// Performs `des = self - a * b`.
+    #[inline]
+    pub fn sub_mul_inplace(&self, a: &Self, b: &Self, des: &mut Self) {
+        crate::count::mul::add(self.coeff_count());
+        des.into_iter()
+            .zip(self)
+            .zip(a)
+            .zip(b)
+            .for_each(|(((d, &z), &x), &y)| {
+                *d = F::MODULUS.reduce_mul_add(x, F::MODULUS.reduce_neg(y), z)
+            });
+    }
 }
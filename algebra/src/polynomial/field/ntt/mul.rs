@@ -1,9 +1,10 @@
-use std::ops::{Mul, MulAssign};
+use core::ops::{Mul, MulAssign};
 
 use crate::{
     modulus::ShoupFactor,
+    ntt::NumberTheoryTransform,
     reduce::{ReduceAddAssign, ReduceMul, ReduceMulAdd, ReduceMulAssign},
-    Field, NttField,
+    Field, NttField, ScalarFactor,
 };
 
 use super::FieldNttPolynomial;
@@ -19,6 +20,7 @@ impl<F: NttField> FieldNttPolynomial<F> {
     /// Multiply `self` with a scalar and assign self.
     #[inline]
     pub fn mul_scalar_assign(&mut self, scalar: <F as Field>::ValueT) {
+        crate::count::mul::add(self.coeff_count());
         self.iter_mut()
             .for_each(|v| <F as Field>::MODULUS.reduce_mul_assign(v, scalar));
     }
@@ -26,6 +28,7 @@ impl<F: NttField> FieldNttPolynomial<F> {
     /// Multiply `self` with a scalar and add to self.
     #[inline]
     pub fn add_mul_scalar_assign(&mut self, rhs: &Self, scalar: <F as Field>::ValueT) {
+        crate::count::mul::add(self.coeff_count());
         self.iter_mut()
             .zip(rhs.iter())
             .for_each(|(r, &v)| *r = <F as Field>::MODULUS.reduce_mul_add(v, scalar, *r))
@@ -41,6 +44,7 @@ impl<F: NttField> FieldNttPolynomial<F> {
     /// Multiply `self` with a scalar and assign self.
     #[inline]
     pub fn mul_shoup_scalar_assign(&mut self, scalar: ShoupFactor<<F as Field>::ValueT>) {
+        crate::count::mul::add(self.coeff_count());
         self.iter_mut()
             .for_each(|v| <F as Field>::MODULUS_VALUE.reduce_mul_assign(v, scalar));
     }
@@ -52,16 +56,50 @@ impl<F: NttField> FieldNttPolynomial<F> {
         rhs: &Self,
         scalar: ShoupFactor<<F as Field>::ValueT>,
     ) {
+        crate::count::mul::add(self.coeff_count());
         self.iter_mut().zip(rhs.iter()).for_each(|(r, &v)| {
             <F as Field>::MODULUS
                 .reduce_add_assign(r, <F as Field>::MODULUS_VALUE.reduce_mul(v, scalar))
         })
     }
 
+    /// Multiply `self` with the a precomputed scalar factor and assign self.
+    #[inline]
+    pub fn mul_factor_assign(&mut self, factor: &ScalarFactor<F>) {
+        self.mul_shoup_scalar_assign(factor.factor());
+    }
+
+    /// Multiply `rhs` with the a precomputed scalar factor and add to self.
+    #[inline]
+    pub fn add_mul_factor_assign(&mut self, rhs: &Self, factor: &ScalarFactor<F>) {
+        self.add_mul_shoup_scalar_assign(rhs, factor.factor());
+    }
+
+    /// Multiply `self` with the monomial `X^exp` modulo `X^n + 1` in place.
+    ///
+    /// The monomial is transformed by `ntt_table` and multiplied pointwise,
+    /// `exp` is reduced modulo `2n`.
+    pub fn mul_monomial_assign(&mut self, exp: usize, ntt_table: &<F as NttField>::Table) {
+        let n = self.coeff_count();
+        if n == 0 {
+            return;
+        }
+
+        let exp = exp % (n << 1);
+        if exp == 0 {
+            return;
+        }
+
+        let mut monomial = Self::zero(n);
+        ntt_table.transform_coeff_one_monomial(exp, monomial.as_mut_slice());
+        *self *= &monomial;
+    }
+
     /// Performs subtraction operation:`self * rhs`,
     /// and puts the result to the `destination`.
     #[inline]
     pub fn mul_inplace(&self, rhs: &Self, destination: &mut Self) {
+        crate::count::mul::add(self.coeff_count());
         self.iter()
             .zip(rhs)
             .zip(destination)
@@ -74,6 +112,7 @@ impl<F: NttField> FieldNttPolynomial<F> {
 impl<F: NttField> MulAssign for FieldNttPolynomial<F> {
     #[inline]
     fn mul_assign(&mut self, rhs: Self) {
+        crate::count::mul::add(self.coeff_count());
         debug_assert_eq!(self.coeff_count(), rhs.coeff_count());
         self.iter_mut()
             .zip(rhs)
@@ -84,6 +123,7 @@ impl<F: NttField> MulAssign for FieldNttPolynomial<F> {
 impl<F: NttField> MulAssign<&Self> for FieldNttPolynomial<F> {
     #[inline]
     fn mul_assign(&mut self, rhs: &Self) {
+        crate::count::mul::add(self.coeff_count());
         debug_assert_eq!(self.coeff_count(), rhs.coeff_count());
         self.iter_mut()
             .zip(rhs)
@@ -120,3 +160,12 @@ impl<F: NttField> Mul<FieldNttPolynomial<F>> for &FieldNttPolynomial<F> {
         rhs
     }
 }
+
+impl<F: NttField> Mul<&FieldNttPolynomial<F>> for &FieldNttPolynomial<F> {
+    type Output = FieldNttPolynomial<F>;
+
+    #[inline]
+    fn mul(self, rhs: &FieldNttPolynomial<F>) -> Self::Output {
+        Mul::mul(self.clone(), rhs)
+    }
+}
@@ -1,4 +1,4 @@
-use std::ops::Neg;
+use core::ops::Neg;
 
 use crate::{reduce::ReduceNegAssign, Field, NttField};
 
@@ -22,3 +22,12 @@ impl<F: NttField> Neg for FieldNttPolynomial<F> {
         self
     }
 }
+
+impl<F: NttField> Neg for &FieldNttPolynomial<F> {
+    type Output = FieldNttPolynomial<F>;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        -self.clone()
+    }
+}
@@ -2,7 +2,7 @@ use num_traits::ConstZero;
 use rand::{CryptoRng, Rng};
 use rand_distr::{Distribution, Uniform};
 
-use crate::{Field, NttField};
+use crate::{polynomial::FieldPolynomial, random::DiscreteGaussian, Field, NttField};
 
 use super::FieldNttPolynomial;
 
@@ -40,4 +40,39 @@ impl<F: NttField> FieldNttPolynomial<F> {
     {
         Self::new(distribution.sample_iter(rng).take(n).collect())
     }
+
+    /// Generate a [`FieldNttPolynomial<F>`] whose coefficient form is
+    /// a random binary polynomial.
+    #[inline]
+    pub fn random_binary<R>(n: usize, ntt_table: &<F as NttField>::Table, rng: &mut R) -> Self
+    where
+        R: Rng + CryptoRng,
+    {
+        FieldPolynomial::random_binary(n, rng).into_ntt_poly(ntt_table)
+    }
+
+    /// Generate a [`FieldNttPolynomial<F>`] whose coefficient form is
+    /// a random ternary polynomial.
+    #[inline]
+    pub fn random_ternary<R>(n: usize, ntt_table: &<F as NttField>::Table, rng: &mut R) -> Self
+    where
+        R: Rng + CryptoRng,
+    {
+        FieldPolynomial::random_ternary(n, rng).into_ntt_poly(ntt_table)
+    }
+
+    /// Generate a [`FieldNttPolynomial<F>`] whose coefficient form is
+    /// a random polynomial sampled from the discrete gaussian distribution.
+    #[inline]
+    pub fn random_gaussian<R>(
+        n: usize,
+        gaussian: DiscreteGaussian<<F as Field>::ValueT>,
+        ntt_table: &<F as NttField>::Table,
+        rng: &mut R,
+    ) -> Self
+    where
+        R: Rng + CryptoRng,
+    {
+        FieldPolynomial::random_gaussian(n, gaussian, rng).into_ntt_poly(ntt_table)
+    }
 }
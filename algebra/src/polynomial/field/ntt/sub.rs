@@ -1,4 +1,4 @@
-use std::ops::{Sub, SubAssign};
+use core::ops::{Sub, SubAssign};
 
 use crate::{
     reduce::{ReduceSub, ReduceSubAssign},
@@ -71,3 +71,12 @@ impl<F: NttField> Sub<FieldNttPolynomial<F>> for &FieldNttPolynomial<F> {
         rhs
     }
 }
+
+impl<F: NttField> Sub<&FieldNttPolynomial<F>> for &FieldNttPolynomial<F> {
+    type Output = FieldNttPolynomial<F>;
+
+    #[inline]
+    fn sub(self, rhs: &FieldNttPolynomial<F>) -> Self::Output {
+        Sub::sub(self.clone(), rhs)
+    }
+}
@@ -0,0 +1,158 @@
+//! Bit-packed serialization of polynomials.
+//!
+//! The format is a header made of the coefficient count `n` as a little-endian `u64`
+//! and the bit width `w` as a `u8`, followed by the `n` coefficients packed into `w` bits each,
+//! least significant bit first, padded with zero bits to a whole byte.
+//! `w` is the bit count of `q - 1`, so no value of the field is lost.
+
+use std::io::{self, Read, Write};
+
+use crate::{
+    integer::{AsFrom, AsInto, Bits},
+    Field, NttField,
+};
+
+use super::{FieldNttPolynomial, FieldPolynomial};
+
+/// Returns the bit width of the packed coefficients of the field `F`.
+#[inline]
+fn packed_bit_width<F: Field>() -> u32 {
+    let minus_one = <F as Field>::MINUS_ONE;
+    <<F as Field>::ValueT as Bits>::BITS - minus_one.leading_zeros()
+}
+
+fn write_packed_values<F: Field, W: Write>(
+    values: &[<F as Field>::ValueT],
+    mut writer: W,
+) -> io::Result<()> {
+    let width = packed_bit_width::<F>();
+    debug_assert!(width <= 64);
+
+    let mut bytes = Vec::with_capacity(9 + (values.len() * width as usize).div_ceil(8));
+    bytes.extend_from_slice(&(values.len() as u64).to_le_bytes());
+    bytes.push(width as u8);
+
+    let mut buffer: u128 = 0;
+    let mut buffered_bits = 0u32;
+    for &value in values {
+        let value: u64 = value.as_into();
+        buffer |= (value as u128) << buffered_bits;
+        buffered_bits += width;
+        while buffered_bits >= 8 {
+            bytes.push(buffer as u8);
+            buffer >>= 8;
+            buffered_bits -= 8;
+        }
+    }
+    if buffered_bits > 0 {
+        bytes.push(buffer as u8);
+    }
+
+    writer.write_all(&bytes)
+}
+
+fn read_packed_values<F: Field, R: Read>(mut reader: R) -> io::Result<Vec<<F as Field>::ValueT>> {
+    let invalid = |msg: String| io::Error::new(io::ErrorKind::InvalidData, msg);
+
+    let mut header = [0u8; 9];
+    reader.read_exact(&mut header)?;
+
+    let n = u64::from_le_bytes(header[..8].try_into().unwrap());
+    let n =
+        usize::try_from(n).map_err(|_| invalid(format!("coefficient count {n} is too large")))?;
+
+    let width = packed_bit_width::<F>();
+    if header[8] as u32 != width {
+        return Err(invalid(format!(
+            "bit width {} mismatches the field bit width {width}",
+            header[8]
+        )));
+    }
+
+    let byte_count = n
+        .checked_mul(width as usize)
+        .map(|bits| bits.div_ceil(8))
+        .ok_or_else(|| invalid(format!("coefficient count {n} is too large")))?;
+    // `n` comes from the input, so the bytes are read before anything of
+    // that size is allocated, a truncated input fails without the allocation.
+    let mut bytes = Vec::new();
+    reader
+        .by_ref()
+        .take(byte_count as u64)
+        .read_to_end(&mut bytes)?;
+    if bytes.len() != byte_count {
+        return Err(io::Error::new(
+            io::ErrorKind::UnexpectedEof,
+            format!(
+                "expected {byte_count} bytes of coefficients, found {}",
+                bytes.len()
+            ),
+        ));
+    }
+
+    let mask: u128 = (1u128 << width) - 1;
+    let modulus: u64 = <F as Field>::MODULUS_VALUE.as_into();
+
+    let mut values = Vec::with_capacity(n);
+    let mut buffer: u128 = 0;
+    let mut buffered_bits = 0u32;
+    let mut iter = bytes.into_iter();
+    for _ in 0..n {
+        while buffered_bits < width {
+            buffer |= (iter.next().unwrap() as u128) << buffered_bits;
+            buffered_bits += 8;
+        }
+        let value = (buffer & mask) as u64;
+        buffer >>= width;
+        buffered_bits -= width;
+
+        if value >= modulus {
+            return Err(invalid(format!(
+                "coefficient {value} is not less than the modulus {modulus}"
+            )));
+        }
+        values.push(<F as Field>::ValueT::as_from(value));
+    }
+
+    Ok(values)
+}
+
+impl<F: Field> FieldPolynomial<F> {
+    /// Writes `self` to `writer` with each coefficient bit-packed
+    /// into the bit count of the field modulus.
+    #[inline]
+    pub fn write_packed<W: Write>(&self, writer: W) -> io::Result<()> {
+        write_packed_values::<F, W>(self.as_slice(), writer)
+    }
+
+    /// Reads a polynomial written by [`FieldPolynomial::write_packed`] from `reader`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the stream is truncated, if its bit width mismatches the field,
+    /// or if any coefficient is not less than the field modulus.
+    #[inline]
+    pub fn read_packed<R: Read>(reader: R) -> io::Result<Self> {
+        read_packed_values::<F, R>(reader).map(Self::new)
+    }
+}
+
+impl<F: NttField> FieldNttPolynomial<F> {
+    /// Writes `self` to `writer` with each value bit-packed
+    /// into the bit count of the field modulus.
+    #[inline]
+    pub fn write_packed<W: Write>(&self, writer: W) -> io::Result<()> {
+        write_packed_values::<F, W>(self.as_slice(), writer)
+    }
+
+    /// Reads a polynomial written by [`FieldNttPolynomial::write_packed`] from `reader`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the stream is truncated, if its bit width mismatches the field,
+    /// or if any value is not less than the field modulus.
+    #[inline]
+    pub fn read_packed<R: Read>(reader: R) -> io::Result<Self> {
+        read_packed_values::<F, R>(reader).map(Self::new)
+    }
+}
@@ -0,0 +1,299 @@
+use core::ops::{AddAssign, SubAssign};
+
+use num_traits::ConstZero;
+
+use crate::{
+    decompose::NonPowOf2ApproxSignedBasis,
+    ntt::NumberTheoryTransform,
+    polynomial::{poly_add_assign_slice, poly_neg_assign_slice, poly_sub_assign_slice},
+    reduce::{ReduceAdd, ReduceSub},
+    Field, NttField,
+};
+
+use super::{FieldNttPolynomial, FieldPolynomial};
+
+#[inline]
+#[track_caller]
+fn assert_same_len(lhs: usize, rhs: usize) {
+    assert_eq!(
+        lhs, rhs,
+        "polynomial view length mismatch: {lhs} coefficients vs {rhs} coefficients"
+    );
+}
+
+/// A borrowed, read-only view of a polynomial with coefficients in the field `F`.
+///
+/// It allows a sub-slice of a larger buffer to be used as a polynomial without copying.
+pub struct PolyRef<'a, F: Field> {
+    data: &'a [<F as Field>::ValueT],
+}
+
+/// A borrowed, mutable view of a polynomial with coefficients in the field `F`.
+///
+/// It allows a sub-slice of a larger buffer to be used as a polynomial without copying.
+pub struct PolyMut<'a, F: Field> {
+    data: &'a mut [<F as Field>::ValueT],
+}
+
+impl<F: Field> Clone for PolyRef<'_, F> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<F: Field> Copy for PolyRef<'_, F> {}
+
+impl<F: Field> core::fmt::Debug for PolyRef<'_, F> {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("PolyRef").field("data", &self.data).finish()
+    }
+}
+
+impl<F: Field> core::fmt::Debug for PolyMut<'_, F> {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("PolyMut").field("data", &self.data).finish()
+    }
+}
+
+impl<'a, F: Field> PolyRef<'a, F> {
+    /// Creates a new [`PolyRef<'a, F>`] over `data`.
+    #[inline]
+    pub fn new(data: &'a [<F as Field>::ValueT]) -> Self {
+        Self { data }
+    }
+
+    /// Extracts the underlying slice.
+    #[inline]
+    pub fn as_slice(&self) -> &'a [<F as Field>::ValueT] {
+        self.data
+    }
+
+    /// Get the coefficient counts of polynomial.
+    #[inline]
+    pub fn coeff_count(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Returns an iterator that allows reading each coefficient of the polynomial.
+    #[inline]
+    pub fn iter(&self) -> core::slice::Iter<'a, <F as Field>::ValueT> {
+        self.data.iter()
+    }
+
+    /// Copies the view into an owned [`FieldPolynomial<F>`].
+    #[inline]
+    pub fn to_field_polynomial(&self) -> FieldPolynomial<F> {
+        FieldPolynomial::from_slice(self.data)
+    }
+
+    /// Performs addition operation:`self + rhs`,
+    /// and puts the result to the `destination`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the three views have different lengths.
+    #[inline]
+    pub fn add_inplace(&self, rhs: PolyRef<'_, F>, mut destination: PolyMut<'_, F>) {
+        assert_same_len(self.coeff_count(), rhs.coeff_count());
+        assert_same_len(self.coeff_count(), destination.coeff_count());
+        self.iter()
+            .zip(rhs.iter())
+            .zip(destination.iter_mut())
+            .for_each(|((&a, &b), c)| *c = F::MODULUS.reduce_add(a, b));
+    }
+
+    /// Performs subtraction operation:`self - rhs`,
+    /// and puts the result to the `destination`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the three views have different lengths.
+    #[inline]
+    pub fn sub_inplace(&self, rhs: PolyRef<'_, F>, mut destination: PolyMut<'_, F>) {
+        assert_same_len(self.coeff_count(), rhs.coeff_count());
+        assert_same_len(self.coeff_count(), destination.coeff_count());
+        self.iter()
+            .zip(rhs.iter())
+            .zip(destination.iter_mut())
+            .for_each(|((&a, &b), c)| *c = F::MODULUS.reduce_sub(a, b));
+    }
+
+    /// Decomposes the polynomial into `decompose_length` polynomials,
+    /// least significant digit first, reusing the polynomials in `decompose_polys`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `decompose_polys` is less than `decompose_length`,
+    /// or if any of them has a different coefficient count.
+    pub fn decompose_into(
+        &self,
+        basis: &NonPowOf2ApproxSignedBasis<<F as Field>::ValueT>,
+        decompose_polys: &mut [FieldPolynomial<F>],
+    ) {
+        assert!(
+            decompose_polys.len() >= basis.decompose_length(),
+            "polynomial buffer length {} is less than decompose length {}",
+            decompose_polys.len(),
+            basis.decompose_length()
+        );
+        let n = self.coeff_count();
+        decompose_polys
+            .iter()
+            .for_each(|p| assert_same_len(n, p.coeff_count()));
+
+        for (i, &value) in self.iter().enumerate() {
+            let (adjust_value, mut carry) = basis.init_value_carry(value);
+            basis
+                .decompose_iter()
+                .zip(decompose_polys.iter_mut())
+                .for_each(|(once_decompose, poly)| {
+                    once_decompose.decompose_inplace(adjust_value, &mut carry, &mut poly[i])
+                });
+        }
+    }
+}
+
+impl<F: NttField> PolyRef<'_, F> {
+    /// Performs a number theory transform of the view,
+    /// and puts the result to the `destination`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `destination` has a different length.
+    #[inline]
+    pub fn transform_into(
+        &self,
+        ntt_table: &<F as NttField>::Table,
+        destination: &mut FieldNttPolynomial<F>,
+    ) {
+        let values = destination.as_mut_slice();
+        assert_same_len(self.coeff_count(), values.len());
+        values.copy_from_slice(self.data);
+        ntt_table.transform_slice(values);
+    }
+}
+
+impl<'a, F: Field> PolyMut<'a, F> {
+    /// Creates a new [`PolyMut<'a, F>`] over `data`.
+    #[inline]
+    pub fn new(data: &'a mut [<F as Field>::ValueT]) -> Self {
+        Self { data }
+    }
+
+    /// Extracts the underlying slice.
+    #[inline]
+    pub fn as_slice(&self) -> &[<F as Field>::ValueT] {
+        self.data
+    }
+
+    /// Extracts the underlying mutable slice.
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [<F as Field>::ValueT] {
+        self.data
+    }
+
+    /// Reborrows the view as a [`PolyRef<'_, F>`].
+    #[inline]
+    pub fn as_ref_poly(&self) -> PolyRef<'_, F> {
+        PolyRef::new(self.data)
+    }
+
+    /// Reborrows the view with a shorter lifetime.
+    #[inline]
+    pub fn reborrow(&mut self) -> PolyMut<'_, F> {
+        PolyMut::new(self.data)
+    }
+
+    /// Get the coefficient counts of polynomial.
+    #[inline]
+    pub fn coeff_count(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Returns an iterator that allows reading each coefficient of the polynomial.
+    #[inline]
+    pub fn iter(&self) -> core::slice::Iter<'_, <F as Field>::ValueT> {
+        self.data.iter()
+    }
+
+    /// Returns an iterator that allows modifying each coefficient of the polynomial.
+    #[inline]
+    pub fn iter_mut(&mut self) -> core::slice::IterMut<'_, <F as Field>::ValueT> {
+        self.data.iter_mut()
+    }
+
+    /// Copy the coefficients from another view.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `src` has a different length.
+    #[inline]
+    pub fn copy_from(&mut self, src: PolyRef<'_, F>) {
+        assert_same_len(self.coeff_count(), src.coeff_count());
+        self.data.copy_from_slice(src.as_slice());
+    }
+
+    /// Sets `self` to `0`.
+    #[inline]
+    pub fn set_zero(&mut self) {
+        self.data.fill(<F as Field>::ValueT::ZERO);
+    }
+
+    /// Performs the unary `-` operation.
+    #[inline]
+    pub fn neg_assign(&mut self) {
+        poly_neg_assign_slice::<F>(self.data);
+    }
+
+    /// Maps `p(X)` to `p(X^(-1))` in `Z_q[X]/(X^n + 1)`.
+    ///
+    /// The constant term is kept, and the rest coefficients are reversed and negated.
+    #[inline]
+    pub fn reverse_coeffs_negacyclic(&mut self) {
+        if let Some((_, tail)) = self.data.split_first_mut() {
+            tail.reverse();
+            poly_neg_assign_slice::<F>(tail);
+        }
+    }
+}
+
+impl<F: NttField> PolyMut<'_, F> {
+    /// Performs a number theory transform of the view in place.
+    #[inline]
+    pub fn transform_assign(&mut self, ntt_table: &<F as NttField>::Table) {
+        ntt_table.transform_slice(self.data);
+    }
+}
+
+impl<F: Field> AddAssign<PolyRef<'_, F>> for PolyMut<'_, F> {
+    #[inline]
+    fn add_assign(&mut self, rhs: PolyRef<'_, F>) {
+        assert_same_len(self.coeff_count(), rhs.coeff_count());
+        poly_add_assign_slice::<F>(self.data, rhs.as_slice());
+    }
+}
+
+impl<F: Field> SubAssign<PolyRef<'_, F>> for PolyMut<'_, F> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: PolyRef<'_, F>) {
+        assert_same_len(self.coeff_count(), rhs.coeff_count());
+        poly_sub_assign_slice::<F>(self.data, rhs.as_slice());
+    }
+}
+
+impl<F: Field> FieldPolynomial<F> {
+    /// Borrows the polynomial as a [`PolyRef<'_, F>`].
+    #[inline]
+    pub fn as_ref_poly(&self) -> PolyRef<'_, F> {
+        PolyRef::new(self.as_slice())
+    }
+
+    /// Borrows the polynomial as a [`PolyMut<'_, F>`].
+    #[inline]
+    pub fn as_mut_poly(&mut self) -> PolyMut<'_, F> {
+        PolyMut::new(self.as_mut_slice())
+    }
+}
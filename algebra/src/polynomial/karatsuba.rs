@@ -0,0 +1,121 @@
+use alloc::{vec, vec::Vec};
+
+use num_traits::ConstZero;
+
+use crate::reduce::{ReduceAdd, ReduceAddAssign, ReduceMul, ReduceSub, ReduceSubAssign};
+
+/// Below this length, karatsuba multiplication falls back to schoolbook multiplication.
+const KARATSUBA_THRESHOLD: usize = 32;
+
+/// Calculates `a * b mod (x^n + 1)` by schoolbook multiplication.
+pub(crate) fn negacyclic_naive_mul<T, M>(a: &[T], b: &[T], modulus: M) -> Vec<T>
+where
+    T: Copy + ConstZero,
+    M: Copy + ReduceAddAssign<T> + ReduceSubAssign<T> + ReduceMul<T, Output = T>,
+{
+    let n = a.len();
+    assert_eq!(n, b.len());
+
+    let mut result = vec![T::ZERO; n];
+    for (i, &x) in a.iter().enumerate() {
+        for (j, &y) in b.iter().enumerate() {
+            let k = i + j;
+            if k < n {
+                modulus.reduce_add_assign(&mut result[k], modulus.reduce_mul(x, y));
+            } else {
+                modulus.reduce_sub_assign(&mut result[k - n], modulus.reduce_mul(x, y));
+            }
+        }
+    }
+    result
+}
+
+/// Calculates `a * b mod (x^n + 1)` by karatsuba multiplication.
+pub(crate) fn negacyclic_karatsuba_mul<T, M>(a: &[T], b: &[T], modulus: M) -> Vec<T>
+where
+    T: Copy + ConstZero,
+    M: Copy
+        + ReduceAdd<T, Output = T>
+        + ReduceSub<T, Output = T>
+        + ReduceAddAssign<T>
+        + ReduceSubAssign<T>
+        + ReduceMul<T, Output = T>,
+{
+    let n = a.len();
+    assert_eq!(n, b.len());
+    if n == 0 {
+        return Vec::new();
+    }
+
+    let mut product = karatsuba_mul(a, b, modulus);
+
+    // mod (x^n + 1)
+    let high = product.split_off(n);
+    product
+        .iter_mut()
+        .zip(high)
+        .for_each(|(r, h)| modulus.reduce_sub_assign(r, h));
+    product
+}
+
+/// Calculates the full product `a * b`, with `2n - 1` coefficients.
+fn karatsuba_mul<T, M>(a: &[T], b: &[T], modulus: M) -> Vec<T>
+where
+    T: Copy + ConstZero,
+    M: Copy
+        + ReduceAdd<T, Output = T>
+        + ReduceSub<T, Output = T>
+        + ReduceAddAssign<T>
+        + ReduceSubAssign<T>
+        + ReduceMul<T, Output = T>,
+{
+    let n = a.len();
+    debug_assert_eq!(n, b.len());
+
+    if n <= KARATSUBA_THRESHOLD {
+        let mut result = vec![T::ZERO; 2 * n - 1];
+        for (i, &x) in a.iter().enumerate() {
+            for (j, &y) in b.iter().enumerate() {
+                modulus.reduce_add_assign(&mut result[i + j], modulus.reduce_mul(x, y));
+            }
+        }
+        return result;
+    }
+
+    // a = a0 + x^h * a1, b = b0 + x^h * b1
+    let h = n / 2;
+    let (a0, a1) = a.split_at(h);
+    let (b0, b1) = b.split_at(h);
+
+    let z0 = karatsuba_mul(a0, b0, modulus);
+    let z2 = karatsuba_mul(a1, b1, modulus);
+
+    // (a0 + a1) * (b0 + b1) - z0 - z2
+    let mut a_sum = a1.to_vec();
+    a_sum
+        .iter_mut()
+        .zip(a0)
+        .for_each(|(s, &v)| modulus.reduce_add_assign(s, v));
+    let mut b_sum = b1.to_vec();
+    b_sum
+        .iter_mut()
+        .zip(b0)
+        .for_each(|(s, &v)| modulus.reduce_add_assign(s, v));
+    let mut z1 = karatsuba_mul(&a_sum, &b_sum, modulus);
+    z1.iter_mut()
+        .zip(z0.iter())
+        .for_each(|(r, &v)| modulus.reduce_sub_assign(r, v));
+    z1.iter_mut()
+        .zip(z2.iter())
+        .for_each(|(r, &v)| modulus.reduce_sub_assign(r, v));
+
+    // z0 + x^h * z1 + x^(2h) * z2
+    let mut result = vec![T::ZERO; 2 * n - 1];
+    result[..z0.len()].copy_from_slice(&z0);
+    result[2 * h..].copy_from_slice(&z2);
+    result[h..]
+        .iter_mut()
+        .zip(z1)
+        .for_each(|(r, v)| *r = modulus.reduce_add(*r, v));
+    result
+}
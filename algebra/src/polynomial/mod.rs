@@ -1,7 +1,28 @@
 //! Defines polynomial.
 
+pub mod batching;
+
 mod field;
+mod karatsuba;
 mod numeric;
+mod ops;
+#[cfg(feature = "parallel")]
+mod parallel;
+mod rounding;
+mod shift;
+mod slice;
+mod sparse;
 
-pub use field::{FieldNttPolynomial, FieldPolynomial};
+pub use batching::{batch_decode, batch_encode, BatchEncoder};
+pub use field::{rescale_slice, FieldNttPolynomial, FieldPolynomial, PolyMut, PolyRef};
 pub use numeric::{NttPolynomial, Polynomial};
+pub use ops::PolyOps;
+#[cfg(feature = "parallel")]
+pub use parallel::{parallel_threshold, set_parallel_threshold};
+pub use rounding::RoundingMode;
+pub use shift::shift_add_accumulate;
+pub use slice::{
+    poly_add_assign_slice, poly_neg_assign_slice, poly_scalar_mul_assign_slice,
+    poly_sub_assign_slice,
+};
+pub use sparse::SparseTernaryPoly;
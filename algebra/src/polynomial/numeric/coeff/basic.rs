@@ -1,3 +1,4 @@
+use alloc::vec::Vec;
 use core::{
     ops::{Index, IndexMut},
     slice::SliceIndex,
@@ -38,7 +39,7 @@ impl<T> AsMut<[T]> for Polynomial<T> {
 impl<T> IntoIterator for Polynomial<T> {
     type Item = T;
 
-    type IntoIter = std::vec::IntoIter<T>;
+    type IntoIter = alloc::vec::IntoIter<T>;
 
     #[inline]
     fn into_iter(self) -> Self::IntoIter {
@@ -67,3 +68,19 @@ impl<'a, T> IntoIterator for &'a mut Polynomial<T> {
         self.poly.iter_mut()
     }
 }
+
+impl<T> From<Vec<T>> for Polynomial<T> {
+    #[inline]
+    fn from(poly: Vec<T>) -> Self {
+        Self { poly }
+    }
+}
+
+impl<T> FromIterator<T> for Polynomial<T> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self {
+            poly: iter.into_iter().collect(),
+        }
+    }
+}
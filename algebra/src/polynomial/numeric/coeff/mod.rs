@@ -1,3 +1,5 @@
+use alloc::{vec, vec::Vec};
+
 use num_traits::{ConstZero, Zero};
 
 use crate::reduce::ReduceMulAdd;
@@ -13,7 +15,7 @@ mod neg;
 mod sub;
 
 /// Represents a polynomial where coefficients are elements of a specified numeric `T`.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Polynomial<T> {
     poly: Vec<T>,
 }
@@ -62,13 +64,13 @@ impl<T> Polynomial<T> {
 
     /// Returns an iterator that allows reading each value or coefficient of the polynomial.
     #[inline]
-    pub fn iter(&self) -> std::slice::Iter<T> {
+    pub fn iter(&self) -> core::slice::Iter<'_, T> {
         self.poly.iter()
     }
 
     /// Returns an iterator that allows modifying each value or coefficient of the polynomial.
     #[inline]
-    pub fn iter_mut(&mut self) -> std::slice::IterMut<T> {
+    pub fn iter_mut(&mut self) -> core::slice::IterMut<'_, T> {
         self.poly.iter_mut()
     }
 
@@ -147,4 +149,13 @@ where
                 modulus.reduce_mul_add(acc, x, a)
             })
     }
+
+    /// Evaluate `p(x)` for every `x` in `points`.
+    #[inline]
+    pub fn evaluate_many<Modulus>(&self, points: &[T], modulus: Modulus) -> Vec<T>
+    where
+        Modulus: Copy + ReduceMulAdd<T, Output = T>,
+    {
+        points.iter().map(|&x| self.evaluate(x, modulus)).collect()
+    }
 }
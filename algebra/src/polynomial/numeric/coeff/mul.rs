@@ -3,8 +3,12 @@ use crate::{
     modulus::ShoupFactor,
     ntt::{NttTable, NumberTheoryTransform},
     numeric::Numeric,
+    polynomial::karatsuba::{negacyclic_karatsuba_mul, negacyclic_naive_mul},
     polynomial::NttPolynomial,
-    reduce::{ReduceAddAssign, ReduceMul, ReduceMulAdd, ReduceMulAssign, ReduceSubAssign},
+    reduce::{
+        ReduceAdd, ReduceAddAssign, ReduceMul, ReduceMulAdd, ReduceMulAssign, ReduceSub,
+        ReduceSubAssign,
+    },
 };
 
 use super::Polynomial;
@@ -43,6 +47,41 @@ impl<T: Copy> Polynomial<T> {
 }
 
 impl<T: UnsignedInteger> Polynomial<T> {
+    /// Multiply `self` with `rhs` modulo `x^n + 1` by schoolbook multiplication.
+    ///
+    /// It runs in `O(n^2)` and works for any modulus, so it is mainly
+    /// used as a reference for the other multiplication algorithms.
+    pub fn mul_naive<M>(&self, rhs: &Self, modulus: M) -> Self
+    where
+        M: Copy + ReduceAddAssign<T> + ReduceSubAssign<T> + ReduceMul<T, Output = T>,
+    {
+        Self::new(negacyclic_naive_mul(
+            self.as_slice(),
+            rhs.as_slice(),
+            modulus,
+        ))
+    }
+
+    /// Multiply `self` with `rhs` modulo `x^n + 1` by karatsuba multiplication.
+    ///
+    /// It runs in `O(n^1.58)` and works for any modulus, including
+    /// the moduli which do not support number theory transform.
+    pub fn mul_karatsuba<M>(&self, rhs: &Self, modulus: M) -> Self
+    where
+        M: Copy
+            + ReduceAdd<T, Output = T>
+            + ReduceSub<T, Output = T>
+            + ReduceAddAssign<T>
+            + ReduceSubAssign<T>
+            + ReduceMul<T, Output = T>,
+    {
+        Self::new(negacyclic_karatsuba_mul(
+            self.as_slice(),
+            rhs.as_slice(),
+            modulus,
+        ))
+    }
+
     /// A naive multiplication over polynomial.
     pub fn naive_mul_inplace<M>(&self, rhs: impl AsRef<[T]>, modulus: M, destination: &mut Self)
     where
@@ -1,3 +1,4 @@
+use alloc::vec::Vec;
 use core::{
     ops::{Index, IndexMut},
     slice::SliceIndex,
@@ -38,7 +39,7 @@ impl<T> AsMut<[T]> for NttPolynomial<T> {
 impl<T> IntoIterator for NttPolynomial<T> {
     type Item = T;
 
-    type IntoIter = std::vec::IntoIter<T>;
+    type IntoIter = alloc::vec::IntoIter<T>;
 
     #[inline]
     fn into_iter(self) -> Self::IntoIter {
@@ -67,3 +68,19 @@ impl<'a, T> IntoIterator for &'a mut NttPolynomial<T> {
         self.values.iter_mut()
     }
 }
+
+impl<T> From<Vec<T>> for NttPolynomial<T> {
+    #[inline]
+    fn from(values: Vec<T>) -> Self {
+        Self { values }
+    }
+}
+
+impl<T> FromIterator<T> for NttPolynomial<T> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self {
+            values: iter.into_iter().collect(),
+        }
+    }
+}
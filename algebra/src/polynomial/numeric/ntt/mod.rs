@@ -1,3 +1,5 @@
+use alloc::{vec, vec::Vec};
+
 use num_traits::{ConstZero, Zero};
 
 use crate::{
@@ -16,7 +18,9 @@ mod neg;
 mod sub;
 
 /// Represents a ntt polynomial where values are elements of a specified numeric `T`.
-#[derive(Debug, Clone, PartialEq, Eq)]
+///
+/// The values are stored in bit-reversed order, see [`NttOrdering`](crate::ntt::NttOrdering).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct NttPolynomial<T> {
     values: Vec<T>,
 }
@@ -65,13 +69,13 @@ impl<T> NttPolynomial<T> {
 
     /// Returns an iterator that allows reading each value or coefficient of the polynomial.
     #[inline]
-    pub fn iter(&self) -> core::slice::Iter<T> {
+    pub fn iter(&self) -> core::slice::Iter<'_, T> {
         self.values.iter()
     }
 
     /// Returns an iterator that allows modifying each value or coefficient of the polynomial.
     #[inline]
-    pub fn iter_mut(&mut self) -> core::slice::IterMut<T> {
+    pub fn iter_mut(&mut self) -> core::slice::IterMut<'_, T> {
         self.values.iter_mut()
     }
 
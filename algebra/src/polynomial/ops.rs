@@ -0,0 +1,109 @@
+use core::{
+    fmt::Debug,
+    ops::{AddAssign, SubAssign},
+};
+
+use crate::{Field, NttField};
+
+use super::{FieldNttPolynomial, FieldPolynomial};
+
+/// Operations shared by the coefficient form [`FieldPolynomial<F>`]
+/// and the NTT form [`FieldNttPolynomial<F>`] of a polynomial.
+///
+/// It lets code such as RLWE arithmetic be written once for both representations.
+pub trait PolyOps<F: NttField>:
+    Sized + Clone + PartialEq + Debug + for<'a> AddAssign<&'a Self> + for<'a> SubAssign<&'a Self>
+{
+    /// The representation of the same polynomial in the other domain.
+    type Dual: PolyOps<F, Dual = Self>;
+
+    /// Creates a zero polynomial with `coeff_count` coefficients.
+    fn zero(coeff_count: usize) -> Self;
+
+    /// Returns the number of coefficients (or values) of `self`.
+    fn coeff_count(&self) -> usize;
+
+    /// Performs `self = -self`.
+    fn neg_assign(&mut self);
+
+    /// Performs `self = self * scalar`.
+    fn mul_scalar_assign(&mut self, scalar: <F as Field>::ValueT);
+
+    /// Performs `self = self + (a * b)`.
+    ///
+    /// The coefficient form goes through the NTT with `ntt_table`,
+    /// the NTT form ignores it.
+    fn add_mul_assign(&mut self, a: &Self, b: &Self, ntt_table: &<F as NttField>::Table);
+
+    /// Converts `self` into the other representation.
+    fn into_dual(self, ntt_table: &<F as NttField>::Table) -> Self::Dual;
+}
+
+impl<F: NttField> PolyOps<F> for FieldPolynomial<F> {
+    type Dual = FieldNttPolynomial<F>;
+
+    #[inline]
+    fn zero(coeff_count: usize) -> Self {
+        FieldPolynomial::zero(coeff_count)
+    }
+
+    #[inline]
+    fn coeff_count(&self) -> usize {
+        FieldPolynomial::coeff_count(self)
+    }
+
+    #[inline]
+    fn neg_assign(&mut self) {
+        FieldPolynomial::neg_assign(self)
+    }
+
+    #[inline]
+    fn mul_scalar_assign(&mut self, scalar: <F as Field>::ValueT) {
+        FieldPolynomial::mul_scalar_assign(self, scalar)
+    }
+
+    #[inline]
+    fn add_mul_assign(&mut self, a: &Self, b: &Self, ntt_table: &<F as NttField>::Table) {
+        let product = a.clone().mul(b.clone(), ntt_table);
+        *self += &product;
+    }
+
+    #[inline]
+    fn into_dual(self, ntt_table: &<F as NttField>::Table) -> Self::Dual {
+        self.into_ntt_poly(ntt_table)
+    }
+}
+
+impl<F: NttField> PolyOps<F> for FieldNttPolynomial<F> {
+    type Dual = FieldPolynomial<F>;
+
+    #[inline]
+    fn zero(coeff_count: usize) -> Self {
+        FieldNttPolynomial::zero(coeff_count)
+    }
+
+    #[inline]
+    fn coeff_count(&self) -> usize {
+        FieldNttPolynomial::coeff_count(self)
+    }
+
+    #[inline]
+    fn neg_assign(&mut self) {
+        FieldNttPolynomial::neg_assign(self)
+    }
+
+    #[inline]
+    fn mul_scalar_assign(&mut self, scalar: <F as Field>::ValueT) {
+        FieldNttPolynomial::mul_scalar_assign(self, scalar)
+    }
+
+    #[inline]
+    fn add_mul_assign(&mut self, a: &Self, b: &Self, _ntt_table: &<F as NttField>::Table) {
+        FieldNttPolynomial::add_mul_assign(self, a, b)
+    }
+
+    #[inline]
+    fn into_dual(self, ntt_table: &<F as NttField>::Table) -> Self::Dual {
+        self.into_coeff_poly(ntt_table)
+    }
+}
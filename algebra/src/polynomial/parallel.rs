@@ -0,0 +1,122 @@
+//! Rayon-parallel variants of the coefficient-wise polynomial kernels.
+//!
+//! The coefficient range is split into chunks of at least
+//! [`parallel_threshold`] coefficients, polynomials shorter than
+//! that run on the serial kernels.
+
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+use rayon::prelude::*;
+
+use crate::{decompose::SignedOnceDecompose, reduce::ReduceMulAdd, Field, NttField};
+
+use super::{poly_add_assign_slice, FieldNttPolynomial, FieldPolynomial};
+
+static PARALLEL_THRESHOLD: AtomicUsize = AtomicUsize::new(4096);
+
+/// Sets the minimum number of coefficients handled by one parallel task.
+///
+/// # Panics
+///
+/// Panics if `min_chunk_len` is zero.
+#[inline]
+pub fn set_parallel_threshold(min_chunk_len: usize) {
+    assert!(min_chunk_len > 0, "parallel threshold must be positive");
+    PARALLEL_THRESHOLD.store(min_chunk_len, Ordering::Relaxed);
+}
+
+/// Returns the minimum number of coefficients handled by one parallel task.
+#[inline]
+pub fn parallel_threshold() -> usize {
+    PARALLEL_THRESHOLD.load(Ordering::Relaxed)
+}
+
+/// Returns the chunk length for `len` coefficients,
+/// or `None` if they should be handled serially.
+#[inline]
+fn chunk_len(len: usize) -> Option<usize> {
+    let threshold = parallel_threshold();
+    (len >= threshold).then(|| len.div_ceil(rayon::current_num_threads()).max(threshold))
+}
+
+#[inline]
+fn add_mul_assign_slice<F: Field>(
+    values: &mut [<F as Field>::ValueT],
+    a: &[<F as Field>::ValueT],
+    b: &[<F as Field>::ValueT],
+) {
+    values
+        .iter_mut()
+        .zip(a)
+        .zip(b)
+        .for_each(|((z, &x), &y)| *z = F::MODULUS.reduce_mul_add(x, y, *z));
+}
+
+impl<F: Field> FieldPolynomial<F> {
+    /// Performs `self += rhs`, splitting the coefficients across the rayon pool.
+    pub fn par_add_assign(&mut self, rhs: &Self) {
+        assert_eq!(self.coeff_count(), rhs.coeff_count());
+        match chunk_len(self.coeff_count()) {
+            Some(chunk) => self
+                .as_mut_slice()
+                .par_chunks_mut(chunk)
+                .zip(rhs.as_slice().par_chunks(chunk))
+                .for_each(|(x, y)| poly_add_assign_slice::<F>(x, y)),
+            None => poly_add_assign_slice::<F>(self.as_mut_slice(), rhs.as_slice()),
+        }
+    }
+
+    /// Parallel version of [`FieldPolynomial::approx_signed_decompose`].
+    pub fn par_approx_signed_decompose(
+        &self,
+        once_decompose: SignedOnceDecompose<<F as Field>::ValueT>,
+        carries: &mut [bool],
+        decompose_poly: &mut [<F as Field>::ValueT],
+    ) {
+        let n = self.coeff_count();
+        assert!(carries.len() == n && decompose_poly.len() == n);
+        match chunk_len(n) {
+            Some(chunk) => self
+                .as_slice()
+                .par_chunks(chunk)
+                .zip(carries.par_chunks_mut(chunk))
+                .zip(decompose_poly.par_chunks_mut(chunk))
+                .for_each(|((values, carries), des)| {
+                    once_decompose.decompose_slice_inplace(values, carries, des)
+                }),
+            None => {
+                once_decompose.decompose_slice_inplace(self.as_slice(), carries, decompose_poly)
+            }
+        }
+    }
+}
+
+impl<F: NttField> FieldNttPolynomial<F> {
+    /// Performs `self += rhs`, splitting the values across the rayon pool.
+    pub fn par_add_assign(&mut self, rhs: &Self) {
+        assert_eq!(self.coeff_count(), rhs.coeff_count());
+        match chunk_len(self.coeff_count()) {
+            Some(chunk) => self
+                .as_mut_slice()
+                .par_chunks_mut(chunk)
+                .zip(rhs.as_slice().par_chunks(chunk))
+                .for_each(|(x, y)| poly_add_assign_slice::<F>(x, y)),
+            None => poly_add_assign_slice::<F>(self.as_mut_slice(), rhs.as_slice()),
+        }
+    }
+
+    /// Performs `self = self + (a * b)`, splitting the values across the rayon pool.
+    pub fn par_add_mul_assign(&mut self, a: &Self, b: &Self) {
+        let n = self.coeff_count();
+        assert!(a.coeff_count() == n && b.coeff_count() == n);
+        match chunk_len(n) {
+            Some(chunk) => self
+                .as_mut_slice()
+                .par_chunks_mut(chunk)
+                .zip(a.as_slice().par_chunks(chunk))
+                .zip(b.as_slice().par_chunks(chunk))
+                .for_each(|((z, x), y)| add_mul_assign_slice::<F>(z, x, y)),
+            None => add_mul_assign_slice::<F>(self.as_mut_slice(), a.as_slice(), b.as_slice()),
+        }
+    }
+}
@@ -0,0 +1,96 @@
+use rand::{Rng, RngCore};
+
+use crate::{
+    integer::{AsFrom, AsInto, UnsignedInteger},
+    modulus::PowOf2Modulus,
+    Field,
+};
+
+use super::{FieldPolynomial, Polynomial};
+
+/// The rounding rule used when dividing by a power of two.
+pub enum RoundingMode<'a> {
+    /// Rounds toward negative infinity.
+    Floor,
+    /// Rounds to the nearest integer, ties are rounded up.
+    Round,
+    /// Rounds up with probability equal to the fractional part,
+    /// so that the result is unbiased in expectation.
+    Stochastic(&'a mut dyn RngCore),
+}
+
+impl RoundingMode<'_> {
+    /// Returns whether `floor(x / 2^k)` should be rounded up,
+    /// where `remainder = x mod 2^k`.
+    #[inline]
+    fn round_up(&mut self, remainder: u128, k: u32) -> bool {
+        match self {
+            RoundingMode::Floor => false,
+            RoundingMode::Round => k > 0 && (remainder >> (k - 1)) & 1 == 1,
+            RoundingMode::Stochastic(rng) => {
+                remainder != 0 && rng.gen_range(0..1u128 << k) < remainder
+            }
+        }
+    }
+}
+
+impl<T: UnsignedInteger> Polynomial<T> {
+    /// Divides every coefficient by `2^k` with the given rounding `mode`,
+    /// switching the coefficients from `modulus` to `modulus / 2^k`.
+    ///
+    /// The coefficients are treated as their raw representatives in `[0, modulus)`,
+    /// a value rounded up to `modulus / 2^k` wraps around to zero.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `2^k` is larger than the modulus value.
+    pub fn div_round_pow2_assign(
+        &mut self,
+        k: u32,
+        mut mode: RoundingMode<'_>,
+        modulus: PowOf2Modulus<T>,
+    ) {
+        let mask: u128 = modulus.mask().as_into();
+        assert!(
+            k <= u128::BITS - mask.leading_zeros(),
+            "2^k exceeds the modulus"
+        );
+        let out_mask = mask >> k;
+        let remainder_mask = (1u128 << k) - 1;
+
+        self.iter_mut().for_each(|v| {
+            let x: u128 = (*v).as_into();
+            let up = mode.round_up(x & remainder_mask, k) as u128;
+            *v = T::as_from(((x >> k) + up) & out_mask);
+        });
+    }
+}
+
+impl<F: Field> FieldPolynomial<F> {
+    /// Divides every coefficient by `2^k` with the given rounding `mode`.
+    ///
+    /// Each coefficient is lifted to its centered representative `c` in `[-q/2, q/2]`,
+    /// and mapped to the rounded `c / 2^k` modulo `q`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `k` is not less than `64`.
+    pub fn div_round_pow2_assign(&mut self, k: u32, mut mode: RoundingMode<'_>) {
+        assert!(k < 64, "k should be less than 64");
+        let q: u128 = <F as Field>::MODULUS_VALUE.as_into();
+        let q_i = q as i128;
+        let remainder_mask = (1i128 << k) - 1;
+
+        self.iter_mut().for_each(|v| {
+            let x: u128 = (*v).as_into();
+            let centered = if x <= q >> 1 {
+                x as i128
+            } else {
+                x as i128 - q_i
+            };
+            let floor = centered >> k;
+            let up = mode.round_up((centered & remainder_mask) as u128, k) as i128;
+            *v = <F as Field>::ValueT::as_from((floor + up).rem_euclid(q_i) as u128);
+        });
+    }
+}
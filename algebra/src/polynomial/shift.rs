@@ -0,0 +1,58 @@
+use crate::{
+    reduce::{ReduceAddAssign, ReduceSubAssign},
+    Field,
+};
+
+use super::FieldPolynomial;
+
+/// Performs `acc += X^shift * src` (or `acc -= X^shift * src` when `negate` is set)
+/// in the ring `F[X]/(X^n + 1)`.
+///
+/// `shift` is taken modulo `2n`. Since `X^n = -1`, a coefficient of `src` whose
+/// index `i` satisfies `n <= i + shift < 2n` wraps around to index `i + shift - n`
+/// with its sign flipped, and it wraps back with its original sign once
+/// `i + shift` reaches `2n`.
+///
+/// # Panics
+///
+/// Panics if `acc` and `src` have different lengths.
+pub fn shift_add_accumulate<F: Field>(
+    acc: &mut FieldPolynomial<F>,
+    src: &FieldPolynomial<F>,
+    shift: usize,
+    negate: bool,
+) {
+    let n = acc.coeff_count();
+    assert_eq!(n, src.coeff_count());
+    if n == 0 {
+        return;
+    }
+
+    let shift = shift % (n << 1);
+    // After reducing `shift` below `n`, `flip` records whether the
+    // non-wrapping part carries a minus sign.
+    let (shift, flip) = if shift < n {
+        (shift, negate)
+    } else {
+        (shift - n, !negate)
+    };
+    let n_sub_shift = n - shift;
+
+    // Coefficients `src[n - shift..]` wrap past degree `n`
+    // and pick up an extra sign flip.
+    accumulate::<F>(&mut acc[..shift], &src[n_sub_shift..], !flip);
+    accumulate::<F>(&mut acc[shift..], &src[..n_sub_shift], flip);
+}
+
+#[inline]
+fn accumulate<F: Field>(acc: &mut [<F as Field>::ValueT], src: &[<F as Field>::ValueT], sub: bool) {
+    if sub {
+        acc.iter_mut()
+            .zip(src)
+            .for_each(|(a, &b)| F::MODULUS.reduce_sub_assign(a, b));
+    } else {
+        acc.iter_mut()
+            .zip(src)
+            .for_each(|(a, &b)| F::MODULUS.reduce_add_assign(a, b));
+    }
+}
@@ -0,0 +1,52 @@
+//! Coefficient-wise polynomial operations on raw slices.
+//!
+//! They let buffers such as deserialized ciphertexts be operated on
+//! without first being moved into a [`FieldPolynomial`](super::FieldPolynomial).
+
+use crate::{
+    reduce::{ReduceAddAssign, ReduceMulAssign, ReduceNegAssign, ReduceSubAssign},
+    Field,
+};
+
+/// Performs `lhs += rhs` coefficient-wise.
+#[inline]
+pub fn poly_add_assign_slice<F: Field>(
+    lhs: &mut [<F as Field>::ValueT],
+    rhs: &[<F as Field>::ValueT],
+) {
+    debug_assert_eq!(lhs.len(), rhs.len());
+    lhs.iter_mut()
+        .zip(rhs)
+        .for_each(|(a, &b)| F::MODULUS.reduce_add_assign(a, b));
+}
+
+/// Performs `lhs -= rhs` coefficient-wise.
+#[inline]
+pub fn poly_sub_assign_slice<F: Field>(
+    lhs: &mut [<F as Field>::ValueT],
+    rhs: &[<F as Field>::ValueT],
+) {
+    debug_assert_eq!(lhs.len(), rhs.len());
+    lhs.iter_mut()
+        .zip(rhs)
+        .for_each(|(a, &b)| F::MODULUS.reduce_sub_assign(a, b));
+}
+
+/// Performs `values = -values` coefficient-wise.
+#[inline]
+pub fn poly_neg_assign_slice<F: Field>(values: &mut [<F as Field>::ValueT]) {
+    values
+        .iter_mut()
+        .for_each(|v| F::MODULUS.reduce_neg_assign(v));
+}
+
+/// Performs `values *= scalar` coefficient-wise.
+#[inline]
+pub fn poly_scalar_mul_assign_slice<F: Field>(
+    values: &mut [<F as Field>::ValueT],
+    scalar: <F as Field>::ValueT,
+) {
+    values
+        .iter_mut()
+        .for_each(|v| F::MODULUS.reduce_mul_assign(v, scalar));
+}
@@ -0,0 +1,129 @@
+use alloc::vec::Vec;
+
+use crate::{
+    reduce::{ReduceAddAssign, ReduceSubAssign},
+    Field,
+};
+
+use super::FieldPolynomial;
+
+/// A ternary polynomial stored by the indices of its `1` and `-1` coefficients.
+///
+/// Multiplying it with a dense polynomial only needs signed negacyclic
+/// index-shifted additions, which is cheap when the hamming weight is small.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SparseTernaryPoly {
+    coeff_count: usize,
+    plus_indices: Vec<usize>,
+    minus_indices: Vec<usize>,
+}
+
+impl SparseTernaryPoly {
+    /// Creates a new [`SparseTernaryPoly`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if any index is not less than `coeff_count`.
+    #[inline]
+    pub fn new(coeff_count: usize, plus_indices: Vec<usize>, minus_indices: Vec<usize>) -> Self {
+        assert!(plus_indices
+            .iter()
+            .chain(minus_indices.iter())
+            .all(|&i| i < coeff_count));
+        Self {
+            coeff_count,
+            plus_indices,
+            minus_indices,
+        }
+    }
+
+    /// Creates a [`SparseTernaryPoly`] from a [`FieldPolynomial<F>`].
+    ///
+    /// Returns `None` if `poly` has any coefficient not in `{0, 1, -1}`.
+    pub fn from_field_polynomial<F: Field>(poly: &FieldPolynomial<F>) -> Option<Self> {
+        let mut plus_indices = Vec::new();
+        let mut minus_indices = Vec::new();
+        for (i, &c) in poly.iter().enumerate() {
+            if c == F::ONE {
+                plus_indices.push(i);
+            } else if c == F::MINUS_ONE {
+                minus_indices.push(i);
+            } else if c != F::ZERO {
+                return None;
+            }
+        }
+        Some(Self {
+            coeff_count: poly.coeff_count(),
+            plus_indices,
+            minus_indices,
+        })
+    }
+
+    /// Converts `self` into a dense [`FieldPolynomial<F>`].
+    pub fn to_field_polynomial<F: Field>(&self) -> FieldPolynomial<F> {
+        let mut poly = FieldPolynomial::zero(self.coeff_count);
+        self.plus_indices.iter().for_each(|&i| poly[i] = F::ONE);
+        self.minus_indices
+            .iter()
+            .for_each(|&i| poly[i] = F::MINUS_ONE);
+        poly
+    }
+
+    /// Returns the coefficient count of `self`.
+    #[inline]
+    pub fn coeff_count(&self) -> usize {
+        self.coeff_count
+    }
+
+    /// Returns the indices of the `1` coefficients.
+    #[inline]
+    pub fn plus_indices(&self) -> &[usize] {
+        &self.plus_indices
+    }
+
+    /// Returns the indices of the `-1` coefficients.
+    #[inline]
+    pub fn minus_indices(&self) -> &[usize] {
+        &self.minus_indices
+    }
+
+    /// Returns the number of nonzero coefficients.
+    #[inline]
+    pub fn hamming_weight(&self) -> usize {
+        self.plus_indices.len() + self.minus_indices.len()
+    }
+
+    /// Calculates `self * dense` modulo `X^n + 1`,
+    /// and puts the result into `out`.
+    pub fn mul_into<F: Field>(&self, dense: &FieldPolynomial<F>, out: &mut FieldPolynomial<F>) {
+        let n = self.coeff_count;
+        assert_eq!(n, dense.coeff_count());
+        assert_eq!(n, out.coeff_count());
+
+        out.set_zero();
+
+        let modulus = F::MODULUS;
+        // out += dense * X^i
+        for &i in self.plus_indices.iter() {
+            out[i..]
+                .iter_mut()
+                .zip(dense[..n - i].iter())
+                .for_each(|(o, &d)| modulus.reduce_add_assign(o, d));
+            out[..i]
+                .iter_mut()
+                .zip(dense[n - i..].iter())
+                .for_each(|(o, &d)| modulus.reduce_sub_assign(o, d));
+        }
+        // out -= dense * X^i
+        for &i in self.minus_indices.iter() {
+            out[i..]
+                .iter_mut()
+                .zip(dense[..n - i].iter())
+                .for_each(|(o, &d)| modulus.reduce_sub_assign(o, d));
+            out[..i]
+                .iter_mut()
+                .zip(dense[n - i..].iter())
+                .for_each(|(o, &d)| modulus.reduce_add_assign(o, d));
+        }
+    }
+}
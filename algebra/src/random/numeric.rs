@@ -1,3 +1,6 @@
+use alloc::{vec, vec::Vec};
+
+use num_traits::Float;
 use rand::{CryptoRng, Rng};
 use rand_distr::{Distribution, Normal};
 
@@ -53,62 +56,80 @@ where
     v
 }
 
-/// The gaussian distribution `N(mean, std_dev**2)`.
+/// Sample a ternary vector whose values are `T` with exactly `weight` nonzero values.
+///
+/// The positions of the nonzero values are uniformly random,
+/// and each of them is `1` or `-1` with equal probability.
+///
+/// # Panics
+///
+/// Panics if `weight` is greater than `length`.
+pub fn sample_fixed_weight<T, R>(minus_one: T, length: usize, weight: usize, rng: &mut R) -> Vec<T>
+where
+    T: UnsignedInteger,
+    R: Rng + CryptoRng,
+{
+    assert!(
+        weight <= length,
+        "weight {weight} is bigger than the length {length}"
+    );
+    let mut v = vec![T::ZERO; length];
+    for i in rand::seq::index::sample(rng, length, weight) {
+        v[i] = if rng.gen::<bool>() { T::ONE } else { minus_one };
+    }
+    v
+}
+
+/// The discrete gaussian distribution over integers,
+/// sampled by rounding `N(mean, std_dev**2)`.
+///
+/// Samples farther than `max_std_dev` from the mean are rejected.
 #[derive(Clone, Copy, Debug)]
-pub struct DiscreteGaussian<T: UnsignedInteger> {
+pub struct DiscreteGaussianSampler {
     normal: Normal<f64>,
     max_std_dev: f64,
-    modulus_minus_one: T,
 }
 
-impl<T: UnsignedInteger> DiscreteGaussian<T> {
-    /// Construct, from mean and standard deviation
+impl DiscreteGaussianSampler {
+    /// Construct, from mean and standard deviation, the tail is cut at `6σ`.
     ///
     /// Parameters:
     ///
     /// -   mean (`μ`, unrestricted)
     /// -   standard deviation (`σ`, must be finite)
     #[inline]
-    pub fn new(
-        mean: f64,
-        std_dev: f64,
-        modulus_minus_one: T,
-    ) -> Result<DiscreteGaussian<T>, AlgebraError> {
-        let max_std_dev = std_dev * 6.0;
+    pub fn new(mean: f64, std_dev: f64) -> Result<Self, AlgebraError> {
         if std_dev < 0. {
             return Err(AlgebraError::DistributionErr);
         }
-        match Normal::new(mean, std_dev) {
-            Ok(normal) => Ok(DiscreteGaussian {
-                normal,
-                max_std_dev,
-                modulus_minus_one,
-            }),
-            Err(_) => Err(AlgebraError::DistributionErr),
-        }
+        Self::new_inner(mean, std_dev, std_dev * 6.0)
     }
 
-    /// Construct, from mean and standard deviation
+    /// Construct, from mean, standard deviation and tail-cut bound.
     ///
     /// Parameters:
     ///
     /// -   mean (`μ`, unrestricted)
     /// -   standard deviation (`σ`, must be finite)
+    /// -   max deviation (must be bigger than `σ`)
     #[inline]
     pub fn new_with_max_limit(
         mean: f64,
         std_dev: f64,
         max_std_dev: f64,
-        modulus_minus_one: T,
-    ) -> Result<DiscreteGaussian<T>, AlgebraError> {
+    ) -> Result<Self, AlgebraError> {
         if max_std_dev <= std_dev || std_dev < 0. {
             return Err(AlgebraError::DistributionErr);
         }
+        Self::new_inner(mean, std_dev, max_std_dev)
+    }
+
+    #[inline]
+    fn new_inner(mean: f64, std_dev: f64, max_std_dev: f64) -> Result<Self, AlgebraError> {
         match Normal::new(mean, std_dev) {
-            Ok(inner) => Ok(DiscreteGaussian {
-                normal: inner,
+            Ok(normal) => Ok(Self {
+                normal,
                 max_std_dev,
-                modulus_minus_one,
             }),
             Err(_) => Err(AlgebraError::DistributionErr),
         }
@@ -133,24 +154,102 @@ impl<T: UnsignedInteger> DiscreteGaussian<T> {
     }
 }
 
-impl<T: UnsignedInteger> Distribution<T> for DiscreteGaussian<T> {
+impl Distribution<i64> for DiscreteGaussianSampler {
     #[inline]
-    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> T {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> i64 {
         let mean = self.normal.mean();
         loop {
             let value = self.normal.sample(rng);
-            if (value - mean).abs() < self.max_std_dev {
-                let round = value.round();
-                if round < 0.5 {
-                    return self.modulus_minus_one - T::as_from(-round) + T::ONE;
-                } else {
-                    return T::as_from(round);
-                }
+            if Float::abs(value - mean) < self.max_std_dev {
+                return Float::round(value) as i64;
             }
         }
     }
 }
 
+/// The gaussian distribution `N(mean, std_dev**2)`,
+/// whose samples are mapped into `[0, modulus)`.
+#[derive(Clone, Copy, Debug)]
+pub struct DiscreteGaussian<T: UnsignedInteger> {
+    sampler: DiscreteGaussianSampler,
+    modulus_minus_one: T,
+}
+
+impl<T: UnsignedInteger> DiscreteGaussian<T> {
+    /// Construct, from mean and standard deviation
+    ///
+    /// Parameters:
+    ///
+    /// -   mean (`μ`, unrestricted)
+    /// -   standard deviation (`σ`, must be finite)
+    #[inline]
+    pub fn new(
+        mean: f64,
+        std_dev: f64,
+        modulus_minus_one: T,
+    ) -> Result<DiscreteGaussian<T>, AlgebraError> {
+        Ok(DiscreteGaussian {
+            sampler: DiscreteGaussianSampler::new(mean, std_dev)?,
+            modulus_minus_one,
+        })
+    }
+
+    /// Construct, from mean and standard deviation
+    ///
+    /// Parameters:
+    ///
+    /// -   mean (`μ`, unrestricted)
+    /// -   standard deviation (`σ`, must be finite)
+    #[inline]
+    pub fn new_with_max_limit(
+        mean: f64,
+        std_dev: f64,
+        max_std_dev: f64,
+        modulus_minus_one: T,
+    ) -> Result<DiscreteGaussian<T>, AlgebraError> {
+        Ok(DiscreteGaussian {
+            sampler: DiscreteGaussianSampler::new_with_max_limit(mean, std_dev, max_std_dev)?,
+            modulus_minus_one,
+        })
+    }
+
+    /// Returns the mean (`μ`) of the distribution.
+    #[inline]
+    pub fn mean(&self) -> f64 {
+        self.sampler.mean()
+    }
+
+    /// Returns the standard deviation (`σ`) of the distribution.
+    #[inline]
+    pub fn std_dev(&self) -> f64 {
+        self.sampler.std_dev()
+    }
+
+    /// Returns the max deviation of the distribution.
+    #[inline]
+    pub fn max_std_dev(&self) -> f64 {
+        self.sampler.max_std_dev()
+    }
+
+    /// Returns the underlying integer sampler of the distribution.
+    #[inline]
+    pub fn sampler(&self) -> &DiscreteGaussianSampler {
+        &self.sampler
+    }
+}
+
+impl<T: UnsignedInteger> Distribution<T> for DiscreteGaussian<T> {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> T {
+        let value = self.sampler.sample(rng);
+        if value < 0 {
+            self.modulus_minus_one - T::as_from(-value) + T::ONE
+        } else {
+            T::as_from(value)
+        }
+    }
+}
+
 /// The binary sampler.
 ///
 /// prob\[1] = prob\[0] = 0.5
@@ -169,22 +268,61 @@ impl<T: Integer> Distribution<T> for BinarySampler {
 /// prob\[1] = prob\[-1] = 0.25
 ///
 /// prob\[0] = 0.5
+///
+/// The probability of nonzero values can be configured by [`TernarySampler::with_probability`].
 #[derive(Clone, Copy, Debug)]
 pub struct TernarySampler<T: Integer> {
     minus_one: T,
+    prob_nonzero: f64,
 }
 
 impl<T: Integer> TernarySampler<T> {
     /// Creates a new [`TernarySampler`].
     #[inline]
     pub fn new(minus_one: T) -> Self {
-        Self { minus_one }
+        Self {
+            minus_one,
+            prob_nonzero: 0.5,
+        }
+    }
+
+    /// Creates a new [`TernarySampler`] with the probability of nonzero values.
+    ///
+    /// prob\[1] = prob\[-1] = `prob_nonzero / 2`
+    ///
+    /// prob\[0] = `1 - prob_nonzero`
+    #[inline]
+    pub fn with_probability(minus_one: T, prob_nonzero: f64) -> Result<Self, AlgebraError> {
+        if !(0.0..=1.0).contains(&prob_nonzero) {
+            return Err(AlgebraError::DistributionErr);
+        }
+        Ok(Self {
+            minus_one,
+            prob_nonzero,
+        })
+    }
+
+    /// Returns the probability of nonzero values.
+    #[inline]
+    pub fn prob_nonzero(&self) -> f64 {
+        self.prob_nonzero
     }
 }
 
 impl<T: Integer> Distribution<T> for TernarySampler<T> {
     #[inline]
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> T {
-        [T::ZERO, T::ZERO, T::ONE, self.minus_one][(rng.next_u32() & 0b11) as usize]
+        if self.prob_nonzero == 0.5 {
+            return [T::ZERO, T::ZERO, T::ONE, self.minus_one][(rng.next_u32() & 0b11) as usize];
+        }
+
+        let r: f64 = rng.gen();
+        if r >= self.prob_nonzero {
+            T::ZERO
+        } else if r * 2.0 < self.prob_nonzero {
+            T::ONE
+        } else {
+            self.minus_one
+        }
     }
 }
@@ -91,6 +91,7 @@ impl CryptoRng for Prg {}
 
 impl Prg {
     /// New a Prg with random seed.
+    #[cfg(feature = "std")]
     #[inline(always)]
     pub fn new() -> Self {
         let seed = rand::random::<Block>();
@@ -135,6 +136,7 @@ impl Prg {
     }
 }
 
+#[cfg(feature = "std")]
 impl Default for Prg {
     #[inline(always)]
     fn default() -> Self {
@@ -142,6 +144,7 @@ impl Default for Prg {
     }
 }
 
+#[cfg(feature = "std")]
 #[test]
 fn prg_test() {
     let mut prg = Prg::new();
@@ -169,8 +169,8 @@ macro_rules! impl_reduce_ops_for_primitive {
                     Ok(inv)
                 } else {
                     Err($crate::AlgebraError::NoInverse {
-                        value: Box::new(value),
-                        modulus: Box::new(self),
+                        value: alloc::boxed::Box::new(value),
+                        modulus: alloc::boxed::Box::new(self),
                     })
                 }
             }
@@ -5,7 +5,7 @@ mod ops;
 
 mod macros;
 
-use std::fmt::Debug;
+use core::fmt::Debug;
 
 pub use lazy_ops::*;
 use num_traits::ConstOne;
@@ -119,6 +119,8 @@ pub trait RingReduce<T>:
     + ReduceMulAssign<T>
     + ReduceMulAdd<T, Output = T>
     + ReduceMulAddAssign<T>
+    + ReduceSquare<T, Output = T>
+    + ReduceSquareAssign<T>
     + ReduceExp<T>
     + ReduceExpPowOf2<T>
     + ReduceDotProduct<T, Output = T>
@@ -147,6 +149,8 @@ impl<T: UnsignedInteger, M> RingReduce<T> for M where
         + ReduceMulAssign<T>
         + ReduceMulAdd<T, Output = T>
         + ReduceMulAddAssign<T>
+        + ReduceSquare<T, Output = T>
+        + ReduceSquareAssign<T>
         + ReduceExp<T>
         + ReduceExpPowOf2<T>
         + ReduceDotProduct<T, Output = T>
@@ -156,6 +160,7 @@ impl<T: UnsignedInteger, M> RingReduce<T> for M where
 /// An trait indicate the modulus can perform operation like a field.
 pub trait FieldReduce<T>:
     RingReduce<T>
+    + Reduce<[T; 2], Output = T>
     + LazyReduce<T, Output = T>
     + LazyReduceAssign<T>
     + LazyReduceMul<T, Output = T>
@@ -171,6 +176,7 @@ pub trait FieldReduce<T>:
 
 impl<T: Numeric, M> FieldReduce<T> for M where
     M: RingReduce<T>
+        + Reduce<[T; 2], Output = T>
         + LazyReduce<T, Output = T>
         + LazyReduceAssign<T>
         + LazyReduceMul<T, Output = T>
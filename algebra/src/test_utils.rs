@@ -0,0 +1,48 @@
+//! Helpers for comparing noisy polynomials in tests.
+//!
+//! Enabled by the `test-utils` feature.
+
+use crate::{polynomial::FieldPolynomial, Field};
+
+/// Returns the infinity norm of `a - b`,
+/// where each coefficient is lifted to its centered representative in `[-q/2, q/2]`.
+///
+/// # Panics
+///
+/// Panics if `a` and `b` have different lengths.
+#[inline]
+pub fn poly_distance<F: Field>(a: &FieldPolynomial<F>, b: &FieldPolynomial<F>) -> u64 {
+    assert_eq!(
+        a.coeff_count(),
+        b.coeff_count(),
+        "polynomial lengths differ"
+    );
+    a.distance_linf(b)
+}
+
+/// Asserts that every coefficient of `a - b` lies within `max_linf`
+/// of zero in centered representation.
+///
+/// On failure, the panic message reports the coefficient index with the largest
+/// distance along with both coefficient values.
+///
+/// # Panics
+///
+/// Panics if `a` and `b` have different lengths or are not close.
+#[track_caller]
+pub fn assert_poly_close<F: Field>(a: &FieldPolynomial<F>, b: &FieldPolynomial<F>, max_linf: u64) {
+    assert_eq!(
+        a.coeff_count(),
+        b.coeff_count(),
+        "polynomial lengths differ"
+    );
+    if let Some((index, distance)) = a.max_distance(b) {
+        assert!(
+            distance <= max_linf,
+            "polynomials are not close: distance {distance} at coefficient {index} \
+             exceeds {max_linf} (left: {}, right: {})",
+            a[index],
+            b[index],
+        );
+    }
+}
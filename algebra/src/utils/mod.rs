@@ -2,4 +2,4 @@
 
 mod reverse;
 
-pub use reverse::ReverseLsbs;
+pub use reverse::{bit_reverse_permute, ReverseLsbs};
@@ -30,3 +30,21 @@ macro_rules! impl_reverse_lsbs_for_unsigned {
 }
 
 impl_reverse_lsbs_for_unsigned!(u8, u16, u32, u64, u128, usize);
+
+/// Performs an in-place bit-reversal permutation of `slice`,
+/// i.e. the element at index `i` is swapped with the element at index `i.reverse_lsbs(log_n)`.
+///
+/// # Panics
+///
+/// Panics if the length of `slice` is not `2^log_n`.
+pub fn bit_reverse_permute<T>(slice: &mut [T], log_n: u32) {
+    let n = slice.len();
+    assert_eq!(n, 1 << log_n);
+
+    for i in 0..n {
+        let j = i.reverse_lsbs(log_n);
+        if i < j {
+            slice.swap(i, j);
+        }
+    }
+}
@@ -0,0 +1,68 @@
+use algebra::{ntt::ArbitraryLenNtt, Field, U32FieldEval};
+use rand::{distributions::Uniform, prelude::Distribution, thread_rng};
+
+/// `p - 1 = 2^21 * 3^2 * 7`.
+const P: u32 = 132120577;
+type Fp = U32FieldEval<P>;
+
+fn random_values(len: usize) -> Vec<u32> {
+    Uniform::new(0, P)
+        .sample_iter(thread_rng())
+        .take(len)
+        .collect()
+}
+
+/// Calculates `X_k = ∑x_j w^(jk)` directly.
+fn naive_dft(values: &[u32], root: u32) -> Vec<u32> {
+    let len = values.len();
+    (0..len)
+        .map(|k| {
+            let w_k = Fp::exp(root, k);
+            let powers = Fp::pow_table(w_k, len - 1);
+            Fp::dot_product(values, &powers)
+        })
+        .collect()
+}
+
+#[test]
+fn test_arbitrary_len_ntt_naive_dft() {
+    for len in [1, 2, 3, 7, 9, 12, 21, 63, 126] {
+        let ntt = ArbitraryLenNtt::<Fp>::new(len).unwrap();
+        assert_eq!(ntt.dimension(), len);
+
+        let root = ntt.root();
+        assert_eq!(Fp::exp(root, len), Fp::ONE);
+        for q in [2, 3, 7] {
+            if len % q == 0 {
+                assert_ne!(Fp::exp(root, len / q), Fp::ONE, "len = {len}");
+            }
+        }
+
+        let x = random_values(len);
+        let y = ntt.transform(&x);
+        assert_eq!(y, naive_dft(&x, root), "len = {len}");
+        assert_eq!(ntt.inverse_transform(&y), x, "len = {len}");
+    }
+}
+
+#[test]
+fn test_arbitrary_len_ntt_round_trip() {
+    for len in [63 * 4, 9 * 7 * 256, 3 << 14] {
+        let ntt = ArbitraryLenNtt::<Fp>::new(len).unwrap();
+
+        let x = random_values(len);
+        let mut y = x.clone();
+        ntt.transform_slice(&mut y);
+        ntt.inverse_transform_slice(&mut y);
+        assert_eq!(x, y, "len = {len}");
+    }
+}
+
+#[test]
+fn test_arbitrary_len_ntt_invalid_len() {
+    assert!(ArbitraryLenNtt::<Fp>::new(0).is_err());
+    // 5 doesn't divide p - 1.
+    assert!(ArbitraryLenNtt::<Fp>::new(5).is_err());
+    // The convolution would need a transform of dimension 2^23.
+    assert!(ArbitraryLenNtt::<Fp>::new(9 << 18).is_err());
+}
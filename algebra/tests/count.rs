@@ -0,0 +1,73 @@
+#![cfg(feature = "count")]
+
+use algebra::{
+    count,
+    ntt::NumberTheoryTransform,
+    polynomial::{FieldNttPolynomial, FieldPolynomial},
+    NttField, U32FieldEval,
+};
+use rand::thread_rng;
+
+type F = U32FieldEval<132120577>;
+
+#[test]
+fn test_count() {
+    let mut rng = thread_rng();
+    let log_n = 10;
+    let n = 1 << log_n;
+    let table = F::generate_ntt_table(log_n).unwrap();
+
+    let a = FieldPolynomial::<F>::random(n, &mut rng);
+    let b = FieldNttPolynomial::<F>::random(n, &mut rng);
+
+    count::ntt::clear();
+    count::inverse_ntt::clear();
+    count::mul::clear();
+    count::ntt::enable();
+    count::inverse_ntt::enable();
+    count::mul::enable();
+    count::clear_report();
+
+    let c = count::scope("outer", || {
+        let a = table.transform(&a);
+        let c = count::scope("inner", || &a * &b);
+        table.inverse_transform(&c)
+    });
+    let _ = count::scope("outer", || table.transform(&c));
+
+    count::ntt::disable();
+    count::inverse_ntt::disable();
+    count::mul::disable();
+
+    assert_eq!(count::ntt::get(), 2);
+    assert_eq!(count::inverse_ntt::get(), 1);
+    assert_eq!(count::mul::get(), n);
+
+    let report = count::report();
+    assert_eq!(
+        report.get("inner"),
+        Some(&[("mul", n)].into_iter().collect())
+    );
+    assert_eq!(
+        report.get("outer"),
+        Some(
+            &[("ntt", 2), ("inverse_ntt", 1), ("mul", n)]
+                .into_iter()
+                .collect()
+        )
+    );
+    assert_eq!(report.get("outer").unwrap().get("external_product"), 0);
+    assert_eq!(
+        report.iter().map(|(label, _)| label).collect::<Vec<_>>(),
+        ["inner", "outer"]
+    );
+
+    // Scopes count with the counters disabled, the counters don't.
+    count::clear_report();
+    count::scope("disabled", || table.transform(&a));
+    assert_eq!(count::ntt::get(), 2);
+    assert_eq!(count::report().get("disabled").unwrap().get("ntt"), 1);
+
+    count::ntt::clear();
+    assert_eq!(count::ntt::get(), 0);
+}
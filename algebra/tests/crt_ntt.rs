@@ -0,0 +1,49 @@
+use algebra::ntt::CrtNtt;
+
+#[cfg(feature = "test-utils")]
+#[test]
+fn test_crt_ntt_negacyclic_mul() {
+    use algebra::{
+        modulus::{NativeModulus, PowOf2Modulus},
+        ntt::{negacyclic_mul_pow2_modulus, reference::negacyclic_convolution},
+    };
+    use rand::{thread_rng, Rng};
+
+    let mut rng = thread_rng();
+
+    for log_n in 8..=11 {
+        let n = 1 << log_n;
+        let crt = CrtNtt::new(log_n).unwrap();
+        assert_eq!(crt.dimension(), n);
+
+        let a: Vec<u64> = (0..n).map(|_| rng.gen()).collect();
+        let b: Vec<u64> = (0..n).map(|_| rng.gen()).collect();
+
+        for k in [1, 17, 32, 50, 63] {
+            let expected = negacyclic_convolution(&a, &b, PowOf2Modulus::<u64>::new(1 << k));
+            assert_eq!(crt.negacyclic_mul(&a, &b, k), expected, "n = {n}, k = {k}");
+        }
+        let expected = negacyclic_convolution(&a, &b, NativeModulus::<u64>::new());
+        assert_eq!(crt.negacyclic_mul(&a, &b, 64), expected, "n = {n}, k = 64");
+
+        // The largest coefficients give the largest integer product.
+        let max = vec![u64::MAX; n];
+        assert_eq!(
+            crt.negacyclic_mul(&max, &max, 64),
+            negacyclic_convolution(&max, &max, NativeModulus::<u64>::new())
+        );
+    }
+
+    let n = 256;
+    let a: Vec<u64> = (0..n).map(|_| rng.gen::<u32>() as u64).collect();
+    let b: Vec<u64> = (0..n).map(|_| rng.gen::<u32>() as u64).collect();
+    assert_eq!(
+        negacyclic_mul_pow2_modulus(&a, &b, 32),
+        negacyclic_convolution(&a, &b, PowOf2Modulus::<u64>::new(1 << 32))
+    );
+}
+
+#[test]
+fn test_crt_ntt_too_large() {
+    assert!(CrtNtt::new(CrtNtt::MAX_LOG_N + 1).is_err());
+}
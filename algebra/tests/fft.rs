@@ -0,0 +1,115 @@
+use std::f64::consts::PI;
+
+use algebra::{ntt::fft::CanonicalEmbedding, polynomial::FieldPolynomial, Field, U64FieldEval};
+use rand::{distributions::Uniform, prelude::Distribution, thread_rng};
+
+/// A 62-bit prime with `2^17 | p - 1`.
+type Fp = U64FieldEval<4611686018425815041>;
+
+const SCALE: f64 = (1u64 << 40) as f64;
+
+fn random_reals(len: usize, max_abs: f64) -> Vec<f64> {
+    Uniform::new_inclusive(-max_abs, max_abs)
+        .sample_iter(thread_rng())
+        .take(len)
+        .collect()
+}
+
+fn max_error(a: &[f64], b: &[f64]) -> f64 {
+    a.iter()
+        .zip(b)
+        .map(|(x, y)| (x - y).abs())
+        .fold(0.0, f64::max)
+}
+
+#[test]
+fn test_canonical_embedding_round_trip() {
+    for log_n in [1, 3, 10, 13] {
+        let n = 1 << log_n;
+        let embedding = CanonicalEmbedding::new(n);
+        assert_eq!(embedding.dimension(), n);
+        assert_eq!(embedding.slot_count(), n / 2);
+
+        for max_abs in [1.0, 1000.0] {
+            let values = random_reals(n / 2, max_abs);
+            let poly = embedding.encode::<Fp>(&values, SCALE);
+            assert_eq!(poly.coeff_count(), n);
+
+            let decoded = embedding.decode(&poly, SCALE);
+            let error = max_error(&decoded, &values);
+            let bound = embedding.error_bound(SCALE, max_abs);
+            assert!(error <= bound, "error {error} exceeds the bound {bound}");
+            // The relative error stays far below the precision of the scale.
+            assert!(error / max_abs < 1e-9);
+        }
+    }
+}
+
+#[test]
+fn test_canonical_embedding_missing_slots() {
+    let n = 64;
+    let embedding = CanonicalEmbedding::new(n);
+
+    let values = random_reals(5, 1.0);
+    let decoded = embedding.decode(&embedding.encode::<Fp>(&values, SCALE), SCALE);
+    let bound = embedding.error_bound(SCALE, 1.0);
+    assert!(max_error(&decoded[..5], &values) <= bound);
+    assert!(decoded[5..].iter().all(|v| v.abs() <= bound));
+
+    let zero = embedding.encode::<Fp>(&[], SCALE);
+    assert!(zero.is_zero());
+}
+
+#[test]
+fn test_canonical_embedding_is_additive() {
+    let n = 256;
+    let embedding = CanonicalEmbedding::new(n);
+
+    let a = random_reals(n / 2, 1.0);
+    let b = random_reals(n / 2, 1.0);
+    let sum = embedding.encode::<Fp>(&a, SCALE) + embedding.encode::<Fp>(&b, SCALE);
+
+    let expected: Vec<f64> = a.iter().zip(&b).map(|(x, y)| x + y).collect();
+    let decoded = embedding.decode(&sum, SCALE);
+    assert!(max_error(&decoded, &expected) <= 2.0 * embedding.error_bound(SCALE, 2.0));
+}
+
+#[test]
+fn test_canonical_embedding_evaluates_at_roots() {
+    let n = 16;
+    let embedding = CanonicalEmbedding::new(n);
+
+    // The slot `j` is the value at `e^(iπ 5^j / n)`, whose real part is a cosine sum.
+    let coeffs: Vec<i64> = vec![3, -1, 4, 1, -5, 9, 2, -6, 5, 3, -5, 8, 9, -7, 9, 3];
+    let poly = FieldPolynomial::<Fp>::new(
+        coeffs
+            .iter()
+            .map(|&c| {
+                if c >= 0 {
+                    c as u64
+                } else {
+                    Fp::neg(c.unsigned_abs())
+                }
+            })
+            .collect(),
+    );
+    let decoded = embedding.decode(&poly, 1.0);
+
+    let mut five_pow = 1usize;
+    for slot in decoded {
+        let expected: f64 = coeffs
+            .iter()
+            .enumerate()
+            .map(|(k, &c)| c as f64 * (PI * (five_pow * k) as f64 / n as f64).cos())
+            .sum();
+        assert!((slot - expected).abs() < 1e-9);
+        five_pow = five_pow * 5 % (2 * n);
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_canonical_embedding_too_many_values() {
+    let embedding = CanonicalEmbedding::new(8);
+    let _ = embedding.encode::<Fp>(&[0.0; 5], SCALE);
+}
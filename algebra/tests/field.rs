@@ -1,5 +1,8 @@
-use algebra::{decompose::NonPowOf2ApproxSignedBasis, reduce::ReduceExp, Field, U32FieldEval};
+use algebra::{
+    decompose::NonPowOf2ApproxSignedBasis, reduce::ReduceExp, Field, U32FieldEval, U64FieldEval,
+};
 use rand::{distributions::Uniform, thread_rng, Rng};
+use subtle::Choice;
 
 type FF = U32FieldEval<132120577>;
 type ValueT = u32;
@@ -100,6 +103,33 @@ fn test_fp() {
     );
 }
 
+#[test]
+fn test_mul_scalar_u64() {
+    type FF64 = U64FieldEval<1125899906826241>;
+
+    let mut rng = thread_rng();
+
+    let p = FF::MODULUS_VALUE;
+    let a = rng.gen_range(0..p);
+    for scalar in [
+        p as u64,
+        p as u64 + 1,
+        (1u64 << 32) + 3,
+        rng.gen_range(p as u64..u64::MAX),
+        u64::MAX,
+    ] {
+        let expect = ((a as u128 * scalar as u128) % p as u128) as ValueT;
+        assert_eq!(FF::mul_scalar_u64(a, scalar), expect);
+    }
+
+    let p = FF64::MODULUS_VALUE;
+    let a = rng.gen_range(0..p);
+    for scalar in [p, p + 1, rng.gen_range(p..u64::MAX), u64::MAX] {
+        let expect = ((a as u128 * scalar as u128) % p as u128) as u64;
+        assert_eq!(FF64::mul_scalar_u64(a, scalar), expect);
+    }
+}
+
 #[test]
 fn test_decompose() {
     const BITS: u32 = 2;
@@ -132,3 +162,97 @@ fn test_decompose() {
         None => assert_eq!(compose, a),
     };
 }
+
+#[test]
+fn test_square_double() {
+    let mut rng = thread_rng();
+    let p = FF::MODULUS_VALUE;
+
+    for a in [0, 1, p - 1, p / 2, rng.gen_range(0..p)] {
+        assert_eq!(FF::square(a), FF::mul(a, a));
+        assert_eq!(FF::double(a), FF::add(a, a));
+
+        let mut b = a;
+        FF::square_assign(&mut b);
+        assert_eq!(b, FF::mul(a, a));
+
+        let mut b = a;
+        FF::double_assign(&mut b);
+        assert_eq!(b, FF::add(a, a));
+    }
+}
+
+#[test]
+fn test_conditional_select() {
+    let mut rng = thread_rng();
+    let distr = Uniform::new(0, FF::MODULUS_VALUE);
+
+    let a = rng.sample(distr);
+    let b = rng.sample(distr);
+    assert_eq!(FF::conditional_select(a, b, Choice::from(0)), a);
+    assert_eq!(FF::conditional_select(a, b, Choice::from(1)), b);
+}
+
+#[test]
+fn test_pow_table() {
+    let mut rng = thread_rng();
+    let p = FF::MODULUS_VALUE;
+
+    for base in [0, 1, p - 1, rng.gen_range(0..p)] {
+        let table = FF::pow_table(base, 64);
+        assert_eq!(table.len(), 65);
+        for (i, &power) in table.iter().enumerate() {
+            assert_eq!(power, FF::exp(base, i as u32));
+        }
+    }
+
+    assert_eq!(FF::pow_table(rng.gen_range(0..p), 0), vec![1]);
+}
+
+#[test]
+fn test_dot_product_odd_length() {
+    let mut rng = thread_rng();
+    let distr = Uniform::new(0, FF::MODULUS_VALUE);
+
+    for len in [0, 1, 15, 16, 17, 571, 630] {
+        let a: Vec<ValueT> = (&mut rng).sample_iter(distr).take(len).collect();
+        let b: Vec<ValueT> = (&mut rng).sample_iter(distr).take(len).collect();
+
+        let naive = a
+            .iter()
+            .zip(b.iter())
+            .fold(0, |acc, (&x, &y)| FF::add(acc, FF::mul(x, y)));
+        assert_eq!(FF::dot_product(&a, &b), naive, "length {len}");
+    }
+}
+
+#[test]
+fn test_sum_product() {
+    let mut rng = thread_rng();
+    let distr = Uniform::new(0, FF::MODULUS_VALUE);
+
+    for len in [0, 1, 2, 17, 630] {
+        let values: Vec<ValueT> = (&mut rng).sample_iter(distr).take(len).collect();
+
+        let sum = values.iter().fold(FF::ZERO, |acc, &v| FF::add(acc, v));
+        assert_eq!(FF::sum(values.iter().copied()), sum, "length {len}");
+
+        let product = values.iter().fold(FF::ONE, |acc, &v| FF::mul(acc, v));
+        assert_eq!(FF::product(values), product, "length {len}");
+    }
+
+    assert_eq!(FF::sum([]), 0);
+    assert_eq!(FF::product([]), 1);
+    assert_eq!(FF::sum([FF::MINUS_ONE, 1]), 0);
+    assert_eq!(FF::product([FF::MINUS_ONE, FF::MINUS_ONE]), 1);
+
+    type G = U64FieldEval<1125899906826241>;
+    let values: Vec<u64> = (&mut rng)
+        .sample_iter(Uniform::new(0, G::MODULUS_VALUE))
+        .take(64)
+        .collect();
+    let sum = values.iter().fold(G::ZERO, |acc, &v| G::add(acc, v));
+    let product = values.iter().fold(G::ONE, |acc, &v| G::mul(acc, v));
+    assert_eq!(G::sum(values.iter().copied()), sum);
+    assert_eq!(G::product(values), product);
+}
@@ -0,0 +1,111 @@
+use algebra::{arith::PrimitiveRoot, modulus::BarrettModulus, reduce::Reduce, AlgebraError};
+use rand::{rngs::StdRng, SeedableRng};
+
+#[test]
+fn test_barrett_try_new() {
+    for value in [0u32, 1] {
+        assert!(matches!(
+            BarrettModulus::<u32>::try_new(value),
+            Err(AlgebraError::ModulusTooSmall { .. })
+        ));
+    }
+
+    for value in [1u32 << 30, u32::MAX >> 1, u32::MAX] {
+        assert!(matches!(
+            BarrettModulus::<u32>::try_new(value),
+            Err(AlgebraError::ModulusTooManyBits { bits, max_bits: 30, .. })
+                if bits == 32 - value.leading_zeros()
+        ));
+    }
+    assert!(matches!(
+        BarrettModulus::<u8>::try_new(64),
+        Err(AlgebraError::ModulusTooManyBits {
+            bits: 7,
+            max_bits: 6,
+            ..
+        })
+    ));
+    assert!(matches!(
+        BarrettModulus::<u64>::try_new(u64::MAX),
+        Err(AlgebraError::ModulusTooManyBits {
+            bits: 64,
+            max_bits: 62,
+            ..
+        })
+    ));
+
+    for value in [2u32, 3, 132120577, (1 << 30) - 1] {
+        let modulus = BarrettModulus::<u32>::try_new(value).unwrap();
+        assert_eq!(modulus.value(), value);
+        assert_eq!(modulus.reduce(u32::MAX), u32::MAX % value);
+    }
+    assert_eq!(BarrettModulus::<u8>::try_new(63).unwrap().value(), 63);
+}
+
+#[test]
+fn test_barrett_reduce_limbs() {
+    use algebra::reduce::LazyReduce;
+    use rand::{thread_rng, Rng};
+
+    fn check<const N: usize>(rng: &mut impl Rng) {
+        let m32 = BarrettModulus::<u32>::new(132120577);
+        let m64 = BarrettModulus::<u64>::new(1125899906826241);
+
+        let a: [u32; N] = core::array::from_fn(|_| rng.gen());
+        let lazy = m32.lazy_reduce_limbs(a);
+        assert!(lazy < 2 * m32.value());
+        assert_eq!(m32.reduce(lazy), m32.reduce(m32.lazy_reduce(&a[..])));
+        assert_eq!(m32.reduce_limbs(a), m32.reduce(&a[..]));
+
+        let a: [u64; N] = core::array::from_fn(|_| rng.gen());
+        let lazy = m64.lazy_reduce_limbs(a);
+        assert!(lazy < 2 * m64.value());
+        assert_eq!(m64.reduce(lazy), m64.reduce(m64.lazy_reduce(&a[..])));
+        assert_eq!(m64.reduce_limbs(a), m64.reduce(&a[..]));
+
+        let max = [u64::MAX; N];
+        assert_eq!(m64.reduce_limbs(max), m64.reduce(&max[..]));
+    }
+
+    let mut rng = thread_rng();
+    for _ in 0..100 {
+        check::<1>(&mut rng);
+        check::<2>(&mut rng);
+        check::<3>(&mut rng);
+        check::<4>(&mut rng);
+        check::<8>(&mut rng);
+    }
+
+    // [0, 1] is b = 2^64.
+    let m64 = BarrettModulus::<u64>::new(1125899906826241);
+    let expected = ((1u128 << 64) % 1125899906826241) as u64;
+    assert_eq!(m64.reduce_limbs([0, 1]), expected);
+}
+
+#[test]
+fn test_barrett_primitive_root_with_seeded_rng() {
+    let modulus = BarrettModulus::<u32>::new(132120577);
+    let log_degree = 11;
+
+    let root = |seed| {
+        modulus
+            .try_primitive_root_with_rng(log_degree, &mut StdRng::seed_from_u64(seed))
+            .unwrap()
+    };
+    let first = root(7);
+    assert_eq!(first, root(7));
+    assert!(modulus.check_primitive_root(first, log_degree));
+
+    let minimal = modulus
+        .try_minimal_primitive_root_with_rng(log_degree, &mut StdRng::seed_from_u64(7))
+        .unwrap();
+    assert_eq!(
+        minimal,
+        modulus.try_minimal_primitive_root(log_degree).unwrap()
+    );
+
+    assert!(matches!(
+        modulus.try_primitive_root_with_rng(27, &mut StdRng::seed_from_u64(7)),
+        Err(AlgebraError::NoPrimitiveRoot { .. })
+    ));
+}
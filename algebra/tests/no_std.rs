@@ -0,0 +1,48 @@
+//! Exercises the arithmetic core from a `no_std` crate.
+//!
+//! Run it with `cargo test -p algebra --no-default-features`
+//! to check the core without the `std` feature.
+
+#![no_std]
+
+extern crate alloc;
+extern crate std;
+
+use alloc::vec::Vec;
+
+use algebra::{
+    polynomial::{FieldNttPolynomial, FieldPolynomial},
+    random::Prg,
+    Field, NttField, U32FieldEval,
+};
+use rand::SeedableRng;
+
+type FF = U32FieldEval<132120577>;
+
+const LOG_N: u32 = 8;
+const N: usize = 1 << LOG_N;
+
+#[test]
+fn test_no_std_arithmetic() {
+    let a = FF::MODULUS_VALUE - 3;
+    assert_eq!(FF::add(a, 5), 2);
+    assert_eq!(FF::mul(a, FF::inv(a)), 1);
+
+    // The table is generated once and passed explicitly, nothing is cached.
+    let table = FF::generate_ntt_table(LOG_N).unwrap();
+
+    let mut rng = Prg::seed_from_u64(7);
+    let x = FieldPolynomial::<FF>::random(N, &mut rng);
+    let y = FieldPolynomial::<FF>::random(N, &mut rng);
+
+    let expected = x.mul_naive(&y);
+    assert_eq!(x.clone().mul(y.clone(), &table), expected);
+
+    let terms: Vec<FieldNttPolynomial<FF>> = [&x, &y]
+        .into_iter()
+        .map(|poly| poly.clone().into_ntt_poly(&table))
+        .collect();
+    let mut product = FieldNttPolynomial::zero(N);
+    FieldNttPolynomial::sum_of_products([(&terms[0], &terms[1])], &mut product);
+    assert_eq!(product.into_coeff_poly(&table), expected);
+}
@@ -0,0 +1,115 @@
+//! Counts the allocations of the transforms with a warm [`NttWorkspace`].
+//!
+//! The allocator of this test binary counts per thread,
+//! so the tests running in parallel don't disturb each other.
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+};
+
+use algebra::{
+    ntt::{FieldTableWithShoupRoot, NttTable, NttWorkspace, NumberTheoryTransform},
+    Field, U32FieldEval,
+};
+use rand::{distributions::Uniform, prelude::Distribution, thread_rng};
+
+type F = U32FieldEval<132120577>;
+
+struct CountingAllocator;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+fn record_allocation() {
+    let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        record_allocation();
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        record_allocation();
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+/// Runs `f` and returns the number of allocations it made on this thread.
+fn allocations_in(f: impl FnOnce()) -> usize {
+    let before = ALLOCATIONS.with(Cell::get);
+    f();
+    ALLOCATIONS.with(Cell::get) - before
+}
+
+fn random_values(n: usize) -> Vec<u32> {
+    Uniform::new(0, F::MODULUS_VALUE)
+        .sample_iter(thread_rng())
+        .take(n)
+        .collect()
+}
+
+#[test]
+fn test_four_step_with_warm_workspace() {
+    let log_n = 16;
+    let n = 1 << log_n;
+    let table = <FieldTableWithShoupRoot<F>>::new(F::MODULUS, log_n).unwrap();
+    assert!(table.is_four_step());
+
+    let poly = random_values(n);
+    let mut expected = poly.clone();
+    table.transform_slice(&mut expected);
+
+    // The first transforms grow the workspace.
+    let mut workspace = NttWorkspace::new();
+    let mut values = poly.clone();
+    table.transform_slice_with(&mut values, &mut workspace);
+    assert_eq!(values, expected);
+    table.inverse_transform_slice_with(&mut values, &mut workspace);
+    assert_eq!(values, poly);
+
+    let allocations = allocations_in(|| {
+        table.transform_slice_with(&mut values, &mut workspace);
+        table.inverse_transform_slice_with(&mut values, &mut workspace);
+    });
+    assert_eq!(allocations, 0);
+    assert_eq!(values, poly);
+
+    // Without a workspace the four-step transform needs a new scratch buffer.
+    let allocations = allocations_in(|| table.transform_slice(&mut values));
+    assert!(allocations > 0);
+    assert_eq!(values, expected);
+}
+
+#[test]
+fn test_transform_into() {
+    let log_n = 10;
+    let n = 1 << log_n;
+    let table = <FieldTableWithShoupRoot<F>>::new(F::MODULUS, log_n).unwrap();
+    assert!(!table.is_four_step());
+    assert_eq!(table.dimension(), n);
+
+    let poly = random_values(n);
+    let mut expected = poly.clone();
+    table.transform_slice(&mut expected);
+
+    let mut values = vec![0; n];
+    let mut coeffs = vec![0; n];
+    let allocations = allocations_in(|| {
+        table.transform_into(&poly, &mut values);
+        table.inverse_transform_into(&values, &mut coeffs);
+    });
+    assert_eq!(allocations, 0);
+    assert_eq!(values, expected);
+    assert_eq!(coeffs, poly);
+}
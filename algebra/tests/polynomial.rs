@@ -0,0 +1,1225 @@
+use algebra::{
+    decompose::NonPowOf2ApproxSignedBasis,
+    modulus::{BarrettModulus, PowOf2Modulus, ShoupFactor},
+    ntt::NumberTheoryTransform,
+    polynomial::{
+        batch_decode, batch_encode, poly_add_assign_slice, poly_neg_assign_slice,
+        poly_scalar_mul_assign_slice, poly_sub_assign_slice, rescale_slice, shift_add_accumulate,
+        BatchEncoder, FieldNttPolynomial, FieldPolynomial, PolyMut, PolyRef, Polynomial,
+        RoundingMode, SparseTernaryPoly,
+    },
+    random::DiscreteGaussian,
+    reduce::ModulusValue,
+    utils::ReverseLsbs,
+    Field, NttField, ScalarFactor, U32FieldEval, U64FieldEval,
+};
+use rand::{distributions::Uniform, rngs::StdRng, thread_rng, Rng, SeedableRng};
+
+type FF = U32FieldEval<132120577>;
+type PolyFF = FieldPolynomial<FF>;
+
+const N: usize = 1024;
+
+#[test]
+fn test_mul_factor() {
+    let mut rng = thread_rng();
+    let p = FF::MODULUS_VALUE;
+
+    let poly = PolyFF::random(N, &mut rng);
+    let ntt_poly = FieldNttPolynomial::<FF>::random(N, &mut rng);
+
+    let random_scalar = rng.sample(Uniform::new(0, p));
+    for scalar in [
+        random_scalar,
+        p / 4,
+        p - 2,
+        FF::MINUS_ONE,
+        FF::ZERO,
+        FF::ONE,
+    ] {
+        let factor = <ScalarFactor<FF>>::new(scalar);
+        assert_eq!(factor.value(), scalar);
+
+        let mut a = poly.clone();
+        a.mul_factor_assign(&factor);
+        assert_eq!(a, poly.clone().mul_scalar(scalar));
+
+        let mut b = poly.clone();
+        b.add_mul_factor_assign(&poly, &factor);
+        assert_eq!(b, poly.clone() + &poly.clone().mul_scalar(scalar));
+
+        let mut ntt_a = ntt_poly.clone();
+        ntt_a.mul_factor_assign(&factor);
+        assert_eq!(ntt_a, ntt_poly.clone().mul_scalar(scalar));
+
+        let mut ntt_b = ntt_poly.clone();
+        ntt_b.add_mul_factor_assign(&ntt_poly, &factor);
+        let mut expect = ntt_poly.clone();
+        expect.add_mul_scalar_assign(&ntt_poly, scalar);
+        assert_eq!(ntt_b, expect);
+    }
+}
+
+#[test]
+fn test_mul_shoup_scalar() {
+    let mut rng = thread_rng();
+    let p = FF::MODULUS_VALUE;
+    let modulus = <BarrettModulus<u32>>::new(p);
+
+    let poly = <Polynomial<u32>>::random(p - 1, N, &mut rng);
+
+    for scalar in [rng.sample(Uniform::new(0, p)), 0, 1, p - 1] {
+        let factor = ShoupFactor::new(scalar, p);
+        let mut a = poly.clone();
+        a.mul_shoup_scalar_assign(factor, p);
+        assert_eq!(a, poly.clone().mul_scalar(scalar, modulus));
+    }
+}
+
+#[test]
+fn test_random_reproducible() {
+    let table = FF::generate_ntt_table(N.trailing_zeros()).unwrap();
+    let gaussian = DiscreteGaussian::new(0.0, 3.2, FF::MINUS_ONE).unwrap();
+    let distr = Uniform::new(0, 1024);
+
+    let sample = |seed: u64| {
+        let mut rng = StdRng::seed_from_u64(seed);
+        (
+            PolyFF::random_with_distribution(N, distr, &mut rng),
+            PolyFF::random_gaussian(N, gaussian, &mut rng),
+            FieldNttPolynomial::<FF>::random_with_distribution(N, &mut rng, distr),
+            FieldNttPolynomial::<FF>::random_ternary(N, &table, &mut rng),
+            FieldNttPolynomial::<FF>::random_gaussian(N, gaussian, &table, &mut rng),
+        )
+    };
+
+    assert_eq!(sample(7), sample(7));
+    assert_ne!(sample(7), sample(8));
+
+    let mut rng = StdRng::seed_from_u64(7);
+    let binary = PolyFF::random_binary(N, &mut rng);
+    let mut rng = StdRng::seed_from_u64(7);
+    assert_eq!(
+        FieldNttPolynomial::<FF>::random_binary(N, &table, &mut rng),
+        table.transform(&binary)
+    );
+}
+
+#[test]
+fn test_add_mul_assign_lazy() {
+    fn check<F: NttField>(terms: usize) {
+        let mut rng = thread_rng();
+
+        let pairs: Vec<(FieldNttPolynomial<F>, FieldNttPolynomial<F>)> = (0..terms)
+            .map(|_| {
+                (
+                    FieldNttPolynomial::random(N, &mut rng),
+                    FieldNttPolynomial::random(N, &mut rng),
+                )
+            })
+            .collect();
+        let init = FieldNttPolynomial::<F>::random(N, &mut rng);
+
+        let mut eager = init.clone();
+        pairs.iter().for_each(|(a, b)| eager.add_mul_assign(a, b));
+
+        let mut lazy = init;
+        lazy.add_mul_assign_lazy(pairs.iter().map(|(a, b)| (a, b)));
+
+        assert_eq!(lazy, eager);
+    }
+
+    // 30-bit prime, the accumulator is reduced every 16 terms.
+    check::<U32FieldEval<1073479681>>(100);
+    check::<U32FieldEval<1073479681>>(16);
+    check::<U32FieldEval<1073479681>>(0);
+    check::<FF>(100);
+}
+
+#[test]
+fn test_fused_ntt_kernels() {
+    let mut rng = thread_rng();
+    let p = FF::MODULUS_VALUE;
+
+    let acc = FieldNttPolynomial::<FF>::random(N, &mut rng);
+    let a = FieldNttPolynomial::<FF>::random(N, &mut rng);
+    let b = FieldNttPolynomial::<FF>::random(N, &mut rng);
+    let c = FieldNttPolynomial::<FF>::random(N, &mut rng);
+    let normalize =
+        |poly: FieldNttPolynomial<FF>| -> Vec<u32> { poly.copied_iter().map(|v| v % p).collect() };
+
+    // acc - a * b
+    let expect = acc.clone() - &(a.clone() * &b);
+    let mut result = acc.clone();
+    result.sub_mul_assign(&a, &b);
+    assert_eq!(result, expect);
+    let mut result = acc.clone();
+    result.sub_mul_assign_fast(&a, &b);
+    assert_eq!(normalize(result), normalize(expect.clone()));
+    let mut result = FieldNttPolynomial::zero(N);
+    acc.sub_mul_inplace(&a, &b, &mut result);
+    assert_eq!(result, expect);
+
+    // acc * c + a * b
+    let expect = acc.clone() * &c + &(a.clone() * &b);
+    let mut result = acc.clone();
+    result.mul_add_mul_assign(&c, &a, &b);
+    assert_eq!(result, expect);
+    let mut result = acc;
+    result.mul_add_mul_assign_fast(&c, &a, &b);
+    assert_eq!(normalize(result), normalize(expect));
+}
+
+#[test]
+fn test_mul_naive_karatsuba() {
+    let mut rng = thread_rng();
+
+    for log_n in [8, 10, 11] {
+        let n = 1 << log_n;
+
+        let table = FF::generate_ntt_table(log_n).unwrap();
+        let a = PolyFF::random(n, &mut rng);
+        let b = PolyFF::random(n, &mut rng);
+
+        let naive = a.mul_naive(&b);
+        assert_eq!(naive, a.mul_karatsuba(&b));
+        assert_eq!(naive, a.clone().mul(b, &table));
+
+        const Q: u32 = 1 << 14;
+        let modulus = <PowOf2Modulus<u32>>::new(Q);
+        let a = <Polynomial<u32>>::random(Q - 1, n, &mut rng);
+        let b = <Polynomial<u32>>::random(Q - 1, n, &mut rng);
+
+        let naive = a.mul_naive(&b, modulus);
+        let mut destination = <Polynomial<u32>>::zero(n);
+        a.naive_mul_inplace(&b, modulus, &mut destination);
+        assert_eq!(naive, destination);
+        assert_eq!(naive, a.mul_karatsuba(&b, modulus));
+    }
+}
+
+#[test]
+fn test_evaluate() {
+    let mut rng = thread_rng();
+    let distr = Uniform::new(0, FF::MODULUS_VALUE);
+
+    let points: Vec<u32> = (&mut rng).sample_iter(distr).take(8).collect();
+
+    // zero polynomial
+    let zero = PolyFF::zero(N);
+    assert!(zero.evaluate_many(&points).iter().all(|&v| v == FF::ZERO));
+    assert_eq!(PolyFF::new(Vec::new()).evaluate(points[0]), FF::ZERO);
+
+    // single-coefficient polynomial
+    let c = rng.sample(distr);
+    let constant = PolyFF::new(vec![c]);
+    assert!(constant.evaluate_many(&points).iter().all(|&v| v == c));
+
+    let poly = PolyFF::random(N, &mut rng);
+    let values = poly.evaluate_many(&points);
+    for (&x, &v) in points.iter().zip(values.iter()) {
+        let expect = poly
+            .copied_iter()
+            .enumerate()
+            .fold(FF::ZERO, |acc, (i, a)| {
+                FF::add(acc, FF::mul(a, FF::exp(x, i as u32)))
+            });
+        assert_eq!(v, expect);
+    }
+}
+
+#[test]
+fn test_ntt_is_evaluation() {
+    let mut rng = thread_rng();
+    let log_n = N.trailing_zeros();
+
+    let table = FF::generate_ntt_table(log_n).unwrap();
+    let root = table.root();
+
+    let poly = PolyFF::random(N, &mut rng);
+    let ntt_poly = table.transform(&poly);
+
+    // forward transform equals evaluation at bit-reversed odd root powers
+    for (i, &v) in ntt_poly.iter().enumerate() {
+        let x = FF::exp(root, 2 * i.reverse_lsbs(log_n) as u32 + 1);
+        assert_eq!(v, poly.evaluate(x));
+    }
+
+    let values = poly.evaluate_at_roots(&table);
+    for (i, &v) in values.iter().enumerate() {
+        assert_eq!(v, poly.evaluate(FF::exp(root, 2 * i as u32 + 1)));
+    }
+}
+
+#[test]
+fn test_automorphism() {
+    let mut rng = thread_rng();
+    let log_n = N.trailing_zeros();
+    let twice_n = N << 1;
+
+    let table = FF::generate_ntt_table(log_n).unwrap();
+    let poly = PolyFF::random(N, &mut rng);
+    let ntt_poly = table.transform(&poly);
+
+    let random_odd = rng.gen_range(0..N) * 2 + 1;
+    for k in [1, 3, 5, N - 1, N + 1, twice_n - 1, random_odd] {
+        let k_inv = (1..twice_n)
+            .step_by(2)
+            .find(|i| i * k % twice_n == 1)
+            .unwrap();
+
+        let auto = poly.apply_automorphism(k);
+        assert_eq!(auto.apply_automorphism(k_inv), poly);
+
+        let ntt_auto = ntt_poly.apply_automorphism(k);
+        assert_eq!(ntt_auto, table.transform(&auto));
+        assert_eq!(ntt_auto.apply_automorphism(k_inv), ntt_poly);
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_automorphism_even_degree() {
+    let poly = PolyFF::zero(N);
+    poly.apply_automorphism(2);
+}
+
+#[test]
+fn test_mul_monomial() {
+    let mut rng = thread_rng();
+    let log_n = N.trailing_zeros();
+
+    let table = FF::generate_ntt_table(log_n).unwrap();
+    let poly = PolyFF::random(N, &mut rng);
+    let ntt_poly = table.transform(&poly);
+
+    let random_exp = rng.gen_range(0..N << 2);
+    for exp in [
+        0,
+        1,
+        N - 1,
+        N,
+        N + 1,
+        2 * N - 1,
+        2 * N,
+        3 * N + 7,
+        random_exp,
+    ] {
+        let mut monomial = PolyFF::zero(N);
+        let r = exp % (2 * N);
+        if r < N {
+            monomial[r] = FF::ONE;
+        } else {
+            monomial[r - N] = FF::MINUS_ONE;
+        }
+        let expect = poly.clone().mul(monomial, &table);
+
+        let mut result = poly.clone();
+        result.mul_monomial_assign(exp);
+        assert_eq!(result, expect);
+
+        let mut ntt_result = ntt_poly.clone();
+        ntt_result.mul_monomial_assign(exp, &table);
+        assert_eq!(ntt_result, table.transform(&expect));
+    }
+
+    // An empty polynomial stays empty.
+    let mut empty = PolyFF::zero(0);
+    empty.mul_monomial_assign(3);
+    assert_eq!(empty.coeff_count(), 0);
+
+    let mut empty_ntt = FieldNttPolynomial::<FF>::zero(0);
+    empty_ntt.mul_monomial_assign(3, &table);
+    assert_eq!(empty_ntt.coeff_count(), 0);
+}
+
+#[test]
+fn test_rotate_reverse() {
+    let mut rng = thread_rng();
+    let table = FF::generate_ntt_table(N.trailing_zeros()).unwrap();
+
+    let poly = PolyFF::random(N, &mut rng);
+    let ntt_poly = table.transform(&poly);
+
+    for k in [0, 1, N - 1, N, N + 3, 2 * N, rng.gen_range(0..N << 2)] {
+        let mut rotated = poly.clone();
+        rotated.rotate_right(k);
+
+        // rotating in the ntt domain agrees with the coefficient domain
+        let mut ntt_rotated = ntt_poly.clone();
+        ntt_rotated.mul_monomial_assign(k, &table);
+        assert_eq!(table.transform(&rotated), ntt_rotated);
+
+        rotated.rotate_left(k);
+        assert_eq!(rotated, poly);
+
+        let mut rotated = poly.clone();
+        rotated.rotate_left(k);
+        rotated.rotate_right(k);
+        assert_eq!(rotated, poly);
+    }
+
+    // p(X^(-1)) is the automorphism X -> X^(2n-1)
+    let mut reversed = poly.clone();
+    reversed.reverse_coeffs_negacyclic();
+    assert_eq!(reversed[0], poly[0]);
+    assert_eq!(reversed, poly.apply_automorphism(2 * N - 1));
+
+    reversed.reverse_coeffs_negacyclic();
+    assert_eq!(reversed, poly);
+}
+
+#[test]
+fn test_sparse_ternary_mul() {
+    let mut rng = thread_rng();
+    let table = FF::generate_ntt_table(N.trailing_zeros()).unwrap();
+
+    let dense = PolyFF::random(N, &mut rng);
+
+    let mut single_plus = PolyFF::zero(N);
+    single_plus[rng.gen_range(0..N)] = FF::ONE;
+    let mut single_minus = PolyFF::zero(N);
+    single_minus[rng.gen_range(0..N)] = FF::MINUS_ONE;
+
+    for ternary in [
+        PolyFF::random_ternary(N, &mut rng),
+        PolyFF::random_binary(N, &mut rng),
+        PolyFF::zero(N),
+        single_plus,
+        single_minus,
+    ] {
+        let sparse = SparseTernaryPoly::from_field_polynomial(&ternary).unwrap();
+        assert_eq!(sparse.to_field_polynomial::<FF>(), ternary);
+        assert_eq!(
+            sparse.hamming_weight(),
+            ternary.iter().filter(|&&c| c != FF::ZERO).count()
+        );
+
+        let mut out = PolyFF::random(N, &mut rng);
+        sparse.mul_into(&dense, &mut out);
+        assert_eq!(out, ternary.mul(dense.clone(), &table));
+    }
+
+    assert!(SparseTernaryPoly::from_field_polynomial(&PolyFF::new(vec![FF::ZERO, 2])).is_none());
+}
+
+#[test]
+fn test_decompose_into() {
+    let mut rng = thread_rng();
+    let basis = NonPowOf2ApproxSignedBasis::new(FF::MODULUS_VALUE, 3, None);
+    let len = basis.decompose_length();
+
+    let poly = PolyFF::random(N, &mut rng);
+
+    let mut decompose_polys = vec![PolyFF::zero(N); len];
+    poly.decompose_into(&basis, &mut decompose_polys);
+
+    // compare with the digit-by-digit decomposition
+    let mut carries = vec![false; N];
+    let mut adjust_poly = PolyFF::zero(N);
+    poly.init_adjust_poly_carries(&basis, &mut carries, &mut adjust_poly);
+    let mut digit_poly = PolyFF::zero(N);
+    for (once_decompose, expect) in basis.decompose_iter().zip(decompose_polys.iter()) {
+        adjust_poly.approx_signed_decompose(
+            once_decompose,
+            &mut carries,
+            digit_poly.as_mut_slice(),
+        );
+        assert_eq!(&digit_poly, expect);
+    }
+
+    for (i, value) in poly.copied_iter().enumerate() {
+        let digits: Vec<u32> = decompose_polys.iter().map(|p| p[i]).collect();
+        assert_eq!(digits, basis.decompose(value));
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_decompose_into_short_buffer() {
+    let basis = NonPowOf2ApproxSignedBasis::new(FF::MODULUS_VALUE, 3, None);
+    let poly = PolyFF::zero(N);
+    let mut decompose_polys = vec![PolyFF::zero(N); basis.decompose_length() - 1];
+    poly.decompose_into(&basis, &mut decompose_polys);
+}
+
+#[test]
+fn test_poly_views() {
+    let mut rng = thread_rng();
+    let ntt_table = FF::generate_ntt_table(N.trailing_zeros()).unwrap();
+
+    let a = PolyFF::random(N, &mut rng);
+    let b = PolyFF::random(N, &mut rng);
+
+    // two polynomials stored contiguously in one buffer
+    let mut buffer = [a.as_slice(), b.as_slice()].concat();
+    let (front, back) = buffer.split_at_mut(N);
+    let mut a_view = PolyMut::<FF>::new(front);
+    let b_view = PolyRef::<FF>::new(back);
+
+    a_view += b_view;
+    assert_eq!(a_view.as_slice(), (a.clone() + &b).as_slice());
+    a_view -= b_view;
+    assert_eq!(a_view.as_slice(), a.as_slice());
+
+    let mut destination = PolyFF::zero(N);
+    a.as_ref_poly()
+        .add_inplace(b_view, destination.as_mut_poly());
+    assert_eq!(destination, a.clone() + &b);
+    a.as_ref_poly()
+        .sub_inplace(b_view, destination.as_mut_poly());
+    assert_eq!(destination, a.clone() - &b);
+
+    let mut ntt_poly = FieldNttPolynomial::zero(N);
+    b_view.transform_into(&ntt_table, &mut ntt_poly);
+    assert_eq!(ntt_poly, ntt_table.transform(&b));
+
+    let basis = NonPowOf2ApproxSignedBasis::new(FF::MODULUS_VALUE, 3, None);
+    let mut expect = vec![PolyFF::zero(N); basis.decompose_length()];
+    let mut decompose_polys = vec![PolyFF::zero(N); basis.decompose_length()];
+    b.decompose_into(&basis, &mut expect);
+    b_view.decompose_into(&basis, &mut decompose_polys);
+    assert_eq!(decompose_polys, expect);
+}
+
+#[test]
+#[should_panic(expected = "polynomial view length mismatch")]
+fn test_poly_views_misaligned() {
+    let mut buffer = vec![0u32; 2 * N + 1];
+    let (front, back) = buffer.split_at_mut(N);
+    let mut a_view = PolyMut::<FF>::new(front);
+    a_view += PolyRef::<FF>::new(back);
+}
+
+#[test]
+fn test_norms() {
+    let p = FF::MODULUS_VALUE;
+    let half = p / 2;
+
+    let poly = PolyFF::new(vec![0, 1, p - 1, 5, p - 7, half, half + 1]);
+    assert_eq!(poly.linf_norm(), half as u64);
+    let expect = [0u128, 1, 1, 5, 7, half as u128, half as u128]
+        .iter()
+        .map(|v| v * v)
+        .sum::<u128>();
+    assert_eq!(poly.l2_norm_squared(), expect);
+
+    let small = PolyFF::new(vec![0, 1, p - 1, 5, p - 7, 0, 0]);
+    assert_eq!(small.linf_norm(), 7);
+    assert_eq!(small.l2_norm_squared(), 1 + 1 + 25 + 49);
+
+    let other = PolyFF::new(vec![3, 1, 2, 5, 0, 0, p - 1]);
+    assert_eq!(small.distance_linf(&other), 7);
+    assert_eq!(small.distance_linf(&small), 0);
+
+    assert_eq!(PolyFF::zero(N).linf_norm(), 0);
+    assert_eq!(PolyFF::zero(N).l2_norm_squared(), 0);
+}
+
+#[test]
+fn test_from_centered() {
+    let p = FF::MODULUS_VALUE;
+    let half = (p / 2) as i64;
+
+    let poly = PolyFF::from_centered(&[0, 1, -1, 5, -7, half, -half]);
+    assert!(poly == PolyFF::new(vec![0, 1, p - 1, 5, p - 7, p / 2, p - p / 2]));
+    assert_eq!(poly.linf_norm(), half as u64);
+
+    let mut rng = thread_rng();
+    let bound = 100;
+    let coeffs: Vec<i64> = (0..N).map(|_| rng.gen_range(-bound..=bound)).collect();
+    let poly = PolyFF::from_centered(&coeffs);
+    let expect = coeffs.iter().map(|v| v.unsigned_abs()).max().unwrap();
+    assert_eq!(poly.linf_norm(), expect);
+    assert!(poly.linf_norm() <= bound as u64);
+
+    assert!(PolyFF::from_centered(&[0; N]).is_zero());
+    assert!(PolyFF::from_centered(&[]).is_zero());
+}
+
+#[test]
+#[should_panic]
+fn test_from_centered_out_of_range() {
+    let _ = PolyFF::from_centered(&[FF::MODULUS_VALUE as i64]);
+}
+
+#[test]
+fn test_ntt_poly_operators() {
+    let mut rng = thread_rng();
+    let table = FF::generate_ntt_table(N.trailing_zeros()).unwrap();
+
+    let a = PolyFF::random(N, &mut rng);
+    let b = PolyFF::random(N, &mut rng);
+
+    let a_ntt = a.clone().into_ntt_poly(&table);
+    let b_ntt = b.clone().into_ntt_poly(&table);
+
+    let product = (&a_ntt * &b_ntt).into_coeff_poly(&table);
+    assert_eq!(product, a.mul_naive(&b));
+    assert_eq!(
+        (a_ntt.clone() * b_ntt.clone()).into_coeff_poly(&table),
+        product
+    );
+
+    assert_eq!((&a_ntt + &b_ntt).into_coeff_poly(&table), a.clone() + &b);
+    assert_eq!((&a_ntt - &b_ntt).into_coeff_poly(&table), a.clone() - &b);
+    assert_eq!((-&a_ntt).into_coeff_poly(&table), -a.clone());
+
+    let mut c = a_ntt.clone();
+    c += &b_ntt;
+    c -= &b_ntt;
+    assert_eq!(c, a_ntt);
+    c *= &b_ntt;
+    assert_eq!(c, &a_ntt * &b_ntt);
+}
+
+#[test]
+fn test_random_ternary_with_hamming_weight() {
+    let mut rng = thread_rng();
+    let h = 64;
+
+    let mut ones = 0;
+    let mut minus_ones = 0;
+    for _ in 0..64 {
+        let poly = PolyFF::random_ternary_with_hamming_weight(N, h, &mut rng);
+        assert!(poly.iter().all(|&v| v == 0 || v == 1 || v == FF::MINUS_ONE));
+        ones += poly.iter().filter(|&&v| v == 1).count();
+        minus_ones += poly.iter().filter(|&&v| v == FF::MINUS_ONE).count();
+        assert_eq!(poly.iter().filter(|&&v| v != 0).count(), h);
+    }
+
+    // 4096 signs, the standard deviation of the count of `1` is 32.
+    assert_eq!(ones + minus_ones, 64 * h);
+    assert!(ones.abs_diff(minus_ones) < 400, "{ones} vs {minus_ones}");
+
+    assert_eq!(
+        PolyFF::random_ternary_with_hamming_weight(N, 0, &mut rng),
+        PolyFF::zero(N)
+    );
+}
+
+#[test]
+fn test_poly_constructors() {
+    let mut rng = thread_rng();
+    let table = FF::generate_ntt_table(N.trailing_zeros()).unwrap();
+
+    let values: Vec<u32> = (&mut rng)
+        .sample_iter(Uniform::new(0, FF::MODULUS_VALUE))
+        .take(N)
+        .collect();
+
+    let from_slice = PolyFF::from_slice(&values);
+    let from_iter: PolyFF = values.iter().copied().collect();
+    let from_vec = PolyFF::from(values.clone());
+    assert_eq!(from_slice, from_iter);
+    assert_eq!(from_slice, from_vec);
+    assert_eq!(from_iter.coeff_count(), N);
+
+    let other = PolyFF::random(N, &mut rng);
+    assert_eq!(from_iter.clone() + &other, from_slice.clone() + &other);
+    assert_eq!(
+        from_iter.clone().mul(other.clone(), &table),
+        from_vec.mul(other, &table)
+    );
+
+    let ntt: FieldNttPolynomial<FF> = values.iter().copied().collect();
+    assert_eq!(ntt, FieldNttPolynomial::from_slice(&values));
+    assert_eq!(ntt, FieldNttPolynomial::from(values.clone()));
+
+    let numeric: Polynomial<u32> = values.iter().copied().collect();
+    assert_eq!(numeric, Polynomial::from(values));
+}
+
+#[cfg(feature = "std")]
+fn check_packed_round_trip<F: NttField>(n: usize, bit_width: usize) {
+    let mut rng = thread_rng();
+
+    let poly = FieldPolynomial::<F>::random(n, &mut rng);
+    let mut bytes = Vec::new();
+    poly.write_packed(&mut bytes).unwrap();
+    assert_eq!(bytes.len(), 9 + (n * bit_width).div_ceil(8));
+    assert_eq!(
+        FieldPolynomial::<F>::read_packed(bytes.as_slice()).unwrap(),
+        poly
+    );
+
+    // Every truncation of the stream is rejected.
+    for len in [0, 8, 9, bytes.len() - 1] {
+        assert!(FieldPolynomial::<F>::read_packed(&bytes[..len]).is_err());
+    }
+
+    let ntt_poly = FieldNttPolynomial::<F>::new(poly.inner_data());
+    let mut ntt_bytes = Vec::new();
+    ntt_poly.write_packed(&mut ntt_bytes).unwrap();
+    assert_eq!(ntt_bytes, bytes);
+    assert_eq!(
+        FieldNttPolynomial::<F>::read_packed(ntt_bytes.as_slice()).unwrap(),
+        ntt_poly
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_packed_serialization() {
+    type BigFF = U32FieldEval<1073479681>;
+    type F64 = U64FieldEval<1125899906826241>;
+
+    for n in [1024, 2048] {
+        check_packed_round_trip::<FF>(n, 27);
+        check_packed_round_trip::<BigFF>(n, 30);
+        check_packed_round_trip::<F64>(n, 50);
+    }
+
+    // A stream written for another modulus is rejected.
+    let mut bytes = Vec::new();
+    PolyFF::random(N, &mut thread_rng())
+        .write_packed(&mut bytes)
+        .unwrap();
+    assert!(FieldPolynomial::<U32FieldEval<1073479681>>::read_packed(bytes.as_slice()).is_err());
+
+    // Values not less than the modulus are rejected.
+    let p = FF::MODULUS_VALUE;
+    let mut bytes = Vec::new();
+    PolyFF::new(vec![p - 1]).write_packed(&mut bytes).unwrap();
+    bytes[9..].fill(0xff);
+    assert!(PolyFF::read_packed(bytes.as_slice()).is_err());
+
+    // A huge coefficient count without the coefficients is rejected
+    // without allocating for it.
+    let mut bytes = Vec::new();
+    PolyFF::new(vec![1]).write_packed(&mut bytes).unwrap();
+    bytes[..8].copy_from_slice(&(u64::MAX / 64).to_le_bytes());
+    let err = PolyFF::read_packed(bytes.as_slice()).unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::UnexpectedEof);
+}
+
+#[cfg(feature = "std")]
+fn check_cached_ntt_conversion<F>()
+where
+    F: NttField + 'static,
+    <F as NttField>::Table: 'static,
+{
+    let mut rng = thread_rng();
+
+    for log_n in [5, 8, 10, 11] {
+        let n = 1 << log_n;
+        let table = F::generate_ntt_table(log_n).unwrap();
+
+        let poly = FieldPolynomial::<F>::random(n, &mut rng);
+        let ntt_poly = poly.to_ntt();
+        assert_eq!(ntt_poly, table.transform(&poly));
+        assert_eq!(ntt_poly.to_coeff(), poly);
+
+        let ntt_poly = FieldNttPolynomial::<F>::random(n, &mut rng);
+        assert_eq!(ntt_poly.to_coeff(), table.inverse_transform(&ntt_poly));
+        assert_eq!(ntt_poly.to_coeff().to_ntt(), ntt_poly);
+
+        assert!(std::sync::Arc::ptr_eq(
+            &F::get_ntt_table(log_n).unwrap(),
+            &F::get_ntt_table(log_n).unwrap()
+        ));
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_cached_ntt_conversion() {
+    check_cached_ntt_conversion::<FF>();
+    check_cached_ntt_conversion::<U64FieldEval<1125899906826241>>();
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_init_ntt_tables_concurrently() {
+    type F = U64FieldEval<1125899865948161>;
+
+    let log_ns = [10, 11, 12, 13, 14, 15];
+
+    std::thread::scope(|s| {
+        for _ in 0..4 {
+            s.spawn(|| F::init_ntt_tables(&log_ns).unwrap());
+        }
+    });
+    F::init_ntt_tables(&log_ns).unwrap();
+
+    let mut rng = thread_rng();
+    for log_n in log_ns {
+        assert!(std::sync::Arc::ptr_eq(
+            &F::get_ntt_table(log_n).unwrap(),
+            &F::get_ntt_table(log_n).unwrap()
+        ));
+
+        let table = F::generate_ntt_table(log_n).unwrap();
+        let poly = FieldPolynomial::<F>::random(1 << log_n, &mut rng);
+        assert_eq!(poly.to_ntt(), table.transform(&poly));
+    }
+
+    // 2^31 doesn't divide p - 1.
+    assert!(F::init_ntt_tables(&[16, 30]).is_err());
+}
+
+#[cfg(feature = "std")]
+#[test]
+#[should_panic(expected = "not a power of two")]
+fn test_to_ntt_rejects_non_power_of_two() {
+    PolyFF::random(12, &mut thread_rng()).to_ntt();
+}
+
+#[test]
+fn test_slice_ops() {
+    let mut rng = thread_rng();
+    let p = FF::MODULUS_VALUE;
+
+    let a = PolyFF::random(N, &mut rng);
+    let b = PolyFF::random(N, &mut rng);
+    let scalar = rng.gen_range(0..p);
+
+    let naive = |f: &dyn Fn(u32, u32) -> u32| -> PolyFF {
+        PolyFF::new(a.iter().zip(b.iter()).map(|(&x, &y)| f(x, y)).collect())
+    };
+
+    let mut buffer = a.as_slice().to_vec();
+    poly_add_assign_slice::<FF>(&mut buffer, b.as_slice());
+    assert_eq!(buffer, naive(&|x, y| FF::add(x, y)).as_slice());
+    assert_eq!(buffer, (a.clone() + &b).as_slice());
+
+    let mut buffer = a.as_slice().to_vec();
+    poly_sub_assign_slice::<FF>(&mut buffer, b.as_slice());
+    assert_eq!(buffer, naive(&|x, y| FF::sub(x, y)).as_slice());
+    assert_eq!(buffer, (a.clone() - &b).as_slice());
+
+    let mut buffer = a.as_slice().to_vec();
+    poly_neg_assign_slice::<FF>(&mut buffer);
+    assert_eq!(buffer, naive(&|x, _| FF::neg(x)).as_slice());
+    assert_eq!(buffer, (-a.clone()).as_slice());
+
+    let mut buffer = a.as_slice().to_vec();
+    poly_scalar_mul_assign_slice::<FF>(&mut buffer, scalar);
+    assert_eq!(buffer, naive(&|x, _| FF::mul(x, scalar)).as_slice());
+    assert_eq!(buffer, a.clone().mul_scalar(scalar).as_slice());
+}
+
+#[test]
+fn test_rescale() {
+    type BigFF = U32FieldEval<1073479681>;
+
+    let mut rng = thread_rng();
+    let p = FF::MODULUS_VALUE;
+    let big = BigFF::MODULUS_VALUE;
+
+    let a = PolyFF::random(N, &mut rng);
+    assert_eq!(a.rescale_to::<FF>(), a);
+
+    // Raising the modulus and switching back is exact up to rounding.
+    let raised = a.rescale_to::<BigFF>();
+    assert!(raised.rescale_to::<FF>().distance_linf(&a) <= 1);
+
+    // Lowering the modulus loses about `q1 / (2 * q2)` at most.
+    let b = FieldPolynomial::<BigFF>::random(N, &mut rng);
+    let bound = (big / p / 2 + 1) as u64;
+    assert!(b.rescale_to::<FF>().rescale_to::<BigFF>().distance_linf(&b) <= bound);
+
+    // Coefficients at `±q1/2` keep their sign.
+    let half = (p - 1) / 2;
+    let c = PolyFF::new(vec![0, 1, p - 1, half, half + 1]);
+    let rescaled = c.rescale_to::<BigFF>();
+    let ratio = big as f64 / p as f64;
+    let expect_half = (half as f64 * ratio).round() as u32;
+    assert_eq!(
+        rescaled.as_slice(),
+        &[
+            0,
+            ratio.round() as u32,
+            big - ratio.round() as u32,
+            expect_half,
+            big - expect_half
+        ]
+    );
+
+    let mut out = vec![0u16; 5];
+    rescale_slice::<FF, u16>(c.as_slice(), ModulusValue::PowerOf2(1 << 14), &mut out);
+    assert_eq!(out, [0, 0, 0, 1 << 13, 1 << 13]);
+
+    let mut out = vec![0u16; 5];
+    rescale_slice::<FF, u16>(c.as_slice(), ModulusValue::Native, &mut out);
+    assert_eq!(out, [0, 0, 0, 1 << 15, 1 << 15]);
+}
+
+#[test]
+fn test_batch_encode() {
+    const T: u64 = 65537;
+
+    let mut rng = thread_rng();
+    let encoder = BatchEncoder::new(T, N).unwrap();
+    assert_eq!(encoder.slot_count(), N);
+
+    let a: Vec<u64> = (0..N).map(|_| rng.gen_range(0..T)).collect();
+    let b: Vec<u64> = (0..N).map(|_| rng.gen_range(0..T)).collect();
+
+    let pa = encoder.encode(&a);
+    let pb = encoder.encode(&b);
+    assert!(pa.iter().all(|&v| v < T));
+    assert_eq!(encoder.decode(&pa), a);
+    assert_eq!(
+        batch_decode(&batch_encode(&a, T, N).unwrap(), T, N).unwrap(),
+        a
+    );
+
+    let sum = Polynomial::new(
+        pa.iter()
+            .zip(pb.iter())
+            .map(|(&x, &y)| (x + y) % T)
+            .collect(),
+    );
+    let expect: Vec<u64> = a.iter().zip(b.iter()).map(|(&x, &y)| (x + y) % T).collect();
+    assert_eq!(encoder.decode(&sum), expect);
+
+    let partial = encoder.encode(&a[..3]);
+    let decoded = encoder.decode(&partial);
+    assert_eq!(decoded[..3], a[..3]);
+    assert!(decoded[3..].iter().all(|&v| v == 0));
+
+    // 65537 - 1 is not divisible by 2 * 2^16.
+    assert!(BatchEncoder::new(T, 1 << 16).is_err());
+}
+
+#[cfg(feature = "noise-analysis")]
+#[test]
+fn test_mul_full() {
+    let mut rng = thread_rng();
+    let table = FF::generate_ntt_table(N.trailing_zeros()).unwrap();
+
+    let a = PolyFF::random(N, &mut rng);
+    let b = PolyFF::random(N, &mut rng);
+
+    let full = a.mul_full(&b);
+    assert_eq!(full.len(), 2 * N - 1);
+    assert_eq!(
+        PolyFF::fold_full_product(&full, N),
+        a.clone().mul(b, &table)
+    );
+
+    let p = FF::MODULUS_VALUE;
+    let x = PolyFF::new(vec![1, p - 2]);
+    let y = PolyFF::new(vec![p - 3, 4]);
+    assert_eq!(x.mul_full(&y), vec![-3, 10, -8]);
+    assert_eq!(
+        PolyFF::fold_full_product(&[-3, 10, -8], 2),
+        PolyFF::new(vec![5, 10])
+    );
+}
+
+#[cfg(feature = "noise-analysis")]
+#[test]
+#[should_panic(expected = "overflows the i128 coefficients")]
+fn test_mul_full_overflow() {
+    type F = U64FieldEval<4611686018425815041>;
+    let n = 512;
+    let a = <FieldPolynomial<F>>::zero(n);
+    let _ = a.mul_full(&a);
+}
+
+#[test]
+fn test_shift_add_accumulate() {
+    let mut rng = thread_rng();
+
+    for _ in 0..16 {
+        let acc = PolyFF::random(N, &mut rng);
+        let src = PolyFF::random(N, &mut rng);
+        let shift = rng.gen_range(0..4 * N);
+
+        let mut monomial = src.clone();
+        monomial.mul_monomial_assign(shift);
+
+        let mut result = acc.clone();
+        shift_add_accumulate(&mut result, &src, shift, false);
+        assert_eq!(result, acc.clone() + &monomial);
+
+        let mut result = acc.clone();
+        shift_add_accumulate(&mut result, &src, shift, true);
+        assert_eq!(result, acc - &monomial);
+    }
+}
+
+#[test]
+fn test_poly_iter_and_index() {
+    let mut rng = thread_rng();
+
+    let mut poly = PolyFF::random(N, &mut rng);
+    let expected: Vec<_> = poly.iter().map(|&v| FF::add(v, FF::ONE)).collect();
+
+    poly.iter_mut().for_each(|v| *v = FF::add(*v, FF::ONE));
+    assert!(poly.iter().eq(expected.iter()));
+
+    for (i, &v) in expected.iter().enumerate() {
+        assert_eq!(poly[i], v);
+    }
+
+    poly[0] = FF::ZERO;
+    assert_eq!(poly[0], FF::ZERO);
+    assert_eq!(&poly[1..], &expected[1..]);
+
+    let mut ntt_poly = FieldNttPolynomial::<FF>::from(expected.clone());
+    ntt_poly.iter_mut().for_each(|v| *v = FF::neg(*v));
+    ntt_poly[N - 1] = FF::ONE;
+    assert_eq!(ntt_poly[0], FF::neg(expected[0]));
+    assert_eq!(ntt_poly[N - 1], FF::ONE);
+}
+
+#[test]
+#[should_panic]
+fn test_poly_index_out_of_bounds() {
+    let poly = PolyFF::zero(N);
+    let _ = poly[N];
+}
+
+#[cfg(feature = "test-utils")]
+#[test]
+fn test_assert_poly_close() {
+    use algebra::test_utils::{assert_poly_close, poly_distance};
+
+    let mut rng = thread_rng();
+
+    let a = PolyFF::random(N, &mut rng);
+    let mut b = a.clone();
+    b[3] = FF::add(b[3], 5);
+    b[7] = FF::sub(b[7], 9);
+
+    assert_eq!(poly_distance(&a, &b), 9);
+    assert_poly_close(&a, &b, 9);
+
+    let message = std::panic::catch_unwind(|| assert_poly_close(&a, &b, 8))
+        .unwrap_err()
+        .downcast::<String>()
+        .unwrap();
+    assert!(message.contains("distance 9 at coefficient 7"), "{message}");
+}
+
+#[cfg(feature = "parallel")]
+#[test]
+fn test_parallel_kernels() {
+    use algebra::polynomial::{parallel_threshold, set_parallel_threshold};
+
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(2)
+        .build()
+        .unwrap();
+
+    let mut rng = thread_rng();
+    let n = 1 << 13;
+
+    let basis = NonPowOf2ApproxSignedBasis::new(FF::MODULUS_VALUE, 4, None);
+
+    let a = PolyFF::random(n, &mut rng);
+    let b = PolyFF::random(n, &mut rng);
+    let x = FieldNttPolynomial::<FF>::random(n, &mut rng);
+    let y = FieldNttPolynomial::<FF>::random(n, &mut rng);
+
+    // Below and above the threshold, so that both code paths are exercised.
+    for threshold in [n * 2, 256] {
+        set_parallel_threshold(threshold);
+        assert_eq!(parallel_threshold(), threshold);
+
+        pool.install(|| {
+            let mut serial = a.clone();
+            serial += &b;
+            let mut parallel = a.clone();
+            parallel.par_add_assign(&b);
+            assert_eq!(parallel, serial);
+
+            let mut serial = x.clone();
+            serial.add_mul_assign(&x, &y);
+            let mut parallel = x.clone();
+            parallel.par_add_mul_assign(&x, &y);
+            assert_eq!(parallel, serial);
+
+            let mut serial = x.clone();
+            serial += &y;
+            let mut parallel = x.clone();
+            parallel.par_add_assign(&y);
+            assert_eq!(parallel, serial);
+
+            let mut serial_carries = vec![false; n];
+            let mut parallel_carries = vec![false; n];
+            let mut serial_adjust = PolyFF::zero(n);
+            a.init_adjust_poly_carries(&basis, &mut serial_carries, &mut serial_adjust);
+            parallel_carries.copy_from_slice(&serial_carries);
+            let parallel_adjust = serial_adjust.clone();
+
+            for (serial_decompose, parallel_decompose) in
+                basis.decompose_iter().zip(basis.decompose_iter())
+            {
+                let mut serial_poly = vec![0; n];
+                let mut parallel_poly = vec![0; n];
+                serial_adjust.approx_signed_decompose(
+                    serial_decompose,
+                    &mut serial_carries,
+                    &mut serial_poly,
+                );
+                parallel_adjust.par_approx_signed_decompose(
+                    parallel_decompose,
+                    &mut parallel_carries,
+                    &mut parallel_poly,
+                );
+                assert_eq!(parallel_poly, serial_poly);
+                assert_eq!(parallel_carries, serial_carries);
+            }
+        });
+    }
+}
+
+#[test]
+fn test_convert_field() {
+    type BigFF = U32FieldEval<1073479681>;
+    type WideFF = U64FieldEval<1125899906826241>;
+
+    let mut rng = thread_rng();
+
+    let a = PolyFF::random(N, &mut rng);
+    let b = FieldPolynomial::<BigFF>::random(N, &mut rng);
+
+    // q2 = q1
+    assert_eq!(a.convert_field::<FF>(), a);
+    assert_eq!(a.lift_field::<FF>().unwrap(), a);
+
+    // q2 < q1 and q2 > q1, odd moduli never produce ties.
+    assert_eq!(b.convert_field::<FF>(), b.rescale_to::<FF>());
+    assert_eq!(a.convert_field::<WideFF>(), a.rescale_to::<WideFF>());
+    assert_eq!(a.convert_field::<WideFF>().convert_field::<FF>(), a);
+
+    // Prime moduli only divide each other when they are equal.
+    assert!(a.lift_field::<BigFF>().is_err());
+    assert!(b.lift_field::<FF>().is_err());
+}
+
+#[test]
+fn test_div_round_pow2() {
+    let mut rng = thread_rng();
+    let k = 5;
+
+    // Raw representatives under a power of two modulus.
+    let modulus = <PowOf2Modulus<u16>>::new(1 << 12);
+    let values: Vec<u16> = (&mut rng)
+        .sample_iter(Uniform::new(0, 1 << 12))
+        .take(N)
+        .collect();
+    let poly = Polynomial::new(values.clone());
+
+    let mut floor = poly.clone();
+    floor.div_round_pow2_assign(k, RoundingMode::Floor, modulus);
+    let mut round = poly.clone();
+    round.div_round_pow2_assign(k, RoundingMode::Round, modulus);
+    let mut stochastic = poly.clone();
+    stochastic.div_round_pow2_assign(k, RoundingMode::Stochastic(&mut rng), modulus);
+
+    for (i, &v) in values.iter().enumerate() {
+        assert_eq!(floor[i], v >> k);
+        assert_eq!(round[i], ((v + (1 << (k - 1))) >> k) & 0x7f);
+        let low = v >> k;
+        assert!(stochastic[i] == low || stochastic[i] == (low + 1) & 0x7f);
+    }
+
+    // Centered representatives for field elements.
+    let p = FF::MODULUS_VALUE as i64;
+    let centered = |v: u32| {
+        let v = v as i64;
+        if v <= p / 2 {
+            v
+        } else {
+            v - p
+        }
+    };
+    let poly = PolyFF::random(N, &mut rng);
+    for mode in [
+        RoundingMode::Floor,
+        RoundingMode::Round,
+        RoundingMode::Stochastic(&mut rng),
+    ] {
+        let exact_floor = matches!(mode, RoundingMode::Floor);
+        let mut result = poly.clone();
+        result.div_round_pow2_assign(k, mode);
+        for (&r, &v) in result.iter().zip(poly.iter()) {
+            let diff = (centered(r) << k) - centered(v);
+            assert!(diff.abs() < 1 << k, "{diff}");
+            if exact_floor {
+                assert!(diff <= 0);
+            }
+        }
+    }
+
+    // Stochastic rounding is unbiased, `3 / 4` should round up about 3/4 of the time.
+    let trials = 4000;
+    let mut ups = 0;
+    for _ in 0..trials {
+        let mut poly = PolyFF::new(vec![3 << (k - 2)]);
+        poly.div_round_pow2_assign(k, RoundingMode::Stochastic(&mut rng));
+        ups += poly[0] as usize;
+    }
+    let ratio = ups as f64 / trials as f64;
+    assert!((ratio - 0.75).abs() < 0.05, "{ratio}");
+}
+
+#[test]
+fn test_probabilistic_eq() {
+    let mut rng = thread_rng();
+
+    let a = PolyFF::random(N, &mut rng);
+    for _ in 0..16 {
+        assert!(a.probabilistic_eq(&a.clone(), &mut rng));
+    }
+
+    // `a - b = 1 - x^2` vanishes at exactly 2 of the 17 points.
+    type SmallFF = U32FieldEval<17>;
+    let a = FieldPolynomial::<SmallFF>::random(4, &mut rng);
+    let mut b = a.clone();
+    b[0] = SmallFF::sub(b[0], SmallFF::ONE);
+    b[2] = SmallFF::add(b[2], SmallFF::ONE);
+
+    let trials = 17000;
+    let passes = (0..trials)
+        .filter(|_| a.probabilistic_eq(&b, &mut rng))
+        .count();
+    // The expected count is 2000 with a standard deviation of about 42.
+    assert!((1700..2300).contains(&passes), "passes: {passes}");
+}
+
+#[test]
+fn test_polynomial_hash() {
+    use std::collections::HashMap;
+    use std::hash::{BuildHasher, RandomState};
+
+    let mut rng = thread_rng();
+    let state = RandomState::new();
+
+    // Field values are plain integers and already hashable.
+    let value = rng.gen_range(0..FF::MODULUS_VALUE);
+    assert_eq!(state.hash_one(value), state.hash_one(FF::add(value, 0)));
+
+    let a = PolyFF::random(N, &mut rng);
+    let b = a.clone();
+    assert!(a == b);
+    assert_eq!(state.hash_one(&a), state.hash_one(&b));
+
+    let ntt_a = FieldNttPolynomial::<FF>::random(N, &mut rng);
+    assert_eq!(state.hash_one(&ntt_a), state.hash_one(ntt_a.clone()));
+
+    let numeric = Polynomial::new(a.as_slice().to_vec());
+    assert_eq!(state.hash_one(&numeric), state.hash_one(numeric.clone()));
+
+    // Memoizes some lookup tables keyed by their polynomials.
+    let luts: Vec<PolyFF> = (0..8).map(|_| PolyFF::random(N, &mut rng)).collect();
+    let mut memo: HashMap<PolyFF, usize> = HashMap::new();
+    for (i, lut) in luts.iter().enumerate() {
+        assert_eq!(memo.insert(lut.clone(), i), None);
+    }
+    for (i, lut) in luts.iter().enumerate() {
+        assert_eq!(memo.get(lut), Some(&i));
+    }
+    assert_eq!(memo.insert(luts[3].clone(), 42), Some(3));
+    assert_eq!(memo.len(), luts.len());
+
+    let mut ntt_memo: HashMap<FieldNttPolynomial<FF>, u32> = HashMap::new();
+    ntt_memo.insert(ntt_a.clone(), 1);
+    assert_eq!(ntt_memo.get(&ntt_a), Some(&1));
+    assert_eq!(ntt_memo.get(&FieldNttPolynomial::zero(N)), None);
+}
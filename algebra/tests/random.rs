@@ -0,0 +1,89 @@
+use algebra::random::{
+    sample_fixed_weight, DiscreteGaussian, DiscreteGaussianSampler, TernarySampler,
+};
+use rand::{distributions::Distribution, thread_rng};
+
+const MINUS_ONE: u32 = 132120576;
+
+#[test]
+fn test_ternary_with_probability() {
+    let mut rng = thread_rng();
+    let n = 100000;
+
+    for prob in [0.0, 0.1, 0.25, 0.5, 0.9, 1.0] {
+        let sampler = TernarySampler::with_probability(MINUS_ONE, prob).unwrap();
+
+        let (mut ones, mut minus_ones) = (0usize, 0usize);
+        for v in sampler.sample_iter(&mut rng).take(n) {
+            match v {
+                0 => {}
+                1 => ones += 1,
+                MINUS_ONE => minus_ones += 1,
+                _ => panic!("unexpected value {v}"),
+            }
+        }
+
+        let rate = (ones + minus_ones) as f64 / n as f64;
+        assert!((rate - prob).abs() < 0.01, "rate: {rate}, target: {prob}");
+        let one_rate = ones as f64 / n as f64;
+        assert!((one_rate - prob / 2.0).abs() < 0.01);
+    }
+
+    assert!(TernarySampler::with_probability(MINUS_ONE, 1.5).is_err());
+    assert!(TernarySampler::with_probability(MINUS_ONE, -0.1).is_err());
+}
+
+#[test]
+fn test_sample_fixed_weight() {
+    let mut rng = thread_rng();
+
+    for (n, weight) in [(1024, 0), (1024, 64), (1024, 1024), (500, 123)] {
+        let v = sample_fixed_weight(MINUS_ONE, n, weight, &mut rng);
+        assert_eq!(v.len(), n);
+        assert!(v.iter().all(|&x| x == 0 || x == 1 || x == MINUS_ONE));
+        assert_eq!(v.iter().filter(|&&x| x != 0).count(), weight);
+    }
+}
+
+#[test]
+fn test_discrete_gaussian_sampler() {
+    let mut rng = thread_rng();
+    let n = 100000;
+
+    for (std_dev, max_std_dev) in [(3.2, 19.2), (3.2, 5.0), (50.0, 300.0)] {
+        let sampler =
+            DiscreteGaussianSampler::new_with_max_limit(0.0, std_dev, max_std_dev).unwrap();
+
+        let samples: Vec<i64> = sampler.sample_iter(&mut rng).take(n).collect();
+        assert!(samples
+            .iter()
+            .all(|&v| (v as f64).abs() <= max_std_dev.round()));
+
+        if max_std_dev >= 6.0 * std_dev {
+            let mean = samples.iter().sum::<i64>() as f64 / n as f64;
+            let variance = samples
+                .iter()
+                .map(|&v| (v as f64 - mean).powi(2))
+                .sum::<f64>()
+                / n as f64;
+            // rounding adds `1/12` to the variance
+            let expect = (std_dev * std_dev + 1.0 / 12.0).sqrt();
+            assert!(mean.abs() < 0.05 * std_dev, "mean: {mean}");
+            assert!(
+                (variance.sqrt() - expect).abs() < 0.03 * expect,
+                "std dev: {}, target: {expect}",
+                variance.sqrt()
+            );
+        }
+    }
+
+    assert!(DiscreteGaussianSampler::new_with_max_limit(0.0, 3.2, 3.0).is_err());
+    assert!(DiscreteGaussianSampler::new(0.0, -1.0).is_err());
+
+    // the field sampler maps the integer samples into `[0, p)`.
+    let gaussian = DiscreteGaussian::new(0.0, 3.2, MINUS_ONE).unwrap();
+    assert!(gaussian
+        .sample_iter(&mut rng)
+        .take(n)
+        .all(|v: u32| v <= 20 || v >= MINUS_ONE - 19));
+}
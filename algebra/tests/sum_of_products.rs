@@ -0,0 +1,73 @@
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+};
+
+use algebra::{polynomial::FieldNttPolynomial, U32FieldEval};
+use rand::thread_rng;
+
+/// Counts the allocations made by the current thread.
+struct CountingAllocator;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.with(|count| count.set(count.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+fn allocations() -> usize {
+    ALLOCATIONS.with(Cell::get)
+}
+
+type FF = U32FieldEval<132120577>;
+type NttPolyFF = FieldNttPolynomial<FF>;
+
+const N: usize = 1024;
+const TERMS: usize = 4;
+
+#[test]
+fn test_sum_of_products() {
+    let mut rng = thread_rng();
+
+    let a: Vec<NttPolyFF> = (0..TERMS).map(|_| NttPolyFF::random(N, &mut rng)).collect();
+    let b: Vec<NttPolyFF> = (0..TERMS).map(|_| NttPolyFF::random(N, &mut rng)).collect();
+
+    let before = allocations();
+    let naive = a
+        .iter()
+        .zip(&b)
+        .map(|(x, y)| x * y)
+        .reduce(|acc, p| acc + &p)
+        .unwrap();
+    let naive_allocations = allocations() - before;
+
+    let mut looped = NttPolyFF::zero(N);
+    a.iter()
+        .zip(&b)
+        .for_each(|(x, y)| looped.add_mul_assign(x, y));
+
+    let mut out = NttPolyFF::random(N, &mut rng);
+    let before = allocations();
+    NttPolyFF::sum_of_products(a.iter().zip(&b), &mut out);
+    let fused_allocations = allocations() - before;
+
+    assert_eq!(out, naive);
+    assert_eq!(out, looped);
+    assert_eq!(fused_allocations, 0);
+    assert!(naive_allocations >= TERMS);
+
+    NttPolyFF::sum_of_products(std::iter::empty(), &mut out);
+    assert_eq!(out, NttPolyFF::zero(N));
+}
@@ -1,10 +1,18 @@
 use algebra::{
-    modulus::BarrettModulus,
-    ntt::{NttTable, NumberTheoryTransform, TableWithShoupRoot},
-    reduce::{ReduceAdd, ReduceAddAssign, ReduceMul, ReduceSubAssign},
+    modulus::{BarrettModulus, ShoupFactor},
+    ntt::{
+        FieldTableWithShoupRoot, NaiveNttTable, NttAlgorithm, NttBackend, NttKernel, NttTable,
+        NttWorkspace, NumberTheoryTransform, TableWithShoupRoot, DEFAULT_FOUR_STEP_LOG_N,
+    },
+    polynomial::{FieldNttPolynomial, FieldPolynomial, NttPolynomial, Polynomial},
+    reduce::{Reduce, ReduceMul},
+    Field, NttField, U32FieldEval, U64FieldEval,
 };
 use rand::{distributions::Uniform, prelude::Distribution, thread_rng, Rng};
 
+#[cfg(feature = "test-utils")]
+use algebra::{ntt::reference::negacyclic_convolution, reduce::ReduceAdd};
+
 type P = u64;
 const M: P = 132120577;
 const N: usize = 1024;
@@ -26,29 +34,7 @@ fn test_transform() {
     assert_eq!(a, b);
 }
 
-fn naive_mul(poly1: &[P], poly2: &[P], modulus: &BarrettModulus<P>) -> Vec<P> {
-    assert_eq!(poly1.len(), poly2.len());
-    let n = poly1.len();
-
-    let mut result = vec![0; n];
-
-    for i in 0..n {
-        for j in 0..=i {
-            modulus.reduce_add_assign(&mut result[i], modulus.reduce_mul(poly1[j], poly2[i - j]));
-        }
-    }
-
-    // mod (x^n + 1)
-    for i in n..n * 2 - 1 {
-        let k = i - n;
-        for j in i - n + 1..n {
-            modulus.reduce_sub_assign(&mut result[k], modulus.reduce_mul(poly1[j], poly2[i - j]));
-        }
-    }
-
-    result
-}
-
+#[cfg(feature = "test-utils")]
 #[test]
 fn test_cal() {
     let mut rng = thread_rng();
@@ -65,7 +51,7 @@ fn test_cal() {
         .zip(b.iter())
         .map(|(&x, &y)| modulus.reduce_add(x, y))
         .collect();
-    let mul = naive_mul(&a, &b, &modulus);
+    let mul = negacyclic_convolution(&a, &b, modulus);
 
     table.transform_slice(&mut a);
     table.transform_slice(&mut b);
@@ -107,3 +93,889 @@ fn test_transform_monomial() {
         assert_eq!(a, b);
     }
 }
+
+/// Checks the dispatched transforms, which use SIMD when it's available,
+/// against the scalar butterflies.
+fn check_simd_transform<F>()
+where
+    F: NttField<Modulus = BarrettModulus<<F as Field>::ValueT>>,
+{
+    let mut rng = thread_rng();
+    let p = F::MODULUS_VALUE;
+    let twice_p = p + p;
+    let four_p = twice_p + twice_p;
+
+    for log_n in 1..=11 {
+        let table = <FieldTableWithShoupRoot<F>>::new(F::MODULUS, log_n).unwrap();
+        let n = 1 << log_n;
+
+        let a: Vec<_> = Uniform::new(F::ZERO, four_p)
+            .sample_iter(&mut rng)
+            .take(n)
+            .collect();
+        let mut simd = a.clone();
+        let mut scalar = a;
+        table.lazy_transform_slice(&mut simd);
+        table.lazy_transform_slice_scalar(&mut scalar);
+        assert_eq!(simd, scalar);
+
+        let a: Vec<_> = Uniform::new(F::ZERO, twice_p)
+            .sample_iter(&mut rng)
+            .take(n)
+            .collect();
+        let mut simd = a.clone();
+        let mut scalar = a.clone();
+        table.lazy_inverse_transform_slice(&mut simd);
+        table.lazy_inverse_transform_slice_scalar(&mut scalar);
+        assert_eq!(simd, scalar);
+
+        let mut b = a.iter().map(|&v| F::MODULUS.reduce(v)).collect::<Vec<_>>();
+        let expected = b.clone();
+        table.transform_slice(&mut b);
+        table.inverse_transform_slice(&mut b);
+        assert_eq!(b, expected);
+    }
+}
+
+#[test]
+fn test_simd_transform() {
+    check_simd_transform::<U32FieldEval<132120577>>();
+    check_simd_transform::<U32FieldEval<1073479681>>();
+    check_simd_transform::<U64FieldEval<1125899906826241>>();
+}
+
+fn check_radix4_transform<F>(max_log_n: u32)
+where
+    F: NttField<Modulus = BarrettModulus<<F as Field>::ValueT>>,
+{
+    let mut rng = thread_rng();
+    let p = F::MODULUS_VALUE;
+    let twice_p = p + p;
+    let four_p = twice_p + twice_p;
+
+    for log_n in 1..=max_log_n {
+        let radix2 = <FieldTableWithShoupRoot<F>>::new(F::MODULUS, log_n).unwrap();
+        let radix4 =
+            <FieldTableWithShoupRoot<F>>::with_algorithm(F::MODULUS, log_n, NttAlgorithm::Radix4)
+                .unwrap();
+        assert_eq!(radix2.algorithm(), NttAlgorithm::Radix2);
+        assert_eq!(radix4.algorithm(), NttAlgorithm::Radix4);
+        let n = 1 << log_n;
+
+        let a: Vec<_> = Uniform::new(F::ZERO, four_p)
+            .sample_iter(&mut rng)
+            .take(n)
+            .collect();
+        let mut b = a.clone();
+        let mut c = a;
+        radix2.lazy_transform_slice(&mut b);
+        radix4.lazy_transform_slice(&mut c);
+        assert_eq!(b, c);
+
+        let a: Vec<_> = Uniform::new(F::ZERO, twice_p)
+            .sample_iter(&mut rng)
+            .take(n)
+            .collect();
+        let mut b = a.clone();
+        let mut c = a;
+        radix2.lazy_inverse_transform_slice(&mut b);
+        radix4.lazy_inverse_transform_slice(&mut c);
+        assert_eq!(b, c);
+
+        let a: Vec<_> = Uniform::new(F::ZERO, p)
+            .sample_iter(&mut rng)
+            .take(n)
+            .collect();
+        let mut b = a.clone();
+        radix4.transform_slice(&mut b);
+        radix4.inverse_transform_slice(&mut b);
+        assert_eq!(a, b);
+    }
+}
+
+#[test]
+fn test_radix4_transform() {
+    check_radix4_transform::<U32FieldEval<132120577>>(16);
+    check_radix4_transform::<U64FieldEval<1125899906826241>>(13);
+}
+
+fn check_forced_kernels<F>(max_log_n: u32)
+where
+    F: NttField<Modulus = BarrettModulus<<F as Field>::ValueT>>,
+{
+    let mut rng = thread_rng();
+    let p = F::MODULUS_VALUE;
+    let twice_p = p + p;
+    let four_p = twice_p + twice_p;
+
+    for log_n in 1..=max_log_n {
+        let mut scalar = <FieldTableWithShoupRoot<F>>::new(F::MODULUS, log_n).unwrap();
+        let mut best = scalar.clone();
+        scalar.force_kernel(NttKernel::Scalar);
+        best.force_kernel(NttKernel::best());
+        assert_eq!(scalar.kernel(), NttKernel::Scalar);
+        assert_eq!(best.kernel(), NttKernel::best());
+        let n = 1 << log_n;
+
+        let a: Vec<_> = Uniform::new(F::ZERO, four_p)
+            .sample_iter(&mut rng)
+            .take(n)
+            .collect();
+        let mut b = a.clone();
+        let mut c = a;
+        scalar.lazy_transform_slice(&mut b);
+        best.lazy_transform_slice(&mut c);
+        assert_eq!(b, c);
+
+        let a: Vec<_> = Uniform::new(F::ZERO, twice_p)
+            .sample_iter(&mut rng)
+            .take(n)
+            .collect();
+        let mut b = a.clone();
+        let mut c = a;
+        scalar.lazy_inverse_transform_slice(&mut b);
+        best.lazy_inverse_transform_slice(&mut c);
+        assert_eq!(b, c);
+    }
+}
+
+#[test]
+fn test_forced_kernels() {
+    assert!(NttKernel::Scalar.is_available());
+    assert!(NttKernel::best().is_available());
+    assert!(NttKernel::detect().is_available());
+    #[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64")))]
+    assert_eq!(NttKernel::best(), NttKernel::Scalar);
+
+    check_forced_kernels::<U32FieldEval<132120577>>(13);
+    check_forced_kernels::<U64FieldEval<1125899906826241>>(13);
+}
+
+#[test]
+#[should_panic(expected = "only the direct radix-2 transforms have a kernel")]
+fn test_forced_kernel_on_four_step() {
+    type F = U32FieldEval<132120577>;
+    let mut table =
+        <FieldTableWithShoupRoot<F>>::with_four_step_threshold(F::MODULUS, 4, 2).unwrap();
+    table.force_kernel(NttKernel::Scalar);
+}
+
+/// A forward transform whose butterflies reduce to `[0, modulus)` at every layer.
+fn reduced_transform<F: Field>(values: &mut [F::ValueT], roots: &[ShoupFactor<F::ValueT>]) {
+    let log_n = values.len().trailing_zeros();
+    let mut root_iter = roots[1..].iter();
+    for gap in (0..log_n).rev().map(|x| 1usize << x) {
+        for vc in values.chunks_exact_mut(gap << 1) {
+            let root = root_iter.next().unwrap().value();
+            let (v0, v1) = vc.split_at_mut(gap);
+            for (i, j) in v0.iter_mut().zip(v1) {
+                let v = F::mul(root, *j);
+                *j = F::sub(*i, v);
+                *i = F::add(*i, v);
+            }
+        }
+    }
+}
+
+/// An inverse transform whose butterflies reduce to `[0, modulus)` at every layer.
+fn reduced_inverse_transform<F: Field>(
+    values: &mut [F::ValueT],
+    inv_roots: &[ShoupFactor<F::ValueT>],
+    inv_n: F::ValueT,
+) {
+    let log_n = values.len().trailing_zeros();
+    let mut root_iter = inv_roots[1..].iter();
+    for gap in (0..log_n).map(|x| 1usize << x) {
+        for vc in values.chunks_exact_mut(gap << 1) {
+            let root = root_iter.next().unwrap().value();
+            let (v0, v1) = vc.split_at_mut(gap);
+            for (i, j) in v0.iter_mut().zip(v1) {
+                let u = *i;
+                *i = F::add(u, *j);
+                *j = F::mul(F::sub(u, *j), root);
+            }
+        }
+    }
+    values.iter_mut().for_each(|v| *v = F::mul(*v, inv_n));
+}
+
+/// Checks the lazy butterflies of every layout against fully reduced butterflies.
+fn check_lazy_butterflies<F>(max_log_n: u32)
+where
+    F: NttField<Modulus = BarrettModulus<<F as Field>::ValueT>>,
+{
+    let mut rng = thread_rng();
+
+    for log_n in 1..=max_log_n {
+        let n = 1 << log_n;
+        let tables = [
+            <FieldTableWithShoupRoot<F>>::with_four_step_threshold(F::MODULUS, log_n, u32::MAX)
+                .unwrap(),
+            <FieldTableWithShoupRoot<F>>::with_algorithm(F::MODULUS, log_n, NttAlgorithm::Radix4)
+                .unwrap(),
+            <FieldTableWithShoupRoot<F>>::with_four_step_threshold(F::MODULUS, log_n, 2).unwrap(),
+        ];
+
+        for table in &tables {
+            let a: Vec<_> = Uniform::new(F::ZERO, F::MODULUS_VALUE)
+                .sample_iter(&mut rng)
+                .take(n)
+                .collect();
+
+            let mut expected = a.clone();
+            reduced_transform::<F>(&mut expected, table.root_powers());
+            let mut b = a.clone();
+            table.transform_slice(&mut b);
+            assert_eq!(b, expected);
+
+            reduced_inverse_transform::<F>(
+                &mut expected,
+                table.inv_root_powers(),
+                table.inv_n().value(),
+            );
+            assert_eq!(expected, a);
+            table.inverse_transform_slice(&mut b);
+            assert_eq!(b, a);
+        }
+    }
+}
+
+#[test]
+fn test_lazy_butterflies() {
+    let max_log_n = if cfg!(miri) { 5 } else { 11 };
+    check_lazy_butterflies::<U32FieldEval<132120577>>(max_log_n);
+    check_lazy_butterflies::<U32FieldEval<1073479681>>(max_log_n);
+    check_lazy_butterflies::<U64FieldEval<1125899906826241>>(max_log_n);
+
+    let modulus = <BarrettModulus<P>>::new(M);
+    for log_n in 1..=max_log_n {
+        let table = <TableWithShoupRoot<P>>::new(modulus, log_n).unwrap();
+        let a: Vec<P> = Uniform::new(0, M)
+            .sample_iter(thread_rng())
+            .take(1 << log_n)
+            .collect();
+
+        let mut expected = a.clone();
+        reduced_transform::<U64FieldEval<M>>(&mut expected, table.root_powers());
+        let mut b = a.clone();
+        table.transform_slice(&mut b);
+        assert_eq!(b, expected);
+
+        table.inverse_transform_slice(&mut b);
+        assert_eq!(b, a);
+    }
+}
+
+/// Checks the four-step transforms against the direct butterflies.
+fn check_four_step_transform<F>(log_n: u32)
+where
+    F: NttField<Modulus = BarrettModulus<<F as Field>::ValueT>>,
+{
+    let mut rng = thread_rng();
+    let p = F::MODULUS_VALUE;
+    let twice_p = p + p;
+    let four_p = twice_p + twice_p;
+
+    let direct =
+        <FieldTableWithShoupRoot<F>>::with_four_step_threshold(F::MODULUS, log_n, u32::MAX)
+            .unwrap();
+    let four_step =
+        <FieldTableWithShoupRoot<F>>::with_four_step_threshold(F::MODULUS, log_n, 2).unwrap();
+    assert!(!direct.is_four_step());
+    assert!(four_step.is_four_step());
+    let n = 1 << log_n;
+
+    let a: Vec<_> = Uniform::new(F::ZERO, four_p)
+        .sample_iter(&mut rng)
+        .take(n)
+        .collect();
+    let mut b = a.clone();
+    let mut c = a;
+    direct.lazy_transform_slice(&mut b);
+    four_step.lazy_transform_slice(&mut c);
+    assert_eq!(b, c);
+
+    let a: Vec<_> = Uniform::new(F::ZERO, twice_p)
+        .sample_iter(&mut rng)
+        .take(n)
+        .collect();
+    let mut b = a.clone();
+    let mut c = a;
+    direct.lazy_inverse_transform_slice(&mut b);
+    four_step.lazy_inverse_transform_slice(&mut c);
+    assert_eq!(b, c);
+
+    let a: Vec<_> = Uniform::new(F::ZERO, p)
+        .sample_iter(&mut rng)
+        .take(n)
+        .collect();
+    let mut b = a.clone();
+    four_step.transform_slice(&mut b);
+    four_step.inverse_transform_slice(&mut b);
+    assert_eq!(a, b);
+}
+
+#[test]
+fn test_four_step_transform() {
+    for log_n in 2..=11 {
+        check_four_step_transform::<U32FieldEval<132120577>>(log_n);
+        check_four_step_transform::<U64FieldEval<1125899906826241>>(log_n);
+    }
+
+    let small = <FieldTableWithShoupRoot<U32FieldEval<132120577>>>::new(
+        <U32FieldEval<132120577>>::MODULUS,
+        DEFAULT_FOUR_STEP_LOG_N - 1,
+    )
+    .unwrap();
+    assert!(!small.is_four_step());
+}
+
+#[cfg(feature = "large-ntt-tests")]
+#[test]
+fn test_large_four_step_transform() {
+    for log_n in [16, 17] {
+        check_four_step_transform::<U32FieldEval<132120577>>(log_n);
+        check_four_step_transform::<U64FieldEval<1125899906826241>>(log_n);
+
+        let table = <U32FieldEval<132120577>>::generate_ntt_table(log_n).unwrap();
+        assert_eq!(table.dimension(), 1 << log_n);
+
+        let default = <FieldTableWithShoupRoot<U32FieldEval<132120577>>>::new(
+            <U32FieldEval<132120577>>::MODULUS,
+            log_n,
+        )
+        .unwrap();
+        assert!(default.is_four_step());
+    }
+}
+
+#[test]
+fn test_transform_batch() {
+    type F = U32FieldEval<132120577>;
+
+    let mut rng = thread_rng();
+    let log_n = 10;
+    let n = 1 << log_n;
+
+    let table = F::generate_ntt_table(log_n).unwrap();
+    let polys: Vec<_> = (0..8)
+        .map(|_| FieldPolynomial::<F>::random(n, &mut rng))
+        .collect();
+
+    let ntt_polys = table.transform_batch(polys.clone());
+    assert_eq!(ntt_polys.len(), polys.len());
+    for (poly, ntt_poly) in polys.iter().zip(&ntt_polys) {
+        assert_eq!(&table.transform(poly), ntt_poly);
+    }
+    assert_eq!(table.inverse_transform_batch(ntt_polys), polys);
+
+    let modulus = <BarrettModulus<P>>::new(M);
+    let table = <TableWithShoupRoot<P>>::new(modulus, log_n).unwrap();
+    let polys: Vec<_> = (0..8)
+        .map(|_| Polynomial::<P>::random(M - 1, n, &mut rng))
+        .collect();
+
+    let ntt_polys = table.transform_batch(polys.clone());
+    for (poly, ntt_poly) in polys.iter().zip(&ntt_polys) {
+        assert_eq!(&table.transform(poly), ntt_poly);
+    }
+    assert_eq!(table.inverse_transform_batch(ntt_polys), polys);
+}
+
+#[test]
+fn test_transform_matrix() {
+    type F = U32FieldEval<132120577>;
+
+    let mut rng = thread_rng();
+    let log_n = 10;
+    let n = 1 << log_n;
+    let rows = 7;
+
+    let table = F::generate_ntt_table(log_n).unwrap();
+    let matrix: Vec<_> = Uniform::new(0, 132120577u32)
+        .sample_iter(&mut rng)
+        .take(rows * n)
+        .collect();
+
+    let mut values = matrix.clone();
+    table.transform_matrix(&mut values, rows, n);
+    for (row, ntt_row) in matrix.chunks_exact(n).zip(values.chunks_exact(n)) {
+        let mut expected = row.to_vec();
+        table.transform_slice(&mut expected);
+        assert_eq!(ntt_row, expected);
+    }
+
+    table.inverse_transform_matrix(&mut values, rows, n);
+    assert_eq!(values, matrix);
+
+    let mut empty: Vec<u32> = Vec::new();
+    table.transform_matrix(&mut empty, 0, n);
+    assert!(empty.is_empty());
+}
+
+/// Checks the fused transform and multiplication of `table` against
+/// the transform followed by the multiplication.
+fn check_transform_and_pointwise_mul<F, T>(table: &T, n: usize)
+where
+    F: NttField<Modulus = BarrettModulus<<F as Field>::ValueT>>,
+    T: NttBackend<F> + ?Sized,
+{
+    let mut rng = thread_rng();
+    let p = F::MODULUS_VALUE;
+    let four_p = p + p + p + p;
+
+    let src: Vec<_> = Uniform::new(F::ZERO, four_p)
+        .sample_iter(&mut rng)
+        .take(n)
+        .collect();
+    let key_row: Vec<_> = Uniform::new(F::ZERO, p)
+        .sample_iter(&mut rng)
+        .take(n)
+        .collect();
+    let acc: Vec<_> = Uniform::new(F::ZERO, p)
+        .sample_iter(&mut rng)
+        .take(n)
+        .collect();
+
+    let mut expected_src = src.clone();
+    table.forward(&mut expected_src);
+    let expected_acc: Vec<_> = acc
+        .iter()
+        .zip(&expected_src)
+        .zip(&key_row)
+        .map(|((&z, &x), &y)| F::mul_add(x, y, z))
+        .collect();
+
+    let mut workspace = NttWorkspace::new();
+    let mut fused_with_src = src.clone();
+    let mut fused_with_acc = acc.clone();
+    table.transform_and_pointwise_mul_with(
+        &mut fused_with_src,
+        &key_row,
+        &mut fused_with_acc,
+        &mut workspace,
+    );
+
+    let mut fused_src = src;
+    let mut fused_acc = acc;
+    table.transform_and_pointwise_mul(&mut fused_src, &key_row, &mut fused_acc);
+    assert_eq!(fused_src, expected_src);
+    assert_eq!(fused_with_src, expected_src);
+    assert_eq!(fused_with_acc, fused_acc);
+
+    let fused_acc: Vec<_> = fused_acc
+        .into_iter()
+        .map(|z| F::MODULUS.reduce(z))
+        .collect();
+    assert_eq!(fused_acc, expected_acc);
+}
+
+#[test]
+fn test_transform_and_pointwise_mul() {
+    type F = U32FieldEval<132120577>;
+    type G = U64FieldEval<1125899906826241>;
+
+    for log_n in 1..=10 {
+        let n = 1 << log_n;
+
+        let mut scalar = <FieldTableWithShoupRoot<F>>::new(F::MODULUS, log_n).unwrap();
+        scalar.force_kernel(NttKernel::Scalar);
+        check_transform_and_pointwise_mul::<F, _>(&scalar, n);
+
+        let mut best = scalar.clone();
+        best.force_kernel(NttKernel::best());
+        check_transform_and_pointwise_mul::<F, _>(&best, n);
+
+        let radix4 =
+            <FieldTableWithShoupRoot<F>>::with_algorithm(F::MODULUS, log_n, NttAlgorithm::Radix4)
+                .unwrap();
+        check_transform_and_pointwise_mul::<F, _>(&radix4, n);
+
+        let four_step =
+            <FieldTableWithShoupRoot<F>>::with_four_step_threshold(F::MODULUS, log_n, 2).unwrap();
+        check_transform_and_pointwise_mul::<F, _>(&four_step, n);
+
+        let naive = <NaiveNttTable<F>>::new(F::MODULUS, log_n).unwrap();
+        check_transform_and_pointwise_mul::<F, _>(&naive, n);
+
+        let mut wide = <FieldTableWithShoupRoot<G>>::new(G::MODULUS, log_n).unwrap();
+        wide.force_kernel(NttKernel::Scalar);
+        check_transform_and_pointwise_mul::<G, _>(&wide, n);
+    }
+
+    // The default tables of the fields.
+    for log_n in 5..=10 {
+        let n = 1 << log_n;
+        check_transform_and_pointwise_mul::<F, _>(&F::generate_ntt_table(log_n).unwrap(), n);
+        check_transform_and_pointwise_mul::<G, _>(&G::generate_ntt_table(log_n).unwrap(), n);
+    }
+}
+
+/// Checks the scaled inverse transforms of `table` against the inverse transform
+/// followed by a multiplication.
+fn check_inverse_transform_scaled<F, T>(table: &T, n: usize)
+where
+    F: NttField,
+    T: NumberTheoryTransform<
+        ValueT = <F as Field>::ValueT,
+        CoeffPoly = FieldPolynomial<F>,
+        NttPoly = FieldNttPolynomial<F>,
+    >,
+{
+    let mut rng = thread_rng();
+    let p = F::MODULUS_VALUE;
+
+    let extras = [
+        F::ZERO,
+        F::ONE,
+        F::MINUS_ONE,
+        Uniform::new(F::ZERO, p).sample(&mut rng),
+    ];
+    for extra in extras {
+        let values = FieldNttPolynomial::<F>::random(n, &mut rng);
+
+        let mut expected = table.inverse_transform(&values);
+        expected.mul_scalar_assign(extra);
+
+        let mut scaled = values.clone().inner_data();
+        table.inverse_transform_scaled_slice(&mut scaled, extra);
+        assert_eq!(scaled, expected.as_slice(), "extra {extra:?}");
+
+        let mut scaled = values.clone().inner_data();
+        table.inverse_transform_scaled_slice_with(&mut scaled, extra, &mut NttWorkspace::new());
+        assert_eq!(scaled, expected.as_slice(), "extra {extra:?}");
+
+        assert_eq!(table.inverse_transform_scaled(values, extra), expected);
+    }
+}
+
+fn check_truncated_transforms<F>(table: &FieldTableWithShoupRoot<F>, n: usize)
+where
+    F: NttField<Modulus = BarrettModulus<<F as Field>::ValueT>>,
+{
+    let mut rng = thread_rng();
+    let half = n >> 1;
+
+    let mut poly = FieldPolynomial::<F>::random(n, &mut rng);
+    poly[half..].fill(F::ZERO);
+    let expected = table.transform(&poly);
+    // The upper half isn't read.
+    let mut values = poly.clone().inner_data();
+    values[half..].fill(F::ONE);
+    table.transform_truncated_slice(&mut values);
+    assert_eq!(values, expected.as_slice());
+
+    let ntt_poly = FieldNttPolynomial::<F>::random(n, &mut rng);
+    let expected = table.inverse_transform(&ntt_poly);
+    let mut values = ntt_poly.inner_data();
+    table.inverse_transform_truncated_slice(&mut values);
+    assert_eq!(values[..half], expected[..half]);
+    assert!(values[half..].iter().all(|&v| v == F::ZERO));
+
+    let a = FieldPolynomial::<F>::random(n, &mut rng);
+    let b = FieldPolynomial::<F>::random(n, &mut rng);
+    let expected = a.mul_naive(&b);
+    let low = a.mul_low_half(&b, table);
+    assert_eq!(low.as_slice(), &expected[..half]);
+
+    let expected = poly.mul_naive(&b);
+    assert_eq!(poly.mul_low_half(&b, table).as_slice(), &expected[..half]);
+    let expected = poly.mul_naive(&poly);
+    assert_eq!(
+        poly.mul_low_half(&poly, table).as_slice(),
+        &expected[..half]
+    );
+}
+
+#[test]
+fn test_truncated_transforms() {
+    fn check<F: NttField<Modulus = BarrettModulus<<F as Field>::ValueT>>>() {
+        for log_n in [1, 2, 3, 5, 10] {
+            let n = 1 << log_n;
+            for algorithm in [NttAlgorithm::Radix2, NttAlgorithm::Radix4] {
+                let table =
+                    <FieldTableWithShoupRoot<F>>::with_algorithm(F::MODULUS, log_n, algorithm)
+                        .unwrap();
+                check_truncated_transforms::<F>(&table, n);
+            }
+            let four_step =
+                <FieldTableWithShoupRoot<F>>::with_four_step_threshold(F::MODULUS, log_n, 2)
+                    .unwrap();
+            check_truncated_transforms::<F>(&four_step, n);
+        }
+    }
+    check::<U32FieldEval<132120577>>();
+    check::<U64FieldEval<1125899906826241>>();
+}
+
+#[test]
+fn test_inverse_transform_scaled() {
+    fn check<F: NttField<Modulus = BarrettModulus<<F as Field>::ValueT>>>() {
+        for log_n in [1, 2, 5, 10] {
+            let n = 1 << log_n;
+            // The concrete tables don't support the smallest dimensions.
+            if log_n >= 5 {
+                let table = F::generate_ntt_table(log_n).unwrap();
+                check_inverse_transform_scaled::<F, _>(&table, n);
+            }
+            for algorithm in [NttAlgorithm::Radix2, NttAlgorithm::Radix4] {
+                let table =
+                    <FieldTableWithShoupRoot<F>>::with_algorithm(F::MODULUS, log_n, algorithm)
+                        .unwrap();
+                check_inverse_transform_scaled::<F, _>(&table, n);
+            }
+            let four_step =
+                <FieldTableWithShoupRoot<F>>::with_four_step_threshold(F::MODULUS, log_n, 2)
+                    .unwrap();
+            check_inverse_transform_scaled::<F, _>(&four_step, n);
+            let naive = <NaiveNttTable<F>>::new(F::MODULUS, log_n).unwrap();
+            check_inverse_transform_scaled::<F, _>(&naive, n);
+        }
+    }
+    check::<U32FieldEval<132120577>>();
+    check::<U64FieldEval<1125899906826241>>();
+
+    let modulus = <BarrettModulus<P>>::new(M);
+    let table = <TableWithShoupRoot<P>>::new(modulus, N.trailing_zeros()).unwrap();
+    for extra in [0, 1, M - 1, thread_rng().gen_range(0..M)] {
+        let values = NttPolynomial::<P>::random(M - 1, N, &mut thread_rng());
+        let expected: Vec<P> = table
+            .inverse_transform(&values)
+            .iter()
+            .map(|&v| modulus.reduce_mul(v, extra))
+            .collect();
+        assert_eq!(
+            table.inverse_transform_scaled(values, extra).as_slice(),
+            expected
+        );
+    }
+}
+
+/// Checks the field table against the naive table, both behind [`NttBackend`].
+fn check_naive_backend<F>(max_log_n: u32)
+where
+    F: NttField<Modulus = BarrettModulus<<F as Field>::ValueT>> + 'static,
+{
+    let mut rng = thread_rng();
+    let p = F::MODULUS_VALUE;
+    let twice_p = p + p;
+    let four_p = twice_p + twice_p;
+
+    for log_n in 1..=max_log_n {
+        let n = 1 << log_n;
+        let field_table = <FieldTableWithShoupRoot<F>>::new(F::MODULUS, log_n).unwrap();
+        let naive_table = <NaiveNttTable<F>>::new(F::MODULUS, log_n).unwrap();
+        assert_eq!(naive_table.root(), field_table.root());
+
+        let backends: [Box<dyn NttBackend<F>>; 2] = [Box::new(field_table), Box::new(naive_table)];
+        assert!(backends.iter().all(|backend| backend.degree() == n));
+
+        let a: Vec<_> = Uniform::new(F::ZERO, four_p)
+            .sample_iter(&mut rng)
+            .take(n)
+            .collect();
+        let [fast, naive] = backends.each_ref().map(|backend| {
+            let mut b = a.clone();
+            backend.forward(&mut b);
+            b
+        });
+        assert_eq!(fast, naive);
+
+        let mut lazy = a.clone();
+        backends[0].forward_lazy(&mut lazy);
+        assert!(lazy.iter().all(|&v| v < four_p));
+        assert!(lazy.iter().zip(&naive).all(|(&v, &w)| v % p == w));
+
+        let [fast, naive] = backends.each_ref().map(|backend| {
+            let mut b = naive.clone();
+            backend.inverse(&mut b);
+            b
+        });
+        assert_eq!(fast, naive);
+        assert!(a.iter().zip(&naive).all(|(&v, &w)| v % p == w));
+    }
+}
+
+#[test]
+fn test_naive_backend() {
+    check_naive_backend::<U32FieldEval<132120577>>(8);
+    check_naive_backend::<U64FieldEval<1125899906826241>>(8);
+
+    type F = U32FieldEval<132120577>;
+    let log_n = 5;
+    let n = 1 << log_n;
+    let fast = <FieldTableWithShoupRoot<F>>::new(F::MODULUS, log_n).unwrap();
+    let naive = <NaiveNttTable<F>>::new(F::MODULUS, log_n).unwrap();
+    let coeff = thread_rng().gen_range(0..F::MODULUS_VALUE);
+    for degree in 0..2 * n {
+        let mut a = vec![0; n];
+        let mut b = vec![0; n];
+        fast.transform_monomial(coeff, degree, &mut a);
+        naive.transform_monomial(coeff, degree, &mut b);
+        assert_eq!(a, b);
+    }
+}
+
+#[cfg(feature = "dyn-ntt")]
+#[test]
+fn test_dyn_ntt_table() {
+    type F = U32FieldEval<132120577>;
+
+    let log_n = 10;
+    let table: std::sync::Arc<dyn NttBackend<F>> = F::get_ntt_table(log_n).unwrap();
+    assert_eq!(table.degree(), 1 << log_n);
+
+    let poly = FieldPolynomial::<F>::random(1 << log_n, &mut thread_rng());
+    let mut values = poly.clone().inner_data();
+    table.forward(&mut values);
+    assert_eq!(
+        values,
+        F::generate_ntt_table(log_n)
+            .unwrap()
+            .transform(&poly)
+            .inner_data()
+    );
+}
+
+#[test]
+fn test_table_accessors() {
+    type F = U32FieldEval<132120577>;
+
+    for log_n in [2, 5, 10] {
+        let n = 1usize << log_n;
+        let n_value = n as u32;
+
+        let table = <FieldTableWithShoupRoot<F>>::new(F::MODULUS, log_n).unwrap();
+        assert_eq!(table.log_n(), log_n);
+        assert_eq!(table.n(), n);
+        assert_eq!(table.dimension(), n);
+        assert_eq!(F::mul(table.inv_degree(), n_value), 1);
+        assert_eq!(table.inv_n().value(), table.inv_degree());
+
+        // `root` is a primitive `2n`-th root of unity.
+        assert_eq!(F::exp(table.root(), n_value), F::MINUS_ONE);
+        assert_eq!(F::mul(table.root(), table.inv_root()), 1);
+
+        // `root_powers()[1]` is `root^(n/2)`, a primitive 4-th root of unity.
+        let w = table.root_powers()[1].value();
+        assert_eq!(w, F::exp(table.root(), n_value / 2));
+        assert_eq!(F::exp(w, n_value), 1);
+
+        let naive = <NaiveNttTable<F>>::new(F::MODULUS, log_n).unwrap();
+        assert_eq!(naive.n(), n);
+        assert_eq!(naive.inv_degree(), table.inv_degree());
+        assert_eq!(naive.reverse_lsbs(), table.reverse_lsbs());
+        assert!(naive
+            .ordinal_root_powers()
+            .iter()
+            .zip(table.ordinal_root_powers())
+            .all(|(&a, b)| a == b.value()));
+
+        let modulus = <BarrettModulus<P>>::new(M);
+        let table = <TableWithShoupRoot<P>>::new(modulus, log_n).unwrap();
+        assert_eq!(modulus.reduce_mul(table.inv_degree(), n as P), 1);
+        assert_eq!(modulus.reduce_mul(table.root(), table.inv_root()), 1);
+    }
+}
+
+/// Checks the products computed with `table` against the schoolbook multiplication.
+#[cfg(feature = "test-utils")]
+fn check_against_reference<F, T>(table: &T, n: usize)
+where
+    F: NttField,
+    T: NumberTheoryTransform<
+        ValueT = <F as Field>::ValueT,
+        CoeffPoly = FieldPolynomial<F>,
+        NttPoly = FieldNttPolynomial<F>,
+    >,
+{
+    let mut rng = thread_rng();
+
+    let a = FieldPolynomial::<F>::random(n, &mut rng);
+    let b = FieldPolynomial::<F>::random(n, &mut rng);
+    let expected = negacyclic_convolution(a.as_slice(), b.as_slice(), F::MODULUS);
+
+    let mut product = table.transform(&a);
+    product *= &table.transform(&b);
+    assert_eq!(
+        table.inverse_transform_inplace(product).as_slice(),
+        expected
+    );
+}
+
+#[cfg(feature = "test-utils")]
+#[test]
+fn test_transforms_against_reference() {
+    fn check<F: NttField<Modulus = BarrettModulus<<F as Field>::ValueT>>>() {
+        for log_n in 1..=9 {
+            let n = 1 << log_n;
+            // The concrete tables don't support the smallest dimensions.
+            if log_n >= 5 {
+                check_against_reference::<F, _>(&F::generate_ntt_table(log_n).unwrap(), n);
+            }
+            for algorithm in [NttAlgorithm::Radix2, NttAlgorithm::Radix4] {
+                let table =
+                    <FieldTableWithShoupRoot<F>>::with_algorithm(F::MODULUS, log_n, algorithm)
+                        .unwrap();
+                check_against_reference::<F, _>(&table, n);
+            }
+            if log_n >= 2 {
+                let four_step =
+                    <FieldTableWithShoupRoot<F>>::with_four_step_threshold(F::MODULUS, log_n, 2)
+                        .unwrap();
+                assert!(four_step.is_four_step());
+                check_against_reference::<F, _>(&four_step, n);
+            }
+            let naive = <NaiveNttTable<F>>::new(F::MODULUS, log_n).unwrap();
+            check_against_reference::<F, _>(&naive, n);
+        }
+    }
+    check::<U32FieldEval<132120577>>();
+    check::<U32FieldEval<1073479681>>();
+    check::<U64FieldEval<1125899906826241>>();
+}
+
+#[test]
+fn test_transform_ordered() {
+    use algebra::{ntt::NttOrdering, utils::bit_reverse_permute};
+
+    type F = U32FieldEval<132120577>;
+
+    let mut rng = thread_rng();
+    for log_n in [1, 5, 10] {
+        let n = 1 << log_n;
+        let tables = [
+            <FieldTableWithShoupRoot<F>>::new(F::MODULUS, log_n).unwrap(),
+            <FieldTableWithShoupRoot<F>>::with_algorithm(F::MODULUS, log_n, NttAlgorithm::Radix4)
+                .unwrap(),
+        ];
+        for table in &tables {
+            let coeff_poly = FieldPolynomial::<F>::random(n, &mut rng);
+            let poly = coeff_poly.clone().inner_data();
+            let mut bitrev_poly = poly.clone();
+            bit_reverse_permute(&mut bitrev_poly, log_n);
+
+            let mut bitrev_values = poly.clone();
+            table.transform_ordered(&mut bitrev_values, NttOrdering::NaturalToBitrev);
+            assert_eq!(bitrev_values, table.transform(&coeff_poly).inner_data());
+
+            let mut natural_values = bitrev_values.clone();
+            bit_reverse_permute(&mut natural_values, log_n);
+
+            let mut values = poly.clone();
+            table.transform_ordered(&mut values, NttOrdering::NaturalToNatural);
+            assert_eq!(values, natural_values);
+            table.inverse_transform_ordered(&mut values, NttOrdering::NaturalToNatural);
+            assert_eq!(values, poly);
+
+            let mut values = bitrev_poly.clone();
+            table.transform_ordered(&mut values, NttOrdering::BitrevToNatural);
+            assert_eq!(values, natural_values);
+            table.inverse_transform_ordered(&mut values, NttOrdering::BitrevToNatural);
+            assert_eq!(values, bitrev_poly);
+
+            table.inverse_transform_ordered(&mut bitrev_values, NttOrdering::NaturalToBitrev);
+            assert_eq!(bitrev_values, poly);
+        }
+    }
+}
@@ -0,0 +1,18 @@
+use algebra::utils::{bit_reverse_permute, ReverseLsbs};
+
+#[test]
+fn test_bit_reverse_permute() {
+    for log_n in 0..=10u32 {
+        let n = 1usize << log_n;
+        let origin: Vec<usize> = (0..n).collect();
+
+        let mut values = origin.clone();
+        bit_reverse_permute(&mut values, log_n);
+
+        let expect: Vec<usize> = (0..n).map(|i| i.reverse_lsbs(log_n)).collect();
+        assert_eq!(values, expect);
+
+        bit_reverse_permute(&mut values, log_n);
+        assert_eq!(values, origin);
+    }
+}
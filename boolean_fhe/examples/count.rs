@@ -0,0 +1,31 @@
+//! Prints the transforms and multiplications spent by each bootstrapping phase.
+//!
+//! Run with `cargo run --release --example count --features count`.
+
+use boolean_fhe::{Decryptor, Encryptor, Evaluator, KeyGen, DEFAULT_128_BITS_PARAMETERS};
+use fhe_core::count;
+
+fn main() {
+    let mut rng = rand::thread_rng();
+
+    let params = *DEFAULT_128_BITS_PARAMETERS;
+
+    let sk = KeyGen::generate_secret_key(params, &mut rng);
+    let enc = Encryptor::new(&sk);
+    let dec = Decryptor::new(&sk);
+    let eval = Evaluator::new(&sk, &mut rng);
+
+    let a = enc.encrypt(true, &mut rng);
+    let b = enc.encrypt(false, &mut rng);
+    let c = enc.encrypt(true, &mut rng);
+
+    count::clear_report();
+    let nand = eval.nand(&a, &b);
+    assert_eq!(dec.decrypt::<u8>(&nand), 1);
+    println!("nand:\n{}", count::report());
+
+    count::clear_report();
+    let mux = eval.mux(&a, &b, &c);
+    assert_eq!(dec.decrypt::<u8>(&mux), 0);
+    println!("mux:\n{}", count::report());
+}
@@ -1,7 +1,7 @@
 use algebra::{integer::UnsignedInteger, reduce::RingReduce, NttField};
 use fhe_core::{LweCiphertext, LweParameters, LweSecretKey};
 
-use crate::SecretKeyPack;
+use crate::{EncryptedInt, SecretKeyPack};
 
 /// Encryptor
 pub struct Decryptor<C: UnsignedInteger, LweModulus: RingReduce<C>> {
@@ -28,6 +28,30 @@ impl<C: UnsignedInteger, LweModulus: RingReduce<C>> Decryptor<C, LweModulus> {
         self.lwe_secret_key.decrypt(cipher_text, &self.params)
     }
 
+    /// Decrypt a batch of ciphertexts into messages.
+    ///
+    /// With the `parallel` feature the decryptions are spread over the rayon thread pool.
+    #[cfg(feature = "parallel")]
+    pub fn decrypt_batch<M>(&self, cipher_texts: &[LweCiphertext<C>]) -> Vec<M>
+    where
+        M: TryFrom<C> + Send,
+    {
+        use rayon::prelude::*;
+
+        cipher_texts.par_iter().map(|c| self.decrypt(c)).collect()
+    }
+
+    /// Decrypt a batch of ciphertexts into messages.
+    ///
+    /// With the `parallel` feature the decryptions are spread over the rayon thread pool.
+    #[cfg(not(feature = "parallel"))]
+    pub fn decrypt_batch<M>(&self, cipher_texts: &[LweCiphertext<C>]) -> Vec<M>
+    where
+        M: TryFrom<C>,
+    {
+        cipher_texts.iter().map(|c| self.decrypt(c)).collect()
+    }
+
     /// Decrypt a ciphertext into a bool message and an error.
     #[inline]
     pub fn decrypt_with_noise<M>(&self, cipher_text: &LweCiphertext<C>) -> (M, C)
@@ -37,4 +61,34 @@ impl<C: UnsignedInteger, LweModulus: RingReduce<C>> Decryptor<C, LweModulus> {
         self.lwe_secret_key
             .decrypt_with_noise(cipher_text, &self.params)
     }
+
+    /// Decrypt an [`EncryptedInt<C>`] into its value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the width of `cipher_text` is larger than 64.
+    pub fn decrypt_int(&self, cipher_text: &EncryptedInt<C>) -> u64 {
+        assert!(cipher_text.width() <= 64, "Width should be at most 64!");
+        cipher_text
+            .bits()
+            .iter()
+            .enumerate()
+            .fold(0, |acc, (i, c)| {
+                let bit: C = self.decrypt(c);
+                acc | (((bit == C::ONE) as u64) << i)
+            })
+    }
+
+    /// Decrypt an [`EncryptedInt<C>`] into a signed value,
+    /// the top bit is the sign of the two's complement.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the width of `cipher_text` is larger than 64.
+    pub fn decrypt_sint(&self, cipher_text: &EncryptedInt<C>) -> i64 {
+        let value = self.decrypt_int(cipher_text);
+        // The width is in `1..=64`, so the shift is in `0..64`.
+        let shift = 64 - cipher_text.width() as u32;
+        ((value << shift) as i64) >> shift
+    }
 }
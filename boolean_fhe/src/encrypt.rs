@@ -1,7 +1,7 @@
 use algebra::{integer::UnsignedInteger, reduce::RingReduce, NttField};
 use fhe_core::{LweCiphertext, LweParameters, LweSecretKey};
 
-use crate::SecretKeyPack;
+use crate::{EncryptedInt, SecretKeyPack};
 
 /// Encryptor
 pub struct Encryptor<C: UnsignedInteger, LweModulus: RingReduce<C>> {
@@ -28,4 +28,63 @@ impl<C: UnsignedInteger, LweModulus: RingReduce<C>> Encryptor<C, LweModulus> {
     {
         self.lwe_secret_key.encrypt(message, &self.params, rng)
     }
+
+    /// Encrypt a slice of bool messages.
+    #[inline]
+    pub fn encrypt_slice<R>(&self, bits: &[bool], rng: &mut R) -> Vec<LweCiphertext<C>>
+    where
+        R: rand::Rng + rand::CryptoRng,
+    {
+        self.encrypt_stream(bits.iter().copied(), rng).collect()
+    }
+
+    /// Lazily encrypt a stream of bool messages.
+    ///
+    /// Each ciphertext is produced only when the returned iterator is advanced,
+    /// so large inputs can be piped to disk or network without
+    /// holding all ciphertexts in memory.
+    #[inline]
+    pub fn encrypt_stream<'a, I, R>(
+        &'a self,
+        bits: I,
+        rng: &'a mut R,
+    ) -> impl Iterator<Item = LweCiphertext<C>> + 'a
+    where
+        I: IntoIterator<Item = bool>,
+        I::IntoIter: 'a,
+        R: rand::Rng + rand::CryptoRng,
+    {
+        bits.into_iter()
+            .map(move |bit| self.encrypt(C::as_from(bit as u8), rng))
+    }
+
+    /// Encrypt `value mod 2^width` as an [`EncryptedInt<C>`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `width` is not in `1..=64`.
+    pub fn encrypt_int<R>(&self, value: u64, width: usize, rng: &mut R) -> EncryptedInt<C>
+    where
+        R: rand::Rng + rand::CryptoRng,
+    {
+        assert!((1..=64).contains(&width), "Width should be in 1..=64!");
+        let bits = (0..width).map(|i| (value >> i) & 1 == 1);
+        EncryptedInt::new(self.encrypt_stream(bits, rng).collect())
+    }
+
+    /// Encrypt the signed `value` as a two's complement [`EncryptedInt<C>`] of `width` bits.
+    ///
+    /// `value` is taken modulo `2^width`, it should be in `[-2^(width-1), 2^(width-1))`
+    /// to be decrypted back by [`Decryptor::decrypt_sint`](crate::Decryptor::decrypt_sint).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `width` is not in `1..=64`.
+    #[inline]
+    pub fn encrypt_sint<R>(&self, value: i64, width: usize, rng: &mut R) -> EncryptedInt<C>
+    where
+        R: rand::Rng + rand::CryptoRng,
+    {
+        self.encrypt_int(value as u64, width, rng)
+    }
 }
@@ -4,16 +4,30 @@ use algebra::{
     integer::UnsignedInteger,
     polynomial::FieldPolynomial,
     reduce::{ModulusValue, ReduceAddAssign, RingReduce},
-    Field, NttField,
+    Field, NttField, ScalarFactor,
 };
 use fhe_core::{
-    lwe_modulus_switch, lwe_modulus_switch_assign, lwe_modulus_switch_inplace, BlindRotationKey,
-    LweCiphertext, LweKeySwitchingKeyRlweMode, LweSecretKey, LweSecretKeyType,
+    encode, lwe_modulus_switch, lwe_modulus_switch_assign, lwe_modulus_switch_inplace,
+    BlindRotationKey, LweCiphertext, LweKeySwitchingKeyRlweMode, LweSecretKey, LweSecretKeyType,
     NonPowOf2LweKeySwitchingKey, PowOf2LweKeySwitchingKey, RingSecretKeyType,
 };
-use rand::{CryptoRng, Rng};
-
-use crate::{parameter::Steps, BooleanFheParameters, LookUpTable, SecretKeyPack};
+use rand::{CryptoRng, Rng, SeedableRng};
+use rand_chacha::ChaCha20Rng;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use subtle::{Choice, ConditionallySelectable};
+
+use crate::{join, parameter::Steps, BooleanFheParameters, LookUpTable, SecretKeyPack};
+
+#[cfg(feature = "count")]
+use fhe_core::count::scope;
+
+/// Runs `f`, with the `count` feature its operations are recorded under `label`.
+#[cfg(not(feature = "count"))]
+#[inline(always)]
+fn scope<R>(_label: &'static str, f: impl FnOnce() -> R) -> R {
+    f()
+}
 
 /// A enum type for different key switching purposes.
 #[derive(Clone)]
@@ -29,6 +43,84 @@ pub enum KeySwitchingKey<C: UnsignedInteger, Q: NttField> {
 }
 
 impl<C: UnsignedInteger, Q: NttField> KeySwitchingKey<C, Q> {
+    /// Generates the key switching key required by the [`Steps`] of the parameters.
+    pub fn generate<LweModulus, R>(
+        secret_key_pack: &SecretKeyPack<C, LweModulus, Q>,
+        rng: &mut R,
+    ) -> Self
+    where
+        LweModulus: RingReduce<C>,
+        R: Rng + CryptoRng,
+    {
+        let parameters = secret_key_pack.parameters();
+
+        let s_in = secret_key_pack.rlwe_secret_key();
+        let s_out = secret_key_pack.lwe_secret_key();
+        match parameters.steps() {
+            Steps::BrMsKs => {
+                let lwe_cipher_modulus_minus_one = parameters.lwe_cipher_modulus_minus_one();
+                let s_in = LweSecretKey::from_rlwe_secret_key(s_in, lwe_cipher_modulus_minus_one);
+
+                let ksk = PowOf2LweKeySwitchingKey::generate(
+                    &s_in,
+                    s_out,
+                    parameters.key_switching_params(),
+                    parameters.lwe_cipher_modulus(),
+                    rng,
+                );
+                KeySwitchingKey::PowOf2ModulusLwe(ksk)
+            }
+            Steps::BrKsRlevMs => {
+                let ksk: LweKeySwitchingKeyRlweMode<Q> = LweKeySwitchingKeyRlweMode::generate(
+                    s_in,
+                    s_out,
+                    parameters.key_switching_params(),
+                    Arc::clone(secret_key_pack.ntt_table()),
+                    rng,
+                );
+                KeySwitchingKey::PowOf2DimensionLwe(ksk)
+            }
+            Steps::BrKsLevMs => {
+                let distr = match s_in.distr() {
+                    RingSecretKeyType::Binary => LweSecretKeyType::Binary,
+                    RingSecretKeyType::Ternary => LweSecretKeyType::Ternary,
+                    RingSecretKeyType::SparseTernary(hamming_weight) => {
+                        LweSecretKeyType::SparseTernary(hamming_weight)
+                    }
+                    RingSecretKeyType::Gaussian => panic!("Not support"),
+                };
+                let s_in = LweSecretKey::new(s_in.as_slice().to_vec(), distr);
+
+                let ksk: NonPowOf2LweKeySwitchingKey<<Q as Field>::ValueT> =
+                    NonPowOf2LweKeySwitchingKey::generate(
+                        &s_in,
+                        s_out,
+                        parameters.key_switching_params(),
+                        Q::MODULUS,
+                        rng,
+                    );
+                KeySwitchingKey::NonPowOf2ModulusLwe(ksk)
+            }
+            Steps::BrMs => KeySwitchingKey::None,
+        }
+    }
+
+    /// Generates the key switching key required by the [`Steps`] of the parameters,
+    /// drawing all the randomness from a [`ChaCha20Rng`] seeded with `seed`.
+    ///
+    /// The same `seed` and secret keys always give the same key switching key.
+    #[inline]
+    pub fn generate_with_seed<LweModulus>(
+        secret_key_pack: &SecretKeyPack<C, LweModulus, Q>,
+        seed: [u8; 32],
+    ) -> Self
+    where
+        LweModulus: RingReduce<C>,
+    {
+        let mut rng = ChaCha20Rng::from_seed(seed);
+        Self::generate(secret_key_pack, &mut rng)
+    }
+
     /// Returns an `Option` containing a reference to the
     /// `LweKeySwitchingKeyRlweMode<Q>` if the key is in `PowOf2DimensionLwe` mode, otherwise `None`.
     #[inline]
@@ -108,6 +200,12 @@ impl<C: UnsignedInteger, LweModulus: RingReduce<C>, Q: NttField> EvaluationKey<C
         &self.parameters
     }
 
+    /// Returns a reference to the blind rotation key of this [`EvaluationKey<C, LweModulus, Q>`].
+    #[inline]
+    pub fn blind_rotation_key(&self) -> &BlindRotationKey<Q> {
+        &self.blind_rotation_key
+    }
+
     /// Creates a new [`EvaluationKey`] from the given [`SecretKeyPack`].
     #[inline]
     pub fn new<R>(secret_key_pack: &SecretKeyPack<C, LweModulus, Q>, rng: &mut R) -> Self
@@ -125,52 +223,7 @@ impl<C: UnsignedInteger, LweModulus: RingReduce<C>, Q: NttField> EvaluationKey<C
             rng,
         );
 
-        let s_in = secret_key_pack.rlwe_secret_key();
-        let s_out = secret_key_pack.lwe_secret_key();
-        let key_switching_key = match parameters.steps() {
-            Steps::BrMsKs => {
-                let lwe_cipher_modulus_minus_one = parameters.lwe_cipher_modulus_minus_one();
-                let s_in = LweSecretKey::from_rlwe_secret_key(s_in, lwe_cipher_modulus_minus_one);
-
-                let ksk = PowOf2LweKeySwitchingKey::generate(
-                    &s_in,
-                    s_out,
-                    parameters.key_switching_params(),
-                    parameters.lwe_cipher_modulus(),
-                    rng,
-                );
-                KeySwitchingKey::PowOf2ModulusLwe(ksk)
-            }
-            Steps::BrKsRlevMs => {
-                let ksk: LweKeySwitchingKeyRlweMode<Q> = LweKeySwitchingKeyRlweMode::generate(
-                    s_in,
-                    s_out,
-                    parameters.key_switching_params(),
-                    Arc::clone(secret_key_pack.ntt_table()),
-                    rng,
-                );
-                KeySwitchingKey::PowOf2DimensionLwe(ksk)
-            }
-            Steps::BrKsLevMs => {
-                let distr = match s_in.distr() {
-                    RingSecretKeyType::Binary => LweSecretKeyType::Binary,
-                    RingSecretKeyType::Ternary => LweSecretKeyType::Ternary,
-                    RingSecretKeyType::Gaussian => panic!("Not support"),
-                };
-                let s_in = LweSecretKey::new(s_in.as_slice().to_vec(), distr);
-
-                let ksk: NonPowOf2LweKeySwitchingKey<<Q as Field>::ValueT> =
-                    NonPowOf2LweKeySwitchingKey::generate(
-                        &s_in,
-                        s_out,
-                        parameters.key_switching_params(),
-                        Q::MODULUS,
-                        rng,
-                    );
-                KeySwitchingKey::NonPowOf2ModulusLwe(ksk)
-            }
-            Steps::BrMs => KeySwitchingKey::None,
-        };
+        let key_switching_key = KeySwitchingKey::generate(secret_key_pack, rng);
 
         Self {
             blind_rotation_key,
@@ -186,33 +239,43 @@ impl<C: UnsignedInteger, LweModulus: RingReduce<C>, Q: NttField> EvaluationKey<C
             C::try_from(parameters.ring_dimension() << 1).ok().unwrap();
 
         // modulus switch q -> 2N
-        lwe_modulus_switch_assign(
-            &mut c,
-            parameters.lwe_cipher_modulus_value(),
-            twice_ring_dimension_value,
-        );
+        scope("modulus_switch", || {
+            lwe_modulus_switch_assign(
+                &mut c,
+                parameters.lwe_cipher_modulus_value(),
+                twice_ring_dimension_value,
+            )
+        });
 
         // blind rotation
-        let mut acc = self.blind_rotation_key.blind_rotate(lut, &c);
+        let mut acc = scope("blind_rotation", || {
+            self.blind_rotation_key.blind_rotate(lut, &c)
+        });
 
-        <Q as Field>::MODULUS.reduce_add_assign(&mut acc.b_mut()[0], Q::MODULUS_VALUE >> 3u32);
+        // The look-up table outputs `Δ` and `-Δ` turn into the encodings of `1` and `0`.
+        let delta = parameters.ring_delta_factor().value();
+        <Q as Field>::MODULUS.reduce_add_assign(&mut acc.b_mut()[0], delta);
 
         // key switch and modulus switch (N, Q) -> (n, q)
         match parameters.steps() {
             Steps::BrMsKs => {
                 let acc = acc.extract_lwe_locally();
-                let cipher = lwe_modulus_switch(
-                    &acc,
-                    parameters.ring_modulus(),
-                    parameters.lwe_cipher_modulus_value(),
-                );
+                let cipher = scope("modulus_switch", || {
+                    lwe_modulus_switch(
+                        &acc,
+                        parameters.ring_modulus(),
+                        parameters.lwe_cipher_modulus_value(),
+                    )
+                });
 
                 let ksk = match self.key_switching_key {
                     KeySwitchingKey::PowOf2ModulusLwe(ref ksk) => ksk,
                     _ => panic!("Unable to get the corresponding key switching key!"),
                 };
 
-                c = ksk.key_switch(&cipher, parameters.lwe_cipher_modulus());
+                c = scope("key_switch", || {
+                    ksk.key_switch(&cipher, parameters.lwe_cipher_modulus())
+                });
             }
             Steps::BrKsRlevMs => {
                 let ksk = match self.key_switching_key {
@@ -220,14 +283,16 @@ impl<C: UnsignedInteger, LweModulus: RingReduce<C>, Q: NttField> EvaluationKey<C
                     _ => panic!("Unable to get the corresponding key switching key!"),
                 };
 
-                let key_switched = ksk.key_switch_for_rlwe(acc);
+                let key_switched = scope("key_switch", || ksk.key_switch_for_rlwe(acc));
 
-                lwe_modulus_switch_inplace(
-                    key_switched,
-                    Q::MODULUS_VALUE,
-                    parameters.lwe_cipher_modulus_value(),
-                    &mut c,
-                );
+                scope("modulus_switch", || {
+                    lwe_modulus_switch_inplace(
+                        key_switched,
+                        Q::MODULUS_VALUE,
+                        parameters.lwe_cipher_modulus_value(),
+                        &mut c,
+                    )
+                });
             }
             Steps::BrKsLevMs => {
                 let acc = acc.extract_lwe_locally();
@@ -235,23 +300,27 @@ impl<C: UnsignedInteger, LweModulus: RingReduce<C>, Q: NttField> EvaluationKey<C
                     .key_switching_key
                     .as_non_pow_of_2_modulus_lwe()
                     .unwrap();
-                let temp = ksk.key_switch(&acc, Q::MODULUS);
-
-                c = lwe_modulus_switch(
-                    &temp,
-                    parameters.ring_modulus(),
-                    parameters.lwe_cipher_modulus_value(),
-                );
+                let temp = scope("key_switch", || ksk.key_switch(&acc, Q::MODULUS));
+
+                c = scope("modulus_switch", || {
+                    lwe_modulus_switch(
+                        &temp,
+                        parameters.ring_modulus(),
+                        parameters.lwe_cipher_modulus_value(),
+                    )
+                });
             }
             Steps::BrMs => {
                 let lwe = acc.extract_lwe_locally();
 
-                lwe_modulus_switch_inplace(
-                    lwe,
-                    Q::MODULUS_VALUE,
-                    parameters.lwe_cipher_modulus_value(),
-                    &mut c,
-                );
+                scope("modulus_switch", || {
+                    lwe_modulus_switch_inplace(
+                        lwe,
+                        Q::MODULUS_VALUE,
+                        parameters.lwe_cipher_modulus_value(),
+                        &mut c,
+                    )
+                });
             }
         }
 
@@ -280,6 +349,12 @@ impl<C: UnsignedInteger, LweModulus: RingReduce<C>, Q: NttField> Evaluator<C, Lw
         self.ek.parameters()
     }
 
+    /// Returns a reference to the blind rotation key of this [`Evaluator<F>`].
+    #[inline]
+    pub fn blind_rotation_key(&self) -> &BlindRotationKey<Q> {
+        self.ek.blind_rotation_key()
+    }
+
     /// Complete the bootstrapping operation with LWE Ciphertext *`c`* and lookup table `lut`.
     #[inline]
     pub fn bootstrap(&self, c: LweCiphertext<C>, lut: FieldPolynomial<Q>) -> LweCiphertext<C> {
@@ -288,6 +363,11 @@ impl<C: UnsignedInteger, LweModulus: RingReduce<C>, Q: NttField> Evaluator<C, Lw
 
     /// Performs the homomorphic not operation.
     ///
+    /// It maps `(a, b)` to `(-a, q/4 - b)`, which only costs a negation and an addition.
+    /// It doesn't bootstrap, so it doesn't refresh the noise: the noise of `c`
+    /// is carried over unchanged, and the result should be fed into a bootstrapped gate
+    /// before more noise accumulates.
+    ///
     /// # Arguments
     ///
     /// * Input: ciphertext `c`, with message `true`(resp. `false`).
@@ -328,11 +408,32 @@ impl<C: UnsignedInteger, LweModulus: RingReduce<C>, Q: NttField> Evaluator<C, Lw
         let lut = nand_lut(
             parameters.ring_dimension(),
             parameters.lwe_plain_modulus().as_into(),
+            &parameters.ring_delta_factor(),
         );
 
         self.bootstrap(add, lut)
     }
 
+    /// Performs the homomorphic nand operation element-wise.
+    ///
+    /// # Arguments
+    ///
+    /// * Input: ciphertexts `a`, with messages `a_i`.
+    /// * Input: ciphertexts `b`, with messages `b_i`.
+    /// * Output: ciphertexts with messages `not(a_i and b_i)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` and `b` have different lengths.
+    #[inline]
+    pub fn nand_elementwise(
+        &self,
+        a: &[LweCiphertext<C>],
+        b: &[LweCiphertext<C>],
+    ) -> Vec<LweCiphertext<C>> {
+        self.word_gate(a, b, Self::nand)
+    }
+
     /// Performs the homomorphic and operation.
     ///
     /// # Arguments
@@ -349,6 +450,7 @@ impl<C: UnsignedInteger, LweModulus: RingReduce<C>, Q: NttField> Evaluator<C, Lw
         let lut = and_majority_lut(
             parameters.ring_dimension(),
             parameters.lwe_plain_modulus().as_into(),
+            &parameters.ring_delta_factor(),
         );
 
         self.bootstrap(add, lut)
@@ -370,6 +472,7 @@ impl<C: UnsignedInteger, LweModulus: RingReduce<C>, Q: NttField> Evaluator<C, Lw
         let lut = or_lut(
             parameters.ring_dimension(),
             parameters.lwe_plain_modulus().as_into(),
+            &parameters.ring_delta_factor(),
         );
 
         self.bootstrap(add, lut)
@@ -391,6 +494,7 @@ impl<C: UnsignedInteger, LweModulus: RingReduce<C>, Q: NttField> Evaluator<C, Lw
         let lut = nor_lut(
             parameters.ring_dimension(),
             parameters.lwe_plain_modulus().as_into(),
+            &parameters.ring_delta_factor(),
         );
 
         self.bootstrap(add, lut)
@@ -413,6 +517,7 @@ impl<C: UnsignedInteger, LweModulus: RingReduce<C>, Q: NttField> Evaluator<C, Lw
         let lut = xor_lut(
             parameters.ring_dimension(),
             parameters.lwe_plain_modulus().as_into(),
+            &parameters.ring_delta_factor(),
         );
 
         self.bootstrap(sub, lut)
@@ -435,11 +540,105 @@ impl<C: UnsignedInteger, LweModulus: RingReduce<C>, Q: NttField> Evaluator<C, Lw
         let lut = xnor_lut(
             parameters.ring_dimension(),
             parameters.lwe_plain_modulus().as_into(),
+            &parameters.ring_delta_factor(),
         );
 
         self.bootstrap(sub, lut)
     }
 
+    /// Performs the homomorphic xor operation on two encrypted words.
+    ///
+    /// # Arguments
+    ///
+    /// * Input: ciphertexts `a`, with the bits of a word, least significant bit first.
+    /// * Input: ciphertexts `b`, with the bits of a word, least significant bit first.
+    /// * Output: ciphertexts with the bits of `a ^ b`, least significant bit first.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` and `b` have different lengths.
+    #[inline]
+    pub fn word_xor(
+        &self,
+        a: &[LweCiphertext<C>],
+        b: &[LweCiphertext<C>],
+    ) -> Vec<LweCiphertext<C>> {
+        self.word_gate(a, b, Self::xor)
+    }
+
+    /// Performs the homomorphic and operation on two encrypted words.
+    ///
+    /// # Arguments
+    ///
+    /// * Input: ciphertexts `a`, with the bits of a word, least significant bit first.
+    /// * Input: ciphertexts `b`, with the bits of a word, least significant bit first.
+    /// * Output: ciphertexts with the bits of `a & b`, least significant bit first.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` and `b` have different lengths.
+    #[inline]
+    pub fn word_and(
+        &self,
+        a: &[LweCiphertext<C>],
+        b: &[LweCiphertext<C>],
+    ) -> Vec<LweCiphertext<C>> {
+        self.word_gate(a, b, Self::and)
+    }
+
+    /// Performs the homomorphic or operation on two encrypted words.
+    ///
+    /// # Arguments
+    ///
+    /// * Input: ciphertexts `a`, with the bits of a word, least significant bit first.
+    /// * Input: ciphertexts `b`, with the bits of a word, least significant bit first.
+    /// * Output: ciphertexts with the bits of `a | b`, least significant bit first.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` and `b` have different lengths.
+    #[inline]
+    pub fn word_or(&self, a: &[LweCiphertext<C>], b: &[LweCiphertext<C>]) -> Vec<LweCiphertext<C>> {
+        self.word_gate(a, b, Self::or)
+    }
+
+    /// Applies `gate` to each pair of bits of `a` and `b`.
+    ///
+    /// With the `parallel` feature the gates are spread over the rayon thread pool.
+    #[cfg(feature = "parallel")]
+    fn word_gate<G>(
+        &self,
+        a: &[LweCiphertext<C>],
+        b: &[LweCiphertext<C>],
+        gate: G,
+    ) -> Vec<LweCiphertext<C>>
+    where
+        G: Fn(&Self, &LweCiphertext<C>, &LweCiphertext<C>) -> LweCiphertext<C> + Sync,
+    {
+        assert_eq!(a.len(), b.len(), "Length mismatch!");
+        a.par_iter()
+            .zip(b)
+            .map(|(c0, c1)| gate(self, c0, c1))
+            .collect()
+    }
+
+    /// Applies `gate` to each pair of bits of `a` and `b`.
+    ///
+    /// With the `parallel` feature the gates are spread over the rayon thread pool.
+    #[cfg(not(feature = "parallel"))]
+    fn word_gate<G>(
+        &self,
+        a: &[LweCiphertext<C>],
+        b: &[LweCiphertext<C>],
+        gate: G,
+    ) -> Vec<LweCiphertext<C>>
+    where
+        G: Fn(&Self, &LweCiphertext<C>, &LweCiphertext<C>) -> LweCiphertext<C>,
+    {
+        assert_eq!(a.len(), b.len(), "Length mismatch!");
+        a.iter().zip(b).map(|(c0, c1)| gate(self, c0, c1)).collect()
+    }
+
     /// Performs the homomorphic majority operation.
     ///
     /// # Arguments
@@ -464,6 +663,7 @@ impl<C: UnsignedInteger, LweModulus: RingReduce<C>, Q: NttField> Evaluator<C, Lw
         let lut = and_majority_lut(
             parameters.ring_dimension(),
             parameters.lwe_plain_modulus().as_into(),
+            &parameters.ring_delta_factor(),
         );
 
         self.bootstrap(add, lut)
@@ -489,7 +689,7 @@ impl<C: UnsignedInteger, LweModulus: RingReduce<C>, Q: NttField> Evaluator<C, Lw
 
         let not_c0 = self.not(c0);
 
-        let (mut t0, t1) = rayon::join(|| self.and(c0, c1), || self.and(&not_c0, c2));
+        let (mut t0, t1) = join(|| self.and(c0, c1), || self.and(&not_c0, c2));
 
         // (a & b) | (!a & c)
         t0.add_reduce_assign_component_wise(&t1, cipher_modulus);
@@ -497,99 +697,259 @@ impl<C: UnsignedInteger, LweModulus: RingReduce<C>, Q: NttField> Evaluator<C, Lw
         let lut = or_lut(
             parameters.ring_dimension(),
             parameters.lwe_plain_modulus().as_into(),
+            &parameters.ring_delta_factor(),
         );
 
         self.bootstrap(t0, lut)
     }
+
+    /// Performs the homomorphic look-up over a boolean truth table.
+    ///
+    /// # Arguments
+    ///
+    /// * Input: ciphertexts `selectors`, with messages `s_0, s_1, ..., s_{k-1}`.
+    /// * Input: boolean truth table `table`, with `2^k` entries.
+    /// * Output: ciphertext with message `table[s_0 + 2*s_1 + ... + 2^{k-1}*s_{k-1}]`.
+    ///
+    /// The table is evaluated by a balanced mux tree, `selectors[0]` picks
+    /// between adjacent entries and `selectors[k-1]` picks the final result.
+    /// The first layer is resolved with the plain entries, so it costs at most
+    /// a `not` per pair, every further layer costs one `mux` per node.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `selectors` is empty or `table.len() != 2^k`.
+    pub fn lut_select(&self, selectors: &[LweCiphertext<C>], table: &[bool]) -> LweCiphertext<C>
+    where
+        C: ConditionallySelectable,
+    {
+        let k = selectors.len();
+        assert!(k > 0, "At least one selector is required!");
+        assert_eq!(table.len(), 1 << k, "Table size should be 2^k!");
+
+        // The first layer is resolved without branching on the table,
+        // which may be private to the caller.
+        let s0 = &selectors[0];
+        let not_s0 = self.not(s0);
+        #[cfg(feature = "parallel")]
+        let pairs = table.par_chunks_exact(2);
+        #[cfg(not(feature = "parallel"))]
+        let pairs = table.chunks_exact(2);
+        let mut layer: Vec<LweCiphertext<C>> = pairs
+            .map(|pair| {
+                let (t0, t1) = (Choice::from(pair[0] as u8), Choice::from(pair[1] as u8));
+                // (f,t) -> s0, (t,f) -> !s0, (f,f) -> false, (t,t) -> true
+                let literal = conditional_select_lwe(&not_s0, s0, t1);
+                conditional_select_lwe(&self.trivial(t0), &literal, t0 ^ t1)
+            })
+            .collect();
+
+        for s in &selectors[1..] {
+            #[cfg(feature = "parallel")]
+            let pairs = layer.par_chunks_exact(2);
+            #[cfg(not(feature = "parallel"))]
+            let pairs = layer.chunks_exact(2);
+            layer = pairs.map(|pair| self.mux(s, &pair[1], &pair[0])).collect();
+        }
+
+        layer.pop().unwrap()
+    }
+
+    /// Creates a noiseless ciphertext with message `m`, whose `a` is zero.
+    fn trivial(&self, m: Choice) -> LweCiphertext<C>
+    where
+        C: ConditionallySelectable,
+    {
+        let parameters = self.parameters();
+        let one = encode::<C, C>(
+            C::ONE,
+            parameters.lwe_plain_modulus(),
+            parameters.lwe_cipher_modulus_value(),
+        );
+        let mut c = LweCiphertext::zero(parameters.lwe_dimension());
+        *c.b_mut() = C::conditional_select(&C::ZERO, &one, m);
+        c
+    }
+}
+
+/// Returns `a` if `choice` is `0`, or `b` if `choice` is `1`, without branching on `choice`.
+fn conditional_select_lwe<C>(
+    a: &LweCiphertext<C>,
+    b: &LweCiphertext<C>,
+    choice: Choice,
+) -> LweCiphertext<C>
+where
+    C: UnsignedInteger + ConditionallySelectable,
+{
+    LweCiphertext::new(
+        a.a()
+            .iter()
+            .zip(b.a())
+            .map(|(x, y)| C::conditional_select(x, y, choice))
+            .collect(),
+        C::conditional_select(&a.b(), &b.b(), choice),
+    )
+}
+
+/// The boolean gates evaluated by [`Evaluator`] through a single bootstrapping.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GateKind {
+    /// `not(a and b)`, bootstraps `c0 + c1`.
+    Nand,
+    /// `a and b`, bootstraps `c0 + c1`.
+    And,
+    /// `a or b`, bootstraps `c0 + c1`.
+    Or,
+    /// `not(a or b)`, bootstraps `c0 + c1`.
+    Nor,
+    /// `a xor b`, bootstraps `2 * (c0 - c1)`.
+    Xor,
+    /// `not(a xor b)`, bootstraps `2 * (c0 - c1)`.
+    Xnor,
+    /// `majority(a, b, c)`, bootstraps `c0 + c1 + c2`.
+    Majority,
+}
+
+/// Returns the test polynomial (look-up table) that [`Evaluator`] uses
+/// to bootstrap the combined ciphertext of `gate`.
+///
+/// Feeding the combination described on each [`GateKind`] variant
+/// and this polynomial into [`Evaluator::bootstrap`] reproduces the gate.
+pub fn gate_lut<C, LweModulus, Q>(
+    gate: GateKind,
+    parameters: &BooleanFheParameters<C, LweModulus, Q>,
+) -> FieldPolynomial<Q>
+where
+    C: UnsignedInteger,
+    LweModulus: RingReduce<C>,
+    Q: NttField,
+{
+    let rlwe_dimension = parameters.ring_dimension();
+    let plain_modulus: usize = parameters.lwe_plain_modulus().as_into();
+    let delta = &parameters.ring_delta_factor();
+    match gate {
+        GateKind::Nand => nand_lut(rlwe_dimension, plain_modulus, delta),
+        GateKind::And | GateKind::Majority => {
+            and_majority_lut(rlwe_dimension, plain_modulus, delta)
+        }
+        GateKind::Or => or_lut(rlwe_dimension, plain_modulus, delta),
+        GateKind::Nor => nor_lut(rlwe_dimension, plain_modulus, delta),
+        GateKind::Xor => xor_lut(rlwe_dimension, plain_modulus, delta),
+        GateKind::Xnor => xnor_lut(rlwe_dimension, plain_modulus, delta),
+    }
 }
 
 /// init lut for bootstrapping which performs homomorphic `nand`.
-fn nand_lut<F>(rlwe_dimension: usize, plain_modulus: usize) -> FieldPolynomial<F>
+fn nand_lut<F>(
+    rlwe_dimension: usize,
+    plain_modulus: usize,
+    delta: &ScalarFactor<F>,
+) -> FieldPolynomial<F>
 where
     F: NttField,
 {
-    let q = F::MODULUS_VALUE;
-    let q_div_8 = q >> 3u32;
-    let neg_q_div_8 = q - q_div_8;
+    let (one, neg_one) = (F::ONE, F::neg(F::ONE));
 
     let log_plain_modulus = plain_modulus.trailing_zeros();
 
-    // 0,1 -> q/8
-    // 2,3 -> -q/8
-    [q_div_8, q_div_8, neg_q_div_8, neg_q_div_8].negacyclic_lut(rlwe_dimension, log_plain_modulus)
+    // 0,1 -> Δ
+    // 2,3 -> -Δ
+    let mut lut = [one, one, neg_one, neg_one].negacyclic_lut(rlwe_dimension, log_plain_modulus);
+    lut.mul_factor_assign(delta);
+    lut
 }
 
 /// init lut for bootstrapping which performs homomorphic `and` or `majority`.
-fn and_majority_lut<F>(rlwe_dimension: usize, plain_modulus: usize) -> FieldPolynomial<F>
+fn and_majority_lut<F>(
+    rlwe_dimension: usize,
+    plain_modulus: usize,
+    delta: &ScalarFactor<F>,
+) -> FieldPolynomial<F>
 where
     F: NttField,
 {
-    let q = F::MODULUS_VALUE;
-    let q_div_8 = q >> 3u32;
-    let neg_q_div_8 = q - q_div_8;
+    let (one, neg_one) = (F::ONE, F::neg(F::ONE));
     let log_plain_modulus = plain_modulus.trailing_zeros();
 
-    // 0,1 -> -q/8
-    // 2,3 -> q/8
-    [neg_q_div_8, neg_q_div_8, q_div_8, q_div_8].negacyclic_lut(rlwe_dimension, log_plain_modulus)
+    // 0,1 -> -Δ
+    // 2,3 -> Δ
+    let mut lut = [neg_one, neg_one, one, one].negacyclic_lut(rlwe_dimension, log_plain_modulus);
+    lut.mul_factor_assign(delta);
+    lut
 }
 
 /// init lut for bootstrapping which performs homomorphic `or`.
-fn or_lut<F>(rlwe_dimension: usize, plain_modulus: usize) -> FieldPolynomial<F>
+fn or_lut<F>(
+    rlwe_dimension: usize,
+    plain_modulus: usize,
+    delta: &ScalarFactor<F>,
+) -> FieldPolynomial<F>
 where
     F: NttField,
 {
-    let q = F::MODULUS_VALUE;
-    let q_div_8 = q >> 3u32;
-    let neg_q_div_8 = q - q_div_8;
+    let (one, neg_one) = (F::ONE, F::neg(F::ONE));
     let log_plain_modulus = plain_modulus.trailing_zeros();
 
-    // 1,2 -> q/8
-    // 0,3 -> -q/8
-    [neg_q_div_8, q_div_8, q_div_8, neg_q_div_8].negacyclic_lut(rlwe_dimension, log_plain_modulus)
+    // 1,2 -> Δ
+    // 0,3 -> -Δ
+    let mut lut = [neg_one, one, one, neg_one].negacyclic_lut(rlwe_dimension, log_plain_modulus);
+    lut.mul_factor_assign(delta);
+    lut
 }
 
 /// init lut for bootstrapping which performs homomorphic `nor`.
-fn nor_lut<F>(rlwe_dimension: usize, plain_modulus: usize) -> FieldPolynomial<F>
+fn nor_lut<F>(
+    rlwe_dimension: usize,
+    plain_modulus: usize,
+    delta: &ScalarFactor<F>,
+) -> FieldPolynomial<F>
 where
     F: NttField,
 {
-    let q = F::MODULUS_VALUE;
-    let q_div_8 = q >> 3u32;
-    let neg_q_div_8 = q - q_div_8;
+    let (one, neg_one) = (F::ONE, F::neg(F::ONE));
     let log_plain_modulus = plain_modulus.trailing_zeros();
 
-    // 1,2 -> -q/8
-    // 0,3 -> q/8
-    [q_div_8, neg_q_div_8, neg_q_div_8, q_div_8].negacyclic_lut(rlwe_dimension, log_plain_modulus)
+    // 1,2 -> -Δ
+    // 0,3 -> Δ
+    let mut lut = [one, neg_one, neg_one, one].negacyclic_lut(rlwe_dimension, log_plain_modulus);
+    lut.mul_factor_assign(delta);
+    lut
 }
 
 /// init lut for bootstrapping which performs homomorphic `xor`.
-fn xor_lut<F>(rlwe_dimension: usize, plain_modulus: usize) -> FieldPolynomial<F>
+fn xor_lut<F>(
+    rlwe_dimension: usize,
+    plain_modulus: usize,
+    delta: &ScalarFactor<F>,
+) -> FieldPolynomial<F>
 where
     F: NttField,
 {
-    let q = F::MODULUS_VALUE;
-    let q_div_8 = q >> 3u32;
-    let neg_q_div_8 = q - q_div_8;
+    let (one, neg_one) = (F::ONE, F::neg(F::ONE));
     let log_plain_modulus = plain_modulus.trailing_zeros();
 
-    // 0 -> -q/8
-    // 2 -> q/8
-    [neg_q_div_8, q_div_8].negacyclic_lut(rlwe_dimension, log_plain_modulus - 1)
+    // 0 -> -Δ
+    // 2 -> Δ
+    let mut lut = [neg_one, one].negacyclic_lut(rlwe_dimension, log_plain_modulus - 1);
+    lut.mul_factor_assign(delta);
+    lut
 }
 
 /// init lut for bootstrapping which performs homomorphic `xor`.
-fn xnor_lut<F>(rlwe_dimension: usize, plain_modulus: usize) -> FieldPolynomial<F>
+fn xnor_lut<F>(
+    rlwe_dimension: usize,
+    plain_modulus: usize,
+    delta: &ScalarFactor<F>,
+) -> FieldPolynomial<F>
 where
     F: NttField,
 {
-    let q = F::MODULUS_VALUE;
-    let q_div_8 = q >> 3u32;
-    let neg_q_div_8 = q - q_div_8;
+    let (one, neg_one) = (F::ONE, F::neg(F::ONE));
     let log_plain_modulus = plain_modulus.trailing_zeros();
 
-    // 0 -> q/8
-    // 2 -> -q/8
-    [q_div_8, neg_q_div_8].negacyclic_lut(rlwe_dimension, log_plain_modulus - 1)
+    // 0 -> Δ
+    // 2 -> -Δ
+    let mut lut = [one, neg_one].negacyclic_lut(rlwe_dimension, log_plain_modulus - 1);
+    lut.mul_factor_assign(delta);
+    lut
 }
@@ -0,0 +1,252 @@
+use algebra::{integer::UnsignedInteger, reduce::RingReduce, NttField};
+use fhe_core::LweCiphertext;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+use crate::{join, Evaluator};
+
+/// An encrypted integer modulo `2^width`,
+/// stored as encrypted bits from the least significant one.
+///
+/// It can be read as unsigned or, with the top bit as the sign, as two's complement.
+///
+/// The integer is kept in binary, not as residues modulo several coprime moduli.
+/// Every gate works on one encrypted bit, so a residue channel would still be
+/// a binary circuit modulo its own prime, and the reconstruction would cost
+/// more gates than the carry chains it saves at these widths.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EncryptedInt<C: UnsignedInteger> {
+    bits: Vec<LweCiphertext<C>>,
+}
+
+impl<C: UnsignedInteger> EncryptedInt<C> {
+    /// Creates a new [`EncryptedInt<C>`] from encrypted bits,
+    /// least significant bit first.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bits` is empty.
+    #[inline]
+    pub fn new(bits: Vec<LweCiphertext<C>>) -> Self {
+        assert!(!bits.is_empty(), "At least one bit is required!");
+        Self { bits }
+    }
+
+    /// Returns the encrypted bits, least significant bit first.
+    #[inline]
+    pub fn bits(&self) -> &[LweCiphertext<C>] {
+        &self.bits
+    }
+
+    /// Returns the bit width of this integer.
+    #[inline]
+    pub fn width(&self) -> usize {
+        self.bits.len()
+    }
+
+    /// Consumes `self` and returns the encrypted bits.
+    #[inline]
+    pub fn into_bits(self) -> Vec<LweCiphertext<C>> {
+        self.bits
+    }
+}
+
+/// Evaluator for arithmetic over [`EncryptedInt<C>`],
+/// built from the gates of an [`Evaluator`].
+///
+/// The arithmetic is bit-serial: additions are ripple-carry adders and
+/// multiplications accumulate shifted partial products.
+pub struct IntEvaluator<'a, C: UnsignedInteger, LweModulus: RingReduce<C>, Q: NttField> {
+    evaluator: &'a Evaluator<C, LweModulus, Q>,
+}
+
+impl<'a, C: UnsignedInteger, LweModulus: RingReduce<C>, Q: NttField>
+    IntEvaluator<'a, C, LweModulus, Q>
+{
+    /// Creates a new [`IntEvaluator`] on top of `evaluator`.
+    #[inline]
+    pub fn new(evaluator: &'a Evaluator<C, LweModulus, Q>) -> Self {
+        Self { evaluator }
+    }
+
+    /// Returns the underlying gate [`Evaluator`].
+    #[inline]
+    pub fn evaluator(&self) -> &'a Evaluator<C, LweModulus, Q> {
+        self.evaluator
+    }
+
+    /// Performs the homomorphic addition `a + b mod 2^width` with a ripple-carry adder.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` and `b` have different widths.
+    pub fn add(&self, a: &EncryptedInt<C>, b: &EncryptedInt<C>) -> EncryptedInt<C> {
+        assert_eq!(a.width(), b.width(), "Width mismatch!");
+        EncryptedInt::new(self.add_bits(a.bits(), b.bits()))
+    }
+
+    /// Performs the homomorphic two's complement negation `-a mod 2^width`.
+    ///
+    /// It adds one to the inverted bits of `a` with a chain of half adders,
+    /// the lowest bit of the result is the lowest bit of `a` and the carry
+    /// into the second bit is the inverted lowest bit of `a`.
+    pub fn neg(&self, a: &EncryptedInt<C>) -> EncryptedInt<C> {
+        let eval = self.evaluator;
+        // `EncryptedInt::new` rejects zero-width integers.
+        let (lowest, rest) = a.bits().split_first().unwrap();
+
+        let mut neg = Vec::with_capacity(a.width());
+        neg.push(lowest.clone());
+
+        if let Some((top, middle)) = rest.split_last() {
+            let mut carry = eval.not(lowest);
+            for x in middle {
+                let x = eval.not(x);
+                let (s, c) = join(|| eval.xor(&x, &carry), || eval.and(&x, &carry));
+                neg.push(s);
+                carry = c;
+            }
+            neg.push(eval.xor(&eval.not(top), &carry));
+        }
+
+        EncryptedInt::new(neg)
+    }
+
+    /// Performs the homomorphic multiplication `a * b mod 2^width`.
+    ///
+    /// The partial products `a * b_i * 2^i` are accumulated one by one,
+    /// only the bits below `2^width` are ever computed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` and `b` have different widths.
+    pub fn mul(&self, a: &EncryptedInt<C>, b: &EncryptedInt<C>) -> EncryptedInt<C> {
+        assert_eq!(a.width(), b.width(), "Width mismatch!");
+        let width = a.width();
+
+        let mut acc = self.and_scalar_bit(a.bits(), &b.bits()[0]);
+        for (i, bi) in b.bits().iter().enumerate().skip(1) {
+            let partial = self.and_scalar_bit(&a.bits()[..width - i], bi);
+            let sum = self.add_bits(&acc[i..], &partial);
+            acc.truncate(i);
+            acc.extend(sum);
+        }
+
+        EncryptedInt::new(acc)
+    }
+
+    /// Performs the homomorphic comparison `a > b` of unsigned integers,
+    /// returning an encrypted bit.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` and `b` have different widths.
+    #[inline]
+    pub fn gt(&self, a: &EncryptedInt<C>, b: &EncryptedInt<C>) -> LweCiphertext<C> {
+        assert_eq!(a.width(), b.width(), "Width mismatch!");
+        self.borrow_bits(b.bits(), a.bits())
+    }
+
+    /// Performs the homomorphic comparison `a < b` of unsigned integers,
+    /// returning an encrypted bit.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` and `b` have different widths.
+    #[inline]
+    pub fn lt(&self, a: &EncryptedInt<C>, b: &EncryptedInt<C>) -> LweCiphertext<C> {
+        assert_eq!(a.width(), b.width(), "Width mismatch!");
+        self.borrow_bits(a.bits(), b.bits())
+    }
+
+    /// Performs the homomorphic comparison `a == b`, returning an encrypted bit.
+    ///
+    /// The bits are compared with xnor gates, whose results are combined
+    /// by a tree of and gates.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` and `b` have different widths.
+    pub fn eq(&self, a: &EncryptedInt<C>, b: &EncryptedInt<C>) -> LweCiphertext<C> {
+        assert_eq!(a.width(), b.width(), "Width mismatch!");
+        let eval = self.evaluator;
+
+        #[cfg(feature = "parallel")]
+        let bits = a.bits().par_iter();
+        #[cfg(not(feature = "parallel"))]
+        let bits = a.bits().iter();
+        let mut equal: Vec<LweCiphertext<C>> =
+            bits.zip(b.bits()).map(|(x, y)| eval.xnor(x, y)).collect();
+
+        while equal.len() > 1 {
+            #[cfg(feature = "parallel")]
+            let pairs = equal.par_chunks(2);
+            #[cfg(not(feature = "parallel"))]
+            let pairs = equal.chunks(2);
+            equal = pairs
+                .map(|pair| match pair {
+                    [x, y] => eval.and(x, y),
+                    [x] => x.clone(),
+                    _ => unreachable!(),
+                })
+                .collect();
+        }
+
+        equal.pop().unwrap()
+    }
+
+    /// Returns the final borrow of the subtraction `a - b`, which is `a < b`.
+    ///
+    /// The borrow out of bit `i` is `(!a_i & b_i) | (!(a_i ^ b_i) & borrow_i)`,
+    /// which is `majority(!a_i, b_i, borrow_i)`.
+    fn borrow_bits(&self, a: &[LweCiphertext<C>], b: &[LweCiphertext<C>]) -> LweCiphertext<C> {
+        let eval = self.evaluator;
+
+        let borrow = eval.and(&eval.not(&a[0]), &b[0]);
+        a.iter().zip(b).skip(1).fold(borrow, |borrow, (x, y)| {
+            eval.majority(&eval.not(x), y, &borrow)
+        })
+    }
+
+    /// Returns `[x_j & bit]` for every `x_j` in `bits`.
+    fn and_scalar_bit(
+        &self,
+        bits: &[LweCiphertext<C>],
+        bit: &LweCiphertext<C>,
+    ) -> Vec<LweCiphertext<C>> {
+        #[cfg(feature = "parallel")]
+        let bits = bits.par_iter();
+        #[cfg(not(feature = "parallel"))]
+        let bits = bits.iter();
+        bits.map(|x| self.evaluator.and(x, bit)).collect()
+    }
+
+    /// Adds two bit vectors of the same length, dropping the final carry.
+    fn add_bits(&self, a: &[LweCiphertext<C>], b: &[LweCiphertext<C>]) -> Vec<LweCiphertext<C>> {
+        let eval = self.evaluator;
+        let width = a.len();
+
+        let mut sum = Vec::with_capacity(width);
+        let mut carry: Option<LweCiphertext<C>> = None;
+
+        for (i, (x, y)) in a.iter().zip(b).enumerate() {
+            let last = i + 1 == width;
+            match carry.take() {
+                None if last => sum.push(eval.xor(x, y)),
+                None => {
+                    let (s, c) = join(|| eval.xor(x, y), || eval.and(x, y));
+                    sum.push(s);
+                    carry = Some(c);
+                }
+                Some(c) if last => sum.push(eval.xor(&eval.xor(x, y), &c)),
+                Some(c) => {
+                    let (s, c) = join(|| eval.xor(&eval.xor(x, y), &c), || eval.majority(x, y, &c));
+                    sum.push(s);
+                    carry = Some(c);
+                }
+            }
+        }
+
+        sum
+    }
+}
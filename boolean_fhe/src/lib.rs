@@ -6,6 +6,7 @@
 mod parameter;
 
 mod evaluate;
+mod integer;
 mod lut;
 
 mod decrypt;
@@ -15,7 +16,22 @@ mod secret_key;
 
 pub use parameter::*;
 
-pub use evaluate::{Evaluator, KeySwitchingKey};
+#[cfg(feature = "parallel")]
+use rayon::join;
+
+/// Runs `a` and then `b`, with the `parallel` feature they may run in parallel.
+#[cfg(not(feature = "parallel"))]
+#[inline(always)]
+fn join<A, B, RA, RB>(a: A, b: B) -> (RA, RB)
+where
+    A: FnOnce() -> RA,
+    B: FnOnce() -> RB,
+{
+    (a(), b())
+}
+
+pub use evaluate::{gate_lut, Evaluator, GateKind, KeySwitchingKey};
+pub use integer::{EncryptedInt, IntEvaluator};
 pub use lut::LookUpTable;
 
 pub use decrypt::Decryptor;
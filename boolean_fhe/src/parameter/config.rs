@@ -0,0 +1,192 @@
+//! Reads and writes [`BooleanFheParameters`] as toml.
+
+use algebra::integer::{AsInto, UnsignedInteger};
+use algebra::reduce::{ModulusValue, RingReduce};
+use algebra::{Field, NttField};
+use fhe_core::{FHECoreError, LweSecretKeyType, RingSecretKeyType};
+use serde::{Deserialize, Serialize};
+
+use super::{BooleanFheParameters, ConstParameters, Steps};
+
+/// The text form of [`ConstParameters`], with every value widened to `u64`.
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ParametersConfig {
+    lwe_dimension: usize,
+    lwe_plain_modulus: u64,
+    lwe_cipher_modulus: ModulusConfig,
+    lwe_noise_standard_deviation: f64,
+    lwe_secret_key_type: LweSecretKeyConfig,
+
+    ring_dimension: usize,
+    ring_modulus: u64,
+    ring_noise_standard_deviation: f64,
+    ring_secret_key_type: RingSecretKeyConfig,
+
+    blind_rotation_basis_bits: u32,
+
+    steps: StepsConfig,
+
+    key_switching_basis_bits: u32,
+    key_switching_standard_deviation: f64,
+}
+
+/// The text form of [`ModulusValue`],
+/// `"native"` or a table like `{ power_of_2 = 16384 }`.
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum ModulusConfig {
+    Native,
+    #[serde(rename = "power_of_2")]
+    PowerOf2(u64),
+    Prime(u64),
+    Others(u64),
+}
+
+/// The text form of [`LweSecretKeyType`],
+/// the sparse ternary key is a table like `{ sparse_ternary = 128 }`.
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum LweSecretKeyConfig {
+    Binary,
+    Ternary,
+    SparseTernary(usize),
+}
+
+/// The text form of [`RingSecretKeyType`],
+/// the sparse ternary key is a table like `{ sparse_ternary = 128 }`.
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum RingSecretKeyConfig {
+    Binary,
+    Ternary,
+    Gaussian,
+    SparseTernary(usize),
+}
+
+/// The text form of [`Steps`].
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[allow(clippy::enum_variant_names)]
+enum StepsConfig {
+    BrMsKs,
+    BrKsRlevMs,
+    BrKsLevMs,
+    BrMs,
+}
+
+impl<C: UnsignedInteger, LweModulus: RingReduce<C>, Q: NttField>
+    BooleanFheParameters<C, LweModulus, Q>
+{
+    /// Reads the parameters from toml, like the output of [`Self::to_toml`].
+    ///
+    /// The values are checked by [`Self::new`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`FHECoreError::ParametersFormatErr`] if `s` is not valid toml,
+    /// misses a field, has a value that doesn't fit `C`, or has a ring modulus
+    /// other than the modulus of `Q`. Otherwise returns the errors of [`Self::new`].
+    pub fn from_toml(s: &str) -> Result<Self, FHECoreError> {
+        let config: ParametersConfig =
+            toml::from_str(s).map_err(|e| FHECoreError::ParametersFormatErr(e.to_string()))?;
+
+        let ring_modulus = config.ring_modulus;
+        let q_value: u64 = <Q as Field>::MODULUS_VALUE.as_into();
+        if ring_modulus != q_value {
+            return Err(FHECoreError::ParametersFormatErr(format!(
+                "ring modulus {ring_modulus} is not the field modulus {q_value}"
+            )));
+        }
+
+        let lwe_cipher_modulus = match config.lwe_cipher_modulus {
+            ModulusConfig::Native => ModulusValue::Native,
+            ModulusConfig::PowerOf2(q) => ModulusValue::PowerOf2(narrow(q, "lwe_cipher_modulus")?),
+            ModulusConfig::Prime(q) => ModulusValue::Prime(narrow(q, "lwe_cipher_modulus")?),
+            ModulusConfig::Others(q) => ModulusValue::Others(narrow(q, "lwe_cipher_modulus")?),
+        };
+
+        Self::new(ConstParameters {
+            lwe_dimension: config.lwe_dimension,
+            lwe_plain_modulus: narrow(config.lwe_plain_modulus, "lwe_plain_modulus")?,
+            lwe_cipher_modulus,
+            lwe_noise_standard_deviation: config.lwe_noise_standard_deviation,
+            lwe_secret_key_type: match config.lwe_secret_key_type {
+                LweSecretKeyConfig::Binary => LweSecretKeyType::Binary,
+                LweSecretKeyConfig::Ternary => LweSecretKeyType::Ternary,
+                LweSecretKeyConfig::SparseTernary(h) => LweSecretKeyType::SparseTernary(h),
+            },
+            ring_dimension: config.ring_dimension,
+            ring_modulus: <Q as Field>::MODULUS_VALUE,
+            ring_noise_standard_deviation: config.ring_noise_standard_deviation,
+            ring_secret_key_type: match config.ring_secret_key_type {
+                RingSecretKeyConfig::Binary => RingSecretKeyType::Binary,
+                RingSecretKeyConfig::Ternary => RingSecretKeyType::Ternary,
+                RingSecretKeyConfig::Gaussian => RingSecretKeyType::Gaussian,
+                RingSecretKeyConfig::SparseTernary(h) => RingSecretKeyType::SparseTernary(h),
+            },
+            blind_rotation_basis_bits: config.blind_rotation_basis_bits,
+            steps: match config.steps {
+                StepsConfig::BrMsKs => Steps::BrMsKs,
+                StepsConfig::BrKsRlevMs => Steps::BrKsRlevMs,
+                StepsConfig::BrKsLevMs => Steps::BrKsLevMs,
+                StepsConfig::BrMs => Steps::BrMs,
+            },
+            key_switching_basis_bits: config.key_switching_basis_bits,
+            key_switching_standard_deviation: config.key_switching_standard_deviation,
+        })
+    }
+
+    /// Writes the parameters as toml, which [`Self::from_toml`] reads back.
+    pub fn to_toml(&self) -> String {
+        let config = ParametersConfig {
+            lwe_dimension: self.lwe_dimension(),
+            lwe_plain_modulus: self.lwe_plain_modulus().as_into(),
+            lwe_cipher_modulus: match self.lwe_cipher_modulus_value() {
+                ModulusValue::Native => ModulusConfig::Native,
+                ModulusValue::PowerOf2(q) => ModulusConfig::PowerOf2(q.as_into()),
+                ModulusValue::Prime(q) => ModulusConfig::Prime(q.as_into()),
+                ModulusValue::Others(q) => ModulusConfig::Others(q.as_into()),
+            },
+            lwe_noise_standard_deviation: self.lwe_noise_standard_deviation(),
+            lwe_secret_key_type: match self.lwe_secret_key_type() {
+                LweSecretKeyType::Binary => LweSecretKeyConfig::Binary,
+                LweSecretKeyType::Ternary => LweSecretKeyConfig::Ternary,
+                LweSecretKeyType::SparseTernary(h) => LweSecretKeyConfig::SparseTernary(h),
+            },
+            ring_dimension: self.ring_dimension(),
+            ring_modulus: self.ring_modulus().as_into(),
+            ring_noise_standard_deviation: self.ring_noise_standard_deviation(),
+            ring_secret_key_type: match self.ring_secret_key_type() {
+                RingSecretKeyType::Binary => RingSecretKeyConfig::Binary,
+                RingSecretKeyType::Ternary => RingSecretKeyConfig::Ternary,
+                RingSecretKeyType::Gaussian => RingSecretKeyConfig::Gaussian,
+                RingSecretKeyType::SparseTernary(h) => RingSecretKeyConfig::SparseTernary(h),
+            },
+            blind_rotation_basis_bits: self.blind_rotation_basis_bits(),
+            steps: match self.steps() {
+                Steps::BrMsKs => StepsConfig::BrMsKs,
+                Steps::BrKsRlevMs => StepsConfig::BrKsRlevMs,
+                Steps::BrKsLevMs => StepsConfig::BrKsLevMs,
+                Steps::BrMs => StepsConfig::BrMs,
+            },
+            key_switching_basis_bits: self.key_switching_basis_bits(),
+            key_switching_standard_deviation: self.key_switching_noise_standard_deviation(),
+        };
+
+        toml::to_string(&config).expect("the parameters only hold numbers and enums")
+    }
+}
+
+/// Converts `value` of the field `name` into `C`, if it fits.
+fn narrow<C: UnsignedInteger>(value: u64, name: &str) -> Result<C, FHECoreError> {
+    let narrowed = C::as_from(value);
+    if AsInto::<u64>::as_into(narrowed) == value {
+        Ok(narrowed)
+    } else {
+        Err(FHECoreError::ParametersFormatErr(format!(
+            "{name} {value} doesn't fit in {} bits",
+            C::BITS
+        )))
+    }
+}
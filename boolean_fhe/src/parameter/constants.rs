@@ -1,30 +1,91 @@
 use std::sync::LazyLock;
 
-use algebra::{modulus::PowOf2Modulus, reduce::ModulusValue, Field, U32FieldEval};
+use algebra::{modulus::PowOf2Modulus, reduce::ModulusValue, NttField, U32FieldEval};
 use fhe_core::{LweSecretKeyType, RingSecretKeyType};
 
 use super::{BooleanFheParameters, ConstParameters, Steps};
 
+/// Ring modulus for the ring dimension `512`.
+type Fp1 = U32FieldEval<4191233>;
+/// Ring modulus for the ring dimension `1024`.
 type Fp = U32FieldEval<132120577>;
+/// Ring modulus for the ring dimension `2048`.
+type Fp2 = U32FieldEval<1073479681>;
 
-/// Default 128-bits security Parameters
-pub static DEFAULT_128_BITS_PARAMETERS: LazyLock<
-    BooleanFheParameters<u16, PowOf2Modulus<u16>, Fp>,
-> = LazyLock::new(|| {
-    BooleanFheParameters::<u16, PowOf2Modulus<u16>, Fp>::new(ConstParameters {
-        lwe_dimension: 512,
+/// Builds the default parameters, which only differ in the ring,
+/// its noise and the secret key type.
+///
+/// All the security levels use the [`Steps::BrMs`] steps, so `n = N` and
+/// the **LWE** secret key is the ring secret key, there is no key switching key.
+/// The **LWE** ciphertexts are modulo `q = 2^14` with `t = 4` and `σ = 3.2`,
+/// which are far harder than the ring for any `N ≥ 512`.
+///
+/// The security estimates of [`BooleanFheParameters::security_bits`] are
+///
+/// | `N`  | `log2(Q)` | ring `σ` | ternary | binary |
+/// |------|-----------|----------|---------|--------|
+/// | 512  | 22        | 6.4      | 85      | 82     |
+/// | 1024 | 27        | 6.4      | 137     | 133    |
+/// | 2048 | 30        | 3.2      | 249     | 242    |
+///
+/// `Q` can't exceed `2^30`, so the `2048` ring has the smaller noise
+/// to stay below the next level. The `512` ring decomposes its small `Q`
+/// with a smaller blind rotation basis to keep the bootstrapping noise low.
+fn default_parameters<Q: NttField<ValueT = u32>>(
+    ring_dimension: usize,
+    ring_noise_standard_deviation: f64,
+    blind_rotation_basis_bits: u32,
+    ring_secret_key_type: RingSecretKeyType,
+) -> BooleanFheParameters<u16, PowOf2Modulus<u16>, Q> {
+    let lwe_secret_key_type = match ring_secret_key_type {
+        RingSecretKeyType::Binary => LweSecretKeyType::Binary,
+        _ => LweSecretKeyType::Ternary,
+    };
+
+    BooleanFheParameters::<u16, PowOf2Modulus<u16>, Q>::new(ConstParameters {
+        lwe_dimension: ring_dimension,
         lwe_plain_modulus: 4,
         lwe_cipher_modulus: ModulusValue::PowerOf2(1 << 14),
         lwe_noise_standard_deviation: 3.20,
-        lwe_secret_key_type: LweSecretKeyType::Binary,
-        ring_dimension: 1024,
-        ring_modulus: Fp::MODULUS_VALUE,
-        ring_noise_standard_deviation: 3.20 * ((1 << 1) as f64),
-        ring_secret_key_type: RingSecretKeyType::Ternary,
-        blind_rotation_basis_bits: 7,
+        lwe_secret_key_type,
+        ring_dimension,
+        ring_modulus: Q::MODULUS_VALUE,
+        ring_noise_standard_deviation,
+        ring_secret_key_type,
+        blind_rotation_basis_bits,
         key_switching_basis_bits: 2,
         key_switching_standard_deviation: 3.2 * ((1 << 1) as f64),
-        steps: Steps::BrKsLevMs,
+        steps: Steps::BrMs,
     })
     .unwrap()
-});
+}
+
+/// Default 80-bits security Parameters
+pub static DEFAULT_80_BITS_PARAMETERS: LazyLock<
+    BooleanFheParameters<u16, PowOf2Modulus<u16>, Fp1>,
+> = LazyLock::new(|| default_parameters(512, 6.4, 3, RingSecretKeyType::Ternary));
+
+/// Default 128-bits security Parameters
+pub static DEFAULT_128_BITS_PARAMETERS: LazyLock<
+    BooleanFheParameters<u16, PowOf2Modulus<u16>, Fp>,
+> = LazyLock::new(|| default_parameters(1024, 6.4, 7, RingSecretKeyType::Ternary));
+
+/// Default 192-bits security Parameters
+pub static DEFAULT_192_BITS_PARAMETERS: LazyLock<
+    BooleanFheParameters<u16, PowOf2Modulus<u16>, Fp2>,
+> = LazyLock::new(|| default_parameters(2048, 3.2, 7, RingSecretKeyType::Ternary));
+
+/// Default 80-bits security Parameters with a binary secret key
+pub static DEFAULT_BINARY_80_BITS_PARAMETERS: LazyLock<
+    BooleanFheParameters<u16, PowOf2Modulus<u16>, Fp1>,
+> = LazyLock::new(|| default_parameters(512, 6.4, 3, RingSecretKeyType::Binary));
+
+/// Default 128-bits security Parameters with a binary secret key
+pub static DEFAULT_BINARY_128_BITS_PARAMETERS: LazyLock<
+    BooleanFheParameters<u16, PowOf2Modulus<u16>, Fp>,
+> = LazyLock::new(|| default_parameters(1024, 6.4, 7, RingSecretKeyType::Binary));
+
+/// Default 192-bits security Parameters with a binary secret key
+pub static DEFAULT_BINARY_192_BITS_PARAMETERS: LazyLock<
+    BooleanFheParameters<u16, PowOf2Modulus<u16>, Fp2>,
+> = LazyLock::new(|| default_parameters(2048, 3.2, 7, RingSecretKeyType::Binary));
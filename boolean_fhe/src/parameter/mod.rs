@@ -4,11 +4,15 @@ use algebra::decompose::NonPowOf2ApproxSignedBasis;
 use algebra::integer::Bits;
 use algebra::random::DiscreteGaussian;
 use algebra::reduce::{ModulusValue, RingReduce};
-use algebra::Field;
 use algebra::{integer::UnsignedInteger, NttField};
-use fhe_core::{FHECoreError, GadgetRlweParameters as BlindRotationParameters};
-use fhe_core::{KeySwitchingParameters, LweParameters, LweSecretKeyType, RingSecretKeyType};
-
+use algebra::{Field, ScalarFactor};
+use fhe_core::{encode_field, FHECoreError, GadgetRlweParameters as BlindRotationParameters};
+use fhe_core::{
+    lwe_security_bits, KeySwitchingParameters, LweParameters, LweSecretKeyType, RingSecretKeyType,
+};
+
+#[cfg(feature = "serde")]
+mod config;
 mod constants;
 mod steps;
 
@@ -59,6 +63,7 @@ pub struct BooleanFheParameters<C: UnsignedInteger, LweModulus: RingReduce<C>, Q
     blind_rotation_params: BlindRotationParameters<Q>,
     key_switching_params: KeySwitchingParameters,
     steps: Steps,
+    ring_delta: ScalarFactor<Q>,
 }
 
 impl<C: UnsignedInteger, LweModulus: RingReduce<C>, Q: NttField> Clone
@@ -110,6 +115,10 @@ impl<C: UnsignedInteger, LweModulus: RingReduce<C>, Q: NttField>
             });
         }
 
+        if !steps.is_compatible(lwe_dimension, ring_dimension, ring_secret_key_type) {
+            return Err(FHECoreError::StepsParametersNotCompatible);
+        }
+
         let t = params.lwe_plain_modulus;
         assert!(t.is_power_of_two());
         assert!(lwe_cipher_modulus.is_native() || lwe_cipher_modulus.is_power_of2());
@@ -118,6 +127,10 @@ impl<C: UnsignedInteger, LweModulus: RingReduce<C>, Q: NttField>
         }
         let lwe_cipher_modulus = LweModulus::from_value(lwe_cipher_modulus);
 
+        // The look-up table outputs `±1` are messages of `Z_2t`, Δ ≈ Q/2t.
+        let t_value: u64 = t.as_into();
+        let ring_delta = ScalarFactor::new(encode_field::<Q, _>(1u64, t_value << 1));
+
         let lwe_params = LweParameters::new(
             lwe_dimension,
             t,
@@ -130,11 +143,7 @@ impl<C: UnsignedInteger, LweModulus: RingReduce<C>, Q: NttField>
             dimension: ring_dimension,
             modulus: ring_modulus,
             noise_standard_deviation: params.ring_noise_standard_deviation,
-            basis: NonPowOf2ApproxSignedBasis::new(
-                <Q as Field>::MODULUS_VALUE,
-                params.blind_rotation_basis_bits,
-                None,
-            ),
+            basis: blind_rotation_basis::<Q>(params.blind_rotation_basis_bits)?,
             secret_key_type: ring_secret_key_type,
         };
 
@@ -160,6 +169,7 @@ impl<C: UnsignedInteger, LweModulus: RingReduce<C>, Q: NttField>
             blind_rotation_params,
             key_switching_params,
             steps,
+            ring_delta,
         })
     }
 
@@ -217,6 +227,13 @@ impl<C: UnsignedInteger, LweModulus: RingReduce<C>, Q: NttField>
         self.blind_rotation_params.modulus
     }
 
+    /// Returns the precomputed encoding constant `Δ ≈ Q/2t` of this [`BooleanFheParameters<C, Q>`],
+    /// which scales the look-up table outputs `±1`, messages of `Z_2t`, to the ring modulus **Q**.
+    #[inline]
+    pub fn ring_delta_factor(&self) -> ScalarFactor<Q> {
+        self.ring_delta
+    }
+
     /// Returns the ring noise error's standard deviation of this [`BooleanFheParameters<C, Q>`].
     #[inline]
     pub fn ring_noise_standard_deviation(&self) -> f64 {
@@ -236,6 +253,22 @@ impl<C: UnsignedInteger, LweModulus: RingReduce<C>, Q: NttField>
         &self.blind_rotation_params.basis
     }
 
+    /// Returns the blind rotation basis' bits of this [`BooleanFheParameters<C, Q>`].
+    #[inline]
+    pub fn blind_rotation_basis_bits(&self) -> u32 {
+        self.blind_rotation_params.basis.log_basis()
+    }
+
+    /// Returns a copy of this [`BooleanFheParameters<C, Q>`]
+    /// whose blind rotation decomposes with a `2^basis_bits` basis.
+    ///
+    /// A larger basis makes smaller blind rotation keys and faster bootstrapping,
+    /// at the cost of more noise.
+    pub fn with_blind_rotation_basis_bits(mut self, basis_bits: u32) -> Result<Self, FHECoreError> {
+        self.blind_rotation_params.basis = blind_rotation_basis::<Q>(basis_bits)?;
+        Ok(self)
+    }
+
     /// Returns the key switching basis' bits of this [`BooleanFheParameters<C, Q>`],
     /// which acts as the decompose basis for `Q` or `q` used for key switching.
     #[inline]
@@ -290,6 +323,62 @@ impl<C: UnsignedInteger, LweModulus: RingReduce<C>, Q: NttField>
         .unwrap()
     }
 
+    /// Returns how many homomorphic additions can be performed on fresh LWE ciphertexts
+    /// before a bootstrapping is mandatory.
+    ///
+    /// The fresh noise is sampled from a gaussian truncated at `6σ`,
+    /// and every addition of a fresh ciphertext adds at most another `6σ`.
+    /// Decryption is correct as long as the noise is less than `q/(2t)`.
+    /// So the sum of `k` fresh ciphertexts is guaranteed to decrypt
+    /// correctly if `k * 6σ ≤ q/(2t)`, which allows
+    ///
+    /// `⌊q / (2t * 6σ)⌋ - 1`
+    ///
+    /// additions.
+    pub fn max_linear_ops_before_bootstrap(&self) -> usize {
+        let q: f64 = match self.lwe_cipher_modulus_value() {
+            ModulusValue::Native => 2.0f64.powi(C::BITS as i32),
+            ModulusValue::PowerOf2(q) | ModulusValue::Prime(q) | ModulusValue::Others(q) => {
+                q.as_into()
+            }
+        };
+        let t: f64 = self.lwe_plain_modulus().as_into();
+
+        let decryption_bound = q / (2.0 * t);
+        let noise_per_add = self.lwe_noise_distribution().max_std_dev();
+
+        ((decryption_bound / noise_per_add).floor() as usize).saturating_sub(1)
+    }
+
+    /// Returns the estimated bit security of this [`BooleanFheParameters<C, Q>`],
+    /// see [`lwe_security_bits`].
+    ///
+    /// It is the least of the estimates of
+    /// - the **LWE** ciphertexts, of dimension **n** modulo **q**,
+    /// - the blind rotation key, encrypted under the ring secret key of dimension **N** modulo **Q**,
+    /// - the key switching key, encrypted under the **LWE** secret key
+    ///   modulo **q** for [`Steps::BrMsKs`] and modulo **Q** for the other steps,
+    ///   except [`Steps::BrMs`] which has no key switching key.
+    pub fn security_bits(&self) -> f64 {
+        let lwe_bits = self.lwe_params.security_bits();
+        let ring_bits = self.blind_rotation_params.security_bits();
+
+        let key_switching_bits = match self.steps {
+            Steps::BrMs => f64::INFINITY,
+            Steps::BrMsKs | Steps::BrKsRlevMs | Steps::BrKsLevMs => {
+                let lwe_dimension = self.lwe_dimension();
+                lwe_security_bits(
+                    lwe_dimension,
+                    self.key_switching_params.log_modulus as f64,
+                    self.key_switching_noise_standard_deviation(),
+                    self.lwe_secret_key_type().standard_deviation(lwe_dimension),
+                )
+            }
+        };
+
+        lwe_bits.min(ring_bits).min(key_switching_bits)
+    }
+
     /// Returns the steps of whole bootstrapping of this [`BooleanFheParameters<C, Q>`].
     #[inline]
     pub fn steps(&self) -> Steps {
@@ -314,3 +403,23 @@ impl<C: UnsignedInteger, LweModulus: RingReduce<C>, Q: NttField>
         self.key_switching_params
     }
 }
+
+/// Creates the blind rotation basis `2^basis_bits` for `Q`,
+/// which must give at least one digit.
+fn blind_rotation_basis<Q: NttField>(
+    basis_bits: u32,
+) -> Result<NonPowOf2ApproxSignedBasis<<Q as Field>::ValueT>, FHECoreError> {
+    let modulus_bits = <Q as Field>::ValueT::BITS - <Q as Field>::MODULUS_VALUE.leading_zeros();
+    if basis_bits == 0 || basis_bits > modulus_bits {
+        return Err(FHECoreError::BlindRotationBasisUnValid {
+            basis_bits,
+            modulus_bits,
+        });
+    }
+
+    Ok(NonPowOf2ApproxSignedBasis::new(
+        <Q as Field>::MODULUS_VALUE,
+        basis_bits,
+        None,
+    ))
+}
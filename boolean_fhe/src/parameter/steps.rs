@@ -1,22 +1,32 @@
+use fhe_core::RingSecretKeyType;
+
 /// The steps of whole bootstrapping.
 ///
 /// First `Modulus Switch` or `Scale` is decided by following two case:
 /// - `Modulus Switch`: `q > 2N`, `2N|q`
 /// - `Scale`:`q < 2N`, `q|2N`
-#[derive(Debug, Default, Clone, Copy)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
 pub enum Steps {
     /// Modulus Switch or Scale? -> Blind Rotation -> Modulus Switch -> Key Switch.
     ///
     /// (n, q) -> (n, 2N) -> (N, Q) -> (N, q) -> (n, q)
+    ///
+    /// The ring secret key must be binary or ternary.
     BrMsKs,
     /// Modulus Switch or Scale? -> Blind Rotation -> Key Switch -> Modulus Switch.
     ///
     /// (n, q) -> (n, 2N) -> (N, Q) -> (n, Q) -> (n, q)
+    ///
+    /// The key switching is done in RLWE mode, which pads the LWE secret key
+    /// to the next power of two of `n`, so that must be no larger than `N`.
     #[default]
     BrKsRlevMs,
     /// Modulus Switch or Scale? -> Blind Rotation -> Key Switch -> Modulus Switch.
     ///
     /// (n, q) -> (n, 2N) -> (N, Q) -> (n, Q) -> (n, q)
+    ///
+    /// The key switching is done in LWE mode,
+    /// so the ring secret key must be binary or ternary.
     BrKsLevMs,
     /// Modulus Switch or Scale? -> Blind Rotation -> Modulus Switch.
     ///
@@ -25,3 +35,26 @@ pub enum Steps {
     /// (n, q) -> (n, 2N) -> (N, Q) -> (n, q)
     BrMs,
 }
+
+impl Steps {
+    /// Checks whether these steps can be carried out with
+    /// the LWE dimension `n`, the ring dimension `N` and the ring secret key type.
+    pub fn is_compatible(
+        self,
+        lwe_dimension: usize,
+        ring_dimension: usize,
+        ring_secret_key_type: RingSecretKeyType,
+    ) -> bool {
+        match self {
+            Steps::BrMsKs | Steps::BrKsLevMs => ring_secret_key_type != RingSecretKeyType::Gaussian,
+            Steps::BrKsRlevMs => lwe_dimension.next_power_of_two() <= ring_dimension,
+            Steps::BrMs => {
+                lwe_dimension == ring_dimension
+                    && matches!(
+                        ring_secret_key_type,
+                        RingSecretKeyType::Binary | RingSecretKeyType::Ternary
+                    )
+            }
+        }
+    }
+}
@@ -89,6 +89,18 @@ impl<C: UnsignedInteger, LweModulus: RingReduce<C>, Q: NttField> SecretKeyPack<C
         &self.rlwe_secret_key
     }
 
+    /// Returns the number of nonzero coefficients of the lwe secret key.
+    #[inline]
+    pub fn lwe_secret_hamming_weight(&self) -> usize {
+        self.lwe_secret_key.hamming_weight()
+    }
+
+    /// Returns the number of nonzero coefficients of the rlwe secret key.
+    #[inline]
+    pub fn rlwe_secret_hamming_weight(&self) -> usize {
+        self.rlwe_secret_key.hamming_weight()
+    }
+
     /// Returns a reference to the ntt rlwe secret key of this [`SecretKeyPack<C, Q>`].
     #[inline]
     pub fn ntt_rlwe_secret_key(&self) -> &NttRlweSecretKey<Q> {
@@ -135,4 +147,12 @@ impl<C: UnsignedInteger, LweModulus: RingReduce<C>, Q: NttField> SecretKeyPack<C
         self.lwe_secret_key
             .decrypt_with_noise(cipher_text, self.lwe_params())
     }
+
+    /// Returns the signed decryption error of the cipher text,
+    /// i.e. the phase minus the nearest encoded message.
+    #[inline]
+    pub fn decryption_error(&self, cipher_text: &fhe_core::LweCiphertext<C>) -> i64 {
+        self.lwe_secret_key
+            .decryption_error(cipher_text, self.lwe_params())
+    }
 }
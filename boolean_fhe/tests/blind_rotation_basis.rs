@@ -0,0 +1,53 @@
+mod common;
+
+use boolean_fhe::DEFAULT_128_BITS_PARAMETERS;
+use common::generate_keys_and_evaluator;
+use fhe_core::FHECoreError;
+use rand::Rng;
+
+/// Returns the mean noise of `nand` outputs under a `2^basis_bits` blind rotation basis.
+fn nand_noise(basis_bits: u32) -> f64 {
+    const TRIALS: usize = 8;
+
+    let mut rng = rand::thread_rng();
+
+    let params = DEFAULT_128_BITS_PARAMETERS
+        .with_blind_rotation_basis_bits(basis_bits)
+        .unwrap();
+    assert_eq!(params.blind_rotation_basis_bits(), basis_bits);
+
+    let (_, enc, dec, eval) = generate_keys_and_evaluator(params, &mut rng);
+
+    let mut total = 0.0;
+    for _ in 0..TRIALS {
+        let a: bool = rng.gen();
+        let b: bool = rng.gen();
+
+        let c = eval.nand(&enc.encrypt(a, &mut rng), &enc.encrypt(b, &mut rng));
+        let (m, noise): (u8, u16) = dec.decrypt_with_noise(&c);
+        assert_eq!(m == 1, !(a && b));
+        total += noise as f64;
+    }
+    total / TRIALS as f64
+}
+
+#[test]
+fn test_blind_rotation_basis() {
+    // A smaller basis takes more digits, but each digit carries much less noise.
+    let small = nand_noise(3);
+    let large = nand_noise(8);
+    assert!(
+        small < large,
+        "2^3 basis noise {small} >= 2^8 basis noise {large}"
+    );
+}
+
+#[test]
+fn test_blind_rotation_basis_not_valid() {
+    for basis_bits in [0, 28] {
+        assert!(matches!(
+            DEFAULT_128_BITS_PARAMETERS.with_blind_rotation_basis_bits(basis_bits),
+            Err(FHECoreError::BlindRotationBasisUnValid { .. })
+        ));
+    }
+}
@@ -0,0 +1,90 @@
+//! The fixtures shared by the integration tests.
+//!
+//! Every test binary compiles its own copy of this module,
+//! so the fixtures a binary doesn't use are allowed to be dead.
+#![allow(dead_code)]
+
+use algebra::{
+    integer::UnsignedInteger,
+    modulus::PowOf2Modulus,
+    reduce::{ModulusValue, RingReduce},
+    Field, NttField, U32FieldEval,
+};
+use boolean_fhe::{
+    BooleanFheParameters, ConstParameters, Decryptor, Encryptor, Evaluator, KeyGen, SecretKeyPack,
+    Steps,
+};
+use fhe_core::{LweSecretKeyType, RingSecretKeyType};
+use rand::{CryptoRng, Rng};
+
+pub type Fp = U32FieldEval<132120577>;
+pub type Params = BooleanFheParameters<u16, PowOf2Modulus<u16>, Fp>;
+
+/// The parameters the tests start from, with the given `steps`.
+///
+/// Tests change the fields they care about with the struct update syntax.
+pub fn const_parameters(steps: Steps) -> ConstParameters<u16, u32> {
+    ConstParameters {
+        lwe_dimension: 512,
+        lwe_plain_modulus: 4,
+        lwe_cipher_modulus: ModulusValue::PowerOf2(1 << 14),
+        lwe_noise_standard_deviation: 3.20,
+        lwe_secret_key_type: LweSecretKeyType::Binary,
+        ring_dimension: 1024,
+        ring_modulus: Fp::MODULUS_VALUE,
+        ring_noise_standard_deviation: 3.20 * ((1 << 1) as f64),
+        ring_secret_key_type: RingSecretKeyType::Ternary,
+        blind_rotation_basis_bits: 7,
+        key_switching_basis_bits: 2,
+        key_switching_standard_deviation: 3.2 * ((1 << 1) as f64),
+        steps,
+    }
+}
+
+/// A secret key with its encryptor and decryptor.
+pub type Keys<C, LweModulus, Q> = (
+    SecretKeyPack<C, LweModulus, Q>,
+    Encryptor<C, LweModulus>,
+    Decryptor<C, LweModulus>,
+);
+
+/// A secret key with its encryptor, decryptor and evaluator.
+pub type KeysAndEvaluator<C, LweModulus, Q> = (
+    SecretKeyPack<C, LweModulus, Q>,
+    Encryptor<C, LweModulus>,
+    Decryptor<C, LweModulus>,
+    Evaluator<C, LweModulus, Q>,
+);
+
+/// Generates a secret key for `params`, with its encryptor and decryptor.
+pub fn generate_keys<C, LweModulus, Q, R>(
+    params: BooleanFheParameters<C, LweModulus, Q>,
+    rng: &mut R,
+) -> Keys<C, LweModulus, Q>
+where
+    C: UnsignedInteger,
+    LweModulus: RingReduce<C>,
+    Q: NttField,
+    R: Rng + CryptoRng,
+{
+    let sk = KeyGen::generate_secret_key(params, rng);
+    let enc = Encryptor::new(&sk);
+    let dec = Decryptor::new(&sk);
+    (sk, enc, dec)
+}
+
+/// Same as [`generate_keys`], also generating the evaluator of the secret key.
+pub fn generate_keys_and_evaluator<C, LweModulus, Q, R>(
+    params: BooleanFheParameters<C, LweModulus, Q>,
+    rng: &mut R,
+) -> KeysAndEvaluator<C, LweModulus, Q>
+where
+    C: UnsignedInteger,
+    LweModulus: RingReduce<C>,
+    Q: NttField,
+    R: Rng + CryptoRng,
+{
+    let (sk, enc, dec) = generate_keys(params, rng);
+    let eval = Evaluator::new(&sk, rng);
+    (sk, enc, dec, eval)
+}
@@ -0,0 +1,65 @@
+#![cfg(feature = "count")]
+
+mod common;
+
+use boolean_fhe::Steps;
+use common::{generate_keys_and_evaluator, Params};
+use fhe_core::{count, lwe_modulus_switch_assign};
+
+#[test]
+fn test_nand_counts() {
+    let mut rng = rand::thread_rng();
+
+    let params = Params::new(common::const_parameters(Steps::BrKsLevMs)).unwrap();
+    let (_, enc, _, eval) = generate_keys_and_evaluator(params, &mut rng);
+
+    let c0 = enc.encrypt(true, &mut rng);
+    let c1 = enc.encrypt(false, &mut rng);
+
+    // The blind rotation skips the zero `a_i` left after the first modulus switch.
+    let mut switched = c0.add_reduce_component_wise_ref(&c1, params.lwe_cipher_modulus());
+    lwe_modulus_switch_assign(
+        &mut switched,
+        params.lwe_cipher_modulus_value(),
+        (params.ring_dimension() << 1) as u16,
+    );
+    let external_products = switched.a().iter().filter(|&&a| a != 0).count();
+
+    count::external_product::clear();
+    count::key_switch::clear();
+    count::modulus_switch::clear();
+    count::external_product::enable();
+    count::key_switch::enable();
+    count::modulus_switch::enable();
+    count::clear_report();
+
+    let _ = eval.nand(&c0, &c1);
+
+    count::external_product::disable();
+    count::key_switch::disable();
+    count::modulus_switch::disable();
+
+    assert_eq!(count::external_product::get(), external_products);
+    assert_eq!(count::key_switch::get(), 1);
+    // q -> 2N before the blind rotation and Q -> q after the key switch.
+    assert_eq!(count::modulus_switch::get(), 2);
+
+    // Every external product takes the ntt of its decomposed polynomials.
+    let report = count::report();
+    let blind_rotation = report.get("blind_rotation").unwrap();
+    assert!(blind_rotation.get("ntt") >= external_products);
+    assert!(blind_rotation.get("mul") > 0);
+    assert_eq!(blind_rotation.get("external_product"), external_products);
+    assert_eq!(
+        report.get("modulus_switch"),
+        Some(&[("modulus_switch", 2)].into_iter().collect())
+    );
+    assert_eq!(report.get("key_switch").unwrap().get("key_switch"), 1);
+
+    // Disabled counters keep their counts.
+    let _ = eval.nand(&c0, &c1);
+    assert_eq!(count::key_switch::get(), 1);
+
+    count::key_switch::clear();
+    assert_eq!(count::key_switch::get(), 0);
+}
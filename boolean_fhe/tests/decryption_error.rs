@@ -0,0 +1,30 @@
+use algebra::reduce::{ReduceAddAssign, ReduceNeg};
+use boolean_fhe::{KeyGen, DEFAULT_128_BITS_PARAMETERS};
+
+type C = u16;
+
+#[test]
+fn test_decryption_error() {
+    let mut rng = rand::thread_rng();
+
+    let params = *DEFAULT_128_BITS_PARAMETERS;
+    let sk = KeyGen::generate_secret_key(params, &mut rng);
+    let modulus = sk.lwe_params().cipher_modulus;
+
+    for _ in 0..32 {
+        let m: bool = rand::random();
+        let c = sk.encrypt(m, &mut rng);
+
+        let error = sk.decryption_error(&c);
+        let (_, noise): (u8, C) = sk.decrypt_with_noise(&c);
+        assert_eq!(error.unsigned_abs(), noise as u64);
+
+        // Shifting `b` by a small delta moves the error by the same signed amount.
+        for delta in [3 as C, modulus.reduce_neg(3)] {
+            let mut shifted = c.clone();
+            modulus.reduce_add_assign(shifted.b_mut(), delta);
+            let expected = if delta == 3 { error + 3 } else { error - 3 };
+            assert_eq!(sk.decryption_error(&shifted), expected);
+        }
+    }
+}
@@ -0,0 +1,47 @@
+mod common;
+
+use boolean_fhe::DEFAULT_128_BITS_PARAMETERS;
+use common::generate_keys_and_evaluator;
+use rand::Rng;
+
+#[test]
+fn test_nand_elementwise() {
+    let mut rng = rand::thread_rng();
+
+    let params = *DEFAULT_128_BITS_PARAMETERS;
+
+    let (_, enc, dec, eval) = generate_keys_and_evaluator(params, &mut rng);
+
+    let n = 16;
+    let a: Vec<bool> = (0..n).map(|_| rng.gen()).collect();
+    let b: Vec<bool> = (0..n).map(|_| rng.gen()).collect();
+
+    let ca = enc.encrypt_slice(&a, &mut rng);
+    let cb = enc.encrypt_slice(&b, &mut rng);
+    assert_eq!(ca.len(), n);
+
+    for (c, &m) in ca.iter().zip(a.iter()) {
+        assert_eq!(dec.decrypt::<u8>(c) == 1, m);
+    }
+
+    let result = eval.nand_elementwise(&ca, &cb);
+    assert_eq!(result.len(), n);
+    for ((c, &x), &y) in result.iter().zip(a.iter()).zip(b.iter()) {
+        assert_eq!(dec.decrypt::<u8>(c) == 1, !(x && y));
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_nand_elementwise_length_mismatch() {
+    let mut rng = rand::thread_rng();
+
+    let params = *DEFAULT_128_BITS_PARAMETERS;
+
+    let (_, enc, _, eval) = generate_keys_and_evaluator(params, &mut rng);
+
+    let ca = enc.encrypt_slice(&[true, false, true], &mut rng);
+    let cb = enc.encrypt_slice(&[true, false], &mut rng);
+
+    eval.nand_elementwise(&ca, &cb);
+}
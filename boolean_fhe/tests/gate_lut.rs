@@ -0,0 +1,49 @@
+mod common;
+
+use boolean_fhe::{gate_lut, GateKind, DEFAULT_128_BITS_PARAMETERS};
+use common::generate_keys_and_evaluator;
+
+#[test]
+fn test_gate_lut() {
+    let mut rng = rand::thread_rng();
+
+    let params = *DEFAULT_128_BITS_PARAMETERS;
+    let cipher_modulus = params.lwe_cipher_modulus();
+
+    let (_, enc, dec, eval) = generate_keys_and_evaluator(params, &mut rng);
+
+    for (a, b) in [(false, false), (false, true), (true, false), (true, true)] {
+        let c0 = enc.encrypt(a, &mut rng);
+        let c1 = enc.encrypt(b, &mut rng);
+
+        let sum = c0.add_reduce_component_wise_ref(&c1, cipher_modulus);
+        let nand = eval.bootstrap(sum.clone(), gate_lut(GateKind::Nand, &params));
+        assert_eq!(dec.decrypt::<u8>(&nand) == 1, !(a && b));
+        assert_eq!(
+            dec.decrypt::<u8>(&nand),
+            dec.decrypt::<u8>(&eval.nand(&c0, &c1))
+        );
+
+        let or = eval.bootstrap(sum, gate_lut(GateKind::Or, &params));
+        assert_eq!(dec.decrypt::<u8>(&or) == 1, a || b);
+
+        let mut diff = c0.sub_reduce_component_wise_ref(&c1, cipher_modulus);
+        diff.mul_scalar_reduce_assign(2, cipher_modulus);
+        let xor = eval.bootstrap(diff, gate_lut(GateKind::Xor, &params));
+        assert_eq!(dec.decrypt::<u8>(&xor) == 1, a ^ b);
+    }
+}
+
+#[test]
+fn test_gate_lut_scaled_by_ring_delta() {
+    let params = *DEFAULT_128_BITS_PARAMETERS;
+
+    // The outputs `±1` of `Z_2t` are scaled by the stored `Δ`.
+    let delta = params.ring_delta_factor().value();
+    let q = params.ring_modulus();
+    let t: u32 = params.lwe_plain_modulus().into();
+    assert_eq!(delta, q / (2 * t));
+
+    let lut = gate_lut(GateKind::Nand, &params);
+    assert!(lut.iter().all(|&c| c == delta || c == q - delta));
+}
@@ -0,0 +1,87 @@
+mod common;
+
+use boolean_fhe::{ConstParameters, KeyGen, Steps};
+use common::{const_parameters, Params};
+use fhe_core::{LweSecretKeyType, RingSecretKeyType};
+
+const LWE_DIMENSION: usize = 1024;
+const RING_DIMENSION: usize = 1024;
+
+fn parameters(
+    lwe_secret_key_type: LweSecretKeyType,
+    ring_secret_key_type: RingSecretKeyType,
+) -> Params {
+    Params::new(ConstParameters {
+        lwe_dimension: LWE_DIMENSION,
+        lwe_secret_key_type,
+        ring_dimension: RING_DIMENSION,
+        ring_secret_key_type,
+        ..const_parameters(Steps::BrKsLevMs)
+    })
+    .unwrap()
+}
+
+/// Checks that `weight` is within 6 standard deviations of the mean
+/// of the binomial distribution `B(n, 1/2)`.
+fn assert_half_weight(weight: usize, n: usize) {
+    let mean = n as f64 / 2.0;
+    let std_dev = (n as f64).sqrt() / 2.0;
+    assert!(
+        (weight as f64 - mean).abs() <= 6.0 * std_dev,
+        "weight {weight} of {n} coefficients"
+    );
+}
+
+#[test]
+fn test_secret_key_hamming_weight() {
+    let mut rng = rand::thread_rng();
+
+    // Both the binary and the ternary keys have nonzero coefficients with probability 1/2.
+    for (lwe_type, ring_type) in [
+        (LweSecretKeyType::Ternary, RingSecretKeyType::Ternary),
+        (LweSecretKeyType::Binary, RingSecretKeyType::Binary),
+    ] {
+        let sk = KeyGen::generate_secret_key(parameters(lwe_type, ring_type), &mut rng);
+
+        let lwe_weight = sk.lwe_secret_hamming_weight();
+        assert_eq!(
+            lwe_weight,
+            sk.lwe_secret_key()
+                .as_ref()
+                .iter()
+                .filter(|&&v| v != 0)
+                .count()
+        );
+        assert_half_weight(lwe_weight, LWE_DIMENSION);
+
+        let ring_weight = sk.rlwe_secret_hamming_weight();
+        assert_eq!(
+            ring_weight,
+            sk.rlwe_secret_key().iter().filter(|&&v| v != 0).count()
+        );
+        assert_half_weight(ring_weight, RING_DIMENSION);
+    }
+
+    let sk = KeyGen::generate_secret_key(
+        parameters(
+            LweSecretKeyType::Ternary,
+            RingSecretKeyType::SparseTernary(128),
+        ),
+        &mut rng,
+    );
+    assert_eq!(sk.rlwe_secret_hamming_weight(), 128);
+
+    let sk = KeyGen::generate_secret_key(
+        parameters(
+            LweSecretKeyType::SparseTernary(256),
+            RingSecretKeyType::Ternary,
+        ),
+        &mut rng,
+    );
+    assert_eq!(sk.lwe_secret_hamming_weight(), 256);
+    assert!(sk
+        .lwe_secret_key()
+        .as_ref()
+        .iter()
+        .all(|&v| v == 0 || v == 1 || v == (1 << 14) - 1));
+}
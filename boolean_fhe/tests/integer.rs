@@ -0,0 +1,90 @@
+mod common;
+
+use boolean_fhe::{EncryptedInt, IntEvaluator, DEFAULT_128_BITS_PARAMETERS};
+use common::generate_keys_and_evaluator;
+use rand::Rng;
+
+#[test]
+fn test_int_add_mul() {
+    let mut rng = rand::thread_rng();
+
+    let params = *DEFAULT_128_BITS_PARAMETERS;
+
+    let (_, enc, dec, eval) = generate_keys_and_evaluator(params, &mut rng);
+    let int_eval = IntEvaluator::new(&eval);
+
+    let x: u8 = rng.gen();
+    let y: u8 = rng.gen();
+
+    let cx = enc.encrypt_int(x as u64, 8, &mut rng);
+    let cy = enc.encrypt_int(y as u64, 8, &mut rng);
+    assert_eq!(cx.width(), 8);
+    assert_eq!(dec.decrypt_int(&cx), x as u64);
+
+    let sum = int_eval.add(&cx, &cy);
+    assert_eq!(dec.decrypt_int(&sum), x.wrapping_add(y) as u64);
+
+    let product = int_eval.mul(&cx, &cy);
+    assert_eq!(product.width(), 8);
+    assert_eq!(dec.decrypt_int(&product), x.wrapping_mul(y) as u64);
+}
+
+#[test]
+fn test_int_neg() {
+    let mut rng = rand::thread_rng();
+
+    let params = *DEFAULT_128_BITS_PARAMETERS;
+
+    let (_, enc, dec, eval) = generate_keys_and_evaluator(params, &mut rng);
+    let int_eval = IntEvaluator::new(&eval);
+
+    let x: i8 = rng.gen_range(1..=i8::MAX);
+
+    let cx = enc.encrypt_sint(x as i64, 8, &mut rng);
+    assert_eq!(dec.decrypt_sint(&cx), x as i64);
+
+    let neg = int_eval.neg(&cx);
+    assert_eq!(neg.width(), 8);
+    assert_eq!(dec.decrypt_sint(&neg), -(x as i64));
+    assert_eq!(dec.decrypt_sint(&int_eval.neg(&neg)), x as i64);
+
+    let min = enc.encrypt_sint(i8::MIN as i64, 8, &mut rng);
+    assert_eq!(dec.decrypt_sint(&min), i8::MIN as i64);
+    assert_eq!(dec.decrypt_sint(&int_eval.neg(&min)), i8::MIN as i64);
+
+    let zero = enc.encrypt_sint(0, 8, &mut rng);
+    assert_eq!(dec.decrypt_sint(&int_eval.neg(&zero)), 0);
+
+    let bit = enc.encrypt_sint(-1, 1, &mut rng);
+    assert_eq!(dec.decrypt_sint(&bit), -1);
+    assert_eq!(dec.decrypt_sint(&int_eval.neg(&bit)), -1);
+}
+
+#[test]
+#[should_panic(expected = "At least one bit is required!")]
+fn test_int_zero_width() {
+    EncryptedInt::<u16>::new(Vec::new());
+}
+
+#[test]
+fn test_int_comparison() {
+    let mut rng = rand::thread_rng();
+
+    let params = *DEFAULT_128_BITS_PARAMETERS;
+
+    let (_, enc, dec, eval) = generate_keys_and_evaluator(params, &mut rng);
+    let int_eval = IntEvaluator::new(&eval);
+
+    let x: u64 = rng.gen_range(0..16);
+    let mut pairs = vec![(x, x)];
+    pairs.extend((0..4).map(|_| (rng.gen_range(0..16), rng.gen_range(0..16))));
+
+    for (x, y) in pairs {
+        let cx = enc.encrypt_int(x, 4, &mut rng);
+        let cy = enc.encrypt_int(y, 4, &mut rng);
+
+        assert_eq!(dec.decrypt::<u8>(&int_eval.gt(&cx, &cy)) == 1, x > y);
+        assert_eq!(dec.decrypt::<u8>(&int_eval.lt(&cx, &cy)) == 1, x < y);
+        assert_eq!(dec.decrypt::<u8>(&int_eval.eq(&cx, &cy)) == 1, x == y);
+    }
+}
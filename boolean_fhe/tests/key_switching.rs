@@ -0,0 +1,40 @@
+mod common;
+
+use algebra::Field;
+use boolean_fhe::{KeyGen, KeySwitchingKey, Steps};
+use common::{Fp, Params};
+use lattice::Lwe;
+use rand::{distributions::Uniform, Rng};
+
+#[test]
+fn test_key_switching_key_with_seed() {
+    let mut rng = rand::thread_rng();
+
+    let params = Params::new(common::const_parameters(Steps::BrKsLevMs)).unwrap();
+    let sk = KeyGen::generate_secret_key(params, &mut rng);
+
+    let seed: [u8; 32] = rng.gen();
+    let ksk1 = KeySwitchingKey::generate_with_seed(&sk, seed);
+    let ksk2 = KeySwitchingKey::generate_with_seed(&sk, seed);
+
+    let mut other_seed = seed;
+    other_seed[0] ^= 1;
+    let ksk3 = KeySwitchingKey::generate_with_seed(&sk, other_seed);
+
+    let (ksk1, ksk2, ksk3) = (
+        ksk1.as_non_pow_of_2_modulus_lwe().unwrap(),
+        ksk2.as_non_pow_of_2_modulus_lwe().unwrap(),
+        ksk3.as_non_pow_of_2_modulus_lwe().unwrap(),
+    );
+
+    let distr = Uniform::new_inclusive(0, Fp::MINUS_ONE);
+    let a: Vec<u32> = (&mut rng)
+        .sample_iter(distr)
+        .take(params.ring_dimension())
+        .collect();
+    let cipher = Lwe::new(a, rng.sample(distr));
+
+    let switched = ksk1.key_switch(&cipher, Fp::MODULUS);
+    assert_eq!(switched, ksk2.key_switch(&cipher, Fp::MODULUS));
+    assert_ne!(switched, ksk3.key_switch(&cipher, Fp::MODULUS));
+}
@@ -0,0 +1,44 @@
+mod common;
+
+use algebra::reduce::ModulusValue;
+use boolean_fhe::DEFAULT_128_BITS_PARAMETERS;
+use common::generate_keys_and_evaluator;
+
+type C = u16;
+
+#[test]
+fn test_lut_select() {
+    let mut rng = rand::thread_rng();
+
+    let params = *DEFAULT_128_BITS_PARAMETERS;
+
+    let noise_max = match params.lwe_cipher_modulus_value() {
+        ModulusValue::Native => (2.0f64.powi(C::BITS as i32 - 2)) as C,
+        ModulusValue::PowerOf2(q) | ModulusValue::Prime(q) | ModulusValue::Others(q) => {
+            (q as f64 / 8.0) as C
+        }
+    };
+
+    let (_, enc, dec, eval) = generate_keys_and_evaluator(params, &mut rng);
+
+    // 2-input xor table and 3-input full adder carry table
+    let tables: [&[bool]; 2] = [
+        &[false, true, true, false],
+        &[false, false, false, true, false, true, true, true],
+    ];
+
+    for table in tables {
+        let k = table.len().trailing_zeros() as usize;
+        for index in 0..table.len() {
+            let selectors: Vec<_> = (0..k)
+                .map(|i| enc.encrypt(((index >> i) & 1) as u8, &mut rng))
+                .collect();
+
+            let c = eval.lut_select(&selectors, table);
+
+            let (m, noise) = dec.decrypt_with_noise::<u8>(&c);
+            assert_eq!(m == 1, table[index], "Index: {index}");
+            assert!(noise < noise_max, "Noise: {noise} >= {noise_max}");
+        }
+    }
+}
@@ -0,0 +1,43 @@
+mod common;
+
+use boolean_fhe::{ConstParameters, Steps};
+use common::{generate_keys_and_evaluator, Params};
+use rand::Rng;
+
+fn const_parameters(lwe_dimension: usize, steps: Steps) -> ConstParameters<u16, u32> {
+    ConstParameters {
+        lwe_dimension,
+        ..common::const_parameters(steps)
+    }
+}
+
+#[test]
+fn test_non_pow_of_2_lwe_dimension() {
+    let mut rng = rand::thread_rng();
+
+    for (lwe_dimension, steps) in [571, 630]
+        .into_iter()
+        .flat_map(|n| [(n, Steps::BrKsLevMs), (n, Steps::BrKsRlevMs)])
+    {
+        let params = Params::new(const_parameters(lwe_dimension, steps)).unwrap();
+
+        let (_, enc, dec, eval) = generate_keys_and_evaluator(params, &mut rng);
+
+        for _ in 0..4 {
+            let a: bool = rng.gen();
+            let b: bool = rng.gen();
+
+            let ca = enc.encrypt(a, &mut rng);
+            let cb = enc.encrypt(b, &mut rng);
+            assert_eq!(ca.a().len(), lwe_dimension);
+            assert_eq!(dec.decrypt::<u8>(&ca) == 1, a);
+
+            let c = eval.nand(&ca, &cb);
+            assert_eq!(c.a().len(), lwe_dimension);
+            assert_eq!(dec.decrypt::<u8>(&c) == 1, !(a && b));
+
+            let c = eval.xor(&c, &ca);
+            assert_eq!(dec.decrypt::<u8>(&c) == 1, !(a && b) ^ a);
+        }
+    }
+}
@@ -0,0 +1,78 @@
+//! Runs the whole boolean pipeline over a field whose ntt table is
+//! [`NaiveNttTable`], to check that another ntt backend can be plugged in.
+
+mod common;
+
+use algebra::{
+    modulus::{BarrettModulus, PowOf2Modulus},
+    ntt::{NaiveNttTable, NttTable},
+    reduce::ModulusValue,
+    AlgebraError, Field, NttField,
+};
+use boolean_fhe::{BooleanFheParameters, ConstParameters, Steps};
+use common::{const_parameters, generate_keys_and_evaluator};
+use rand::Rng;
+
+const P: u32 = 132120577;
+
+#[derive(Debug, Clone, Copy)]
+struct NaiveField;
+
+impl Field for NaiveField {
+    type ValueT = u32;
+    type Modulus = BarrettModulus<u32>;
+
+    const MODULUS_VALUE: u32 = P;
+    const MODULUS: Self::Modulus = <BarrettModulus<u32>>::new(P);
+    const ZERO: u32 = 0;
+    const ONE: u32 = 1;
+    const MINUS_ONE: u32 = P - 1;
+}
+
+impl NttField for NaiveField {
+    type Table = NaiveNttTable<Self>;
+
+    #[inline]
+    fn generate_ntt_table(log_n: u32) -> Result<Self::Table, AlgebraError> {
+        NttTable::new(Self::MODULUS, log_n)
+    }
+}
+
+type Params = BooleanFheParameters<u16, PowOf2Modulus<u16>, NaiveField>;
+
+/// Tiny and insecure parameters, so that the `O(n^2)` transforms stay fast.
+fn tiny_parameters() -> Params {
+    Params::new(ConstParameters {
+        lwe_dimension: 16,
+        lwe_cipher_modulus: ModulusValue::PowerOf2(1 << 10),
+        ring_dimension: 256,
+        ring_modulus: P,
+        ..const_parameters(Steps::BrKsRlevMs)
+    })
+    .unwrap()
+}
+
+#[test]
+fn test_naive_ntt_pipeline() {
+    let mut rng = rand::thread_rng();
+
+    let params = tiny_parameters();
+    let (_, enc, dec, eval) = generate_keys_and_evaluator(params, &mut rng);
+
+    for _ in 0..4 {
+        let a: bool = rng.gen();
+        let b: bool = rng.gen();
+
+        let ca = enc.encrypt(a, &mut rng);
+        let cb = enc.encrypt(b, &mut rng);
+
+        let c = eval.nand(&ca, &cb);
+        assert_eq!(dec.decrypt::<u8>(&c) == 1, !(a && b));
+
+        let c = eval.xor(&c, &ca);
+        assert_eq!(dec.decrypt::<u8>(&c) == 1, !(a && b) ^ a);
+
+        let c = eval.and(&c, &cb);
+        assert_eq!(dec.decrypt::<u8>(&c) == 1, (!(a && b) ^ a) && b);
+    }
+}
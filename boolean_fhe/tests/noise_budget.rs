@@ -0,0 +1,31 @@
+mod common;
+
+use boolean_fhe::DEFAULT_128_BITS_PARAMETERS;
+use common::generate_keys;
+
+#[test]
+fn test_max_linear_ops_before_bootstrap() {
+    let mut rng = rand::thread_rng();
+
+    let params = *DEFAULT_128_BITS_PARAMETERS;
+    let predicted = params.max_linear_ops_before_bootstrap();
+    assert!(predicted > 0);
+
+    let (_, enc, dec) = generate_keys(params, &mut rng);
+    let cipher_modulus = params.lwe_cipher_modulus();
+
+    // Keep adding fresh encryptions of `0` until decryption fails.
+    let limit = predicted * 100;
+    let mut acc = enc.encrypt(0u8, &mut rng);
+    let mut adds = 0;
+    while adds < limit {
+        let c = enc.encrypt(0u8, &mut rng);
+        acc.add_reduce_assign_component_wise(&c, cipher_modulus);
+        if dec.decrypt::<u8>(&acc) != 0 {
+            break;
+        }
+        adds += 1;
+    }
+
+    assert!(predicted <= adds, "Predicted: {predicted} > {adds}");
+}
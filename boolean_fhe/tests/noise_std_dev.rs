@@ -0,0 +1,91 @@
+mod common;
+
+use algebra::Field;
+use boolean_fhe::{ConstParameters, Steps};
+use common::{generate_keys_and_evaluator, Fp, Params};
+use fhe_core::BlindRotationKey;
+use rand::Rng;
+
+const SAMPLES: usize = 1024;
+
+fn const_parameters(lwe_std_dev: f64, ring_std_dev: f64) -> ConstParameters<u16, u32> {
+    ConstParameters {
+        lwe_noise_standard_deviation: lwe_std_dev,
+        ring_noise_standard_deviation: ring_std_dev,
+        ..common::const_parameters(Steps::BrMsKs)
+    }
+}
+
+fn std_dev(errors: &[i64]) -> f64 {
+    let n = errors.len() as f64;
+    let mean = errors.iter().sum::<i64>() as f64 / n;
+    let variance = errors
+        .iter()
+        .map(|&e| (e as f64 - mean).powi(2))
+        .sum::<f64>()
+        / n;
+    variance.sqrt()
+}
+
+fn assert_close(measured: f64, expected: f64) {
+    assert!(
+        (measured - expected).abs() <= expected * 0.25,
+        "measured std-dev {measured} is too far from {expected}"
+    );
+}
+
+fn check_noise(lwe_std_dev: f64, ring_std_dev: f64) {
+    let mut rng = rand::thread_rng();
+
+    let params = Params::new(const_parameters(lwe_std_dev, ring_std_dev)).unwrap();
+    assert_eq!(params.lwe_noise_standard_deviation(), lwe_std_dev);
+    assert_eq!(params.ring_noise_standard_deviation(), ring_std_dev);
+
+    let (sk, _, _, evaluator) = generate_keys_and_evaluator(params, &mut rng);
+
+    // LWE encryption samples from the lwe noise.
+    let lwe_errors: Vec<i64> = (0..SAMPLES)
+        .map(|_| {
+            let c = sk.encrypt(rng.gen::<bool>(), &mut rng);
+            sk.decryption_error(&c)
+        })
+        .collect();
+    assert_close(std_dev(&lwe_errors), lwe_std_dev);
+
+    // The blind rotation key made by the key generation is encrypted under the ring noise.
+    let rgsw = match evaluator.blind_rotation_key() {
+        BlindRotationKey::Binary(key) => &key.key()[0],
+        BlindRotationKey::Ternary(key) => &key.key()[0].0,
+    };
+
+    // The message of each gadget row only lies in the constant term,
+    // the other coefficients of the phase are pure noise.
+    let ntt_table = sk.ntt_table();
+    let half = Fp::MODULUS_VALUE / 2;
+    let ring_errors: Vec<i64> = rgsw
+        .m()
+        .iter()
+        .flat_map(|row| {
+            let c = row.clone().to_rlwe(ntt_table);
+            let phase = sk.rlwe_secret_key().phase(&c, ntt_table);
+            phase
+                .iter()
+                .skip(1)
+                .map(|&e| {
+                    if e > half {
+                        e as i64 - Fp::MODULUS_VALUE as i64
+                    } else {
+                        e as i64
+                    }
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect();
+    assert_close(std_dev(&ring_errors), ring_std_dev);
+}
+
+#[test]
+fn test_independent_noise_std_devs() {
+    check_noise(1.0, 16.0);
+    check_noise(16.0, 1.0);
+}
@@ -0,0 +1,29 @@
+mod common;
+
+use boolean_fhe::DEFAULT_128_BITS_PARAMETERS;
+use common::generate_keys_and_evaluator;
+use rand::Rng;
+
+#[test]
+fn test_not_chained() {
+    let mut rng = rand::thread_rng();
+
+    let params = *DEFAULT_128_BITS_PARAMETERS;
+
+    let (_, enc, dec, eval) = generate_keys_and_evaluator(params, &mut rng);
+
+    for _ in 0..8 {
+        let a: bool = rng.gen();
+        let b: bool = rng.gen();
+
+        let ca = enc.encrypt(a, &mut rng);
+        let cb = enc.encrypt(b, &mut rng);
+
+        let not_a = eval.not(&ca);
+        assert_eq!(dec.decrypt::<u8>(&not_a) == 1, !a);
+        assert_eq!(dec.decrypt::<u8>(&eval.not(&not_a)) == 1, a);
+
+        let and = eval.and(&not_a, &cb);
+        assert_eq!(dec.decrypt::<u8>(&and) == 1, !a && b);
+    }
+}
@@ -0,0 +1,101 @@
+#![cfg(feature = "serde")]
+
+mod common;
+
+use boolean_fhe::{Steps, DEFAULT_128_BITS_PARAMETERS};
+use common::Params;
+use fhe_core::{FHECoreError, LweSecretKeyType, RingSecretKeyType};
+
+#[test]
+fn test_parameters_toml_round_trip() {
+    let params = *DEFAULT_128_BITS_PARAMETERS;
+
+    let s = params.to_toml();
+    let reloaded = Params::from_toml(&s).unwrap();
+
+    assert_eq!(reloaded.lwe_dimension(), params.lwe_dimension());
+    assert_eq!(reloaded.lwe_plain_modulus(), params.lwe_plain_modulus());
+    assert_eq!(
+        reloaded.lwe_cipher_modulus_value(),
+        params.lwe_cipher_modulus_value()
+    );
+    assert_eq!(
+        reloaded.lwe_noise_standard_deviation(),
+        params.lwe_noise_standard_deviation()
+    );
+    assert_eq!(reloaded.lwe_secret_key_type(), params.lwe_secret_key_type());
+    assert_eq!(reloaded.ring_dimension(), params.ring_dimension());
+    assert_eq!(reloaded.ring_modulus(), params.ring_modulus());
+    assert_eq!(
+        reloaded.ring_noise_standard_deviation(),
+        params.ring_noise_standard_deviation()
+    );
+    assert_eq!(
+        reloaded.ring_secret_key_type(),
+        params.ring_secret_key_type()
+    );
+    assert_eq!(
+        reloaded.blind_rotation_basis_bits(),
+        params.blind_rotation_basis_bits()
+    );
+    assert_eq!(reloaded.steps(), params.steps());
+    assert_eq!(
+        reloaded.key_switching_basis_bits(),
+        params.key_switching_basis_bits()
+    );
+    assert_eq!(
+        reloaded.key_switching_noise_standard_deviation(),
+        params.key_switching_noise_standard_deviation()
+    );
+
+    assert_eq!(reloaded.to_toml(), s);
+}
+
+#[test]
+fn test_parameters_from_toml() {
+    let s = r#"
+        lwe_dimension = 512
+        lwe_plain_modulus = 4
+        lwe_cipher_modulus = { power_of_2 = 2048 }
+        lwe_noise_standard_deviation = 3.2
+        lwe_secret_key_type = "ternary"
+        ring_dimension = 1024
+        ring_modulus = 132120577
+        ring_noise_standard_deviation = 3.2
+        ring_secret_key_type = { sparse_ternary = 128 }
+        blind_rotation_basis_bits = 6
+        steps = "br_ks_lev_ms"
+        key_switching_basis_bits = 3
+        key_switching_standard_deviation = 6.4
+    "#;
+
+    let params = Params::from_toml(s).unwrap();
+    assert_eq!(params.lwe_dimension(), 512);
+    assert_eq!(params.lwe_secret_key_type(), LweSecretKeyType::Ternary);
+    assert_eq!(
+        params.ring_secret_key_type(),
+        RingSecretKeyType::SparseTernary(128)
+    );
+    assert_eq!(params.blind_rotation_basis_bits(), 6);
+    assert_eq!(params.steps(), Steps::BrKsLevMs);
+
+    // The builder rejects a ring dimension which is not a power of two.
+    let invalid = s.replace("ring_dimension = 1024", "ring_dimension = 1000");
+    assert!(matches!(
+        Params::from_toml(&invalid),
+        Err(FHECoreError::RingDimensionUnValid(1000))
+    ));
+
+    for invalid in [
+        s.replace("ring_modulus = 132120577", "ring_modulus = 1073479681"),
+        s.replace("{ power_of_2 = 2048 }", "{ power_of_2 = 65536 }"),
+        s.replace("steps = \"br_ks_lev_ms\"", ""),
+        s.replace("= 1024", "= -1024"),
+        "lwe_dimension".to_string(),
+    ] {
+        assert!(matches!(
+            Params::from_toml(&invalid),
+            Err(FHECoreError::ParametersFormatErr(_))
+        ));
+    }
+}
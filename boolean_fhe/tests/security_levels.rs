@@ -0,0 +1,90 @@
+mod common;
+
+use algebra::{modulus::PowOf2Modulus, NttField};
+use boolean_fhe::{
+    BooleanFheParameters, Decryptor, Encryptor, Evaluator, KeyGen, Steps,
+    DEFAULT_128_BITS_PARAMETERS, DEFAULT_192_BITS_PARAMETERS, DEFAULT_80_BITS_PARAMETERS,
+    DEFAULT_BINARY_128_BITS_PARAMETERS, DEFAULT_BINARY_192_BITS_PARAMETERS,
+    DEFAULT_BINARY_80_BITS_PARAMETERS,
+};
+use common::Params;
+use fhe_core::LweSecretKeyType;
+use rand::Rng;
+
+fn check_nand<Q: NttField>(params: BooleanFheParameters<u16, PowOf2Modulus<u16>, Q>) {
+    let mut rng = rand::thread_rng();
+
+    let sk = KeyGen::generate_secret_key(params, &mut rng);
+    let enc = Encryptor::new(&sk);
+    let dec = Decryptor::new(&sk);
+    let eval = Evaluator::new(&sk, &mut rng);
+
+    for _ in 0..4 {
+        let a: bool = rng.gen();
+        let b: bool = rng.gen();
+
+        let ca = enc.encrypt(a, &mut rng);
+        let cb = enc.encrypt(b, &mut rng);
+
+        let c = eval.nand(&ca, &cb);
+        assert_eq!(dec.decrypt::<u8>(&c) == 1, !(a && b));
+    }
+}
+
+#[test]
+fn test_security_levels_nand() {
+    check_nand(*DEFAULT_80_BITS_PARAMETERS);
+    check_nand(*DEFAULT_128_BITS_PARAMETERS);
+    check_nand(*DEFAULT_192_BITS_PARAMETERS);
+    check_nand(*DEFAULT_BINARY_80_BITS_PARAMETERS);
+    check_nand(*DEFAULT_BINARY_128_BITS_PARAMETERS);
+    check_nand(*DEFAULT_BINARY_192_BITS_PARAMETERS);
+}
+
+/// Checks that the estimate reaches the `level` but not the next one.
+fn check_security<Q: NttField>(
+    params: BooleanFheParameters<u16, PowOf2Modulus<u16>, Q>,
+    level: f64,
+    next_level: f64,
+) {
+    let bits = params.security_bits();
+    assert!(
+        level <= bits && bits < next_level,
+        "{bits} bits is not in [{level}, {next_level})"
+    );
+}
+
+#[test]
+fn test_security_levels_bracket_estimates() {
+    check_security(*DEFAULT_80_BITS_PARAMETERS, 80.0, 128.0);
+    check_security(*DEFAULT_128_BITS_PARAMETERS, 128.0, 192.0);
+    check_security(*DEFAULT_192_BITS_PARAMETERS, 192.0, 256.0);
+    check_security(*DEFAULT_BINARY_80_BITS_PARAMETERS, 80.0, 128.0);
+    check_security(*DEFAULT_BINARY_128_BITS_PARAMETERS, 128.0, 192.0);
+    check_security(*DEFAULT_BINARY_192_BITS_PARAMETERS, 192.0, 256.0);
+
+    // A binary secret key is weaker than a ternary one on the same ring.
+    assert!(
+        DEFAULT_BINARY_128_BITS_PARAMETERS.security_bits()
+            < DEFAULT_128_BITS_PARAMETERS.security_bits()
+    );
+    assert_eq!(
+        DEFAULT_BINARY_128_BITS_PARAMETERS.lwe_secret_key_type(),
+        LweSecretKeyType::Binary
+    );
+}
+
+#[test]
+fn test_security_bits_key_switching() {
+    // The key switching key modulo `Q` is far weaker than the LWE ciphertexts modulo `q`.
+    let mut params = common::const_parameters(Steps::BrKsLevMs);
+    let mod_ring = Params::new(params).unwrap();
+    params.steps = Steps::BrMsKs;
+    let mod_lwe = Params::new(params).unwrap();
+
+    assert_eq!(
+        mod_lwe.security_bits(),
+        mod_lwe.lwe_params().security_bits()
+    );
+    assert!(mod_ring.security_bits() < mod_lwe.security_bits() - 40.0);
+}
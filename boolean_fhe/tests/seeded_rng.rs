@@ -0,0 +1,30 @@
+use boolean_fhe::{Encryptor, Evaluator, KeyGen, DEFAULT_128_BITS_PARAMETERS};
+use rand::{rngs::StdRng, SeedableRng};
+
+#[test]
+fn test_same_seed_same_keys_and_ciphertexts() {
+    let params = *DEFAULT_128_BITS_PARAMETERS;
+
+    let sk0 = KeyGen::generate_secret_key(params, &mut StdRng::seed_from_u64(1));
+    let sk1 = KeyGen::generate_secret_key(params, &mut StdRng::seed_from_u64(1));
+    assert_eq!(sk0.lwe_secret_key().as_ref(), sk1.lwe_secret_key().as_ref());
+    assert_eq!(
+        sk0.rlwe_secret_key().as_slice(),
+        sk1.rlwe_secret_key().as_slice()
+    );
+
+    let mut rng0 = StdRng::seed_from_u64(2);
+    let mut rng1 = StdRng::seed_from_u64(2);
+    let enc = Encryptor::new(&sk0);
+    let c0 = [false, true].map(|m| enc.encrypt(m, &mut rng0));
+    let c1 = [false, true].map(|m| enc.encrypt(m, &mut rng1));
+    assert_eq!(c0, c1);
+
+    // The bootstrapping is deterministic, so equal evaluation keys give equal outputs.
+    let eval0 = Evaluator::new(&sk0, &mut StdRng::seed_from_u64(3));
+    let eval1 = Evaluator::new(&sk1, &mut StdRng::seed_from_u64(3));
+    assert_eq!(eval0.nand(&c0[0], &c0[1]), eval1.nand(&c0[0], &c0[1]));
+
+    let sk2 = KeyGen::generate_secret_key(params, &mut StdRng::seed_from_u64(4));
+    assert_ne!(sk0.lwe_secret_key().as_ref(), sk2.lwe_secret_key().as_ref());
+}
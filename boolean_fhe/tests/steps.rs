@@ -0,0 +1,87 @@
+mod common;
+
+use boolean_fhe::Steps;
+use common::{const_parameters, generate_keys_and_evaluator, Params};
+use fhe_core::{FHECoreError, LweSecretKeyType, RingSecretKeyType};
+use rand::Rng;
+
+fn check_nand(params: Params) {
+    let mut rng = rand::thread_rng();
+
+    let (_, enc, dec, eval) = generate_keys_and_evaluator(params, &mut rng);
+
+    for _ in 0..4 {
+        let a: bool = rng.gen();
+        let b: bool = rng.gen();
+
+        let ca = enc.encrypt(a, &mut rng);
+        let cb = enc.encrypt(b, &mut rng);
+
+        let c = eval.nand(&ca, &cb);
+        assert_eq!(dec.decrypt::<u8>(&c) == 1, !(a && b));
+    }
+}
+
+#[test]
+fn test_steps_br_ks_rlev_ms() {
+    check_nand(Params::new(const_parameters(Steps::BrKsRlevMs)).unwrap());
+}
+
+#[test]
+fn test_steps_br_ks_lev_ms() {
+    check_nand(Params::new(const_parameters(Steps::BrKsLevMs)).unwrap());
+}
+
+#[test]
+fn test_steps_br_ms_ks() {
+    // The key switching works modulo `q`, so it needs a smaller noise.
+    let mut params = const_parameters(Steps::BrMsKs);
+    params.key_switching_standard_deviation = 1.0;
+    check_nand(Params::new(params).unwrap());
+}
+
+#[test]
+fn test_steps_br_ms() {
+    let mut params = const_parameters(Steps::BrMs);
+    params.ring_dimension = 512;
+    check_nand(Params::new(params).unwrap());
+}
+
+#[test]
+fn test_sparse_ternary_ring_secret_key() {
+    let mut params = const_parameters(Steps::BrKsLevMs);
+    params.ring_secret_key_type = RingSecretKeyType::SparseTernary(256);
+    check_nand(Params::new(params).unwrap());
+}
+
+#[test]
+fn test_sparse_ternary_lwe_secret_key() {
+    let mut params = const_parameters(Steps::BrKsLevMs);
+    params.lwe_secret_key_type = LweSecretKeyType::SparseTernary(128);
+    check_nand(Params::new(params).unwrap());
+}
+
+#[test]
+fn test_steps_not_compatible() {
+    let is_not_compatible = |params| {
+        matches!(
+            Params::new(params),
+            Err(FHECoreError::StepsParametersNotCompatible)
+        )
+    };
+
+    // `n` must equal `N`.
+    assert!(is_not_compatible(const_parameters(Steps::BrMs)));
+
+    // `n` padded to a power of two must not exceed `N`.
+    let mut params = const_parameters(Steps::BrKsRlevMs);
+    params.lwe_dimension = 1100;
+    assert!(is_not_compatible(params));
+
+    // The ring secret key can't be turned into an LWE secret key.
+    for steps in [Steps::BrMsKs, Steps::BrKsLevMs] {
+        let mut params = const_parameters(steps);
+        params.ring_secret_key_type = RingSecretKeyType::Gaussian;
+        assert!(is_not_compatible(params));
+    }
+}
@@ -0,0 +1,55 @@
+mod common;
+
+use boolean_fhe::DEFAULT_128_BITS_PARAMETERS;
+use common::generate_keys;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+#[test]
+fn test_encrypt_stream() {
+    let mut rng = rand::thread_rng();
+
+    let params = *DEFAULT_128_BITS_PARAMETERS;
+
+    let (_, enc, dec) = generate_keys(params, &mut rng);
+
+    let bits: Vec<bool> = (0..64).map(|_| rng.gen()).collect();
+    let seed: u64 = rng.gen();
+
+    let expected = enc.encrypt_slice(&bits, &mut StdRng::seed_from_u64(seed));
+
+    let mut stream_rng = StdRng::seed_from_u64(seed);
+    let streamed: Vec<_> = enc
+        .encrypt_stream(bits.iter().copied(), &mut stream_rng)
+        .collect();
+    assert_eq!(streamed, expected);
+
+    for (c, &m) in streamed.iter().zip(bits.iter()) {
+        assert_eq!(dec.decrypt::<u8>(c) == 1, m);
+    }
+
+    // Ciphertexts are produced lazily, one per pulled item.
+    let mut stream = enc.encrypt_stream(core::iter::repeat(true), &mut rng);
+    for _ in 0..8 {
+        let c = stream.next().unwrap();
+        assert_eq!(dec.decrypt::<u8>(&c), 1);
+    }
+}
+
+#[test]
+fn test_decrypt_batch() {
+    let mut rng = rand::thread_rng();
+
+    let params = *DEFAULT_128_BITS_PARAMETERS;
+
+    let (_, enc, dec) = generate_keys(params, &mut rng);
+
+    let bits: Vec<bool> = (0..256).map(|_| rng.gen()).collect();
+    let ciphertexts = enc.encrypt_slice(&bits, &mut rng);
+
+    let sequential: Vec<u8> = ciphertexts.iter().map(|c| dec.decrypt(c)).collect();
+    let batch: Vec<u8> = dec.decrypt_batch(&ciphertexts);
+    assert_eq!(batch, sequential);
+    assert!(batch.iter().zip(bits.iter()).all(|(&m, &b)| (m == 1) == b));
+
+    assert!(dec.decrypt_batch::<u8>(&[]).is_empty());
+}
@@ -0,0 +1,50 @@
+mod common;
+
+use boolean_fhe::DEFAULT_128_BITS_PARAMETERS;
+use common::generate_keys_and_evaluator;
+use rand::Rng;
+
+fn to_bits(word: u16) -> Vec<bool> {
+    (0..16).map(|i| (word >> i) & 1 == 1).collect()
+}
+
+#[test]
+fn test_word_gates() {
+    let mut rng = rand::thread_rng();
+
+    let params = *DEFAULT_128_BITS_PARAMETERS;
+
+    let (_, enc, dec, eval) = generate_keys_and_evaluator(params, &mut rng);
+
+    let x: u16 = rng.gen();
+    let y: u16 = rng.gen();
+
+    let cx = enc.encrypt_slice(&to_bits(x), &mut rng);
+    let cy = enc.encrypt_slice(&to_bits(y), &mut rng);
+
+    let decrypt_word = |c: &[_]| {
+        assert_eq!(c.len(), 16);
+        c.iter()
+            .enumerate()
+            .fold(0u16, |w, (i, c)| w | (dec.decrypt::<u16>(c) << i))
+    };
+
+    assert_eq!(decrypt_word(&eval.word_xor(&cx, &cy)), x ^ y);
+    assert_eq!(decrypt_word(&eval.word_and(&cx, &cy)), x & y);
+    assert_eq!(decrypt_word(&eval.word_or(&cx, &cy)), x | y);
+}
+
+#[test]
+#[should_panic]
+fn test_word_gates_length_mismatch() {
+    let mut rng = rand::thread_rng();
+
+    let params = *DEFAULT_128_BITS_PARAMETERS;
+
+    let (_, enc, _, eval) = generate_keys_and_evaluator(params, &mut rng);
+
+    let ca = enc.encrypt_slice(&[true, false, true], &mut rng);
+    let cb = enc.encrypt_slice(&[true, false], &mut rng);
+
+    eval.word_xor(&ca, &cb);
+}
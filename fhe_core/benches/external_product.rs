@@ -0,0 +1,55 @@
+use algebra::{
+    decompose::NonPowOf2ApproxSignedBasis, polynomial::FieldPolynomial, random::DiscreteGaussian,
+    Field, NttField, U32FieldEval,
+};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use fhe_core::{
+    bench_internals::{external_product, BlindRotateSpace},
+    NttRlweSecretKey, RingSecretKeyType, RlweSecretKey,
+};
+use lattice::{NttRgsw, Rlwe};
+
+type Fp = U32FieldEval<132120577>;
+
+const LOG_N: u32 = 10;
+const N: usize = 1 << LOG_N;
+
+pub fn criterion_benchmark(c: &mut Criterion) {
+    let mut rng = rand::thread_rng();
+
+    let ntt_table = Fp::generate_ntt_table(LOG_N).unwrap();
+    let gaussian = DiscreteGaussian::new(0.0, 3.2, Fp::MINUS_ONE).unwrap();
+    let basis = NonPowOf2ApproxSignedBasis::new(Fp::MODULUS_VALUE, 7, None);
+
+    let rlwe_sk = RlweSecretKey::<Fp>::generate(RingSecretKeyType::Ternary, N, None, &mut rng);
+    let ntt_rlwe_sk = NttRlweSecretKey::from_coeff_secret_key(&rlwe_sk, &ntt_table);
+
+    let rgsw = NttRgsw::<Fp>::generate_random_one_sample(
+        &ntt_rlwe_sk,
+        &basis,
+        gaussian,
+        &ntt_table,
+        &mut rng,
+    );
+
+    let mut acc = Rlwe::new(
+        FieldPolynomial::<Fp>::random(N, &mut rng),
+        FieldPolynomial::<Fp>::random(N, &mut rng),
+    );
+    let mut space = BlindRotateSpace::new(N);
+
+    c.bench_function(&format!("blind rotation external product {N}"), |b| {
+        b.iter(|| {
+            external_product(
+                black_box(&mut acc),
+                black_box(&rgsw),
+                black_box(3),
+                &ntt_table,
+                &mut space,
+            )
+        })
+    });
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
@@ -0,0 +1,57 @@
+use std::sync::Arc;
+
+use algebra::{polynomial::FieldPolynomial, Field, NttField, U32FieldEval};
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
+use fhe_core::{
+    bench_internals::key_switch_inner, KeySwitchingParameters, LweKeySwitchingKeyRlweMode,
+    LweSecretKey, LweSecretKeyType, RingSecretKeyType, RlweSecretKey,
+};
+use rand::Rng;
+
+type Fp = U32FieldEval<132120577>;
+
+const RLWE_DIMENSION: usize = 1024;
+const LWE_DIMENSION: usize = 512;
+
+pub fn criterion_benchmark(c: &mut Criterion) {
+    let mut rng = rand::thread_rng();
+
+    let params = KeySwitchingParameters {
+        input_cipher_dimension: RLWE_DIMENSION,
+        output_cipher_dimension: LWE_DIMENSION,
+        log_modulus: Fp::MODULUS_VALUE.ilog2() + 1,
+        log_basis: 7,
+        reverse_length: None,
+        noise_standard_deviation: 3.2,
+    };
+
+    let ntt_table = Arc::new(Fp::generate_ntt_table(LWE_DIMENSION.trailing_zeros()).unwrap());
+
+    let rlwe_sk =
+        RlweSecretKey::<Fp>::generate(RingSecretKeyType::Ternary, RLWE_DIMENSION, None, &mut rng);
+    let lwe_sk = LweSecretKey::new(
+        (0..LWE_DIMENSION)
+            .map(|_| rng.gen_range(0..=1u16))
+            .collect(),
+        LweSecretKeyType::Binary,
+    );
+
+    let key = LweKeySwitchingKeyRlweMode::generate(&rlwe_sk, &lwe_sk, params, ntt_table, &mut rng);
+
+    let a = FieldPolynomial::<Fp>::random(RLWE_DIMENSION, &mut rng);
+    let b = rng.gen_range(0..Fp::MODULUS_VALUE);
+
+    c.bench_function(
+        &format!("key switch inner loop {RLWE_DIMENSION} -> {LWE_DIMENSION}"),
+        |bencher| {
+            bencher.iter_batched(
+                || a.as_slice().to_vec(),
+                |a| key_switch_inner(&key, LWE_DIMENSION, a, b),
+                BatchSize::SmallInput,
+            )
+        },
+    );
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
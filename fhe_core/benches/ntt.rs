@@ -0,0 +1,26 @@
+use algebra::{polynomial::FieldPolynomial, NttField, U32FieldEval};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use fhe_core::bench_internals::NumberTheoryTransform;
+
+type Fp = U32FieldEval<132120577>;
+
+const LOG_N: u32 = 10;
+const N: usize = 1 << LOG_N;
+
+pub fn criterion_benchmark(c: &mut Criterion) {
+    let mut rng = rand::thread_rng();
+
+    let ntt_table = Fp::generate_ntt_table(LOG_N).unwrap();
+    let mut poly = FieldPolynomial::<Fp>::random(N, &mut rng);
+
+    c.bench_function(&format!("ntt transform {N}"), |b| {
+        b.iter(|| ntt_table.transform_slice(black_box(poly.as_mut_slice())))
+    });
+
+    c.bench_function(&format!("ntt inverse transform {N}"), |b| {
+        b.iter(|| ntt_table.inverse_transform_slice(black_box(poly.as_mut_slice())))
+    });
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
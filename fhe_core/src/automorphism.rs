@@ -68,7 +68,7 @@ impl<F: NttField> AutoKey<F> {
                 rng,
             )
         } else {
-            let p_auto = poly_auto(secret_key, degree, rlwe_dimension);
+            let p_auto = secret_key.apply_automorphism(degree);
             let auto_sk = ntt_table.transform_inplace(-p_auto);
             NttGadgetRlwe::generate_random_poly_sample(
                 ntt_secret_key,
@@ -92,7 +92,7 @@ impl<F: NttField> AutoKey<F> {
     pub fn automorphism(&self, ciphertext: &RlweCiphertext<F>) -> RlweCiphertext<F> {
         let rlwe_dimension = ciphertext.dimension();
 
-        let a = poly_auto(ciphertext.a(), self.degree, rlwe_dimension);
+        let a = ciphertext.a().apply_automorphism(self.degree);
 
         let mut result = self
             .key
@@ -141,17 +141,6 @@ impl<F: NttField> AutoKey<F> {
     }
 }
 
-#[inline]
-fn poly_auto<F: NttField>(
-    poly: &FieldPolynomial<F>,
-    degree: usize,
-    dimension: usize,
-) -> FieldPolynomial<F> {
-    let mut res = FieldPolynomial::zero(dimension);
-    poly_auto_inplace(poly, degree, dimension, &mut res);
-    res
-}
-
 #[inline]
 fn poly_auto_inplace<F: NttField>(
     poly: &FieldPolynomial<F>,
@@ -204,7 +193,7 @@ mod tests {
         let mut rng = rand::thread_rng();
 
         let poly = PolyT::random_ternary(N, &mut rng);
-        let result = poly_auto(&poly, N + 1, N);
+        let result = poly.apply_automorphism(N + 1);
 
         let flag = result
             .iter()
@@ -0,0 +1,30 @@
+//! The internal primitives of the bootstrapping, exposed for benchmarks.
+//!
+//! These let a benchmark measure a single primitive without going through
+//! a full gate. They are not a stable API and may change at any time.
+
+use algebra::{Field, NttField};
+
+use crate::{LweCiphertext, LweKeySwitchingKeyRlweMode};
+
+pub use algebra::ntt::{NttTable, NttWorkspace, NumberTheoryTransform};
+pub use lattice::utils::{NttRlweSpace, PolyDecomposeSpace, RlweSpace};
+
+pub use crate::blind_rotation::{external_product, BlindRotateSpace};
+
+/// Runs the inner loop of [`LweKeySwitchingKeyRlweMode`] on `b` and the
+/// prepared mask `a`, whose chunks are the negacyclic reversed pieces of the
+/// mask of the input ciphertext.
+///
+/// # Panics
+///
+/// Panics if the length of `a` isn't a multiple of `lwe_dimension`.
+pub fn key_switch_inner<Q: NttField>(
+    key: &LweKeySwitchingKeyRlweMode<Q>,
+    lwe_dimension: usize,
+    a: Vec<<Q as Field>::ValueT>,
+    b: <Q as Field>::ValueT,
+) -> LweCiphertext<<Q as Field>::ValueT> {
+    assert_eq!(a.len() % lwe_dimension, 0);
+    key.key_switch_inner(lwe_dimension, a, b)
+}
@@ -1,13 +1,8 @@
 use std::sync::Arc;
 
 use algebra::{
-    decompose::NonPowOf2ApproxSignedBasis,
-    integer::{AsInto, UnsignedInteger},
-    ntt::NttTable,
-    polynomial::FieldPolynomial,
-    random::DiscreteGaussian,
-    reduce::ReduceNegAssign,
-    Field, NttField,
+    decompose::NonPowOf2ApproxSignedBasis, integer::UnsignedInteger, ntt::NttTable,
+    polynomial::FieldPolynomial, random::DiscreteGaussian, Field, NttField,
 };
 use lattice::{
     utils::{NttRlweSpace, PolyDecomposeSpace, RlweSpace},
@@ -17,6 +12,8 @@ use rand::{CryptoRng, Rng};
 
 use crate::{utils::Pool, LweCiphertext, LweSecretKey, NttRlweSecretKey, RlweCiphertext};
 
+use super::init_accumulator;
+
 /// The binary blind rotation key.
 pub struct BinaryBlindRotationKey<F: NttField> {
     key: Vec<NttRgsw<F>>,
@@ -36,13 +33,14 @@ impl<F: NttField> Clone for BinaryBlindRotationKey<F> {
 }
 
 /// Preallocated space for blind rotation
-struct BlindRotateSpace<F: NttField> {
+pub struct BlindRotateSpace<F: NttField> {
     decompose_space: PolyDecomposeSpace<F>,
     ntt_rlwe_space: NttRlweSpace<F>,
     rlwe_space: RlweSpace<F>,
 }
 
 impl<F: NttField> BlindRotateSpace<F> {
+    /// Creates a new [`BlindRotateSpace<F>`] for polynomials with `dimension` coefficients.
     #[inline]
     pub fn new(dimension: usize) -> Self {
         Self {
@@ -64,6 +62,12 @@ impl<F: NttField> BinaryBlindRotationKey<F> {
         }
     }
 
+    /// Returns the RGSW ciphertexts of the LWE secret key bits in this [`BinaryBlindRotationKey<F>`].
+    #[inline]
+    pub fn key(&self) -> &[NttRgsw<F>] {
+        &self.key
+    }
+
     /// Returns a reference to the ntt table of this [`BinaryBlindRotationKey<F>`].
     #[inline]
     pub fn ntt_table(&self) -> &<F as NttField>::Table {
@@ -71,64 +75,44 @@ impl<F: NttField> BinaryBlindRotationKey<F> {
     }
 
     /// Performs the blind rotation operation.
+    #[inline]
     pub fn blind_rotate<C: UnsignedInteger>(
         &self,
-        mut lut: FieldPolynomial<F>,
+        lut: FieldPolynomial<F>,
         ciphertext: &LweCiphertext<C>,
+    ) -> RlweCiphertext<F> {
+        let acc = init_accumulator(ciphertext.b(), lut);
+        self.blind_rotate_accumulator(acc, ciphertext.a())
+    }
+
+    /// Performs the rotation steps of the blind rotation on `acc`,
+    /// multiplying it by `X^{a_i s_i}` for every `a_i` in `a`.
+    pub fn blind_rotate_accumulator<C: UnsignedInteger>(
+        &self,
+        acc: RlweCiphertext<F>,
+        a: &[C],
     ) -> RlweCiphertext<F> {
         let ntt_table = self.ntt_table();
         let dimension = ntt_table.dimension();
-        assert_eq!(dimension, lut.coeff_count());
+        assert_eq!(dimension, acc.a().coeff_count());
 
         let mut blind_rotate_space = match self.space.get() {
             Some(sp) => sp,
             None => BlindRotateSpace::new(dimension),
         };
 
-        let decompose_space = &mut blind_rotate_space.decompose_space;
-        let ntt_rlwe_space = &mut blind_rotate_space.ntt_rlwe_space;
-        let external_product = &mut blind_rotate_space.rlwe_space;
-
-        // lut * X^{-b}
-        if !ciphertext.b().is_zero() {
-            let minus_b = (dimension << 1) - AsInto::<usize>::as_into(ciphertext.b());
-            let neg = |v| <F as Field>::MODULUS.reduce_neg_assign(v);
-            if minus_b <= dimension {
-                lut.as_mut_slice().rotate_right(minus_b);
-                lut[..minus_b].iter_mut().for_each(neg);
-            } else {
-                let r = minus_b - dimension;
-                lut.as_mut_slice().rotate_right(r);
-                lut[r..].iter_mut().for_each(neg);
+        let result = self.key.iter().zip(a).fold(acc, |mut acc, (si, &ai)| {
+            if !ai.is_zero() {
+                external_product(
+                    &mut acc,
+                    si,
+                    ai.as_into(),
+                    ntt_table,
+                    &mut blind_rotate_space,
+                );
             }
-        }
-
-        let acc = RlweCiphertext::new(FieldPolynomial::zero(dimension), lut);
-
-        let result = self.key.iter().zip(ciphertext.a()).fold(
-            acc,
-            |mut acc: Rlwe<F>, (si, &ai): (&NttRgsw<F>, &C)| {
-                if !ai.is_zero() {
-                    // external_product = (X^{a_i} - 1) * ACC
-                    acc.mul_monic_monomial_sub_one_inplace(
-                        dimension,
-                        ai.as_into(),
-                        external_product,
-                    );
-                    // external_product = (X^{a_i} - 1) * ACC * RGSW(s_i)
-                    external_product.mul_assign_ntt_rgsw(
-                        si,
-                        ntt_table,
-                        decompose_space,
-                        ntt_rlwe_space,
-                    );
-                    // ACC = ACC + (X^{a_i} - 1) * ACC * RGSW(s_i)
-                    acc.add_assign_element_wise(external_product);
-                }
-
-                acc
-            },
-        );
+            acc
+        });
 
         self.space.store(blind_rotate_space);
 
@@ -136,23 +120,27 @@ impl<F: NttField> BinaryBlindRotationKey<F> {
     }
 
     /// Generates the [`BinaryBlindRotationKey<F>`].
-    pub(crate) fn generate<R, C>(
+    pub(crate) fn generate<R, C, P>(
         lwe_secret_key: &LweSecretKey<C>,
         rlwe_secret_key: &NttRlweSecretKey<F>,
         blind_rotation_basis: &NonPowOf2ApproxSignedBasis<<F as Field>::ValueT>,
         gaussian: DiscreteGaussian<<F as Field>::ValueT>,
         ntt_table: Arc<<F as NttField>::Table>,
         rng: &mut R,
+        progress: &mut P,
     ) -> Self
     where
         C: UnsignedInteger,
         R: Rng + CryptoRng,
+        P: FnMut(usize, usize),
     {
+        let total = lwe_secret_key.as_ref().len();
         let key = lwe_secret_key
             .as_ref()
             .iter()
-            .map(|&s| {
-                if s.is_zero() {
+            .enumerate()
+            .map(|(i, &s)| {
+                let rgsw = if s.is_zero() {
                     <NttRgsw<F>>::generate_random_zero_sample(
                         rlwe_secret_key,
                         blind_rotation_basis,
@@ -168,9 +156,37 @@ impl<F: NttField> BinaryBlindRotationKey<F> {
                         &ntt_table,
                         rng,
                     )
-                }
+                };
+                progress(i + 1, total);
+                rgsw
             })
             .collect();
         BinaryBlindRotationKey::new(key, Arc::clone(&ntt_table))
     }
 }
+
+/// Performs one rotation step of the binary blind rotation,
+/// `ACC = ACC + (X^{a_i} - 1) * ACC * RGSW(s_i)`.
+///
+/// `ai` is `a_i` as an exponent, and `rgsw` is `RGSW(s_i)`.
+pub fn external_product<F: NttField>(
+    acc: &mut Rlwe<F>,
+    rgsw: &NttRgsw<F>,
+    ai: usize,
+    ntt_table: &<F as NttField>::Table,
+    space: &mut BlindRotateSpace<F>,
+) {
+    let product = &mut space.rlwe_space;
+    // product = (X^{a_i} - 1) * ACC
+    acc.mul_monic_monomial_sub_one_inplace(ntt_table.dimension(), ai, product);
+    // product = (X^{a_i} - 1) * ACC * RGSW(s_i)
+    crate::count::external_product::increment();
+    product.mul_assign_ntt_rgsw(
+        rgsw,
+        ntt_table,
+        &mut space.decompose_space,
+        &mut space.ntt_rlwe_space,
+    );
+    // ACC = ACC + (X^{a_i} - 1) * ACC * RGSW(s_i)
+    acc.add_assign_element_wise(product);
+}
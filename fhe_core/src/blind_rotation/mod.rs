@@ -8,6 +8,8 @@ use algebra::{
     random::DiscreteGaussian, Field, NttField,
 };
 pub use binary::BinaryBlindRotationKey;
+#[cfg(feature = "bench-internals")]
+pub use binary::{external_product, BlindRotateSpace};
 use rand::{CryptoRng, Rng};
 pub use ternary::TernaryBlindRotationKey;
 
@@ -56,7 +58,27 @@ impl<F: NttField> BlindRotationKey<F> {
         }
     }
 
+    /// Performs the rotation steps of the blind rotation on `acc`,
+    /// which usually comes from [`init_accumulator`].
+    ///
+    /// [`Self::blind_rotate`] starts from `init_accumulator(lwe.b(), lut)` and rotates by `lwe.a()`.
+    pub fn blind_rotate_accumulator<C: UnsignedInteger>(
+        &self,
+        acc: RlweCiphertext<F>,
+        a: &[C],
+    ) -> RlweCiphertext<F> {
+        match self {
+            BlindRotationKey::Binary(bootstrapping_key) => {
+                bootstrapping_key.blind_rotate_accumulator(acc, a)
+            }
+            BlindRotationKey::Ternary(bootstrapping_key) => {
+                bootstrapping_key.blind_rotate_accumulator(acc, a)
+            }
+        }
+    }
+
     /// Generates the [`BlindRotationKey<F>`].
+    #[inline]
     pub fn generate<C, R>(
         lwe_secret_key: &LweSecretKey<C>,
         rlwe_secret_key: &NttRlweSecretKey<F>,
@@ -68,6 +90,35 @@ impl<F: NttField> BlindRotationKey<F> {
     where
         C: UnsignedInteger,
         R: Rng + CryptoRng,
+    {
+        Self::generate_with_progress(
+            lwe_secret_key,
+            rlwe_secret_key,
+            blind_rotation_basis,
+            gaussian,
+            ntt_table,
+            rng,
+            |_, _| {},
+        )
+    }
+
+    /// Generates the [`BlindRotationKey<F>`], reporting progress along the way.
+    ///
+    /// `progress(done, total)` is invoked once after the RGSW ciphertexts for each
+    /// LWE secret key coefficient are generated, where `total` is the LWE dimension.
+    pub fn generate_with_progress<C, R, P>(
+        lwe_secret_key: &LweSecretKey<C>,
+        rlwe_secret_key: &NttRlweSecretKey<F>,
+        blind_rotation_basis: &NonPowOf2ApproxSignedBasis<<F as Field>::ValueT>,
+        gaussian: DiscreteGaussian<<F as Field>::ValueT>,
+        ntt_table: Arc<<F as NttField>::Table>,
+        rng: &mut R,
+        mut progress: P,
+    ) -> Self
+    where
+        C: UnsignedInteger,
+        R: Rng + CryptoRng,
+        P: FnMut(usize, usize),
     {
         match lwe_secret_key.distr() {
             LweSecretKeyType::Binary => BlindRotationKey::Binary(BinaryBlindRotationKey::generate(
@@ -77,8 +128,9 @@ impl<F: NttField> BlindRotationKey<F> {
                 gaussian,
                 ntt_table,
                 rng,
+                &mut progress,
             )),
-            LweSecretKeyType::Ternary => {
+            LweSecretKeyType::Ternary | LweSecretKeyType::SparseTernary(_) => {
                 BlindRotationKey::Ternary(TernaryBlindRotationKey::generate(
                     lwe_secret_key,
                     rlwe_secret_key,
@@ -86,8 +138,34 @@ impl<F: NttField> BlindRotationKey<F> {
                     gaussian,
                     ntt_table,
                     rng,
+                    &mut progress,
                 ))
             }
         }
     }
 }
+
+/// Creates the initial accumulator of the blind rotation, the trivial
+/// RLWE ciphertext `(0, lut * X^{-b})`.
+///
+/// `b` is the `b` of the LWE ciphertext after the modulus switch to `2N`,
+/// where `N` is the coefficient count of `lut`.
+///
+/// # Panics
+///
+/// Panics if `b` is not less than `2N`.
+pub fn init_accumulator<C: UnsignedInteger, F: NttField>(
+    b: C,
+    mut lut: FieldPolynomial<F>,
+) -> RlweCiphertext<F> {
+    let dimension = lut.coeff_count();
+    let b: usize = b.as_into();
+    assert!(b < dimension << 1, "b should be less than 2N!");
+
+    // lut * X^{-b}
+    if b != 0 {
+        lut.mul_monomial_assign((dimension << 1) - b);
+    }
+
+    RlweCiphertext::new(FieldPolynomial::zero(dimension), lut)
+}
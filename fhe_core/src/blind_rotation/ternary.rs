@@ -2,11 +2,10 @@ use std::sync::Arc;
 
 use algebra::{
     decompose::NonPowOf2ApproxSignedBasis,
-    integer::{AsInto, UnsignedInteger},
+    integer::UnsignedInteger,
     ntt::{NttTable, NumberTheoryTransform},
     polynomial::FieldPolynomial,
     random::DiscreteGaussian,
-    reduce::ReduceNegAssign,
     Field, NttField,
 };
 use lattice::{
@@ -17,6 +16,8 @@ use rand::{CryptoRng, Rng};
 
 use crate::{utils::Pool, LweCiphertext, LweSecretKey, NttRlweSecretKey, RlweCiphertext};
 
+use super::init_accumulator;
+
 /// The ternary blind rotation key.
 pub struct TernaryBlindRotationKey<F: NttField> {
     key: Vec<(NttRgsw<F>, NttRgsw<F>)>,
@@ -73,6 +74,13 @@ impl<F: NttField> TernaryBlindRotationKey<F> {
         }
     }
 
+    /// Returns the pairs of RGSW ciphertexts of the LWE secret key values
+    /// in this [`TernaryBlindRotationKey<F>`].
+    #[inline]
+    pub fn key(&self) -> &[(NttRgsw<F>, NttRgsw<F>)] {
+        &self.key
+    }
+
     /// Returns a reference to the ntt table of this [`TernaryBlindRotationKey<F>`].
     #[inline]
     pub fn ntt_table(&self) -> &<F as NttField>::Table {
@@ -86,14 +94,26 @@ impl<F: NttField> TernaryBlindRotationKey<F> {
     }
 
     /// Performs the blind rotation operation.
+    #[inline]
     pub fn blind_rotate<C: UnsignedInteger>(
         &self,
-        mut lut: FieldPolynomial<F>,
+        lut: FieldPolynomial<F>,
         lwe: &LweCiphertext<C>,
+    ) -> RlweCiphertext<F> {
+        let acc = init_accumulator(lwe.b(), lut);
+        self.blind_rotate_accumulator(acc, lwe.a())
+    }
+
+    /// Performs the rotation steps of the blind rotation on `acc`,
+    /// multiplying it by `X^{a_i s_i}` for every `a_i` in `a`.
+    pub fn blind_rotate_accumulator<C: UnsignedInteger>(
+        &self,
+        acc: RlweCiphertext<F>,
+        a: &[C],
     ) -> RlweCiphertext<F> {
         let ntt_table = self.ntt_table();
         let dimension = ntt_table.dimension();
-        assert_eq!(dimension, lut.coeff_count());
+        assert_eq!(dimension, acc.a().coeff_count());
 
         let mut blind_rotate_space = match self.space.get() {
             Some(sp) => sp,
@@ -105,23 +125,7 @@ impl<F: NttField> TernaryBlindRotationKey<F> {
         let external_product = &mut blind_rotate_space.rlwe_space;
         let evaluation_key = &mut blind_rotate_space.ntt_rgsw;
 
-        // lut * X^{-b}
-        if !lwe.b().is_zero() {
-            let minus_b = (dimension << 1) - AsInto::<usize>::as_into(lwe.b());
-            let neg = |v| <F as Field>::MODULUS.reduce_neg_assign(v);
-            if minus_b <= dimension {
-                lut.as_mut_slice().rotate_right(minus_b);
-                lut[..minus_b].iter_mut().for_each(neg);
-            } else {
-                let r = minus_b - dimension;
-                lut.as_mut_slice().rotate_right(r);
-                lut[r..].iter_mut().for_each(neg);
-            }
-        }
-
-        let acc = Rlwe::new(FieldPolynomial::zero(dimension), lut);
-
-        let result = self.key.iter().zip(lwe.a()).fold(
+        let result = self.key.iter().zip(a).fold(
             acc,
             |mut acc: Rlwe<F>, (si, &ai): (&(NttRgsw<F>, NttRgsw<F>), &C)| {
                 if !ai.is_zero() {
@@ -140,6 +144,7 @@ impl<F: NttField> TernaryBlindRotationKey<F> {
                     acc.mul_monic_monomial_sub_one_inplace(dimension, ai, external_product);
 
                     // external_product = (X^{a_i} - 1) * ACC * (RGSW(s_i_0) - RGSW(s_i_1)*X^{-a_i})
+                    crate::count::external_product::increment();
                     external_product.mul_assign_ntt_rgsw(
                         evaluation_key,
                         ntt_table,
@@ -161,23 +166,27 @@ impl<F: NttField> TernaryBlindRotationKey<F> {
     }
 
     /// Generates the [`TernaryBlindRotationKey<F>`].
-    pub(crate) fn generate<R, C>(
+    pub(crate) fn generate<R, C, P>(
         lwe_secret_key: &LweSecretKey<C>,
         rlwe_secret_key: &NttRlweSecretKey<F>,
         blind_rotation_basis: &NonPowOf2ApproxSignedBasis<<F as Field>::ValueT>,
         gaussian: DiscreteGaussian<<F as Field>::ValueT>,
         ntt_table: Arc<<F as NttField>::Table>,
         rng: &mut R,
+        progress: &mut P,
     ) -> Self
     where
         C: UnsignedInteger,
         R: Rng + CryptoRng,
+        P: FnMut(usize, usize),
     {
+        let total = lwe_secret_key.as_ref().len();
         let key = lwe_secret_key
             .as_ref()
             .iter()
-            .map(|&s| {
-                if s.is_one() {
+            .enumerate()
+            .map(|(i, &s)| {
+                let rgsw_pair = if s.is_one() {
                     (
                         <NttRgsw<F>>::generate_random_one_sample(
                             rlwe_secret_key,
@@ -228,7 +237,9 @@ impl<F: NttField> TernaryBlindRotationKey<F> {
                             rng,
                         ),
                     )
-                }
+                };
+                progress(i + 1, total);
+                rgsw_pair
             })
             .collect();
 
@@ -0,0 +1,29 @@
+//! Counters of the operations performed by the bootstrapping.
+//!
+//! Every counter is process wide and starts disabled. While it's enabled,
+//! each operation of its kind adds one to it, from every thread.
+//! Each operation is also added to the [`Counts`] of the running [`scope`].
+//!
+//! The counters of the transforms and the multiplications, and the labeled
+//! scopes, are re-exported from `algebra::count`.
+//!
+//! The counters are only public with the `count` feature,
+//! without it counting compiles to nothing.
+
+#[cfg(feature = "count")]
+pub use algebra::count::{clear_report, inverse_ntt, mul, ntt, report, scope, Counts, Report};
+
+use algebra::counter;
+
+counter!(
+    /// Counts the external products `RLWE × RGSW` of the blind rotation.
+    external_product
+);
+counter!(
+    /// Counts the key switches of LWE and RLWE ciphertexts.
+    key_switch
+);
+counter!(
+    /// Counts the modulus switches of LWE ciphertexts.
+    modulus_switch
+);
@@ -31,8 +31,35 @@ pub enum FHECoreError {
         /// Polynomial modulus dimension of ring.
         ring_dimension: Box<dyn Debug>,
     },
+    /// Error that occurs when the given blind rotation basis' bits
+    /// is zero or larger than the bit count of the ring modulus.
+    #[error(
+        "Blind rotation basis bits {basis_bits} is not valid for a {modulus_bits}-bit ring modulus!"
+    )]
+    BlindRotationBasisUnValid {
+        /// Decompose basis' bits for blind rotation.
+        basis_bits: u32,
+        /// Bit count of the ring modulus.
+        modulus_bits: u32,
+    },
     /// Error that occurs when the given steps after blind rotation
     /// is not compatible with other parameters.
     #[error("Steps after blind rotation is not compatible with other parameters!")]
     StepsParametersNotCompatible,
+    /// Error that occurs when the length of the given `a`
+    /// does not match the **LWE** dimension of the parameters.
+    #[error("LWE dimension mismatch: expected {expected}, got {actual}!")]
+    LweDimensionMismatch {
+        /// The **LWE** dimension of the parameters.
+        expected: usize,
+        /// The length of the given `a`.
+        actual: usize,
+    },
+    /// Error that occurs when the parameters can't be read from
+    /// or written to their text format.
+    #[error("Parameters format error: {0}")]
+    ParametersFormatErr(
+        /// The description of the error.
+        String,
+    ),
 }
@@ -1,10 +1,10 @@
-use std::{slice::ChunksExact, sync::Arc};
+use std::sync::Arc;
 
 use algebra::{
     decompose::{NonPowOf2ApproxSignedBasis, PowOf2ApproxSignedBasis},
     integer::UnsignedInteger,
-    ntt::NttTable,
-    polynomial::{FieldNttPolynomial, FieldPolynomial},
+    ntt::{NttTable, NumberTheoryTransform},
+    polynomial::{FieldNttPolynomial, FieldPolynomial, PolyMut},
     reduce::{ReduceNegAssign, RingReduce},
     Field, NttField,
 };
@@ -109,6 +109,8 @@ impl<C: UnsignedInteger> PowOf2LweKeySwitchingKey<C> {
         ciphertext: &LweCiphertext<C>,
         modulus: impl RingReduce<C>,
     ) -> LweCiphertext<C> {
+        crate::count::key_switch::increment();
+
         let dimension = self.params.output_cipher_dimension;
         let minus_one = modulus.modulus_minus_one();
 
@@ -237,6 +239,8 @@ impl<C: UnsignedInteger> NonPowOf2LweKeySwitchingKey<C> {
         ciphertext: &LweCiphertext<C>,
         modulus: impl RingReduce<C>,
     ) -> LweCiphertext<C> {
+        crate::count::key_switch::increment();
+
         let dimension = self.params.output_cipher_dimension;
         let minus_one = modulus.modulus_minus_one();
 
@@ -289,6 +293,10 @@ impl<C: UnsignedInteger> NonPowOf2LweKeySwitchingKey<C> {
 
 /// Represents a key switching key for the RLWE mode in the Learning with Errors (LWE) cryptographic scheme.
 ///
+/// The LWE secret key is packed into ring elements of the next power of two dimension,
+/// padded with zero coefficients, so the output dimension doesn't need to be a power of two.
+/// The masks of the padded coefficients are dropped from the output ciphertexts.
+///
 /// # Type Parameters
 ///
 /// * `Q` - A field that supports Number Theoretic Transform (NTT) operations.
@@ -297,7 +305,7 @@ pub struct LweKeySwitchingKeyRlweMode<Q: NttField> {
     key: Vec<NttGadgetRlwe<Q>>,
     key_switching_key_params: KeySwitchingParameters,
     ntt_table: Arc<<Q as NttField>::Table>,
-    space: Pool<(PolyDecomposeSpace<Q>, FieldPolynomial<Q>)>,
+    space: Pool<(PolyDecomposeSpace<Q>, FieldPolynomial<Q>, NttRlwe<Q>)>,
 }
 
 impl<Q: NttField> LweKeySwitchingKeyRlweMode<Q> {
@@ -328,12 +336,13 @@ impl<Q: NttField> LweKeySwitchingKeyRlweMode<Q> {
     {
         let rlwe_dimension = key_switching_key_params.input_cipher_dimension;
         let lwe_dimension = key_switching_key_params.output_cipher_dimension;
-        assert!(lwe_dimension.is_power_of_two() && lwe_dimension <= rlwe_dimension);
+        let padded_dimension = lwe_dimension.next_power_of_two();
+        assert!(padded_dimension <= rlwe_dimension);
 
-        let ntt_table = if ntt_table.dimension() == lwe_dimension {
+        let ntt_table = if ntt_table.dimension() == padded_dimension {
             ntt_table
         } else {
-            Arc::new(Q::generate_ntt_table(lwe_dimension.trailing_zeros()).unwrap())
+            Arc::new(Q::generate_ntt_table(padded_dimension.trailing_zeros()).unwrap())
         };
 
         let gaussian = key_switching_key_params.noise_distribution_for_Q::<Q>();
@@ -344,22 +353,26 @@ impl<Q: NttField> LweKeySwitchingKeyRlweMode<Q> {
             key_switching_key_params.reverse_length,
         );
 
-        let lwe_secret_key = <RlweSecretKey<Q>>::from_lwe_secret_key(lwe_secret_key);
+        let mut padded_key = lwe_secret_key.as_ref().to_vec();
+        padded_key.resize(padded_dimension, C::ZERO);
+        let lwe_secret_key = LweSecretKey::new(padded_key, lwe_secret_key.distr());
+
+        let lwe_secret_key = <RlweSecretKey<Q>>::from_lwe_secret_key(&lwe_secret_key);
         let lwe_secret_key = NttRlweSecretKey::from_coeff_secret_key(&lwe_secret_key, &ntt_table);
 
-        let rlwe_secret_key_chunks: Vec<FieldPolynomial<Q>> = rlwe_secret_key
-            .as_slice()
-            .chunks_exact(lwe_dimension)
-            .map(|part| FieldPolynomial::from_slice(part))
-            .collect();
+        // The chunks of the rlwe secret key are the rows of a contiguous matrix.
+        let mut rlwe_secret_key_chunks = rlwe_secret_key.as_slice().to_vec();
+        let chunk_count = rlwe_secret_key_chunks.len() / padded_dimension;
+        ntt_table.transform_matrix(&mut rlwe_secret_key_chunks, chunk_count, padded_dimension);
 
         let key = rlwe_secret_key_chunks
-            .into_iter()
-            .map(|rlwe_secret_key_chunk| {
-                let ntt_rlwe_secret_key_chunks = rlwe_secret_key_chunk.into_ntt_poly(&ntt_table);
+            .chunks_exact(padded_dimension)
+            .map(|ntt_rlwe_secret_key_chunk| {
+                let ntt_rlwe_secret_key_chunk =
+                    FieldNttPolynomial::from_slice(ntt_rlwe_secret_key_chunk);
                 NttGadgetRlwe::generate_random_poly_sample(
                     &lwe_secret_key,
-                    &ntt_rlwe_secret_key_chunks,
+                    &ntt_rlwe_secret_key_chunk,
                     &key_switching_basis,
                     gaussian,
                     &ntt_table,
@@ -381,26 +394,23 @@ impl<Q: NttField> LweKeySwitchingKeyRlweMode<Q> {
         &self,
         mut ciphertext: RlweCiphertext<Q>,
     ) -> LweCiphertext<<Q as Field>::ValueT> {
-        let lwe_dimension = self.key_switching_key_params.output_cipher_dimension;
-        let b = ciphertext.b()[0];
-        let init = <NttRlwe<Q>>::new(
-            FieldNttPolynomial::zero(lwe_dimension),
-            FieldNttPolynomial::new(vec![b; lwe_dimension]),
-        );
+        crate::count::key_switch::increment();
 
-        if ciphertext.dimension() != lwe_dimension {
+        let padded_dimension = self.padded_dimension();
+
+        if ciphertext.dimension() != padded_dimension {
             let a = ciphertext.a_mut_slice();
             Q::MODULUS.reduce_neg_assign(&mut a[0]);
             a[1..].reverse();
-            a.chunks_exact_mut(lwe_dimension).for_each(|chunk| {
+            a.chunks_exact_mut(padded_dimension).for_each(|chunk| {
                 Q::MODULUS.reduce_neg_assign(&mut chunk[0]);
                 chunk[1..].reverse();
             });
         }
 
-        let iter = ciphertext.a_slice().chunks_exact(lwe_dimension);
+        let (a, b) = ciphertext.into_parts();
 
-        self.key_switch_inner(lwe_dimension, init, iter)
+        self.key_switch_inner(padded_dimension, a.inner_data(), b[0])
     }
 
     /// Performs key switching operation.
@@ -408,45 +418,57 @@ impl<Q: NttField> LweKeySwitchingKeyRlweMode<Q> {
         &self,
         mut ciphertext: LweCiphertext<<Q as Field>::ValueT>,
     ) -> LweCiphertext<<Q as Field>::ValueT> {
-        let lwe_dimension = self.key_switching_key_params.output_cipher_dimension;
-        let b = ciphertext.b();
-        let init = <NttRlwe<Q>>::new(
-            FieldNttPolynomial::zero(lwe_dimension),
-            FieldNttPolynomial::new(vec![b; lwe_dimension]),
-        );
+        crate::count::key_switch::increment();
+
+        let padded_dimension = self.padded_dimension();
 
         let a = ciphertext.a_mut_slice();
-        a.chunks_exact_mut(lwe_dimension).for_each(|chunk| {
-            chunk[1..].reverse();
-            chunk[1..]
-                .iter_mut()
-                .for_each(|v| Q::MODULUS.reduce_neg_assign(v))
-        });
+        a.chunks_exact_mut(padded_dimension)
+            .for_each(|chunk| PolyMut::<Q>::new(chunk).reverse_coeffs_negacyclic());
+
+        let (a, b) = ciphertext.into_parts();
 
-        let iter = ciphertext.a_slice().chunks_exact(lwe_dimension);
+        self.key_switch_inner(padded_dimension, a, b)
+    }
 
-        self.key_switch_inner(lwe_dimension, init, iter)
+    /// Returns the dimension of the ring elements the LWE secret key is packed into,
+    /// the output dimension rounded up to a power of two.
+    #[inline]
+    fn padded_dimension(&self) -> usize {
+        self.key_switching_key_params
+            .output_cipher_dimension
+            .next_power_of_two()
     }
 
-    fn key_switch_inner(
+    /// The inner loop of the key switching, subtracting the products of
+    /// the key rows and the chunks of the prepared mask `a` from `(0, b)`.
+    ///
+    /// `lwe_dimension` is the padded dimension of the key rows.
+    /// The buffer of `a` is reused for the mask of the output ciphertext,
+    /// which drops the masks of the padded coefficients.
+    pub(crate) fn key_switch_inner(
         &self,
         lwe_dimension: usize,
-        mut init: NttRlwe<Q>,
-        iter: ChunksExact<<Q as Field>::ValueT>,
+        mut a: Vec<<Q as Field>::ValueT>,
+        b: <Q as Field>::ValueT,
     ) -> LweCiphertext<<Q as Field>::ValueT> {
         let ntt_table = self.ntt_table.as_ref();
-        let (mut decompose_space, mut poly_space) = match self.space.get() {
+        let (mut decompose_space, mut poly_space, mut acc) = match self.space.get() {
             Some(sp) => sp,
             None => (
                 PolyDecomposeSpace::new(lwe_dimension),
                 FieldPolynomial::zero(lwe_dimension),
+                NttRlwe::zero(lwe_dimension),
             ),
         };
 
-        self.key.iter().zip(iter).for_each(
+        acc.a_mut().set_zero();
+        acc.b_mut_slice().fill(b);
+
+        self.key.iter().zip(a.chunks_exact(lwe_dimension)).for_each(
             |(z_i, a_i): (&NttGadgetRlwe<Q>, &[<Q as Field>::ValueT])| {
                 poly_space.copy_from(a_i);
-                init.sub_assign_gadget_rlwe_mul_polynomial_fast(
+                acc.sub_assign_gadget_rlwe_mul_polynomial_fast(
                     z_i,
                     &poly_space,
                     ntt_table,
@@ -455,8 +477,20 @@ impl<Q: NttField> LweKeySwitchingKeyRlweMode<Q> {
             },
         );
 
-        self.space.store((decompose_space, poly_space));
+        let workspace = &mut decompose_space.ntt_workspace;
+        let (acc_a, acc_b) = acc.a_b_mut_slices();
+        ntt_table.inverse_transform_slice_with(acc_a, workspace);
+        ntt_table.inverse_transform_slice_with(acc_b, workspace);
+
+        a.truncate(lwe_dimension);
+        a.copy_from_slice(acc_a);
+        PolyMut::<Q>::new(&mut a).reverse_coeffs_negacyclic();
+        // The padded coefficients of the secret key are zero.
+        a.truncate(self.key_switching_key_params.output_cipher_dimension);
+        let b = acc_b[0];
+
+        self.space.store((decompose_space, poly_space, acc));
 
-        init.to_rlwe(ntt_table).extract_lwe_locally()
+        LweCiphertext::new(a, b)
     }
 }
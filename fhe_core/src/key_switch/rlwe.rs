@@ -77,6 +77,8 @@ impl<Q: NttField> RlweKeySwitchingKey<Q> {
     ///
     /// A new RLWE ciphertext after key switching.
     pub fn key_switch(&self, ciphertext: &RlweCiphertext<Q>) -> RlweCiphertext<Q> {
+        crate::count::key_switch::increment();
+
         let ntt_table = self.ntt_table.as_ref();
         let coeff_count = ntt_table.dimension();
 
@@ -94,11 +96,15 @@ impl<Q: NttField> RlweKeySwitchingKey<Q> {
             &mut ntt_rlwe,
         );
 
+        // The negation is folded into the inverse transforms.
+        let mut result = ntt_rlwe.to_rlwe_scaled_with(
+            ntt_table,
+            <Q as Field>::MINUS_ONE,
+            &mut decompose_space.ntt_workspace,
+        );
+
         self.space.store(decompose_space);
 
-        let mut result = ntt_rlwe.to_rlwe(ntt_table);
-        result.a_mut().neg_assign();
-        result.b_mut().neg_assign();
         *result.b_mut() += ciphertext.b();
 
         result
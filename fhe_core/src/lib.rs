@@ -23,9 +23,19 @@ mod modulus_switch;
 
 pub mod utils;
 
+#[cfg(feature = "bench-internals")]
+pub mod bench_internals;
+
+#[cfg(feature = "count")]
+pub mod count;
+#[cfg(not(feature = "count"))]
+mod count;
+
 pub use error::FHECoreError;
 
-pub use parameter::{GadgetRlweParameters, KeySwitchingParameters, LweParameters};
+pub use parameter::{
+    lwe_security_bits, GadgetRlweParameters, KeySwitchingParameters, LweParameters,
+};
 
 pub use public_key::{LwePublicKey, LwePublicKeyRlweMode, NttRlwePublicKey};
 pub use secret_key::{
@@ -33,14 +43,15 @@ pub use secret_key::{
 };
 
 pub use ciphertext::{CmLweCiphertext, LweCiphertext, NttRlweCiphertext, RlweCiphertext};
-pub use plaintext::{decode, encode};
+pub use plaintext::{decode, decode_centered, encode, encode_centered, encode_field};
 
-pub use blind_rotation::BlindRotationKey;
+pub use blind_rotation::{init_accumulator, BlindRotationKey};
 pub use key_switch::*;
 
 pub use automorphism::{AutoKey, AutoSpace};
 pub use trace::TraceKey;
 
 pub use modulus_switch::{
-    lwe_modulus_switch, lwe_modulus_switch_assign, lwe_modulus_switch_inplace,
+    lwe_modulus_switch, lwe_modulus_switch_assign, lwe_modulus_switch_error_bound,
+    lwe_modulus_switch_inplace, ModulusSwitchError,
 };
@@ -14,6 +14,8 @@ pub fn lwe_modulus_switch<CIn: UnsignedInteger, COut: UnsignedInteger>(
     modulus_in: CIn,
     modulus_out: ModulusValue<COut>,
 ) -> LweCiphertext<COut> {
+    crate::count::modulus_switch::increment();
+
     match modulus_out {
         ModulusValue::Native => lwe_modulus_switch_to_native(c_in, modulus_in),
         ModulusValue::PowerOf2(modulus_out) => {
@@ -87,6 +89,8 @@ pub fn lwe_modulus_switch_inplace<CIn: UnsignedInteger, COut: UnsignedInteger>(
     modulus_out: ModulusValue<COut>,
     c_out: &mut LweCiphertext<COut>,
 ) {
+    crate::count::modulus_switch::increment();
+
     match modulus_out {
         ModulusValue::Native => lwe_modulus_switch_inplace_to_native(c_in, modulus_in, c_out),
         ModulusValue::PowerOf2(modulus_out) => {
@@ -167,6 +171,8 @@ pub fn lwe_modulus_switch_assign<C: UnsignedInteger>(
     modulus_in: ModulusValue<C>,
     modulus_out: C,
 ) {
+    crate::count::modulus_switch::increment();
+
     match modulus_in {
         ModulusValue::Native => lwe_modulus_switch_assign_native(c, modulus_out),
         ModulusValue::PowerOf2(modulus_in)
@@ -237,3 +243,45 @@ pub fn lwe_modulus_switch_assign_native<C: UnsignedInteger>(
     c.a_mut().iter_mut().for_each(|v| *v = switch(*v));
     *c.b_mut() = switch(c.b());
 }
+
+/// The rounding error added by an LWE modulus switching,
+/// measured in units of the output modulus.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ModulusSwitchError {
+    /// The largest possible absolute value of the added error.
+    pub worst_case: f64,
+    /// The variance of the added error.
+    pub variance: f64,
+}
+
+/// Returns the rounding error that [`lwe_modulus_switch`] adds to the phase of
+/// an LWE ciphertext with dimension `dimension`, assuming every coefficient
+/// of the secret key is in `{-1, 0, 1}`.
+///
+/// Each of the `dimension + 1` rounded values carries an error of the form `k / f`,
+/// where `f = modulus_in / gcd(modulus_in, modulus_out)`, so the error
+/// is at most `floor(f / 2) / f` and its variance is `(f^2 - 1) / (12 * f^2)`
+/// for uniformly distributed inputs.
+pub fn lwe_modulus_switch_error_bound<CIn: UnsignedInteger, COut: UnsignedInteger>(
+    modulus_in: CIn,
+    modulus_out: ModulusValue<COut>,
+    dimension: usize,
+) -> ModulusSwitchError {
+    let modulus_in: u128 = modulus_in.as_into();
+    let modulus_out: u128 = match modulus_out {
+        ModulusValue::Native => 1u128 << COut::BITS,
+        ModulusValue::PowerOf2(q) | ModulusValue::Prime(q) | ModulusValue::Others(q) => q.as_into(),
+    };
+
+    let (mut x, mut y) = (modulus_in, modulus_out);
+    while y != 0 {
+        (x, y) = (y, x % y);
+    }
+    let f = (modulus_in / x) as f64;
+
+    let count = (dimension + 1) as f64;
+    ModulusSwitchError {
+        worst_case: count * (f / 2.0).floor() / f,
+        variance: count * (f * f - 1.0) / (12.0 * f * f),
+    }
+}
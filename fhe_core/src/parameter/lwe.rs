@@ -4,7 +4,9 @@ use algebra::{
     reduce::{ModulusValue, RingReduce},
 };
 
-use crate::LweSecretKeyType;
+use crate::{FHECoreError, LweCiphertext, LweSecretKeyType};
+
+use super::lwe_security_bits;
 
 /// Lwe Parameters.
 #[derive(Debug, Clone, Copy)]
@@ -92,6 +94,23 @@ impl<LweValue: UnsignedInteger, LweModulus: RingReduce<LweValue>>
         self.noise_standard_deviation
     }
 
+    /// Returns the estimated bit security of the **LWE** ciphertexts, see [`lwe_security_bits`].
+    pub fn security_bits(&self) -> f64 {
+        let log_modulus = match self.cipher_modulus_value {
+            ModulusValue::Native => LweValue::BITS as f64,
+            ModulusValue::PowerOf2(q) | ModulusValue::Prime(q) | ModulusValue::Others(q) => {
+                let q: f64 = q.as_into();
+                q.log2()
+            }
+        };
+        lwe_security_bits(
+            self.dimension,
+            log_modulus,
+            self.noise_standard_deviation,
+            self.secret_key_type.standard_deviation(self.dimension),
+        )
+    }
+
     /// Gets the discrete gaussian noise distribution.
     #[inline]
     pub fn noise_distribution(&self) -> DiscreteGaussian<LweValue> {
@@ -102,4 +121,23 @@ impl<LweValue: UnsignedInteger, LweModulus: RingReduce<LweValue>>
         )
         .unwrap()
     }
+
+    /// Builds a [`LweCiphertext<LweValue>`] from its raw parts `a` and `b`,
+    /// e.g. for interoperating with other libraries.
+    ///
+    /// Returns an error if the length of `a` is not equal to the **LWE** dimension.
+    #[inline]
+    pub fn ciphertext_from_parts(
+        &self,
+        a: Vec<LweValue>,
+        b: LweValue,
+    ) -> Result<LweCiphertext<LweValue>, FHECoreError> {
+        if a.len() != self.dimension {
+            return Err(FHECoreError::LweDimensionMismatch {
+                expected: self.dimension,
+                actual: a.len(),
+            });
+        }
+        Ok(LweCiphertext::new(a, b))
+    }
 }
@@ -1,7 +1,12 @@
-use algebra::{decompose::NonPowOf2ApproxSignedBasis, random::DiscreteGaussian, Field, NttField};
+use algebra::{
+    decompose::NonPowOf2ApproxSignedBasis, integer::AsInto, random::DiscreteGaussian, Field,
+    NttField,
+};
 
 use crate::RingSecretKeyType;
 
+use super::lwe_security_bits;
+
 /// Rgsw Parameters.
 #[derive(Debug)]
 pub struct GadgetRlweParameters<Q: NttField> {
@@ -35,6 +40,18 @@ impl<Q: NttField> GadgetRlweParameters<Q> {
     pub fn dimension(&self) -> usize {
         self.dimension
     }
+
+    /// Returns the estimated bit security of the ring secret key, see [`lwe_security_bits`].
+    pub fn security_bits(&self) -> f64 {
+        let modulus: f64 = self.modulus.as_into();
+        lwe_security_bits(
+            self.dimension,
+            modulus.log2(),
+            self.noise_standard_deviation,
+            self.secret_key_type
+                .standard_deviation(self.dimension, self.noise_standard_deviation),
+        )
+    }
 }
 
 impl<Q: NttField> Copy for GadgetRlweParameters<Q> {}
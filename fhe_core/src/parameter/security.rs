@@ -0,0 +1,70 @@
+use core::f64::consts::{E, PI};
+
+/// The smallest block size considered by [`lwe_security_bits`].
+const MIN_BLOCK_SIZE: usize = 40;
+
+/// Estimates the bit security of an LWE instance against the primal uSVP attack.
+///
+/// # Parameters
+///
+/// - `dimension` is the dimension `n` of the secret.
+/// - `log_modulus` is `log2(q)`.
+/// - `noise_standard_deviation` is the standard deviation `σ_e` of the noise.
+/// - `secret_standard_deviation` is the standard deviation `σ_s` of the secret coefficients.
+///
+/// `m` samples are embedded into a lattice of dimension `d = m + n + 1`, whose secret part
+/// is rescaled by `σ_e/σ_s`. BKZ with block size `β` finds the embedded short vector once
+///
+/// `σ_e·√β ≤ δ_β^(2β-d-1)·(q^m·(σ_e/σ_s)^n)^(1/d)`,
+///
+/// which is the 2016 estimate under the geometric series assumption.
+/// The smallest such `β` over all `m ≤ 2n` costs `0.292β + 16.4 + log2(8d)` bits,
+/// the classical sieving cost model of the lattice estimator.
+///
+/// Hybrid attacks, which matter for sparse secrets, are not taken into account.
+pub fn lwe_security_bits(
+    dimension: usize,
+    log_modulus: f64,
+    noise_standard_deviation: f64,
+    secret_standard_deviation: f64,
+) -> f64 {
+    let max_samples = 2 * dimension;
+    let max_lattice_dimension = max_samples + dimension + 1;
+
+    let ln_modulus = log_modulus * core::f64::consts::LN_2;
+    let ln_noise = noise_standard_deviation.ln();
+    let ln_scale = ln_noise - secret_standard_deviation.ln();
+
+    // Returns the smallest lattice dimension at which the block size `beta` succeeds.
+    let lattice_dimension = |beta: usize| {
+        let beta_f = beta as f64;
+        let ln_delta =
+            ((PI * beta_f).ln() / beta_f + (beta_f / (2.0 * PI * E)).ln()) / (2.0 * (beta_f - 1.0));
+        let lhs = ln_noise + 0.5 * beta_f.ln();
+
+        (0..=max_samples)
+            .map(|m| m + dimension + 1)
+            .filter(|&d| d >= beta)
+            .find(|&d| {
+                let d_f = d as f64;
+                let m_f = (d - dimension - 1) as f64;
+                let ln_volume = m_f * ln_modulus + dimension as f64 * ln_scale;
+                lhs <= (2.0 * beta_f - d_f - 1.0) * ln_delta + ln_volume / d_f
+            })
+    };
+
+    // A larger block size never fails where a smaller one succeeds.
+    let (mut low, mut high) = (MIN_BLOCK_SIZE, max_lattice_dimension);
+    while low < high {
+        let mid = (low + high) / 2;
+        if lattice_dimension(mid).is_some() {
+            high = mid;
+        } else {
+            low = mid + 1;
+        }
+    }
+
+    let beta = low;
+    let d = lattice_dimension(beta).unwrap_or(max_lattice_dimension);
+    0.292 * beta as f64 + 16.4 + (8.0 * d as f64).log2()
+}
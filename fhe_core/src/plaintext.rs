@@ -1,4 +1,8 @@
-use algebra::{integer::UnsignedInteger, reduce::ModulusValue};
+use algebra::{
+    integer::{AsFrom, AsInto, UnsignedInteger},
+    reduce::ModulusValue,
+    Field,
+};
 
 /// Encodes a message.
 ///
@@ -151,3 +155,95 @@ where
         .map_err(|_| "out of range integral type conversion attempted")
         .unwrap()
 }
+
+/// Encodes a signed message.
+///
+/// The message is first mapped into `[0, t)` and then encoded by [`encode`].
+///
+/// # Parameters
+///
+/// - `t` is message space
+/// - `q` is LWE modulus value.
+///
+/// # Panic
+///
+/// Panics if the message is not in `[-t/2, t/2]`.
+#[inline]
+pub fn encode_centered<M, C>(message: M, t: C, q: ModulusValue<C>) -> C
+where
+    C: UnsignedInteger,
+    M: Into<i64>,
+{
+    let message: i64 = message.into();
+    let t_half: i64 = (t >> 1u32).as_into();
+    assert!(
+        -t_half <= message && message <= t_half,
+        "message {message} is out of the centered message space"
+    );
+
+    let message = if message < 0 {
+        t - C::as_from(-message)
+    } else {
+        C::as_from(message)
+    };
+    encode(message, t, q)
+}
+
+/// Decodes an encode value into a signed message in `(-t/2, t/2]`.
+///
+/// # Parameters
+///
+/// - `t` is message space
+/// - `q` is LWE modulus value.
+///
+/// # Panic
+///
+/// Panics if the decoded message cannot fit in `M`.
+#[inline]
+pub fn decode_centered<M, C>(cipher: C, t: C, q: ModulusValue<C>) -> M
+where
+    M: TryFrom<i64>,
+    C: UnsignedInteger,
+{
+    let decoded: C = decode(cipher, t, q);
+    let decoded: i64 = decoded.as_into();
+    let t: i64 = t.as_into();
+
+    let centered = if decoded > t / 2 {
+        decoded - t
+    } else {
+        decoded
+    };
+
+    M::try_from(centered)
+        .map_err(|_| "out of range integral type conversion attempted")
+        .unwrap()
+}
+
+/// Encodes a message into the field `F`.
+///
+/// The message is scaled by `round(p / t)`, where `p` is the modulus of `F`,
+/// so `t` may be any message space below `p`.
+///
+/// # Parameters
+///
+/// - `t` is message space
+///
+/// # Panic
+///
+/// Panics if the message exceeds the message space.
+#[inline]
+pub fn encode_field<F, M>(message: M, t: u64) -> <F as Field>::ValueT
+where
+    F: Field,
+    M: Into<u64>,
+{
+    let message: u64 = message.into();
+    assert!(
+        message < t,
+        "message {message} is bigger than the message space"
+    );
+    let p: u64 = F::MODULUS_VALUE.as_into();
+    let delta = (p + (t >> 1)) / t;
+    F::mul_scalar_u64(<F as Field>::ValueT::as_from(delta), message)
+}
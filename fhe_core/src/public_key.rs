@@ -1,5 +1,6 @@
 use algebra::{
     integer::UnsignedInteger,
+    ntt::NumberTheoryTransform,
     polynomial::{FieldNttPolynomial, FieldPolynomial, Polynomial},
     random::{sample_binary_values, DiscreteGaussian},
     reduce::RingReduce,
@@ -10,6 +11,7 @@ use rand::{prelude::Distribution, CryptoRng, Rng};
 
 use crate::{
     encode, CmLweCiphertext, LweCiphertext, LweParameters, LweSecretKey, NttRlweSecretKey,
+    RlweCiphertext,
 };
 
 /// Represents a public key for the Learning with Errors (LWE) cryptographic scheme.
@@ -342,8 +344,7 @@ impl<F: NttField> NttRlwePublicKey<F> {
         let dimension = secret_key.coeff_count();
 
         let a = FieldNttPolynomial::random(dimension, rng);
-        let mut b =
-            FieldPolynomial::random_gaussian(dimension, gaussian, rng).into_ntt_poly(ntt_table);
+        let mut b = FieldNttPolynomial::random_gaussian(dimension, gaussian, ntt_table, rng);
 
         b.add_mul_assign(&a, secret_key);
 
@@ -360,17 +361,41 @@ impl<F: NttField> NttRlwePublicKey<F> {
 
     /// Encrypts a message using the NTT RLWE public key.
     ///
+    /// With the public key `(a, b = a*s + e)`, it samples a ternary `r` and
+    /// two gaussian errors `e0, e1`, and outputs `(a*r + e0, b*r + e1 + message)`.
+    ///
     /// # Arguments
     ///
-    /// * `_message` - The message to be encrypted.
+    /// * `message` - The encoded message to be encrypted.
+    /// * `gaussian` - The Gaussian distribution used for generating the errors.
+    /// * `ntt_table` - The NTT table used for Number Theoretic Transform operations.
+    /// * `rng` - A mutable reference to a random number generator.
     ///
-    /// # Type Parameters
+    /// # Returns
     ///
-    /// * `R` - A random number generator that implements `Rng` and `CryptoRng`.
-    pub fn encrypt<R>(_message: &FieldPolynomial<F>)
+    /// An `RlweCiphertext` containing the encrypted message.
+    pub fn encrypt<R>(
+        &self,
+        message: &FieldPolynomial<F>,
+        gaussian: DiscreteGaussian<<F as Field>::ValueT>,
+        ntt_table: &<F as NttField>::Table,
+        rng: &mut R,
+    ) -> RlweCiphertext<F>
     where
         R: Rng + CryptoRng,
     {
-        todo!()
+        let dimension = self.key.dimension();
+        debug_assert_eq!(dimension, message.coeff_count());
+
+        let r = FieldNttPolynomial::random_ternary(dimension, ntt_table, rng);
+
+        let mut a = ntt_table.inverse_transform_inplace(self.key.a().clone() * &r);
+        a += FieldPolynomial::random_gaussian(dimension, gaussian, rng);
+
+        let mut b = ntt_table.inverse_transform_inplace(self.key.b().clone() * &r);
+        b += FieldPolynomial::random_gaussian(dimension, gaussian, rng);
+        b += message;
+
+        RlweCiphertext::new(a, b)
     }
 }
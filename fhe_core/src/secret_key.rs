@@ -3,15 +3,16 @@ use std::ops::Deref;
 use algebra::{
     integer::UnsignedInteger,
     ntt::NumberTheoryTransform,
-    polynomial::{FieldNttPolynomial, FieldPolynomial},
-    random::{sample_binary_values, sample_ternary_values, DiscreteGaussian},
+    polynomial::{FieldNttPolynomial, FieldPolynomial, SparseTernaryPoly},
+    random::{sample_binary_values, sample_fixed_weight, sample_ternary_values, DiscreteGaussian},
     reduce::RingReduce,
     Field, NttField,
 };
+use lattice::{NttRlwe, NttSecretKey};
 use num_traits::{ConstOne, ConstZero, One, Zero};
 use rand::{CryptoRng, Rng};
 
-use crate::{decode, encode, LweCiphertext, LweParameters};
+use crate::{decode, encode, LweCiphertext, LweParameters, RlweCiphertext};
 
 /// The distribution type of the LWE Secret Key.
 #[derive(Debug, Default, Clone, Copy, PartialEq)]
@@ -21,6 +22,8 @@ pub enum LweSecretKeyType {
     /// Ternary SecretKey Distribution.
     #[default]
     Ternary,
+    /// Ternary SecretKey Distribution with exactly the given number of nonzero coefficients.
+    SparseTernary(usize),
 }
 
 /// The distribution type of the Ring Secret Key.
@@ -33,6 +36,33 @@ pub enum RingSecretKeyType {
     Ternary,
     /// Gaussian SecretKey Distribution.
     Gaussian,
+    /// Ternary SecretKey Distribution with exactly the given number of nonzero coefficients.
+    SparseTernary(usize),
+}
+
+impl LweSecretKeyType {
+    /// Returns the standard deviation of the coefficients of a secret key of `dimension`.
+    pub fn standard_deviation(self, dimension: usize) -> f64 {
+        match self {
+            LweSecretKeyType::Binary => 0.5,
+            LweSecretKeyType::Ternary => (2.0f64 / 3.0).sqrt(),
+            LweSecretKeyType::SparseTernary(weight) => (weight as f64 / dimension as f64).sqrt(),
+        }
+    }
+}
+
+impl RingSecretKeyType {
+    /// Returns the standard deviation of the coefficients of a secret key of `dimension`.
+    ///
+    /// A gaussian secret key is sampled from the noise distribution of `noise_standard_deviation`.
+    pub fn standard_deviation(self, dimension: usize, noise_standard_deviation: f64) -> f64 {
+        match self {
+            RingSecretKeyType::Binary => 0.5,
+            RingSecretKeyType::Ternary => (2.0f64 / 3.0).sqrt(),
+            RingSecretKeyType::Gaussian => noise_standard_deviation,
+            RingSecretKeyType::SparseTernary(weight) => (weight as f64 / dimension as f64).sqrt(),
+        }
+    }
 }
 
 /// Represents a secret key for the Learning with Errors (LWE) cryptographic scheme.
@@ -89,6 +119,10 @@ impl<C: UnsignedInteger> LweSecretKey<C> {
     /// # Returns
     ///
     /// A new instance of `LweSecretKey` with random coefficients.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the hamming weight of [`LweSecretKeyType::SparseTernary`] is greater than the dimension.
     #[inline]
     pub fn generate<R, M>(params: &LweParameters<C, M>, rng: &mut R) -> Self
     where
@@ -101,6 +135,12 @@ impl<C: UnsignedInteger> LweSecretKey<C> {
             LweSecretKeyType::Ternary => {
                 sample_ternary_values(params.cipher_modulus_minus_one, params.dimension, rng)
             }
+            LweSecretKeyType::SparseTernary(hamming_weight) => sample_fixed_weight(
+                params.cipher_modulus_minus_one,
+                params.dimension,
+                hamming_weight,
+                rng,
+            ),
         };
         Self { key, distr }
     }
@@ -123,6 +163,9 @@ impl<C: UnsignedInteger> LweSecretKey<C> {
         let distr = match rlwe_secret_key.distr {
             RingSecretKeyType::Binary => LweSecretKeyType::Binary,
             RingSecretKeyType::Ternary => LweSecretKeyType::Ternary,
+            RingSecretKeyType::SparseTernary(hamming_weight) => {
+                LweSecretKeyType::SparseTernary(hamming_weight)
+            }
             RingSecretKeyType::Gaussian => panic!("Not support"),
         };
         let convert = |value: &<F as Field>::ValueT| {
@@ -147,6 +190,12 @@ impl<C: UnsignedInteger> LweSecretKey<C> {
         self.distr
     }
 
+    /// Returns the number of nonzero coefficients of the secret key.
+    #[inline]
+    pub fn hamming_weight(&self) -> usize {
+        self.key.iter().filter(|v| !v.is_zero()).count()
+    }
+
     /// Encrypts message into [`LweCiphertext<C>`].
     #[inline]
     pub fn encrypt<Msg, R, Modulus>(
@@ -227,6 +276,37 @@ impl<C: UnsignedInteger> LweSecretKey<C> {
                 .min(modulus.reduce_sub(fresh, plaintext)),
         )
     }
+
+    /// Returns the signed decryption error of the [`LweCiphertext`],
+    /// i.e. the phase minus the nearest encoded message, centered around zero.
+    ///
+    /// Its magnitude equals the noise returned by [`Self::decrypt_with_noise`].
+    #[inline]
+    pub fn decryption_error<Modulus>(
+        &self,
+        cipher_text: &LweCiphertext<C>,
+        params: &LweParameters<C, Modulus>,
+    ) -> i64
+    where
+        Modulus: RingReduce<C>,
+    {
+        let modulus = params.cipher_modulus;
+        let a_mul_s = modulus.reduce_dot_product(cipher_text.a(), self);
+        let plaintext = modulus.reduce_sub(cipher_text.b(), a_mul_s);
+
+        let t = params.plain_modulus_value;
+        let q = params.cipher_modulus_value;
+        let fresh = encode::<C, C>(decode::<C, C>(plaintext, t, q), t, q);
+
+        let up = modulus.reduce_sub(plaintext, fresh);
+        let down = modulus.reduce_sub(fresh, plaintext);
+        if up <= down {
+            up.as_into()
+        } else {
+            let down: i64 = down.as_into();
+            -down
+        }
+    }
 }
 
 /// Represents a secret key for the Ring Learning with Errors (RLWE) cryptographic scheme.
@@ -238,6 +318,7 @@ impl<C: UnsignedInteger> LweSecretKey<C> {
 pub struct RlweSecretKey<F: NttField> {
     key: FieldPolynomial<F>,
     distr: RingSecretKeyType,
+    sparse: Option<SparseTernaryPoly>,
 }
 
 impl<F: NttField> Deref for RlweSecretKey<F> {
@@ -262,7 +343,11 @@ impl<F: NttField> RlweSecretKey<F> {
     /// A new instance of `RlweSecretKey`.
     #[inline]
     pub fn new(key: FieldPolynomial<F>, distr: RingSecretKeyType) -> Self {
-        Self { key, distr }
+        Self {
+            key,
+            distr,
+            sparse: None,
+        }
     }
 
     /// Generates a new `RlweSecretKey` with random coefficients.
@@ -277,6 +362,10 @@ impl<F: NttField> RlweSecretKey<F> {
     /// # Returns
     ///
     /// A new instance of `RlweSecretKey` with random coefficients.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the hamming weight of [`RingSecretKeyType::SparseTernary`] is greater than `dimension`.
     #[inline]
     pub fn generate<R: Rng + CryptoRng>(
         secret_key_type: RingSecretKeyType,
@@ -291,9 +380,22 @@ impl<F: NttField> RlweSecretKey<F> {
             RingSecretKeyType::Gaussian => {
                 FieldPolynomial::random_gaussian(dimension, gaussian.unwrap(), rng)
             }
+            RingSecretKeyType::SparseTernary(hamming_weight) => {
+                FieldPolynomial::random_ternary_with_hamming_weight(dimension, hamming_weight, rng)
+            }
         };
 
-        Self { key, distr }
+        let secret_key = Self {
+            key,
+            distr,
+            sparse: None,
+        };
+
+        // A key with a fixed small weight is worth multiplying in its sparse form.
+        match distr {
+            RingSecretKeyType::SparseTernary(_) => secret_key.with_sparse_form(),
+            _ => secret_key,
+        }
     }
 
     /// Creates a new `RlweSecretKey` from an LWE secret key.
@@ -319,11 +421,20 @@ impl<F: NttField> RlweSecretKey<F> {
         let distr = match lwe_secret_key.distr {
             LweSecretKeyType::Binary => RingSecretKeyType::Binary,
             LweSecretKeyType::Ternary => RingSecretKeyType::Ternary,
+            LweSecretKeyType::SparseTernary(hamming_weight) => {
+                RingSecretKeyType::SparseTernary(hamming_weight)
+            }
         };
 
-        RlweSecretKey {
+        let secret_key = RlweSecretKey {
             key: FieldPolynomial::new(lwe_secret_key.as_ref().iter().map(convert).collect()),
             distr,
+            sparse: None,
+        };
+
+        match distr {
+            RingSecretKeyType::SparseTernary(_) => secret_key.with_sparse_form(),
+            _ => secret_key,
         }
     }
 
@@ -336,6 +447,67 @@ impl<F: NttField> RlweSecretKey<F> {
     pub fn distr(&self) -> RingSecretKeyType {
         self.distr
     }
+
+    /// Returns the number of nonzero coefficients of the secret key.
+    #[inline]
+    pub fn hamming_weight(&self) -> usize {
+        self.key.iter().filter(|v| !v.is_zero()).count()
+    }
+
+    /// Stores the sparse form of the secret key besides the dense one,
+    /// which speeds up multiplications by the secret key when it has few nonzero coefficients.
+    ///
+    /// The secret key is left unchanged if it is not binary or ternary.
+    #[inline]
+    pub fn with_sparse_form(mut self) -> Self {
+        self.sparse = SparseTernaryPoly::from_field_polynomial(&self.key);
+        self
+    }
+
+    /// Returns the sparse form of the secret key if it is stored.
+    #[inline]
+    pub fn sparse_form(&self) -> Option<&SparseTernaryPoly> {
+        self.sparse.as_ref()
+    }
+
+    /// Calculates `poly * s`, using the sparse form of the secret key if it is stored.
+    pub fn mul_polynomial(
+        &self,
+        poly: &FieldPolynomial<F>,
+        ntt_table: &<F as NttField>::Table,
+    ) -> FieldPolynomial<F> {
+        match self.sparse {
+            Some(ref sparse) => {
+                let mut result = FieldPolynomial::zero(self.key.coeff_count());
+                sparse.mul_into(poly, &mut result);
+                result
+            }
+            None => poly.clone().mul(self.key.clone(), ntt_table),
+        }
+    }
+
+    /// Calculates the phase `b - a * s` of the RLWE ciphertext,
+    /// which is the encoded message plus noise.
+    #[inline]
+    pub fn phase(
+        &self,
+        ciphertext: &RlweCiphertext<F>,
+        ntt_table: &<F as NttField>::Table,
+    ) -> FieldPolynomial<F> {
+        ciphertext.b() - self.mul_polynomial(ciphertext.a(), ntt_table)
+    }
+
+    /// Returns the infinity norm of the noise in the RLWE ciphertext,
+    /// given the `encoded` message it should decrypt to.
+    #[inline]
+    pub fn noise_linf(
+        &self,
+        ciphertext: &RlweCiphertext<F>,
+        encoded: &FieldPolynomial<F>,
+        ntt_table: &<F as NttField>::Table,
+    ) -> u64 {
+        self.phase(ciphertext, ntt_table).distance_linf(encoded)
+    }
 }
 
 /// Represents a secret key for the Number Theoretic Transform (NTT) Ring Learning with Errors (RLWE) cryptographic scheme.
@@ -347,6 +519,8 @@ impl<F: NttField> RlweSecretKey<F> {
 pub struct NttRlweSecretKey<F: NttField> {
     key: FieldNttPolynomial<F>,
     distr: RingSecretKeyType,
+    /// The sparse form of the coefficient secret key, if it stores one.
+    sparse: Option<SparseTernaryPoly>,
 }
 
 impl<F: NttField> Deref for NttRlweSecretKey<F> {
@@ -377,6 +551,7 @@ impl<F: NttField> NttRlweSecretKey<F> {
         Self {
             key: ntt_table.transform(&secret_key.key),
             distr: secret_key.distr,
+            sparse: secret_key.sparse.clone(),
         }
     }
 
@@ -390,3 +565,32 @@ impl<F: NttField> NttRlweSecretKey<F> {
         self.distr
     }
 }
+
+impl<F: NttField> NttSecretKey<F> for NttRlweSecretKey<F> {
+    #[inline]
+    fn ntt_form(&self) -> &FieldNttPolynomial<F> {
+        &self.key
+    }
+
+    /// Generates `count` [`NttRlwe<F>`] samples which encrypt `0`,
+    /// using the sparse form of the secret key if it is stored.
+    fn generate_random_zero_samples<R>(
+        &self,
+        count: usize,
+        gaussian: DiscreteGaussian<<F as Field>::ValueT>,
+        ntt_table: &<F as NttField>::Table,
+        rng: &mut R,
+    ) -> Vec<NttRlwe<F>>
+    where
+        R: Rng + CryptoRng,
+    {
+        match self.sparse {
+            Some(ref sparse) => NttRlwe::generate_random_zero_samples_sparse(
+                count, sparse, gaussian, ntt_table, rng,
+            ),
+            None => {
+                NttRlwe::generate_random_zero_samples(count, &self.key, gaussian, ntt_table, rng)
+            }
+        }
+    }
+}
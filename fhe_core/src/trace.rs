@@ -96,7 +96,9 @@ impl<F: NttField> TraceKey<F> {
 
 #[cfg(test)]
 mod tests {
-    use algebra::{ntt::NumberTheoryTransform, polynomial::FieldPolynomial, Field, U32FieldEval};
+    use algebra::{
+        ntt::NumberTheoryTransform, polynomial::FieldPolynomial, Field, ScalarFactor, U32FieldEval,
+    };
     use rand::{distributions::Uniform, prelude::Distribution};
 
     use crate::RingSecretKeyType;
@@ -157,9 +159,9 @@ mod tests {
         );
         *cipher.b_mut() += &encoded_values;
 
-        let n_inv = FieldT::inv(N as ValT);
-        cipher.a_mut().mul_scalar_assign(n_inv);
-        cipher.b_mut().mul_scalar_assign(n_inv);
+        let n_inv = <ScalarFactor<FieldT>>::new(FieldT::inv(N as ValT));
+        cipher.a_mut().mul_factor_assign(&n_inv);
+        cipher.b_mut().mul_factor_assign(&n_inv);
 
         let result = trace_key.trace(&cipher);
 
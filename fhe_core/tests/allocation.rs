@@ -0,0 +1,205 @@
+//! Counts the allocations of the bootstrapping primitives once their spaces are warm.
+//!
+//! The allocator of this test binary counts per thread,
+//! so the tests running in parallel don't disturb each other.
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+    sync::Arc,
+};
+
+use algebra::{
+    decompose::NonPowOf2ApproxSignedBasis, integer::AsInto, modulus::PowOf2Modulus,
+    ntt::FieldTableWithShoupRoot, polynomial::FieldPolynomial, random::DiscreteGaussian, Field,
+    NttField, U16FieldEval, U32FieldEval,
+};
+use fhe_core::{
+    init_accumulator, lwe_modulus_switch_assign, lwe_modulus_switch_inplace, BlindRotationKey,
+    KeySwitchingParameters, LweCiphertext, LweKeySwitchingKeyRlweMode, LweParameters, LweSecretKey,
+    LweSecretKeyType, NttRlweSecretKey, RingSecretKeyType, RlweSecretKey,
+};
+use rand::{thread_rng, Rng};
+
+type Fp = U32FieldEval<132120577>;
+
+/// A field whose table is always a [`FieldTableWithShoupRoot`],
+/// so the four-step transforms can be exercised at a small size.
+type SmallFp = U16FieldEval<7681>;
+
+const LOG_N: u32 = 8;
+const N: usize = 1 << LOG_N;
+
+struct CountingAllocator;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+fn record_allocation() {
+    let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        record_allocation();
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        record_allocation();
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+/// Runs `f` and returns its output and the number of allocations it made on this thread.
+fn allocations_in<T>(f: impl FnOnce() -> T) -> (T, usize) {
+    let before = ALLOCATIONS.with(Cell::get);
+    let output = f();
+    (output, ALLOCATIONS.with(Cell::get) - before)
+}
+
+#[test]
+fn test_warm_blind_rotation_does_not_allocate() {
+    let mut rng = thread_rng();
+
+    let ntt_table = Arc::new(Fp::generate_ntt_table(LOG_N).unwrap());
+    let gaussian = DiscreteGaussian::new(0.0, 3.2, Fp::MINUS_ONE).unwrap();
+    let basis = NonPowOf2ApproxSignedBasis::new(Fp::MODULUS_VALUE, 7, None);
+
+    let rlwe_sk = RlweSecretKey::<Fp>::generate(RingSecretKeyType::Ternary, N, None, &mut rng);
+    let ntt_rlwe_sk = NttRlweSecretKey::from_coeff_secret_key(&rlwe_sk, &ntt_table);
+
+    let lut = FieldPolynomial::<Fp>::random(N, &mut rng);
+
+    for secret_key_type in [LweSecretKeyType::Binary, LweSecretKeyType::Ternary] {
+        let params = lwe_parameters(secret_key_type);
+        let lwe_sk = LweSecretKey::generate(&params, &mut rng);
+
+        let key = BlindRotationKey::generate(
+            &lwe_sk,
+            &ntt_rlwe_sk,
+            &basis,
+            gaussian,
+            Arc::clone(&ntt_table),
+            &mut rng,
+        );
+
+        // The ciphertext after the modulus switch to 2N.
+        let a: Vec<u16> = (0..params.dimension)
+            .map(|_| rng.gen_range(1..2 * N as u16))
+            .collect();
+        let b: u16 = rng.gen_range(0..2 * N as u16);
+
+        // The first rotation fills the pool of spaces.
+        let expected = key.blind_rotate_accumulator(init_accumulator(b, lut.clone()), &a);
+
+        // The accumulator becomes the output ciphertext, so it's made outside.
+        let acc = init_accumulator(b, lut.clone());
+        let (result, allocations) = allocations_in(|| key.blind_rotate_accumulator(acc, &a));
+        assert_eq!(allocations, 0);
+        assert!(result == expected);
+    }
+}
+
+#[test]
+fn test_warm_gate_does_not_allocate() {
+    let ntt_table = Arc::new(Fp::generate_ntt_table(LOG_N).unwrap());
+    check_warm_gate::<Fp>(ntt_table);
+}
+
+#[test]
+fn test_warm_gate_with_four_step_table_does_not_allocate() {
+    let ntt_table =
+        FieldTableWithShoupRoot::<SmallFp>::with_four_step_threshold(SmallFp::MODULUS, LOG_N, 4)
+            .unwrap();
+    assert!(ntt_table.is_four_step());
+    check_warm_gate::<SmallFp>(Arc::new(ntt_table));
+}
+
+fn lwe_parameters(secret_key_type: LweSecretKeyType) -> LweParameters<u16, PowOf2Modulus<u16>> {
+    LweParameters::new(
+        16,
+        4u16,
+        PowOf2Modulus::<u16>::new(1 << 10),
+        secret_key_type,
+        3.2,
+    )
+}
+
+/// Runs the steps of a gate bootstrapping, the modulus switch to 2N,
+/// the blind rotation, the key switch and the modulus switch back,
+/// and checks that none of them allocates once the spaces are warm.
+fn check_warm_gate<F: NttField>(ntt_table: Arc<<F as NttField>::Table>) {
+    let mut rng = thread_rng();
+
+    let params = lwe_parameters(LweSecretKeyType::Binary);
+    let lwe_modulus = params.cipher_modulus_value;
+    let twice_n = 2 * N as u16;
+
+    let gaussian = DiscreteGaussian::new(0.0, 3.2, F::MINUS_ONE).unwrap();
+    let basis = NonPowOf2ApproxSignedBasis::new(F::MODULUS_VALUE, 3, None);
+
+    let rlwe_sk = RlweSecretKey::<F>::generate(RingSecretKeyType::Ternary, N, None, &mut rng);
+    let ntt_rlwe_sk = NttRlweSecretKey::from_coeff_secret_key(&rlwe_sk, &ntt_table);
+    let lwe_sk = LweSecretKey::generate(&params, &mut rng);
+
+    let br_key = BlindRotationKey::generate(
+        &lwe_sk,
+        &ntt_rlwe_sk,
+        &basis,
+        gaussian,
+        Arc::clone(&ntt_table),
+        &mut rng,
+    );
+
+    let ks_params = KeySwitchingParameters {
+        input_cipher_dimension: N,
+        output_cipher_dimension: params.dimension,
+        log_modulus: AsInto::<f64>::as_into(F::MODULUS_VALUE).log2().ceil() as u32,
+        log_basis: 3,
+        reverse_length: None,
+        noise_standard_deviation: 3.2,
+    };
+    let ks_key =
+        LweKeySwitchingKeyRlweMode::generate(&rlwe_sk, &lwe_sk, ks_params, ntt_table, &mut rng);
+
+    let lut = FieldPolynomial::<F>::random(N, &mut rng);
+    let c = LweCiphertext::new(
+        (0..params.dimension)
+            .map(|_| rng.gen_range(0..1u16 << 10))
+            .collect(),
+        rng.gen_range(0..1u16 << 10),
+    );
+
+    let switch_to_twice_n = |c: &mut LweCiphertext<u16>| {
+        lwe_modulus_switch_assign(c, lwe_modulus, twice_n);
+    };
+    let rotate_and_switch = |acc, c: &mut LweCiphertext<u16>| {
+        let acc = br_key.blind_rotate_accumulator(acc, c.a());
+        let key_switched = ks_key.key_switch_for_rlwe(acc);
+        lwe_modulus_switch_inplace(key_switched, F::MODULUS_VALUE, lwe_modulus, c);
+    };
+
+    // The first gate fills the pools of spaces.
+    let mut expected = c.clone();
+    switch_to_twice_n(&mut expected);
+    let acc = init_accumulator(expected.b(), lut.clone());
+    rotate_and_switch(acc, &mut expected);
+
+    let mut result = c;
+    let ((), mut allocations) = allocations_in(|| switch_to_twice_n(&mut result));
+    // The accumulator is the only buffer taken by the gate, so it's made outside.
+    let acc = init_accumulator(result.b(), lut);
+    allocations += allocations_in(|| rotate_and_switch(acc, &mut result)).1;
+
+    assert_eq!(allocations, 0);
+    assert!(result == expected);
+}
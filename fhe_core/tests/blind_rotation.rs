@@ -0,0 +1,108 @@
+use std::sync::Arc;
+
+use algebra::{
+    decompose::NonPowOf2ApproxSignedBasis, modulus::PowOf2Modulus, random::DiscreteGaussian, Field,
+    NttField, U32FieldEval,
+};
+use fhe_core::{
+    init_accumulator, BlindRotationKey, LweCiphertext, LweParameters, LweSecretKey,
+    LweSecretKeyType, NttRlweSecretKey, RingSecretKeyType, RlweSecretKey,
+};
+use rand::{thread_rng, Rng};
+
+type Fp = U32FieldEval<132120577>;
+
+const LOG_N: u32 = 8;
+const N: usize = 1 << LOG_N;
+
+#[test]
+fn test_generate_with_progress() {
+    let mut rng = thread_rng();
+
+    let ntt_table = Arc::new(Fp::generate_ntt_table(LOG_N).unwrap());
+    let gaussian = DiscreteGaussian::new(0.0, 3.2, Fp::MINUS_ONE).unwrap();
+    let basis = NonPowOf2ApproxSignedBasis::new(Fp::MODULUS_VALUE, 7, None);
+
+    let rlwe_sk = RlweSecretKey::<Fp>::generate(RingSecretKeyType::Ternary, N, None, &mut rng);
+    let ntt_rlwe_sk = NttRlweSecretKey::from_coeff_secret_key(&rlwe_sk, &ntt_table);
+
+    for secret_key_type in [LweSecretKeyType::Binary, LweSecretKeyType::Ternary] {
+        let params = LweParameters::new(
+            16,
+            4u16,
+            PowOf2Modulus::<u16>::new(1 << 10),
+            secret_key_type,
+            3.2,
+        );
+        let lwe_sk = LweSecretKey::generate(&params, &mut rng);
+
+        let mut calls = Vec::new();
+        let _key = BlindRotationKey::generate_with_progress(
+            &lwe_sk,
+            &ntt_rlwe_sk,
+            &basis,
+            gaussian,
+            Arc::clone(&ntt_table),
+            &mut rng,
+            |done, total| calls.push((done, total)),
+        );
+
+        let expected: Vec<_> = (1..=params.dimension)
+            .map(|done| (done, params.dimension))
+            .collect();
+        assert_eq!(calls, expected);
+    }
+}
+
+#[test]
+fn test_blind_rotate_from_accumulator() {
+    use algebra::polynomial::FieldPolynomial;
+
+    let mut rng = thread_rng();
+
+    let ntt_table = Arc::new(Fp::generate_ntt_table(LOG_N).unwrap());
+    let gaussian = DiscreteGaussian::new(0.0, 3.2, Fp::MINUS_ONE).unwrap();
+    let basis = NonPowOf2ApproxSignedBasis::new(Fp::MODULUS_VALUE, 7, None);
+
+    let rlwe_sk = RlweSecretKey::<Fp>::generate(RingSecretKeyType::Ternary, N, None, &mut rng);
+    let ntt_rlwe_sk = NttRlweSecretKey::from_coeff_secret_key(&rlwe_sk, &ntt_table);
+
+    let lut = FieldPolynomial::<Fp>::random(N, &mut rng);
+
+    let acc = init_accumulator(0u16, lut.clone());
+    assert!(acc.a().is_zero());
+    assert_eq!(acc.b(), &lut);
+    let acc = init_accumulator(1u16, lut.clone());
+    assert_eq!(acc.b()[N - 1], Fp::neg(lut[0]));
+
+    for secret_key_type in [LweSecretKeyType::Binary, LweSecretKeyType::Ternary] {
+        let params = LweParameters::new(
+            16,
+            4u16,
+            PowOf2Modulus::<u16>::new(1 << 10),
+            secret_key_type,
+            3.2,
+        );
+        let lwe_sk = LweSecretKey::generate(&params, &mut rng);
+
+        let key = BlindRotationKey::generate(
+            &lwe_sk,
+            &ntt_rlwe_sk,
+            &basis,
+            gaussian,
+            Arc::clone(&ntt_table),
+            &mut rng,
+        );
+
+        // The ciphertext after the modulus switch to 2N.
+        let a: Vec<u16> = (0..params.dimension)
+            .map(|_| rng.gen_range(0..2 * N as u16))
+            .collect();
+        let b: u16 = rng.gen_range(0..2 * N as u16);
+        let lwe = LweCiphertext::new(a, b);
+
+        let expected = key.blind_rotate(lut.clone(), &lwe);
+        let acc = init_accumulator(lwe.b(), lut.clone());
+        assert!(key.blind_rotate_accumulator(acc, lwe.a()) == expected);
+    }
+}
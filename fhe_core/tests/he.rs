@@ -1,5 +1,11 @@
-use algebra::{modulus::PowOf2Modulus, reduce::ModulusValue};
-use fhe_core::{LweParameters, LwePublicKey, LwePublicKeyRlweMode, LweSecretKey, LweSecretKeyType};
+use algebra::{
+    modulus::PowOf2Modulus, polynomial::FieldPolynomial, random::DiscreteGaussian,
+    reduce::ModulusValue, test_utils::assert_poly_close, Field, NttField, U32FieldEval,
+};
+use fhe_core::{
+    FHECoreError, LweParameters, LwePublicKey, LwePublicKeyRlweMode, LweSecretKey,
+    LweSecretKeyType, NttRlwePublicKey, NttRlweSecretKey, RingSecretKeyType, RlweSecretKey,
+};
 use lattice::Lwe;
 use rand::{distributions::Uniform, thread_rng, Rng};
 
@@ -63,3 +69,63 @@ fn test_lwe_pk() {
     let m: MsgT = sk.decrypt(&c1, &params);
     assert_eq!(m, messages[index]);
 }
+
+#[test]
+fn test_lwe_from_parts() {
+    type CipherT = u16;
+
+    let mut rng = thread_rng();
+
+    let cipher_modulus = 2048;
+    let params = LweParameters::new(
+        512,
+        4 as CipherT,
+        PowOf2Modulus::<CipherT>::new(cipher_modulus),
+        LweSecretKeyType::Binary,
+        3.20,
+    );
+
+    let sk = LweSecretKey::generate(&params, &mut rng);
+    let c: Lwe<CipherT> = sk.encrypt(3u8, &params, &mut rng);
+
+    let rebuilt = params.ciphertext_from_parts(c.a().to_vec(), c.b()).unwrap();
+    assert_eq!(rebuilt, c);
+    let m: u8 = sk.decrypt(&rebuilt, &params);
+    assert_eq!(m, 3);
+
+    let (a, b) = rebuilt.into_parts();
+    assert!(matches!(
+        params.ciphertext_from_parts(a[1..].to_vec(), b),
+        Err(FHECoreError::LweDimensionMismatch {
+            expected: 512,
+            actual: 511
+        })
+    ));
+}
+
+#[test]
+fn test_rlwe_pk() {
+    type Fp = U32FieldEval<132120577>;
+    const LOG_N: u32 = 10;
+    const N: usize = 1 << LOG_N;
+
+    let mut rng = thread_rng();
+
+    let ntt_table = Fp::generate_ntt_table(LOG_N).unwrap();
+    let gaussian = DiscreteGaussian::new(0.0, 3.2, Fp::MINUS_ONE).unwrap();
+
+    let sk = RlweSecretKey::<Fp>::generate(RingSecretKeyType::Ternary, N, None, &mut rng);
+    let ntt_sk = NttRlweSecretKey::from_coeff_secret_key(&sk, &ntt_table);
+    let pk = NttRlwePublicKey::new(&ntt_sk, gaussian, &ntt_table, &mut rng);
+
+    let t = 4;
+    let delta = Fp::MODULUS_VALUE / t;
+    let messages: Vec<u32> = (&mut rng).sample_iter(Uniform::new(0, t)).take(N).collect();
+    let encoded = FieldPolynomial::<Fp>::new(messages.iter().map(|&m| m * delta).collect());
+
+    let cipher = pk.encrypt(&encoded, gaussian, &ntt_table, &mut rng);
+
+    let phase = sk.phase(&cipher, &ntt_table);
+    assert_poly_close(&phase, &encoded, (delta / 2 - 1) as u64);
+    assert!(sk.noise_linf(&cipher, &encoded, &ntt_table) < (delta / 2) as u64);
+}
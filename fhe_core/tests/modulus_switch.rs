@@ -0,0 +1,69 @@
+use algebra::reduce::ModulusValue;
+use fhe_core::{lwe_modulus_switch, lwe_modulus_switch_error_bound, LweCiphertext};
+use rand::{distributions::Uniform, thread_rng, Rng};
+
+/// The phase `b - <a, s>` as an integer in `[0, q)`.
+fn phase<C: Copy + Into<i64>>(c: &LweCiphertext<C>, s: &[i64], q: i64) -> i64 {
+    let sum = c
+        .a()
+        .iter()
+        .zip(s)
+        .fold(0i64, |acc, (&a, &s)| (acc + a.into() * s) % q);
+    (c.b().into() - sum).rem_euclid(q)
+}
+
+fn check_modulus_switch_error(modulus_in: u32, modulus_out: u16, dimension: usize) {
+    let mut rng = thread_rng();
+
+    let bound =
+        lwe_modulus_switch_error_bound(modulus_in, ModulusValue::PowerOf2(modulus_out), dimension);
+
+    let trials = 2000;
+    let mut sum_sq = 0.0;
+    for _ in 0..trials {
+        let s: Vec<i64> = (0..dimension).map(|_| rng.gen_range(-1..=1)).collect();
+        let c = LweCiphertext::new(
+            (&mut rng)
+                .sample_iter(Uniform::new(0, modulus_in))
+                .take(dimension)
+                .collect(),
+            rng.gen_range(0..modulus_in),
+        );
+        let switched: LweCiphertext<u16> =
+            lwe_modulus_switch(&c, modulus_in, ModulusValue::PowerOf2(modulus_out));
+
+        let phase_in = phase(&c, &s, modulus_in as i64);
+        let phase_out = phase(&switched, &s, modulus_out as i64);
+
+        let q_out = modulus_out as f64;
+        let expected = phase_in as f64 * q_out / modulus_in as f64;
+        let error = (phase_out as f64 - expected).rem_euclid(q_out);
+        let error = if error > q_out / 2.0 {
+            error - q_out
+        } else {
+            error
+        };
+
+        assert!(
+            error.abs() <= bound.worst_case,
+            "error {error} exceeds {}",
+            bound.worst_case
+        );
+        sum_sq += error * error;
+    }
+
+    // Ternary keys have zero coefficients, so the measured variance is below the bound.
+    let variance = sum_sq / trials as f64;
+    assert!(
+        variance <= bound.variance * 1.1,
+        "variance {variance} exceeds {}",
+        bound.variance
+    );
+}
+
+#[test]
+fn test_modulus_switch_error_bound() {
+    check_modulus_switch_error(132120577, 2048, 512);
+    check_modulus_switch_error(1 << 20, 1024, 512);
+    check_modulus_switch_error(1 << 10, 1024, 512);
+}
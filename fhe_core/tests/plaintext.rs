@@ -0,0 +1,45 @@
+use algebra::{reduce::ModulusValue, Field, U32FieldEval};
+use fhe_core::{decode_centered, encode_centered, encode_field};
+
+#[test]
+fn test_centered_encode_decode() {
+    let t: u16 = 16;
+    for q in [ModulusValue::PowerOf2(1 << 10), ModulusValue::Native] {
+        for m in -8i8..=8 {
+            let encoded = encode_centered(m, t, q);
+            let decoded: i8 = decode_centered(encoded, t, q);
+            let expected = if m == -8 { 8 } else { m };
+            assert_eq!(decoded, expected);
+        }
+    }
+
+    // a value slightly below `q` decodes to a small negative message.
+    let q: u16 = 1 << 10;
+    let delta = q / t;
+    let decoded: i8 = decode_centered(q - delta + 3, t, ModulusValue::PowerOf2(q));
+    assert_eq!(decoded, -1);
+    let decoded: i8 = decode_centered(q - 3, t, ModulusValue::PowerOf2(q));
+    assert_eq!(decoded, 0);
+}
+
+#[test]
+fn test_encode_field() {
+    type Fp = U32FieldEval<132120577>;
+    let p = Fp::MODULUS_VALUE as u64;
+
+    for t in [8u64, 1 << 20, p - 1] {
+        let delta = (p + (t >> 1)) / t;
+        for m in [0, 1, t / 2, t - 1] {
+            assert_eq!(
+                encode_field::<Fp, _>(m, t) as u64,
+                (delta as u128 * m as u128 % p as u128) as u64
+            );
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_encode_field_out_of_message_space() {
+    encode_field::<U32FieldEval<132120577>, _>(8u64, 8);
+}
@@ -0,0 +1,79 @@
+use algebra::{
+    polynomial::FieldPolynomial, random::DiscreteGaussian, Field, NttField, U32FieldEval,
+};
+use fhe_core::{NttRlweSecretKey, RingSecretKeyType, RlweSecretKey};
+use lattice::{NttSecretKey, Rlwe};
+
+type Fp = U32FieldEval<132120577>;
+
+const LOG_N: u32 = 10;
+const N: usize = 1 << LOG_N;
+
+#[test]
+fn test_sparse_secret_key_phase() {
+    let mut rng = rand::thread_rng();
+
+    let ntt_table = Fp::generate_ntt_table(LOG_N).unwrap();
+    let gaussian = DiscreteGaussian::new(0.0, 3.2, Fp::MINUS_ONE).unwrap();
+
+    let sk = RlweSecretKey::<Fp>::generate(RingSecretKeyType::Ternary, N, None, &mut rng);
+    let ntt_sk = NttRlweSecretKey::from_coeff_secret_key(&sk, &ntt_table);
+    assert!(sk.sparse_form().is_none());
+
+    let sparse_sk = sk.clone().with_sparse_form();
+    assert_eq!(
+        sparse_sk.sparse_form().unwrap().to_field_polynomial::<Fp>(),
+        *sk
+    );
+
+    let cipher = <Rlwe<Fp>>::generate_random_zero_sample(&ntt_sk, gaussian, &ntt_table, &mut rng);
+    assert_eq!(
+        sk.phase(&cipher, &ntt_table),
+        sparse_sk.phase(&cipher, &ntt_table)
+    );
+
+    // a fresh zero encryption only carries the tail-cut gaussian noise
+    let zero = FieldPolynomial::zero(N);
+    let noise = sk.noise_linf(&cipher, &zero, &ntt_table);
+    assert!(noise as f64 <= gaussian.max_std_dev());
+    assert_eq!(noise, sparse_sk.noise_linf(&cipher, &zero, &ntt_table));
+
+    // gaussian secret keys have no sparse form
+    let gaussian_sk =
+        RlweSecretKey::<Fp>::generate(RingSecretKeyType::Gaussian, N, Some(gaussian), &mut rng)
+            .with_sparse_form();
+    assert!(gaussian_sk.sparse_form().is_none());
+}
+
+#[test]
+fn test_sparse_ternary_secret_key() {
+    let mut rng = rand::thread_rng();
+
+    let h = 64;
+    let sk = RlweSecretKey::<Fp>::generate(RingSecretKeyType::SparseTernary(h), N, None, &mut rng);
+    assert_eq!(sk.distr(), RingSecretKeyType::SparseTernary(h));
+    assert_eq!(sk.iter().filter(|&&v| v != 0).count(), h);
+    assert_eq!(sk.hamming_weight(), h);
+    assert!(sk.iter().all(|&v| v == 0 || v == 1 || v == Fp::MINUS_ONE));
+    assert_eq!(sk.sparse_form().unwrap().hamming_weight(), h);
+}
+
+#[test]
+fn test_sparse_secret_key_zero_samples() {
+    let mut rng = rand::thread_rng();
+
+    let ntt_table = Fp::generate_ntt_table(LOG_N).unwrap();
+    let gaussian = DiscreteGaussian::new(0.0, 3.2, Fp::MINUS_ONE).unwrap();
+
+    let sk = RlweSecretKey::<Fp>::generate(RingSecretKeyType::SparseTernary(64), N, None, &mut rng);
+    let ntt_sk = NttRlweSecretKey::from_coeff_secret_key(&sk, &ntt_table);
+
+    // the samples of a key with a sparse form take their products from it
+    let zero = FieldPolynomial::zero(N);
+    let samples = ntt_sk.generate_random_zero_samples(4, gaussian, &ntt_table, &mut rng);
+    assert_eq!(samples.len(), 4);
+    for sample in samples {
+        let cipher = sample.to_rlwe(&ntt_table);
+        assert!(sk.noise_linf(&cipher, &zero, &ntt_table) as f64 <= gaussian.max_std_dev());
+    }
+}
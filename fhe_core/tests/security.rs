@@ -0,0 +1,38 @@
+use fhe_core::{lwe_security_bits, LweSecretKeyType};
+
+#[test]
+fn test_lwe_security_bits_he_standard() {
+    // The classical levels of the homomorphic encryption standard for a ternary secret,
+    // `σ = 3.19` and the largest `log2(q)` of each level.
+    let ternary = LweSecretKeyType::Ternary.standard_deviation(1024);
+    for (dimension, log_modulus, level) in [
+        (1024, 27.0, 128.0),
+        (1024, 19.0, 192.0),
+        (1024, 14.0, 256.0),
+        (2048, 54.0, 128.0),
+        (2048, 37.0, 192.0),
+        (2048, 29.0, 256.0),
+    ] {
+        let bits = lwe_security_bits(dimension, log_modulus, 3.19, ternary);
+        assert!(
+            (level..level + 12.0).contains(&bits),
+            "n = {dimension}, log2(q) = {log_modulus}: {bits} bits is not around {level}"
+        );
+    }
+}
+
+#[test]
+fn test_lwe_security_bits_monotone() {
+    let binary = LweSecretKeyType::Binary.standard_deviation(512);
+    let ternary = LweSecretKeyType::Ternary.standard_deviation(512);
+    let bits = |n, log_q, sigma, secret| lwe_security_bits(n, log_q, sigma, secret);
+
+    assert!(bits(512, 14.0, 3.2, binary) < bits(512, 14.0, 3.2, ternary));
+    assert!(bits(512, 14.0, 3.2, ternary) < bits(630, 14.0, 3.2, ternary));
+    assert!(bits(512, 20.0, 3.2, ternary) < bits(512, 14.0, 3.2, ternary));
+    assert!(bits(512, 14.0, 3.2, ternary) < bits(512, 14.0, 6.4, ternary));
+
+    // A sparse secret is weaker than a dense one.
+    let sparse = LweSecretKeyType::SparseTernary(64).standard_deviation(512);
+    assert!(bits(512, 14.0, 3.2, sparse) < bits(512, 14.0, 3.2, ternary));
+}
@@ -154,7 +154,8 @@ impl<F: NttField> GadgetRlwe<F> {
         let coeff_count = polynomial.coeff_count();
 
         let mut decompose_space = <PolyDecomposeSpace<F>>::new(coeff_count);
-        let (adjust_poly, carries, decompose_poly) = decompose_space.get_mut();
+        let (adjust_poly, carries, decompose_poly, ntt_workspace) =
+            decompose_space.get_mut_with_workspace();
         polynomial.init_adjust_poly_carries(self.basis(), carries, adjust_poly);
 
         let mut ntt_rlwe = <NttRlwe<F>>::zero(coeff_count);
@@ -167,7 +168,7 @@ impl<F: NttField> GadgetRlwe<F> {
                     carries,
                     decompose_poly.as_mut(),
                 );
-                ntt_table.transform_slice(decompose_poly.as_mut());
+                ntt_table.transform_slice_with(decompose_poly.as_mut(), ntt_workspace);
                 g_rlwe.mul_ntt_polynomial_inplace(decompose_poly, ntt_table, &mut temp);
                 ntt_rlwe.add_assign_element_wise(&temp);
             },
@@ -187,7 +188,8 @@ impl<F: NttField> GadgetRlwe<F> {
         let coeff_count = polynomial.coeff_count();
 
         let mut decompose_space = <PolyDecomposeSpace<F>>::new(coeff_count);
-        let (adjust_poly, carries, decompose_poly) = decompose_space.get_mut();
+        let (adjust_poly, carries, decompose_poly, ntt_workspace) =
+            decompose_space.get_mut_with_workspace();
         polynomial.init_adjust_poly_carries(self.basis(), carries, adjust_poly);
 
         let mut ntt_rlwe = rlwe.to_ntt_rlwe(ntt_table);
@@ -200,7 +202,7 @@ impl<F: NttField> GadgetRlwe<F> {
                     carries,
                     decompose_poly.as_mut(),
                 );
-                ntt_table.transform_slice(decompose_poly.as_mut());
+                ntt_table.transform_slice_with(decompose_poly.as_mut(), ntt_workspace);
                 gadget.mul_ntt_polynomial_inplace(decompose_poly, ntt_table, &mut temp);
                 ntt_rlwe.add_assign_element_wise(&temp);
             },
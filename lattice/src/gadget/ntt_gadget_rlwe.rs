@@ -1,6 +1,6 @@
 use algebra::{
     decompose::{NonPowOf2ApproxSignedBasis, SignedOnceDecompose},
-    ntt::NumberTheoryTransform,
+    ntt::{NttBackend, NumberTheoryTransform},
     polynomial::{FieldNttPolynomial, FieldPolynomial},
     random::DiscreteGaussian,
     reduce::ReduceAddAssign,
@@ -8,7 +8,7 @@ use algebra::{
 };
 use rand::{CryptoRng, Rng};
 
-use crate::{utils::PolyDecomposeSpace, NttRlwe};
+use crate::{utils::PolyDecomposeSpace, NttRlwe, NttSecretKey};
 
 use super::GadgetRlwe;
 
@@ -175,22 +175,9 @@ impl<F: NttField> NttGadgetRlwe<F> {
         let coeff_count = polynomial.coeff_count();
 
         let mut decompose_space = PolyDecomposeSpace::new(coeff_count);
-        let (adjust_poly, carries, decompose_poly) = decompose_space.get_mut();
-        polynomial.init_adjust_poly_carries(self.basis(), carries, adjust_poly);
-
         let mut ntt_rlwe = <NttRlwe<F>>::zero(coeff_count);
 
-        self.iter().zip(self.basis.decompose_iter()).for_each(
-            |(gadget, once_decompose): (&NttRlwe<F>, SignedOnceDecompose<<F as Field>::ValueT>)| {
-                adjust_poly.approx_signed_decompose(
-                    once_decompose,
-                    carries,
-                    decompose_poly.as_mut(),
-                );
-                ntt_table.transform_slice(decompose_poly.as_mut());
-                ntt_rlwe.add_ntt_rlwe_mul_ntt_polynomial_assign(gadget, decompose_poly);
-            },
-        );
+        self.mul_polynomial_inplace(polynomial, ntt_table, &mut decompose_space, &mut ntt_rlwe);
 
         ntt_rlwe
     }
@@ -206,7 +193,8 @@ impl<F: NttField> NttGadgetRlwe<F> {
     ) {
         destination.set_zero();
 
-        let (adjust_poly, carries, decompose_poly) = decompose_space.get_mut();
+        let (adjust_poly, carries, decompose_poly, ntt_workspace) =
+            decompose_space.get_mut_with_workspace();
 
         polynomial.init_adjust_poly_carries(self.basis(), carries, adjust_poly);
 
@@ -217,7 +205,7 @@ impl<F: NttField> NttGadgetRlwe<F> {
                     carries,
                     decompose_poly.as_mut_slice(),
                 );
-                ntt_table.transform_slice(decompose_poly.as_mut_slice());
+                ntt_table.transform_slice_with(decompose_poly.as_mut_slice(), ntt_workspace);
                 destination.add_ntt_rlwe_mul_ntt_polynomial_assign(g_rlwe, decompose_poly);
             },
         )
@@ -228,6 +216,9 @@ impl<F: NttField> NttGadgetRlwe<F> {
     ///
     /// The coefficients in the `destination` may be in [0, 2*modulus) for some case,
     /// and fall back to [0, modulus) for normal case.
+    ///
+    /// Every decomposed polynomial is kept in `decompose_space`,
+    /// so the `b` part is a single sum of products.
     pub fn mul_polynomial_inplace_fast(
         &self,
         polynomial: &FieldPolynomial<F>,
@@ -235,41 +226,55 @@ impl<F: NttField> NttGadgetRlwe<F> {
         decompose_space: &mut PolyDecomposeSpace<F>,
         destination: &mut NttRlwe<F>,
     ) {
-        destination.set_zero();
+        destination.a_mut().set_zero();
 
-        let (adjust_poly, carries, decompose_poly) = decompose_space.get_mut();
+        let (adjust_poly, carries, decompose_polys, ntt_workspace) =
+            decompose_space.get_mut_with_decomposed_polys(self.data.len());
 
         polynomial.init_adjust_poly_carries(self.basis(), carries, adjust_poly);
 
-        self.iter().zip(self.basis.decompose_iter()).for_each(
-            |(g_rlwe, once_decompose): (&NttRlwe<F>, SignedOnceDecompose<<F as Field>::ValueT>)| {
+        self.iter()
+            .zip(self.basis.decompose_iter())
+            .zip(decompose_polys.iter_mut())
+            .for_each(|((g_rlwe, once_decompose), decompose_poly)| {
                 adjust_poly.approx_signed_decompose(
                     once_decompose,
                     carries,
                     decompose_poly.as_mut_slice(),
                 );
-                ntt_table.transform_slice(decompose_poly.as_mut_slice());
-                destination.add_ntt_rlwe_mul_ntt_polynomial_assign_fast(g_rlwe, decompose_poly);
-            },
-        )
+                // The `a` part is multiplied in the last layer of the transform.
+                ntt_table.transform_and_pointwise_mul_with(
+                    decompose_poly.as_mut_slice(),
+                    g_rlwe.a().as_slice(),
+                    destination.a_mut_slice(),
+                    ntt_workspace,
+                );
+            });
+
+        FieldNttPolynomial::sum_of_products(
+            self.iter().map(NttRlwe::b).zip(&*decompose_polys),
+            destination.b_mut(),
+        );
     }
 
     /// Generate a [`NttGadgetRlwe<F>`] sample which encrypts `0`.
-    pub fn generate_random_zero_sample<R>(
-        secret_key: &FieldNttPolynomial<F>,
+    pub fn generate_random_zero_sample<S, R>(
+        secret_key: &S,
         basis: &NonPowOf2ApproxSignedBasis<<F as Field>::ValueT>,
         gaussian: DiscreteGaussian<<F as Field>::ValueT>,
         ntt_table: &<F as NttField>::Table,
         rng: &mut R,
     ) -> Self
     where
+        S: NttSecretKey<F> + ?Sized,
         R: Rng + CryptoRng,
     {
-        let data = (0..basis.decompose_length())
-            .map(|_| {
-                <NttRlwe<F>>::generate_random_zero_sample(secret_key, gaussian, ntt_table, rng)
-            })
-            .collect();
+        let data = secret_key.generate_random_zero_samples(
+            basis.decompose_length(),
+            gaussian,
+            ntt_table,
+            rng,
+        );
         Self {
             data,
             basis: *basis,
@@ -277,24 +282,32 @@ impl<F: NttField> NttGadgetRlwe<F> {
     }
 
     /// Generate a [`NttGadgetRlwe<F>`] sample which encrypts `1`.
-    pub fn generate_random_one_sample<R>(
-        secret_key: &FieldNttPolynomial<F>,
+    pub fn generate_random_one_sample<S, R>(
+        secret_key: &S,
         basis: &NonPowOf2ApproxSignedBasis<<F as Field>::ValueT>,
         gaussian: DiscreteGaussian<<F as Field>::ValueT>,
         ntt_table: &<F as NttField>::Table,
         rng: &mut R,
     ) -> Self
     where
+        S: NttSecretKey<F> + ?Sized,
         R: Rng + CryptoRng,
     {
-        let data = basis
-            .scalar_iter()
-            .map(|scalar| {
-                <NttRlwe<F>>::generate_random_value_sample(
-                    secret_key, scalar, gaussian, ntt_table, rng,
-                )
-            })
-            .collect();
+        // Adding `scalar` to the constant term of the error
+        // adds it to every value of the transformed error.
+        let mut data = secret_key.generate_random_zero_samples(
+            basis.decompose_length(),
+            gaussian,
+            ntt_table,
+            rng,
+        );
+        data.iter_mut()
+            .zip(basis.scalar_iter())
+            .for_each(|(r, scalar)| {
+                r.b_mut()
+                    .iter_mut()
+                    .for_each(|v| F::MODULUS.reduce_add_assign(v, scalar))
+            });
 
         Self {
             data,
@@ -303,8 +316,8 @@ impl<F: NttField> NttGadgetRlwe<F> {
     }
 
     /// Generate a [`NttGadgetRlwe<F>`] sample which encrypts `poly`.
-    pub fn generate_random_poly_sample<R>(
-        secret_key: &FieldNttPolynomial<F>,
+    pub fn generate_random_poly_sample<S, R>(
+        secret_key: &S,
         poly: &FieldNttPolynomial<F>,
         basis: &NonPowOf2ApproxSignedBasis<<F as Field>::ValueT>,
         gaussian: DiscreteGaussian<<F as Field>::ValueT>,
@@ -312,17 +325,18 @@ impl<F: NttField> NttGadgetRlwe<F> {
         rng: &mut R,
     ) -> Self
     where
+        S: NttSecretKey<F> + ?Sized,
         R: Rng + CryptoRng,
     {
-        let data = basis
-            .scalar_iter()
-            .map(|scalar| {
-                let mut r =
-                    <NttRlwe<F>>::generate_random_zero_sample(secret_key, gaussian, ntt_table, rng);
-                r.b_mut().add_mul_scalar_assign(poly, scalar);
-                r
-            })
-            .collect();
+        let mut data = secret_key.generate_random_zero_samples(
+            basis.decompose_length(),
+            gaussian,
+            ntt_table,
+            rng,
+        );
+        data.iter_mut()
+            .zip(basis.scalar_iter())
+            .for_each(|(r, scalar)| r.b_mut().add_mul_scalar_assign(poly, scalar));
 
         Self {
             data,
@@ -331,27 +345,30 @@ impl<F: NttField> NttGadgetRlwe<F> {
     }
 
     /// Generate a [`NttGadgetRlwe<F>`] sample which encrypts `-s`.
-    pub fn generate_random_neg_secret_sample<R>(
-        secret_key: &FieldNttPolynomial<F>,
+    pub fn generate_random_neg_secret_sample<S, R>(
+        secret_key: &S,
         basis: &NonPowOf2ApproxSignedBasis<<F as Field>::ValueT>,
         gaussian: DiscreteGaussian<<F as Field>::ValueT>,
         ntt_table: &<F as NttField>::Table,
         rng: &mut R,
     ) -> Self
     where
+        S: NttSecretKey<F> + ?Sized,
         R: Rng + CryptoRng,
     {
-        let data = basis
-            .scalar_iter()
-            .map(|scalar| {
-                let mut r =
-                    <NttRlwe<F>>::generate_random_zero_sample(secret_key, gaussian, ntt_table, rng);
+        let mut data = secret_key.generate_random_zero_samples(
+            basis.decompose_length(),
+            gaussian,
+            ntt_table,
+            rng,
+        );
+        data.iter_mut()
+            .zip(basis.scalar_iter())
+            .for_each(|(r, scalar)| {
                 r.a_mut_slice()
                     .iter_mut()
-                    .for_each(|v| F::MODULUS.reduce_add_assign(v, scalar));
-                r
-            })
-            .collect();
+                    .for_each(|v| F::MODULUS.reduce_add_assign(v, scalar))
+            });
 
         Self {
             data,
@@ -33,6 +33,12 @@ impl<T: Copy> Lwe<T> {
         Self { a: a.to_vec(), b }
     }
 
+    /// Consumes this [`Lwe<T>`], returns its `a` and `b`.
+    #[inline]
+    pub fn into_parts(self) -> (Vec<T>, T) {
+        (self.a, self.b)
+    }
+
     /// Returns a reference to the `a` of this [`Lwe<T>`].
     #[inline]
     pub fn a(&self) -> &[T] {
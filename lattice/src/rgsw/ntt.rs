@@ -4,7 +4,7 @@ use algebra::{
 };
 use rand::{CryptoRng, Rng};
 
-use crate::NttGadgetRlwe;
+use crate::{NttGadgetRlwe, NttSecretKey};
 
 use super::Rgsw;
 
@@ -151,14 +151,15 @@ impl<F: NttField> NttRgsw<F> {
     }
 
     /// Generate a [`NttRgsw<F>`] sample which encrypts `0`.
-    pub fn generate_random_zero_sample<R>(
-        secret_key: &FieldNttPolynomial<F>,
+    pub fn generate_random_zero_sample<S, R>(
+        secret_key: &S,
         basis: &NonPowOf2ApproxSignedBasis<<F as Field>::ValueT>,
         gaussian: DiscreteGaussian<<F as Field>::ValueT>,
         ntt_table: &<F as NttField>::Table,
         rng: &mut R,
     ) -> Self
     where
+        S: NttSecretKey<F> + ?Sized,
         R: Rng + CryptoRng,
     {
         Self {
@@ -172,14 +173,15 @@ impl<F: NttField> NttRgsw<F> {
     }
 
     /// Generate a [`NttRgsw<F>`] sample which encrypts `1`.
-    pub fn generate_random_one_sample<R>(
-        secret_key: &FieldNttPolynomial<F>,
+    pub fn generate_random_one_sample<S, R>(
+        secret_key: &S,
         basis: &NonPowOf2ApproxSignedBasis<<F as Field>::ValueT>,
         gaussian: DiscreteGaussian<<F as Field>::ValueT>,
         ntt_table: &<F as NttField>::Table,
         rng: &mut R,
     ) -> Self
     where
+        S: NttSecretKey<F> + ?Sized,
         R: Rng + CryptoRng,
     {
         Self {
@@ -1,7 +1,12 @@
 mod normal;
 mod ntt;
 mod num;
+mod ops;
+mod packed;
+mod secret;
 
 pub use normal::Rlwe;
 pub use ntt::NttRlwe;
 pub use num::NumRlwe;
+pub use ops::RlweOps;
+pub use secret::NttSecretKey;
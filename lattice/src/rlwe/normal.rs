@@ -1,8 +1,13 @@
+use std::ops::{Neg, Sub, SubAssign};
+
 use algebra::{
     ntt::NumberTheoryTransform,
-    polynomial::{FieldNttPolynomial, FieldPolynomial},
+    polynomial::{
+        poly_add_assign_slice, poly_sub_assign_slice, shift_add_accumulate, FieldNttPolynomial,
+        FieldPolynomial, PolyMut, PolyRef,
+    },
     random::DiscreteGaussian,
-    reduce::{ReduceAddAssign, ReduceNeg, ReduceNegAssign, ReduceSubAssign},
+    reduce::ReduceNegAssign,
     Field, NttField,
 };
 use rand::{CryptoRng, Rng};
@@ -64,6 +69,50 @@ impl<F: Field> Default for Rlwe<F> {
     }
 }
 
+impl<F: Field> Neg for Rlwe<F> {
+    type Output = Self;
+
+    /// Negates both components, which negates the encrypted message.
+    #[inline]
+    fn neg(mut self) -> Self::Output {
+        self.a.neg_assign();
+        self.b.neg_assign();
+        self
+    }
+}
+
+impl<F: Field> Sub<&Self> for Rlwe<F> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: &Self) -> Self::Output {
+        self.sub_element_wise(rhs)
+    }
+}
+
+impl<F: Field> Sub for Rlwe<F> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.sub_element_wise(&rhs)
+    }
+}
+
+impl<F: Field> SubAssign<&Self> for Rlwe<F> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: &Self) {
+        self.sub_assign_element_wise(rhs);
+    }
+}
+
+impl<F: Field> SubAssign for Rlwe<F> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        self.sub_assign_element_wise(&rhs);
+    }
+}
+
 impl<F: Field> Rlwe<F> {
     /// Creates a new [`Rlwe<F>`].
     #[inline]
@@ -82,6 +131,12 @@ impl<F: Field> Rlwe<F> {
         }
     }
 
+    /// Consumes this [`Rlwe<F>`], returns its `a` and `b`.
+    #[inline]
+    pub fn into_parts(self) -> (FieldPolynomial<F>, FieldPolynomial<F>) {
+        (self.a, self.b)
+    }
+
     /// Returns a reference to the `a` of this [`Rlwe<F>`].
     #[inline]
     pub fn a(&self) -> &FieldPolynomial<F> {
@@ -142,6 +197,24 @@ impl<F: Field> Rlwe<F> {
         (self.a.as_mut_slice(), self.b.as_mut_slice())
     }
 
+    /// Borrows `a` of this [`Rlwe<F>`] as a [`PolyRef<'_, F>`].
+    #[inline]
+    pub fn a_view(&self) -> PolyRef<'_, F> {
+        self.a.as_ref_poly()
+    }
+
+    /// Borrows `b` of this [`Rlwe<F>`] as a [`PolyRef<'_, F>`].
+    #[inline]
+    pub fn b_view(&self) -> PolyRef<'_, F> {
+        self.b.as_ref_poly()
+    }
+
+    /// Borrows `a` and `b` of this [`Rlwe<F>`] as [`PolyMut<'_, F>`]s.
+    #[inline]
+    pub fn a_b_mut_views(&mut self) -> (PolyMut<'_, F>, PolyMut<'_, F>) {
+        (self.a.as_mut_poly(), self.b.as_mut_poly())
+    }
+
     /// Gets the dimension of this [`Rlwe<F>`].
     #[inline]
     pub fn dimension(&self) -> usize {
@@ -197,32 +270,34 @@ impl<F: Field> Rlwe<F> {
     /// on the `self` [`Rlwe<F>`] with another `rhs` [`Rlwe<F>`].
     #[inline]
     pub fn add_assign_element_wise(&mut self, rhs: &Self) {
-        self.a += rhs.a();
-        self.b += rhs.b();
+        poly_add_assign_slice::<F>(self.a.as_mut_slice(), rhs.a.as_slice());
+        poly_add_assign_slice::<F>(self.b.as_mut_slice(), rhs.b.as_slice());
     }
 
     /// Performs an in-place element-wise subtraction
     /// on the `self` [`Rlwe<F>`] with another `rhs` [`Rlwe<F>`].
     #[inline]
     pub fn sub_assign_element_wise(&mut self, rhs: &Self) {
-        self.a -= rhs.a();
-        self.b -= rhs.b();
+        poly_sub_assign_slice::<F>(self.a.as_mut_slice(), rhs.a.as_slice());
+        poly_sub_assign_slice::<F>(self.b.as_mut_slice(), rhs.b.as_slice());
     }
 
     /// Performs addition operation:`self + rhs`,
     /// and puts the result to the `destination`.
     #[inline]
     pub fn add_inplace(&self, rhs: &Self, destination: &mut Self) {
-        self.a.add_inplace(rhs.a(), destination.a_mut());
-        self.b.add_inplace(rhs.b(), destination.b_mut());
+        let (a, b) = destination.a_b_mut_views();
+        self.a_view().add_inplace(rhs.a_view(), a);
+        self.b_view().add_inplace(rhs.b_view(), b);
     }
 
     /// Performs subtraction operation:`self - rhs`,
     /// and put the result to the `destination`.
     #[inline]
     pub fn sub_inplace(&self, rhs: &Self, destination: &mut Self) {
-        self.a.sub_inplace(rhs.a(), destination.a_mut());
-        self.b.sub_inplace(rhs.b(), destination.b_mut());
+        let (a, b) = destination.a_b_mut_views();
+        self.a_view().sub_inplace(rhs.a_view(), a);
+        self.b_view().sub_inplace(rhs.b_view(), b);
     }
 
     /// Extract an LWE sample from RLWE.
@@ -244,34 +319,28 @@ impl<F: Field> Rlwe<F> {
     /// Extract an LWE sample from RLWE.
     #[inline]
     pub fn extract_first_few_lwe(&self, count: usize) -> CmLwe<<F as Field>::ValueT> {
-        let mut a: Vec<_> = self.a.iter().map(|&x| F::MODULUS.reduce_neg(x)).collect();
-        a[1..].reverse();
-        F::MODULUS.reduce_neg_assign(&mut a[0]);
+        let mut a = self.a.clone();
+        a.reverse_coeffs_negacyclic();
 
-        CmLwe::new(a, self.b[..count].to_vec())
+        CmLwe::new(a.inner_data(), self.b[..count].to_vec())
     }
 
     /// Extract an LWE sample from RLWE.
     #[inline]
     pub fn extract_lwe(&self) -> Lwe<<F as Field>::ValueT> {
-        let mut a: Vec<_> = self.a.iter().map(|&x| F::MODULUS.reduce_neg(x)).collect();
-        a[1..].reverse();
-        F::MODULUS.reduce_neg_assign(&mut a[0]);
+        let mut a = self.a.clone();
+        a.reverse_coeffs_negacyclic();
 
-        Lwe::new(a, self.b[0])
+        Lwe::new(a.inner_data(), self.b[0])
     }
 
     /// Extract an LWE sample from RLWE.
     #[inline]
     pub fn extract_lwe_locally(self) -> Lwe<<F as Field>::ValueT> {
-        let Self { a, b } = self;
-        let mut a = a.inner_data();
-        a[1..].reverse();
-        a[1..]
-            .iter_mut()
-            .for_each(|v| F::MODULUS.reduce_neg_assign(v));
+        let Self { mut a, b } = self;
+        a.reverse_coeffs_negacyclic();
 
-        Lwe::new(a, b[0])
+        Lwe::new(a.inner_data(), b[0])
     }
 
     /// Extract an LWE sample from RLWE reverselly.
@@ -296,14 +365,10 @@ impl<F: Field> Rlwe<F> {
     /// Extract an LWE sample from RLWE.
     #[inline]
     pub fn extract_partial_lwe_locally(self, dimension: usize) -> Lwe<<F as Field>::ValueT> {
-        let Self { a, b } = self;
+        let Self { mut a, b } = self;
+        a.reverse_coeffs_negacyclic();
 
         let mut a = a.inner_data();
-        a[1..].reverse();
-        a[1..]
-            .iter_mut()
-            .for_each(|v| F::MODULUS.reduce_neg_assign(v));
-
         a.truncate(dimension);
         Lwe::new(a, b[0])
     }
@@ -365,50 +430,16 @@ impl<F: NttField> Rlwe<F> {
         r: usize,
         destination: &mut Rlwe<F>,
     ) {
-        if r <= dimension {
-            #[inline]
-            fn rotate_sub<F: NttField>(
-                x: &mut FieldPolynomial<F>,
-                y: &FieldPolynomial<F>,
-                r: usize,
-                n_sub_r: usize,
-            ) {
-                x[0..r]
-                    .iter_mut()
-                    .zip(y[n_sub_r..].iter())
-                    .for_each(|(u, &v)| *u = <F as Field>::MODULUS.reduce_neg(v));
-                x[r..]
-                    .iter_mut()
-                    .zip(y[0..n_sub_r].iter())
-                    .for_each(|(u, &v)| *u = v);
-                *x -= y;
-            }
-            let n_sub_r = dimension - r;
-            rotate_sub(destination.a_mut(), self.a(), r, n_sub_r);
-            rotate_sub(destination.b_mut(), self.b(), r, n_sub_r);
-        } else {
-            #[inline]
-            fn rotate_sub<F: NttField>(
-                x: &mut FieldPolynomial<F>,
-                y: &FieldPolynomial<F>,
-                r: usize,
-                n_sub_r: usize,
-            ) {
-                x[0..r]
-                    .iter_mut()
-                    .zip(y[n_sub_r..].iter())
-                    .for_each(|(u, &v)| *u = v);
-                x[r..]
-                    .iter_mut()
-                    .zip(y[0..n_sub_r].iter())
-                    .for_each(|(u, &v)| *u = <F as Field>::MODULUS.reduce_neg(v));
-                *x -= y;
-            }
-            let r = r - dimension;
-            let n_sub_r = dimension.checked_sub(r).expect("r > 2N !");
-            rotate_sub(destination.a_mut(), self.a(), r, n_sub_r);
-            rotate_sub(destination.b_mut(), self.b(), r, n_sub_r);
-        }
+        assert!(r <= dimension << 1, "r > 2N !");
+        let (a, b) = destination.a_b_mut();
+
+        a.copy_from(self.a());
+        a.neg_assign();
+        shift_add_accumulate(a, self.a(), r, false);
+
+        b.copy_from(self.b());
+        b.neg_assign();
+        shift_add_accumulate(b, self.b(), r, false);
     }
 
     /// Perform `self = self + rhs * X^r`.
@@ -418,48 +449,27 @@ impl<F: NttField> Rlwe<F> {
         dimension: usize, // N
         r: usize,
     ) {
-        if r <= dimension {
-            #[inline]
-            fn rotate_add<F: NttField>(
-                x: &mut FieldPolynomial<F>,
-                y: &FieldPolynomial<F>,
-                r: usize,
-                n_sub_r: usize,
-            ) {
-                x[0..r]
-                    .iter_mut()
-                    .zip(y[n_sub_r..].iter())
-                    .for_each(|(u, &v)| <F as Field>::MODULUS.reduce_sub_assign(u, v));
-                x[r..]
-                    .iter_mut()
-                    .zip(y[0..n_sub_r].iter())
-                    .for_each(|(u, &v)| <F as Field>::MODULUS.reduce_add_assign(u, v));
-            }
-            let n_sub_r = dimension - r;
-            rotate_add(self.a_mut(), rhs.a(), r, n_sub_r);
-            rotate_add(self.b_mut(), rhs.b(), r, n_sub_r);
-        } else {
-            #[inline]
-            fn rotate_add<F: NttField>(
-                x: &mut FieldPolynomial<F>,
-                y: &FieldPolynomial<F>,
-                r: usize,
-                n_sub_r: usize,
-            ) {
-                x[0..r]
-                    .iter_mut()
-                    .zip(y[n_sub_r..].iter())
-                    .for_each(|(u, &v)| <F as Field>::MODULUS.reduce_add_assign(u, v));
-                x[r..]
-                    .iter_mut()
-                    .zip(y[0..n_sub_r].iter())
-                    .for_each(|(u, &v)| <F as Field>::MODULUS.reduce_sub_assign(u, v));
-            }
-            let r = r - dimension;
-            let n_sub_r = dimension.checked_sub(r).unwrap();
-            rotate_add(self.a_mut(), rhs.a(), r, n_sub_r);
-            rotate_add(self.b_mut(), rhs.b(), r, n_sub_r);
-        }
+        assert!(r <= dimension << 1, "r > 2N !");
+        shift_add_accumulate(self.a_mut(), rhs.a(), r, false);
+        shift_add_accumulate(self.b_mut(), rhs.b(), r, false);
+    }
+
+    /// Multiplies both `a` and `b` of `self` with `X^k` in `Z_q[X]/(X^n + 1)`,
+    /// which rotates the coefficients of the encrypted polynomial `k` places to the right.
+    ///
+    /// Coefficients wrapping around the end have their signs flipped, `k` is reduced modulo `2n`.
+    #[inline]
+    pub fn rotate(&self, k: usize) -> Self {
+        let mut result = self.clone();
+        result.rotate_assign(k);
+        result
+    }
+
+    /// Multiplies both `a` and `b` of `self` with `X^k` in place, see [`Rlwe::rotate`].
+    #[inline]
+    pub fn rotate_assign(&mut self, k: usize) {
+        self.a.rotate_right(k);
+        self.b.rotate_right(k);
     }
 
     /// Performs a multiplication on the `self` [`Rlwe<F>`] with another `ntt_rgsw` [`NttRgsw<F>`],
@@ -486,7 +496,11 @@ impl<F: NttField> Rlwe<F> {
             decompose_space,
         );
 
-        median.inverse_transform_inplace(ntt_table, destination)
+        median.inverse_transform_inplace_with(
+            ntt_table,
+            &mut decompose_space.ntt_workspace,
+            destination,
+        )
     }
 
     /// Performs a multiplication on the `self` [`Rlwe<F>`] with another `ntt_rgsw` [`NttRgsw<F>`],
@@ -512,7 +526,7 @@ impl<F: NttField> Rlwe<F> {
             decompose_space,
         );
 
-        median.inverse_transform_inplace(ntt_table, self)
+        median.inverse_transform_inplace_with(ntt_table, &mut decompose_space.ntt_workspace, self)
     }
 
     /// Generate a `Rlwe<F>` sample which encrypts `0`.
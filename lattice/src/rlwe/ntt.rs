@@ -1,8 +1,8 @@
 use std::ops::MulAssign;
 
 use algebra::{
-    ntt::NumberTheoryTransform,
-    polynomial::{FieldNttPolynomial, FieldPolynomial},
+    ntt::{NttBackend, NttWorkspace, NumberTheoryTransform},
+    polynomial::{FieldNttPolynomial, FieldPolynomial, SparseTernaryPoly},
     random::DiscreteGaussian,
     reduce::ReduceAddAssign,
     Field, NttField,
@@ -11,7 +11,7 @@ use rand::{CryptoRng, Rng};
 
 use crate::{utils::PolyDecomposeSpace, NttGadgetRlwe};
 
-use super::Rlwe;
+use super::{Rlwe, RlweOps};
 
 /// A cryptographic structure for Ring Learning with Errors (RLWE).
 /// This structure is used in advanced cryptographic systems and protocols, particularly
@@ -51,6 +51,50 @@ impl<F: NttField> Clone for NttRlwe<F> {
     }
 }
 
+impl<F> From<&Rlwe<F>> for NttRlwe<F>
+where
+    F: NttField + 'static,
+    <F as NttField>::Table: 'static,
+{
+    /// Converts [`Rlwe<F>`] into [`NttRlwe<F>`].
+    ///
+    /// The ntt table is taken from the cache of [`NttField::get_ntt_table`]
+    /// according to the dimension of `rlwe`.
+    #[inline]
+    fn from(rlwe: &Rlwe<F>) -> Self {
+        let ntt_table = F::get_ntt_table(rlwe.dimension().trailing_zeros()).unwrap();
+        let mut ntt_rlwe = Self::zero(rlwe.dimension());
+        let (a, b) = ntt_rlwe.a_b_mut_slices();
+        a.copy_from_slice(rlwe.a_slice());
+        b.copy_from_slice(rlwe.b_slice());
+        NttBackend::forward(&*ntt_table, a);
+        NttBackend::forward(&*ntt_table, b);
+        ntt_rlwe
+    }
+}
+
+impl<F> From<&NttRlwe<F>> for Rlwe<F>
+where
+    F: NttField + 'static,
+    <F as NttField>::Table: 'static,
+{
+    /// Converts [`NttRlwe<F>`] into [`Rlwe<F>`].
+    ///
+    /// The ntt table is taken from the cache of [`NttField::get_ntt_table`]
+    /// according to the dimension of `ntt_rlwe`.
+    #[inline]
+    fn from(ntt_rlwe: &NttRlwe<F>) -> Self {
+        let ntt_table = F::get_ntt_table(ntt_rlwe.dimension().trailing_zeros()).unwrap();
+        let mut rlwe = Self::zero(ntt_rlwe.dimension());
+        let (a, b) = rlwe.a_b_mut_slices();
+        a.copy_from_slice(ntt_rlwe.a_slice());
+        b.copy_from_slice(ntt_rlwe.b_slice());
+        NttBackend::inverse(&*ntt_table, a);
+        NttBackend::inverse(&*ntt_table, b);
+        rlwe
+    }
+}
+
 impl<F: NttField> NttRlwe<F> {
     /// Creates a new [`NttRlwe<F>`].
     #[inline]
@@ -96,20 +140,71 @@ impl<F: NttField> NttRlwe<F> {
         Rlwe::new(a, b)
     }
 
+    /// ntt inverse transform, taking the temporaries of the transforms from `workspace`.
+    #[inline]
+    pub fn to_rlwe_with(
+        self,
+        ntt_table: &<F as NttField>::Table,
+        workspace: &mut NttWorkspace<<F as Field>::ValueT>,
+    ) -> Rlwe<F> {
+        let Self { mut a, mut b } = self;
+
+        ntt_table.inverse_transform_slice_with(a.as_mut_slice(), workspace);
+        ntt_table.inverse_transform_slice_with(b.as_mut_slice(), workspace);
+
+        Rlwe::new(
+            FieldPolynomial::new(a.inner_data()),
+            FieldPolynomial::new(b.inner_data()),
+        )
+    }
+
+    /// ntt inverse transform, multiplying the result by `extra`
+    /// and taking the temporaries of the transforms from `workspace`.
+    ///
+    /// `extra` is folded into the scaling by `n^(-1)` of the transforms.
+    #[inline]
+    pub fn to_rlwe_scaled_with(
+        self,
+        ntt_table: &<F as NttField>::Table,
+        extra: <F as Field>::ValueT,
+        workspace: &mut NttWorkspace<<F as Field>::ValueT>,
+    ) -> Rlwe<F> {
+        let Self { mut a, mut b } = self;
+
+        ntt_table.inverse_transform_scaled_slice_with(a.as_mut_slice(), extra, workspace);
+        ntt_table.inverse_transform_scaled_slice_with(b.as_mut_slice(), extra, workspace);
+
+        Rlwe::new(
+            FieldPolynomial::new(a.inner_data()),
+            FieldPolynomial::new(b.inner_data()),
+        )
+    }
+
     /// ntt inverse transform
     #[inline]
     pub fn inverse_transform_inplace(
         &self,
         ntt_table: &<F as NttField>::Table,
         destination: &mut Rlwe<F>,
+    ) {
+        self.inverse_transform_inplace_with(ntt_table, &mut NttWorkspace::new(), destination);
+    }
+
+    /// ntt inverse transform, taking the temporaries of the transforms from `workspace`.
+    #[inline]
+    pub fn inverse_transform_inplace_with(
+        &self,
+        ntt_table: &<F as NttField>::Table,
+        workspace: &mut NttWorkspace<<F as Field>::ValueT>,
+        destination: &mut Rlwe<F>,
     ) {
         let (a, b) = destination.a_b_mut_slices();
 
         a.copy_from_slice(self.a_slice());
         b.copy_from_slice(self.b_slice());
 
-        ntt_table.inverse_transform_slice(a);
-        ntt_table.inverse_transform_slice(b);
+        ntt_table.inverse_transform_slice_with(a, workspace);
+        ntt_table.inverse_transform_slice_with(b, workspace);
     }
 
     /// Returns a reference to the a of this [`NttRlwe<F>`].
@@ -180,36 +275,30 @@ impl<F: NttField> NttRlwe<F> {
 
     /// Perform element-wise addition of two [`NttRlwe<F>`].
     #[inline]
-    pub fn add_element_wise(self, rhs: &Self) -> Self {
-        Self {
-            a: self.a + rhs.a(),
-            b: self.b + rhs.b(),
-        }
+    pub fn add_element_wise(mut self, rhs: &Self) -> Self {
+        self.add_assign_element_wise(rhs);
+        self
     }
 
     /// Perform element-wise subtraction of two [`NttRlwe<F>`].
     #[inline]
-    pub fn sub_element_wise(self, rhs: &Self) -> Self {
-        Self {
-            a: self.a - rhs.a(),
-            b: self.b - rhs.b(),
-        }
+    pub fn sub_element_wise(mut self, rhs: &Self) -> Self {
+        self.sub_assign_element_wise(rhs);
+        self
     }
 
     /// Performs an in-place element-wise addition
     /// on the `self` [`NttRlwe<F>`] with another `rhs` [`NttRlwe<F>`].
     #[inline]
     pub fn add_assign_element_wise(&mut self, rhs: &Self) {
-        self.a += rhs.a();
-        self.b += rhs.b();
+        RlweOps::add_assign_element_wise(self, rhs);
     }
 
     /// Performs an in-place element-wise subtraction
     /// on the `self` [`NttRlwe<F>`] with another `rhs` [`NttRlwe<F>`].
     #[inline]
     pub fn sub_assign_element_wise(&mut self, rhs: &Self) {
-        self.a -= rhs.a();
-        self.b -= rhs.b();
+        RlweOps::sub_assign_element_wise(self, rhs);
     }
 
     /// Performs addition operation:`self + rhs`,
@@ -274,6 +363,33 @@ impl<F: NttField> NttRlwe<F> {
             .add_mul_assign_fast(ntt_rlwe.b(), ntt_polynomial);
     }
 
+    /// Performs `self = self - ntt_rlwe * ntt_polynomial`.
+    #[inline]
+    pub fn sub_ntt_rlwe_mul_ntt_polynomial_assign(
+        &mut self,
+        ntt_rlwe: &Self,
+        ntt_polynomial: &FieldNttPolynomial<F>,
+    ) {
+        self.a_mut().sub_mul_assign(ntt_rlwe.a(), ntt_polynomial);
+        self.b_mut().sub_mul_assign(ntt_rlwe.b(), ntt_polynomial);
+    }
+
+    /// Performs `self = self - ntt_rlwe * ntt_polynomial`.
+    ///
+    /// The result coefficients may be in [0, 2*modulus) for some case,
+    /// and fall back to [0, modulus) for normal case.
+    #[inline]
+    pub fn sub_ntt_rlwe_mul_ntt_polynomial_assign_fast(
+        &mut self,
+        ntt_rlwe: &Self,
+        ntt_polynomial: &FieldNttPolynomial<F>,
+    ) {
+        self.a_mut()
+            .sub_mul_assign_fast(ntt_rlwe.a(), ntt_polynomial);
+        self.b_mut()
+            .sub_mul_assign_fast(ntt_rlwe.b(), ntt_polynomial);
+    }
+
     /// Performs `destination = self + ntt_rlwe * ntt_polynomial`.
     #[inline]
     pub fn add_ntt_rlwe_mul_ntt_polynomial_inplace(
@@ -299,7 +415,8 @@ impl<F: NttField> NttRlwe<F> {
         ntt_table: &<F as NttField>::Table,
         decompose_space: &mut PolyDecomposeSpace<F>,
     ) {
-        let (adjust_poly, carries, decompose_poly) = decompose_space.get_mut();
+        let (adjust_poly, carries, decompose_poly, ntt_workspace) =
+            decompose_space.get_mut_with_workspace();
 
         polynomial.init_adjust_poly_carries(gadget_rlwe.basis(), carries, adjust_poly);
 
@@ -312,7 +429,7 @@ impl<F: NttField> NttRlwe<F> {
                     carries,
                     decompose_poly.as_mut(),
                 );
-                ntt_table.transform_slice(decompose_poly.as_mut());
+                ntt_table.transform_slice_with(decompose_poly.as_mut(), ntt_workspace);
                 self.add_ntt_rlwe_mul_ntt_polynomial_assign(g_rlwe, decompose_poly);
             });
     }
@@ -329,7 +446,8 @@ impl<F: NttField> NttRlwe<F> {
         ntt_table: &<F as NttField>::Table,
         decompose_space: &mut PolyDecomposeSpace<F>,
     ) {
-        let (adjust_poly, carries, decompose_poly) = decompose_space.get_mut();
+        let (adjust_poly, carries, decompose_poly, ntt_workspace) =
+            decompose_space.get_mut_with_workspace();
 
         polynomial.init_adjust_poly_carries(gadget_rlwe.basis(), carries, adjust_poly);
 
@@ -342,7 +460,7 @@ impl<F: NttField> NttRlwe<F> {
                     carries,
                     decompose_poly.as_mut(),
                 );
-                ntt_table.transform_slice(decompose_poly.as_mut());
+                ntt_table.transform_slice_with(decompose_poly.as_mut(), ntt_workspace);
                 self.add_ntt_rlwe_mul_ntt_polynomial_assign_fast(g_rlwe, decompose_poly);
             });
     }
@@ -356,9 +474,10 @@ impl<F: NttField> NttRlwe<F> {
         ntt_table: &<F as NttField>::Table,
         decompose_space: &mut PolyDecomposeSpace<F>,
     ) {
-        let (adjust_poly, carries, decompose_poly) = decompose_space.get_mut();
-        polynomial.neg_inplace(adjust_poly);
-        adjust_poly.init_adjust_poly_carries_assign(gadget_rlwe.basis(), carries);
+        let (adjust_poly, carries, decompose_poly, ntt_workspace) =
+            decompose_space.get_mut_with_workspace();
+
+        polynomial.init_adjust_poly_carries(gadget_rlwe.basis(), carries, adjust_poly);
 
         gadget_rlwe
             .iter()
@@ -369,8 +488,8 @@ impl<F: NttField> NttRlwe<F> {
                     carries,
                     decompose_poly.as_mut(),
                 );
-                ntt_table.transform_slice(decompose_poly.as_mut());
-                self.add_ntt_rlwe_mul_ntt_polynomial_assign(g_rlwe, decompose_poly);
+                ntt_table.transform_slice_with(decompose_poly.as_mut(), ntt_workspace);
+                self.sub_ntt_rlwe_mul_ntt_polynomial_assign(g_rlwe, decompose_poly);
             });
     }
 
@@ -383,9 +502,10 @@ impl<F: NttField> NttRlwe<F> {
         ntt_table: &<F as NttField>::Table,
         decompose_space: &mut PolyDecomposeSpace<F>,
     ) {
-        let (adjust_poly, carries, decompose_poly) = decompose_space.get_mut();
-        polynomial.neg_inplace(adjust_poly);
-        adjust_poly.init_adjust_poly_carries_assign(gadget_rlwe.basis(), carries);
+        let (adjust_poly, carries, decompose_poly, ntt_workspace) =
+            decompose_space.get_mut_with_workspace();
+
+        polynomial.init_adjust_poly_carries(gadget_rlwe.basis(), carries, adjust_poly);
 
         gadget_rlwe
             .iter()
@@ -396,8 +516,8 @@ impl<F: NttField> NttRlwe<F> {
                     carries,
                     decompose_poly.as_mut_slice(),
                 );
-                ntt_table.transform_slice(decompose_poly.as_mut_slice());
-                self.add_ntt_rlwe_mul_ntt_polynomial_assign_fast(g_rlwe, decompose_poly);
+                ntt_table.transform_slice_with(decompose_poly.as_mut_slice(), ntt_workspace);
+                self.sub_ntt_rlwe_mul_ntt_polynomial_assign_fast(g_rlwe, decompose_poly);
             });
     }
 
@@ -421,6 +541,77 @@ impl<F: NttField> NttRlwe<F> {
         Self { a, b: e }
     }
 
+    /// Generate `count` [`NttRlwe<F>`] samples which encrypt `0`.
+    ///
+    /// The randomness is drawn in the same order as `count` calls of
+    /// [`Self::generate_random_zero_sample`], but the errors are transformed
+    /// in one batch with [`NumberTheoryTransform::transform_batch`].
+    pub fn generate_random_zero_samples<R>(
+        count: usize,
+        secret_key: &FieldNttPolynomial<F>,
+        gaussian: DiscreteGaussian<<F as Field>::ValueT>,
+        ntt_table: &<F as NttField>::Table,
+        rng: &mut R,
+    ) -> Vec<Self>
+    where
+        R: Rng + CryptoRng,
+    {
+        let rlwe_dimension = secret_key.coeff_count();
+        let (a, e): (Vec<_>, Vec<_>) = (0..count)
+            .map(|_| {
+                let a = <FieldNttPolynomial<F>>::random(rlwe_dimension, rng);
+                let e = <FieldPolynomial<F>>::random_gaussian(rlwe_dimension, gaussian, rng);
+                (a, e)
+            })
+            .unzip();
+
+        ntt_table
+            .transform_batch(e)
+            .into_iter()
+            .zip(a)
+            .map(|(mut e, a)| {
+                e.add_mul_assign(&a, secret_key);
+                Self { a, b: e }
+            })
+            .collect()
+    }
+
+    /// Generate `count` [`NttRlwe<F>`] samples which encrypt `0`,
+    /// calculating the products `a * s` with the sparse form of the secret key.
+    ///
+    /// The `a` of the samples are drawn in the coefficient domain,
+    /// so `a * s` only costs [`SparseTernaryPoly::mul_into`],
+    /// and the `a` and `b` of all the samples are transformed in one batch.
+    pub fn generate_random_zero_samples_sparse<R>(
+        count: usize,
+        secret_key: &SparseTernaryPoly,
+        gaussian: DiscreteGaussian<<F as Field>::ValueT>,
+        ntt_table: &<F as NttField>::Table,
+        rng: &mut R,
+    ) -> Vec<Self>
+    where
+        R: Rng + CryptoRng,
+    {
+        let rlwe_dimension = secret_key.coeff_count();
+        let mut a_mul_s = <FieldPolynomial<F>>::zero(rlwe_dimension);
+        let (a, b): (Vec<_>, Vec<_>) = (0..count)
+            .map(|_| {
+                let a = <FieldPolynomial<F>>::random(rlwe_dimension, rng);
+                let mut b = <FieldPolynomial<F>>::random_gaussian(rlwe_dimension, gaussian, rng);
+                secret_key.mul_into(&a, &mut a_mul_s);
+                b += &a_mul_s;
+                (a, b)
+            })
+            .unzip();
+
+        ntt_table
+            .transform_batch(a)
+            .into_iter()
+            .zip(ntt_table.transform_batch(b))
+            .map(|(a, b)| Self { a, b })
+            .collect()
+    }
+
     /// Generate a [`NttRlwe<F>`] sample which encrypts `value`.
     pub fn generate_random_value_sample<R>(
         secret_key: &FieldNttPolynomial<F>,
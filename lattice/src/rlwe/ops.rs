@@ -0,0 +1,135 @@
+use algebra::{
+    polynomial::{FieldNttPolynomial, FieldPolynomial, PolyOps},
+    Field, NttField,
+};
+
+use super::{NttRlwe, Rlwe};
+
+/// RLWE arithmetic shared by [`Rlwe<F>`] and [`NttRlwe<F>`],
+/// written once over the [`PolyOps<F>`] of their components.
+pub trait RlweOps<F: NttField>: Sized {
+    /// The polynomial type of the `a` and `b` components.
+    type Poly: PolyOps<F>;
+
+    /// The same ciphertext with components in the other domain.
+    type Dual: RlweOps<F, Poly = <Self::Poly as PolyOps<F>>::Dual, Dual = Self>;
+
+    /// Creates a ciphertext from its `a` and `b` components.
+    fn from_parts(a: Self::Poly, b: Self::Poly) -> Self;
+
+    /// Splits `self` into its `a` and `b` components.
+    fn into_parts(self) -> (Self::Poly, Self::Poly);
+
+    /// Returns references to the `a` and `b` components.
+    fn parts(&self) -> (&Self::Poly, &Self::Poly);
+
+    /// Returns mutable references to the `a` and `b` components.
+    fn parts_mut(&mut self) -> (&mut Self::Poly, &mut Self::Poly);
+
+    /// Performs `self = self + rhs` component-wise.
+    #[inline]
+    fn add_assign_element_wise(&mut self, rhs: &Self) {
+        let (a, b) = self.parts_mut();
+        let (rhs_a, rhs_b) = rhs.parts();
+        *a += rhs_a;
+        *b += rhs_b;
+    }
+
+    /// Performs `self = self - rhs` component-wise.
+    #[inline]
+    fn sub_assign_element_wise(&mut self, rhs: &Self) {
+        let (a, b) = self.parts_mut();
+        let (rhs_a, rhs_b) = rhs.parts();
+        *a -= rhs_a;
+        *b -= rhs_b;
+    }
+
+    /// Performs `self = -self` component-wise.
+    #[inline]
+    fn neg_assign(&mut self) {
+        let (a, b) = self.parts_mut();
+        a.neg_assign();
+        b.neg_assign();
+    }
+
+    /// Performs `self = self * scalar` component-wise.
+    #[inline]
+    fn mul_scalar_assign(&mut self, scalar: <F as Field>::ValueT) {
+        let (a, b) = self.parts_mut();
+        a.mul_scalar_assign(scalar);
+        b.mul_scalar_assign(scalar);
+    }
+
+    /// Performs `self = self + rhs * poly` component-wise.
+    #[inline]
+    fn add_rlwe_mul_poly_assign(
+        &mut self,
+        rhs: &Self,
+        poly: &Self::Poly,
+        ntt_table: &<F as NttField>::Table,
+    ) {
+        let (a, b) = self.parts_mut();
+        let (rhs_a, rhs_b) = rhs.parts();
+        a.add_mul_assign(rhs_a, poly, ntt_table);
+        b.add_mul_assign(rhs_b, poly, ntt_table);
+    }
+
+    /// Converts `self` into the other domain.
+    #[inline]
+    fn into_dual(self, ntt_table: &<F as NttField>::Table) -> Self::Dual {
+        let (a, b) = self.into_parts();
+        Self::Dual::from_parts(a.into_dual(ntt_table), b.into_dual(ntt_table))
+    }
+}
+
+impl<F: NttField> RlweOps<F> for Rlwe<F> {
+    type Poly = FieldPolynomial<F>;
+
+    type Dual = NttRlwe<F>;
+
+    #[inline]
+    fn from_parts(a: Self::Poly, b: Self::Poly) -> Self {
+        Rlwe::new(a, b)
+    }
+
+    #[inline]
+    fn into_parts(self) -> (Self::Poly, Self::Poly) {
+        (self.a, self.b)
+    }
+
+    #[inline]
+    fn parts(&self) -> (&Self::Poly, &Self::Poly) {
+        (&self.a, &self.b)
+    }
+
+    #[inline]
+    fn parts_mut(&mut self) -> (&mut Self::Poly, &mut Self::Poly) {
+        (&mut self.a, &mut self.b)
+    }
+}
+
+impl<F: NttField> RlweOps<F> for NttRlwe<F> {
+    type Poly = FieldNttPolynomial<F>;
+
+    type Dual = Rlwe<F>;
+
+    #[inline]
+    fn from_parts(a: Self::Poly, b: Self::Poly) -> Self {
+        NttRlwe::new(a, b)
+    }
+
+    #[inline]
+    fn into_parts(self) -> (Self::Poly, Self::Poly) {
+        (self.a, self.b)
+    }
+
+    #[inline]
+    fn parts(&self) -> (&Self::Poly, &Self::Poly) {
+        (&self.a, &self.b)
+    }
+
+    #[inline]
+    fn parts_mut(&mut self) -> (&mut Self::Poly, &mut Self::Poly) {
+        (&mut self.a, &mut self.b)
+    }
+}
@@ -0,0 +1,66 @@
+use std::io::{self, Read, Write};
+
+use algebra::{
+    polynomial::{FieldNttPolynomial, FieldPolynomial},
+    Field, NttField,
+};
+
+use super::{NttRlwe, Rlwe};
+
+#[inline]
+fn dimension_mismatch(a: usize, b: usize) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!("rlwe dimension mismatch: a has {a} coefficients, b has {b} coefficients"),
+    )
+}
+
+impl<F: Field> Rlwe<F> {
+    /// Writes `self` to `writer` as `a` followed by `b`,
+    /// both in the format of [`FieldPolynomial::write_packed`].
+    #[inline]
+    pub fn write_packed<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        self.a().write_packed(&mut writer)?;
+        self.b().write_packed(&mut writer)
+    }
+
+    /// Reads a [`Rlwe<F>`] written by [`Rlwe::write_packed`] from `reader`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if either polynomial can't be read,
+    /// or if `a` and `b` have different dimensions.
+    pub fn read_packed<R: Read>(mut reader: R) -> io::Result<Self> {
+        let a = FieldPolynomial::read_packed(&mut reader)?;
+        let b = FieldPolynomial::read_packed(&mut reader)?;
+        if a.coeff_count() != b.coeff_count() {
+            return Err(dimension_mismatch(a.coeff_count(), b.coeff_count()));
+        }
+        Ok(Self::new(a, b))
+    }
+}
+
+impl<F: NttField> NttRlwe<F> {
+    /// Writes `self` to `writer` as `a` followed by `b`,
+    /// both in the format of [`FieldNttPolynomial::write_packed`].
+    #[inline]
+    pub fn write_packed<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        self.a().write_packed(&mut writer)?;
+        self.b().write_packed(&mut writer)
+    }
+
+    /// Reads a [`NttRlwe<F>`] written by [`NttRlwe::write_packed`] from `reader`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if either polynomial can't be read,
+    /// or if `a` and `b` have different dimensions.
+    pub fn read_packed<R: Read>(mut reader: R) -> io::Result<Self> {
+        let a = FieldNttPolynomial::read_packed(&mut reader)?;
+        let b = FieldNttPolynomial::read_packed(&mut reader)?;
+        if a.coeff_count() != b.coeff_count() {
+            return Err(dimension_mismatch(a.coeff_count(), b.coeff_count()));
+        }
+        Ok(Self::new(a, b))
+    }
+}
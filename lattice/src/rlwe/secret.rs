@@ -0,0 +1,37 @@
+use algebra::{polynomial::FieldNttPolynomial, random::DiscreteGaussian, Field, NttField};
+use rand::{CryptoRng, Rng};
+
+use super::NttRlwe;
+
+/// A ring secret key which the [`NttRlwe<F>`] based samples are generated with.
+///
+/// The ntt form of the key is enough to compute the products `a * s` of the samples.
+/// Keys which also hold a sparse form can override
+/// [`NttSecretKey::generate_random_zero_samples`] to compute them with it,
+/// e.g. by [`NttRlwe::generate_random_zero_samples_sparse`].
+pub trait NttSecretKey<F: NttField> {
+    /// Returns the ntt form of the secret key.
+    fn ntt_form(&self) -> &FieldNttPolynomial<F>;
+
+    /// Generates `count` [`NttRlwe<F>`] samples which encrypt `0`.
+    #[inline]
+    fn generate_random_zero_samples<R>(
+        &self,
+        count: usize,
+        gaussian: DiscreteGaussian<<F as Field>::ValueT>,
+        ntt_table: &<F as NttField>::Table,
+        rng: &mut R,
+    ) -> Vec<NttRlwe<F>>
+    where
+        R: Rng + CryptoRng,
+    {
+        <NttRlwe<F>>::generate_random_zero_samples(count, self.ntt_form(), gaussian, ntt_table, rng)
+    }
+}
+
+impl<F: NttField> NttSecretKey<F> for FieldNttPolynomial<F> {
+    #[inline]
+    fn ntt_form(&self) -> &FieldNttPolynomial<F> {
+        self
+    }
+}
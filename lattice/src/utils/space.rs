@@ -2,6 +2,7 @@ use std::ops::{Deref, DerefMut};
 
 use algebra::{
     decompose::NonPowOf2ApproxSignedBasis,
+    ntt::NttWorkspace,
     polynomial::{FieldNttPolynomial, FieldPolynomial},
     Field, NttField,
 };
@@ -16,6 +17,10 @@ pub struct PolyDecomposeSpace<F: NttField> {
     pub decomposed_poly: FieldNttPolynomial<F>,
     /// store carries
     pub carries: Vec<bool>,
+    /// store the temporaries of the transforms of the decomposed polynomials
+    pub ntt_workspace: NttWorkspace<<F as Field>::ValueT>,
+    /// store all the decomposed polynomials, allocated on first use
+    pub decomposed_polys: Vec<FieldNttPolynomial<F>>,
 }
 
 impl<F: NttField> PolyDecomposeSpace<F> {
@@ -26,6 +31,8 @@ impl<F: NttField> PolyDecomposeSpace<F> {
             adjust_poly: FieldPolynomial::zero(coeff_count),
             decomposed_poly: FieldNttPolynomial::zero(coeff_count),
             carries: vec![false; coeff_count],
+            ntt_workspace: NttWorkspace::new(),
+            decomposed_polys: Vec::new(),
         }
     }
 
@@ -44,6 +51,56 @@ impl<F: NttField> PolyDecomposeSpace<F> {
             &mut self.decomposed_poly,
         )
     }
+
+    /// Gets the mutable pre allocated space for decomposition,
+    /// and the workspace for transforming the decomposed polynomial.
+    #[inline]
+    #[allow(clippy::type_complexity)]
+    pub fn get_mut_with_workspace(
+        &mut self,
+    ) -> (
+        &mut FieldPolynomial<F>,
+        &mut [bool],
+        &mut FieldNttPolynomial<F>,
+        &mut NttWorkspace<<F as Field>::ValueT>,
+    ) {
+        (
+            &mut self.adjust_poly,
+            self.carries.as_mut_slice(),
+            &mut self.decomposed_poly,
+            &mut self.ntt_workspace,
+        )
+    }
+
+    /// Gets the mutable pre allocated space for decomposition,
+    /// with a polynomial for each of the `count` decomposed polynomials
+    /// and the workspace for transforming them.
+    ///
+    /// The decomposed polynomials are allocated by the first call asking
+    /// for them, later calls reuse them.
+    #[inline]
+    #[allow(clippy::type_complexity)]
+    pub fn get_mut_with_decomposed_polys(
+        &mut self,
+        count: usize,
+    ) -> (
+        &mut FieldPolynomial<F>,
+        &mut [bool],
+        &mut [FieldNttPolynomial<F>],
+        &mut NttWorkspace<<F as Field>::ValueT>,
+    ) {
+        if self.decomposed_polys.len() < count {
+            let coeff_count = self.adjust_poly.coeff_count();
+            self.decomposed_polys
+                .resize_with(count, || FieldNttPolynomial::zero(coeff_count));
+        }
+        (
+            &mut self.adjust_poly,
+            self.carries.as_mut_slice(),
+            &mut self.decomposed_polys[..count],
+            &mut self.ntt_workspace,
+        )
+    }
 }
 
 /// Pre allocated space.
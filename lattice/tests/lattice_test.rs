@@ -3,11 +3,13 @@ use std::sync::LazyLock;
 use algebra::decompose::NonPowOf2ApproxSignedBasis;
 use algebra::modulus::PowOf2Modulus;
 use algebra::ntt::NumberTheoryTransform;
-use algebra::polynomial::FieldPolynomial;
+use algebra::polynomial::{FieldPolynomial, SparseTernaryPoly};
 use algebra::random::DiscreteGaussian;
 use algebra::reduce::{ReduceAdd, ReduceMulAdd, ReduceSub};
+use algebra::test_utils::assert_poly_close;
 use algebra::{Field, NttField, U32FieldEval};
-use lattice::{GadgetRlwe, Lwe, NttRlwe, Rlwe};
+use lattice::utils::PolyDecomposeSpace;
+use lattice::{GadgetRlwe, Lwe, NttGadgetRlwe, NttRlwe, Rlwe, RlweOps};
 use rand::distributions::Uniform;
 use rand::prelude::Distribution;
 use rand::{thread_rng, Rng};
@@ -135,20 +137,73 @@ fn test_rlwe() {
             == rlwe2
     );
 
+    let mut sum = rlwe1.clone();
+    sum.add_assign_element_wise(&rlwe2);
+    let mut destination = Rlwe::zero(N);
+    rlwe1.add_inplace(&rlwe2, &mut destination);
+    assert!(sum == destination);
+    assert!(sum == rlwe1.clone().add_element_wise(&rlwe2));
+    sum.sub_assign_element_wise(&rlwe2);
+    assert!(sum == rlwe1);
+    sum.sub_inplace(&rlwe2, &mut destination);
+    assert!(destination == rlwe1.clone().sub_element_wise(&rlwe2));
+
     let mut d = NttRlwe::zero(N);
     rlwe1.mul_ntt_polynomial_inplace(&ntt_r, &NTT_TABLE, &mut d);
     d.inverse_transform_inplace(&NTT_TABLE, &mut rlwe2);
     assert!(rlwe2 == rlwe3);
 }
 
-#[inline]
-fn encode(m: Inner) -> Inner {
-    (m as f64 * FP as f64 / FT as f64).round() as Inner
+#[test]
+fn test_rlwe_ntt_conversion() {
+    let mut rng = thread_rng();
+
+    let rlwe1 = Rlwe::new(PolyFF::random(N, &mut rng), PolyFF::random(N, &mut rng));
+    let rlwe2 = Rlwe::new(PolyFF::random(N, &mut rng), PolyFF::random(N, &mut rng));
+
+    // round trip
+    let ntt_rlwe1 = NttRlwe::from(&rlwe1);
+    assert!(ntt_rlwe1 == rlwe1.clone().to_ntt_rlwe(&NTT_TABLE));
+    assert!(Rlwe::from(&ntt_rlwe1) == rlwe1);
+
+    // arithmetic in ntt domain matches coefficient domain
+    let ntt_rlwe2 = NttRlwe::from(&rlwe2);
+    let ntt_sum = ntt_rlwe1.add_element_wise(&ntt_rlwe2);
+    assert!(Rlwe::from(&ntt_sum) == rlwe1.clone().add_element_wise(&rlwe2));
+
+    let ntt_diff = NttRlwe::from(&rlwe1).sub_element_wise(&ntt_rlwe2);
+    assert!(Rlwe::from(&ntt_diff) == rlwe1.sub_element_wise(&rlwe2));
+}
+
+#[test]
+fn test_rlwe_packed() {
+    let mut rng = thread_rng();
+
+    let rlwe = Rlwe::new(PolyFF::random(N, &mut rng), PolyFF::random(N, &mut rng));
+    let mut bytes = Vec::new();
+    rlwe.write_packed(&mut bytes).unwrap();
+    assert!(Rlwe::<FF>::read_packed(bytes.as_slice()).unwrap() == rlwe);
+    assert!(Rlwe::<FF>::read_packed(&bytes[..bytes.len() - 1]).is_err());
+
+    let ntt_rlwe = rlwe.to_ntt_rlwe(&NTT_TABLE);
+    let mut bytes = Vec::new();
+    ntt_rlwe.write_packed(&mut bytes).unwrap();
+    assert!(NttRlwe::<FF>::read_packed(bytes.as_slice()).unwrap() == ntt_rlwe);
+
+    // `a` and `b` must have the same dimension.
+    let mut bytes = Vec::new();
+    PolyFF::random(N, &mut rng)
+        .write_packed(&mut bytes)
+        .unwrap();
+    PolyFF::random(N / 2, &mut rng)
+        .write_packed(&mut bytes)
+        .unwrap();
+    assert!(Rlwe::<FF>::read_packed(bytes.as_slice()).is_err());
 }
 
 #[inline]
-fn decode(c: Inner) -> Inner {
-    (c as f64 * FT as f64 / FP as f64).round() as Inner % FT
+fn encode(m: Inner) -> Inner {
+    (m as f64 * FP as f64 / FT as f64).round() as Inner
 }
 
 #[inline]
@@ -196,12 +251,108 @@ fn test_rlwe_he() {
 
     let a_mul_s = NTT_TABLE.inverse_transform_inplace(NTT_TABLE.transform(rlwe_add.a()) * &ntt_s);
 
-    let decrypted_add = (rlwe_add.b() - a_mul_s)
-        .into_iter()
-        .map(decode)
-        .collect::<Vec<u32>>();
+    let phase_add = rlwe_add.b() - a_mul_s;
+    let encoded_add = PolyFF::new(v_add.iter().copied().map(encode).collect());
+    assert_poly_close(&phase_add, &encoded_add, (FP / FT / 2 - 2) as u64);
+}
+
+#[test]
+fn test_rlwe_neg_sub() {
+    let mut rng = rand::thread_rng();
+    let chi = DiscreteGaussian::new(0., 3.2, FF::MINUS_ONE).unwrap();
+    let dis = Uniform::new(0, FT);
+
+    let v0: Vec<Inner> = dis.sample_iter(&mut rng).take(N).collect();
+    let v1: Vec<Inner> = dis.sample_iter(&mut rng).take(N).collect();
+
+    let v_sub: Vec<Inner> = v0
+        .iter()
+        .zip(v1.iter())
+        .map(|(a, b)| (*a + FT - b) % FT)
+        .collect();
+
+    let v0 = PolyFF::new(v0.into_iter().map(encode).collect());
+    let v1 = PolyFF::new(v1.into_iter().map(encode).collect());
+
+    let s = PolyFF::random(N, &mut rng);
+    let ntt_s = NTT_TABLE.transform(&s);
+
+    let mut encrypt = |v: PolyFF| {
+        let a = PolyFF::random(N, &mut rng);
+        let e = PolyFF::random_with_distribution(N, chi, &mut rng);
+
+        let a_mul_s = NTT_TABLE.inverse_transform_inplace(NTT_TABLE.transform(&a) * &ntt_s);
+
+        let b = a_mul_s + v + e;
+
+        Rlwe::new(a, b)
+    };
+    let phase = |rlwe: &Rlwe<FF>| {
+        let a_mul_s = NTT_TABLE.inverse_transform_inplace(NTT_TABLE.transform(rlwe.a()) * &ntt_s);
+        rlwe.b() - a_mul_s
+    };
+
+    let rlwe0 = encrypt(v0);
+    let rlwe1 = encrypt(v1);
+
+    let rlwe_sub = rlwe0.clone() - &rlwe1;
+    let mut rlwe_sub_assign = rlwe0.clone();
+    rlwe_sub_assign -= &rlwe1;
+    assert!(rlwe_sub == rlwe_sub_assign);
+
+    let phase_sub = phase(&rlwe_sub);
+    let encoded_sub = PolyFF::new(v_sub.iter().copied().map(encode).collect());
+    assert_poly_close(&phase_sub, &encoded_sub, (FP / FT / 2 - 2) as u64);
+
+    let zero = (-rlwe0.clone()).add_element_wise(&rlwe0);
+    assert!(zero == Rlwe::zero(N));
+    assert!(phase(&zero).is_zero());
+}
+
+#[test]
+fn test_rlwe_rotate() {
+    let mut rng = rand::thread_rng();
+    let chi = DiscreteGaussian::new(0., 3.2, FF::MINUS_ONE).unwrap();
+    let dis = Uniform::new(0, FT);
+
+    let v: Vec<Inner> = dis.sample_iter(&mut rng).take(N).collect();
+    let v = PolyFF::new(v.into_iter().map(encode).collect());
+
+    let s = PolyFF::random(N, &mut rng);
+    let ntt_s = NTT_TABLE.transform(&s);
 
-    assert_eq!(decrypted_add, v_add);
+    let a = PolyFF::random(N, &mut rng);
+    let e = PolyFF::random_with_distribution(N, chi, &mut rng);
+    let b = NTT_TABLE.inverse_transform_inplace(NTT_TABLE.transform(&a) * &ntt_s) + &v + e;
+    let rlwe = Rlwe::new(a, b);
+
+    let phase = |rlwe: &Rlwe<FF>| {
+        let a_mul_s = NTT_TABLE.inverse_transform_inplace(NTT_TABLE.transform(rlwe.a()) * &ntt_s);
+        rlwe.b() - a_mul_s
+    };
+
+    for k in [
+        0,
+        1,
+        rng.gen_range(2..N),
+        N,
+        N + 1,
+        rng.gen_range(N + 2..2 * N),
+    ] {
+        let rotated = rlwe.rotate(k);
+
+        let mut rotated_assign = rlwe.clone();
+        rotated_assign.rotate_assign(k);
+        assert!(rotated == rotated_assign);
+
+        let mut expected_phase = phase(&rlwe);
+        expected_phase.rotate_right(k);
+        assert_eq!(phase(&rotated), expected_phase);
+
+        let mut expected = v.clone();
+        expected.rotate_right(k);
+        assert_poly_close(&phase(&rotated), &expected, (FP / FT / 2 - 2) as u64);
+    }
 }
 
 #[test]
@@ -290,7 +441,106 @@ fn test_gadget_rlwe() {
     println!("bad_diff_std_dev={}", bad_diff_std_dev);
     assert!(diff_std_dev < bad_diff_std_dev);
 
-    let decrypted: Vec<Inner> = good_mul.into_iter().map(decode).collect();
-    let decoded: Vec<Inner> = poly_mul_m.into_iter().map(decode).collect();
-    assert_eq!(decrypted, decoded);
+    assert_poly_close(&good_mul, &poly_mul_m, (FP / FT / 2 - 1) as u64);
+}
+
+#[test]
+fn test_ntt_gadget_rlwe_mul_polynomial_fast() {
+    let mut rng = rand::thread_rng();
+
+    let s = PolyFF::random(N, &mut rng);
+    let ntt_s = NTT_TABLE.transform(&s);
+    let gaussian = DiscreteGaussian::new(0., 1.0, FF::MINUS_ONE).unwrap();
+    let basis = <NonPowOf2ApproxSignedBasis<Inner>>::new(FF::MODULUS_VALUE, BASE_BITS, None);
+
+    let gadget_rlwe =
+        NttGadgetRlwe::generate_random_zero_sample(&ntt_s, &basis, gaussian, &NTT_TABLE, &mut rng);
+    let poly = PolyFF::random(N, &mut rng);
+
+    let mut decompose_space = PolyDecomposeSpace::new(N);
+    let mut fused = NttRlwe::zero(N);
+    let mut unfused = NttRlwe::zero(N);
+
+    // The fast version multiplies inside the transforms of the digits.
+    gadget_rlwe.mul_polynomial_inplace_fast(&poly, &NTT_TABLE, &mut decompose_space, &mut fused);
+    gadget_rlwe.mul_polynomial_inplace(&poly, &NTT_TABLE, &mut decompose_space, &mut unfused);
+
+    assert!(fused.to_rlwe(&NTT_TABLE) == unfused.to_rlwe(&NTT_TABLE));
+}
+
+fn generic_rlwe_ops<R: RlweOps<FF>>(c0: &R, c1: &R, c2: &R, poly: &R::Poly, scalar: Inner) -> R {
+    let mut result = R::from_parts(c0.parts().0.clone(), c0.parts().1.clone());
+    result.add_assign_element_wise(c1);
+    result.sub_assign_element_wise(c2);
+    result.mul_scalar_assign(scalar);
+    result.neg_assign();
+    result.add_rlwe_mul_poly_assign(c1, poly, &NTT_TABLE);
+    result
+}
+
+#[test]
+fn test_rlwe_ops_generic() {
+    let mut rng = thread_rng();
+
+    let random_rlwe = |rng: &mut rand::rngs::ThreadRng| {
+        Rlwe::new(PolyFF::random(N, &mut *rng), PolyFF::random(N, &mut *rng))
+    };
+
+    let c0 = random_rlwe(&mut rng);
+    let c1 = random_rlwe(&mut rng);
+    let c2 = random_rlwe(&mut rng);
+    let poly = PolyFF::random(N, &mut rng);
+    let scalar = rng.gen_range(0..FP);
+
+    let coeff_result = generic_rlwe_ops(&c0, &c1, &c2, &poly, scalar);
+
+    let ntt_result = generic_rlwe_ops(
+        &c0.into_dual(&NTT_TABLE),
+        &c1.into_dual(&NTT_TABLE),
+        &c2.into_dual(&NTT_TABLE),
+        &NTT_TABLE.transform(&poly),
+        scalar,
+    );
+
+    assert!(ntt_result.into_dual(&NTT_TABLE) == coeff_result);
+}
+
+#[test]
+fn test_ntt_rlwe_zero_samples() {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    let seed: u64 = thread_rng().gen();
+    let gaussian = DiscreteGaussian::new(0., 3.2, FF::MINUS_ONE).unwrap();
+    let secret_key = NTT_TABLE.transform_inplace(PolyFF::random_ternary(N, &mut thread_rng()));
+
+    let mut rng = StdRng::seed_from_u64(seed);
+    let batch =
+        NttRlwe::generate_random_zero_samples(4, &secret_key, gaussian, &NTT_TABLE, &mut rng);
+
+    let mut rng = StdRng::seed_from_u64(seed);
+    let one_by_one: Vec<_> = (0..4)
+        .map(|_| NttRlwe::generate_random_zero_sample(&secret_key, gaussian, &NTT_TABLE, &mut rng))
+        .collect();
+
+    assert_eq!(batch.len(), 4);
+    assert!(batch == one_by_one);
+}
+
+#[test]
+fn test_ntt_rlwe_zero_samples_sparse() {
+    let mut rng = thread_rng();
+    let gaussian = DiscreteGaussian::new(0., 3.2, FF::MINUS_ONE).unwrap();
+
+    let sparse = SparseTernaryPoly::new(N, vec![1, 7, 20], vec![3, 30]);
+    let secret_key = NTT_TABLE.transform_inplace(sparse.to_field_polynomial::<FF>());
+
+    let samples = <NttRlwe<FF>>::generate_random_zero_samples_sparse(
+        4, &sparse, gaussian, &NTT_TABLE, &mut rng,
+    );
+    assert_eq!(samples.len(), 4);
+    for sample in samples {
+        let phase = sample.b().clone() - &(sample.a().clone() * &secret_key);
+        let phase = NTT_TABLE.inverse_transform_inplace(phase);
+        assert_poly_close(&phase, &PolyFF::zero(N), gaussian.max_std_dev() as u64);
+    }
 }